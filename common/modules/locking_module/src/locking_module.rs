@@ -38,6 +38,18 @@ pub trait LockingModule {
         self.lock_common(OptionalValue::Some(to), token_id, amount)
     }
 
+    /// Locks the tokens until the given epoch, instead of the configured unlock epoch
+    #[inline]
+    fn lock_tokens_until_and_forward(
+        &self,
+        to: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        unlock_epoch: u64,
+    ) -> EgldOrEsdtTokenPayment<Self::Api> {
+        self.lock_until_common(OptionalValue::Some(to), token_id, amount, unlock_epoch)
+    }
+
     fn lock_common(
         &self,
         opt_dest: OptionalValue<ManagedAddress>,
@@ -45,6 +57,16 @@ pub trait LockingModule {
         amount: BigUint,
     ) -> EgldOrEsdtTokenPayment<Self::Api> {
         let unlock_epoch = self.unlock_epoch().get();
+        self.lock_until_common(opt_dest, token_id, amount, unlock_epoch)
+    }
+
+    fn lock_until_common(
+        &self,
+        opt_dest: OptionalValue<ManagedAddress>,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        unlock_epoch: u64,
+    ) -> EgldOrEsdtTokenPayment<Self::Api> {
         let mut proxy_instance = self.get_locking_sc_proxy_instance();
 
         proxy_instance
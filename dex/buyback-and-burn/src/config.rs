@@ -0,0 +1,103 @@
+multiversx_sc::imports!();
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+
+#[multiversx_sc::module]
+pub trait ConfigModule: permissions_module::PermissionsModule {
+    #[endpoint(setBurnPercentage)]
+    fn set_burn_percentage(&self, burn_percentage: u64) {
+        self.require_caller_has_owner_permissions();
+        self.set_burn_percentage_unchecked(burn_percentage);
+    }
+
+    /// Maximum accepted deviation of the spot price from the TWAP, where 10_000 = 100%
+    #[endpoint(setMaxPriceDeviation)]
+    fn set_max_price_deviation(&self, max_price_deviation: u64) {
+        self.require_caller_has_owner_permissions();
+        self.set_max_price_deviation_unchecked(max_price_deviation);
+    }
+
+    #[endpoint(setFeesCollectorAddress)]
+    fn set_fees_collector_address(&self, fees_collector_address: ManagedAddress) {
+        self.require_caller_has_owner_permissions();
+        self.set_fees_collector_address_unchecked(fees_collector_address);
+    }
+
+    /// The pair must swap the given token directly into MEX
+    #[endpoint(addSwapPair)]
+    fn add_swap_pair(&self, token_id: TokenIdentifier, pair_address: ManagedAddress) {
+        self.require_caller_has_owner_permissions();
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+        require!(
+            token_id != self.mex_token_id().get(),
+            "Cannot add swap pair for MEX"
+        );
+        require!(
+            self.blockchain().is_smart_contract(&pair_address),
+            "Invalid SC address"
+        );
+
+        let _ = self.swap_pairs().insert(token_id, pair_address);
+    }
+
+    #[endpoint(removeSwapPair)]
+    fn remove_swap_pair(&self, token_id: TokenIdentifier) {
+        self.require_caller_has_owner_permissions();
+        let removed = self.swap_pairs().remove(&token_id);
+        require!(removed.is_some(), "Unknown swap pair");
+    }
+
+    fn set_burn_percentage_unchecked(&self, burn_percentage: u64) {
+        require!(burn_percentage <= MAX_PERCENTAGE, "Invalid burn percentage");
+        self.burn_percentage().set(burn_percentage);
+    }
+
+    fn set_max_price_deviation_unchecked(&self, max_price_deviation: u64) {
+        require!(
+            max_price_deviation < MAX_PERCENTAGE,
+            "Invalid price deviation"
+        );
+        self.max_price_deviation().set(max_price_deviation);
+    }
+
+    fn set_fees_collector_address_unchecked(&self, fees_collector_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&fees_collector_address),
+            "Invalid fees collector address"
+        );
+        self.fees_collector_address().set(fees_collector_address);
+    }
+
+    fn is_accepted_token(&self, token_id: &TokenIdentifier) -> bool {
+        token_id == &self.mex_token_id().get() || self.swap_pairs().contains_key(token_id)
+    }
+
+    #[view(getSwapPairs)]
+    fn get_swap_pairs(&self) -> MultiValueEncoded<MultiValue2<TokenIdentifier, ManagedAddress>> {
+        let mut result = MultiValueEncoded::new();
+        for (token_id, pair_address) in self.swap_pairs().iter() {
+            result.push((token_id, pair_address).into());
+        }
+
+        result
+    }
+
+    #[view(getMexTokenId)]
+    #[storage_mapper("mexTokenId")]
+    fn mex_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getFeesCollectorAddress)]
+    #[storage_mapper("feesCollectorAddress")]
+    fn fees_collector_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getBurnPercentage)]
+    #[storage_mapper("burnPercentage")]
+    fn burn_percentage(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMaxPriceDeviation)]
+    #[storage_mapper("maxPriceDeviation")]
+    fn max_price_deviation(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("swapPairs")]
+    fn swap_pairs(&self) -> MapMapper<TokenIdentifier, ManagedAddress>;
+}
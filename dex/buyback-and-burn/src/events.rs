@@ -0,0 +1,59 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_types::Week;
+
+#[derive(TypeAbi, TopEncode)]
+pub struct BuybackEvent<M: ManagedTypeApi> {
+    input: EsdtTokenPayment<M>,
+    mex_bought: BigUint<M>,
+    mex_burned: BigUint<M>,
+    mex_deposited: BigUint<M>,
+    block: u64,
+    epoch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_deposit_fees_event(&self, caller: ManagedAddress, payment: EsdtTokenPayment) {
+        self.deposit_fees_event(caller, payment);
+    }
+
+    fn emit_buyback_event(
+        &self,
+        week: Week,
+        input: EsdtTokenPayment,
+        mex_bought: BigUint,
+        mex_burned: BigUint,
+        mex_deposited: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.buyback_event(
+            caller,
+            week,
+            BuybackEvent {
+                input,
+                mex_bought,
+                mex_burned,
+                mex_deposited,
+                block: self.blockchain().get_block_nonce(),
+                epoch: self.blockchain().get_block_epoch(),
+            },
+        );
+    }
+
+    #[event("depositFees")]
+    fn deposit_fees_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] payment: EsdtTokenPayment,
+    );
+
+    #[event("buyback")]
+    fn buyback_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] week: Week,
+        buyback_event: BuybackEvent<Self::Api>,
+    );
+}
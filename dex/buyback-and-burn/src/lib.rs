@@ -0,0 +1,142 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod config;
+pub mod events;
+pub mod pair_interactions;
+pub mod weekly_budget;
+
+use config::MAX_PERCENTAGE;
+use fees_collector::fees_accumulation::ProxyTrait as _;
+use permissions_module::Permissions;
+
+pub type BuybackResultType<M> = MultiValue2<BigUint<M>, BigUint<M>>;
+
+#[multiversx_sc::contract]
+pub trait BuybackAndBurn:
+    config::ConfigModule
+    + weekly_budget::WeeklyBudgetModule
+    + pair_interactions::PairInteractionsModule
+    + events::EventsModule
+    + permissions_module::PermissionsModule
+    + week_timekeeping::WeekTimekeepingModule
+{
+    /// `burn_percentage` of the bought MEX is burned, the rest is deposited into the fees collector.
+    /// Admins can trigger buybacks, within the weekly budgets set by the owner.
+    #[init]
+    fn init(
+        &self,
+        mex_token_id: TokenIdentifier,
+        fees_collector_address: ManagedAddress,
+        burn_percentage: u64,
+        max_price_deviation: u64,
+        admins: MultiValueEncoded<ManagedAddress>,
+    ) {
+        require!(mex_token_id.is_valid_esdt_identifier(), "Invalid token ID");
+
+        self.mex_token_id().set_if_empty(mex_token_id);
+        self.set_fees_collector_address_unchecked(fees_collector_address);
+        self.set_burn_percentage_unchecked(burn_percentage);
+        self.set_max_price_deviation_unchecked(max_price_deviation);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        self.first_week_start_epoch().set_if_empty(current_epoch);
+
+        let caller = self.blockchain().get_caller();
+        self.add_permissions(caller, Permissions::OWNER);
+        self.add_permissions_for_all(admins, Permissions::ADMIN);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Same signature as the fees collector endpoint,
+    /// so pairs can be configured to send their fees here
+    #[payable("*")]
+    #[endpoint(depositSwapFees)]
+    fn deposit_swap_fees(&self) {
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, "Only fungible tokens accepted");
+        require!(
+            self.is_accepted_token(&payment.token_identifier),
+            "Invalid payment token"
+        );
+
+        self.pending_fees(&payment.token_identifier)
+            .update(|pending| *pending += &payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        self.emit_deposit_fees_event(caller, payment);
+    }
+
+    /// Swaps up to `amount` of the pending fees into MEX, limited by the token's remaining weekly budget.
+    /// MEX fees are not swapped, and don't count towards any budget.
+    ///
+    /// Returns the burned and deposited MEX amounts.
+    #[endpoint(executeBuyback)]
+    fn execute_buyback(
+        &self,
+        token_id: TokenIdentifier,
+        amount: BigUint,
+    ) -> BuybackResultType<Self::Api> {
+        self.require_caller_has_owner_or_admin_permissions();
+        require!(amount > 0, "Invalid amount");
+
+        let pending_fees_mapper = self.pending_fees(&token_id);
+        let pending_fees = pending_fees_mapper.get();
+        require!(amount <= pending_fees, "Not enough pending fees");
+
+        let mex_token_id = self.mex_token_id().get();
+        let is_mex = token_id == mex_token_id;
+        let amount_to_spend = if is_mex {
+            amount
+        } else {
+            self.consume_weekly_budget(&token_id, &amount)
+        };
+        require!(amount_to_spend > 0, "Weekly budget exhausted");
+
+        pending_fees_mapper.set(&pending_fees - &amount_to_spend);
+
+        let input = EsdtTokenPayment::new(token_id, 0, amount_to_spend);
+        let mex_bought = if is_mex {
+            input.amount.clone()
+        } else {
+            self.swap_to_mex(input.clone()).amount
+        };
+
+        let burn_percentage = self.burn_percentage().get();
+        let mex_burned = &mex_bought * burn_percentage / MAX_PERCENTAGE;
+        let mex_deposited = &mex_bought - &mex_burned;
+
+        if mex_burned > 0 {
+            self.send().esdt_local_burn(&mex_token_id, 0, &mex_burned);
+        }
+        if mex_deposited > 0 {
+            let fees_collector_address = self.fees_collector_address().get();
+            let _: IgnoreValue = self
+                .fees_collector_proxy(fees_collector_address)
+                .deposit_swap_fees()
+                .with_esdt_transfer((mex_token_id, 0, mex_deposited.clone()))
+                .execute_on_dest_context();
+        }
+
+        let current_week = self.get_current_week();
+        self.emit_buyback_event(
+            current_week,
+            input,
+            mex_bought,
+            mex_burned.clone(),
+            mex_deposited.clone(),
+        );
+
+        (mex_burned, mex_deposited).into()
+    }
+
+    #[proxy]
+    fn fees_collector_proxy(&self, sc_address: ManagedAddress) -> fees_collector::Proxy<Self::Api>;
+
+    #[view(getPendingFees)]
+    #[storage_mapper("pendingFees")]
+    fn pending_fees(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
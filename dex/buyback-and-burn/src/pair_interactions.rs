@@ -0,0 +1,54 @@
+multiversx_sc::imports!();
+
+use pair::{
+    pair_actions::{swap::ProxyTrait as _, views::ProxyTrait as _},
+    safe_price_view::ProxyTrait as _,
+};
+
+use crate::config::MAX_PERCENTAGE;
+
+#[multiversx_sc::module]
+pub trait PairInteractionsModule:
+    crate::config::ConfigModule + permissions_module::PermissionsModule
+{
+    /// Swaps through the whitelisted pair, refusing to trade if the spot price
+    /// is worse than the pair's TWAP by more than the configured deviation
+    fn swap_to_mex(&self, payment: EsdtTokenPayment) -> EsdtTokenPayment {
+        let pair_address = match self.swap_pairs().get(&payment.token_identifier) {
+            Some(address) => address,
+            None => sc_panic!("Unknown swap pair"),
+        };
+        let mex_token_id = self.mex_token_id().get();
+
+        let safe_price_output: EsdtTokenPayment = self
+            .pair_proxy(pair_address.clone())
+            .get_safe_price_by_default_offset(pair_address.clone(), payment.clone())
+            .execute_on_dest_context();
+        require!(
+            safe_price_output.token_identifier == mex_token_id,
+            "Invalid swap pair"
+        );
+
+        let spot_price_output: BigUint = self
+            .pair_proxy(pair_address.clone())
+            .get_amount_out_view(payment.token_identifier.clone(), payment.amount.clone())
+            .execute_on_dest_context();
+
+        let max_price_deviation = self.max_price_deviation().get();
+        let amount_out_min =
+            safe_price_output.amount * (MAX_PERCENTAGE - max_price_deviation) / MAX_PERCENTAGE;
+        require!(amount_out_min > 0, "Swap amount too low");
+        require!(
+            spot_price_output >= amount_out_min,
+            "Price deviates too much from TWAP"
+        );
+
+        self.pair_proxy(pair_address)
+            .swap_tokens_fixed_input(mex_token_id, amount_out_min)
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context()
+    }
+
+    #[proxy]
+    fn pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+}
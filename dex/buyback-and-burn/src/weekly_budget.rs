@@ -0,0 +1,56 @@
+multiversx_sc::imports!();
+
+use common_types::Week;
+
+#[multiversx_sc::module]
+pub trait WeeklyBudgetModule:
+    permissions_module::PermissionsModule + week_timekeeping::WeekTimekeepingModule
+{
+    /// Maximum amount of the given token that can be spent on buybacks each week
+    #[endpoint(setWeeklyBudget)]
+    fn set_weekly_budget(&self, token_id: TokenIdentifier, budget: BigUint) {
+        self.require_caller_has_owner_permissions();
+        self.weekly_budget(&token_id).set(budget);
+    }
+
+    /// Returns the amount that can actually be spent, and marks it as spent for the current week
+    fn consume_weekly_budget(
+        &self,
+        token_id: &TokenIdentifier,
+        requested_amount: &BigUint,
+    ) -> BigUint {
+        let current_week = self.get_current_week();
+        let remaining_budget = self.get_remaining_budget_for_week(current_week, token_id);
+        let amount = core::cmp::min(remaining_budget, requested_amount.clone());
+        if amount > 0 {
+            self.spent_budget(current_week, token_id)
+                .update(|spent| *spent += &amount);
+        }
+
+        amount
+    }
+
+    fn get_remaining_budget_for_week(&self, week: Week, token_id: &TokenIdentifier) -> BigUint {
+        let budget = self.weekly_budget(token_id).get();
+        let spent = self.spent_budget(week, token_id).get();
+        if budget > spent {
+            budget - spent
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    #[view(getRemainingBudget)]
+    fn get_remaining_budget(&self, token_id: TokenIdentifier) -> BigUint {
+        let current_week = self.get_current_week();
+        self.get_remaining_budget_for_week(current_week, &token_id)
+    }
+
+    #[view(getWeeklyBudget)]
+    #[storage_mapper("weeklyBudget")]
+    fn weekly_budget(&self, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getSpentBudget)]
+    #[storage_mapper("spentBudget")]
+    fn spent_budget(&self, week: Week, token_id: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,119 @@
+#![allow(deprecated)]
+
+mod buyback_test_setup;
+
+use buyback_and_burn::{weekly_budget::WeeklyBudgetModule, BuybackAndBurn};
+use buyback_test_setup::*;
+use fees_collector::fees_accumulation::FeesAccumulationModule;
+use multiversx_sc_scenario::{managed_biguint, managed_token_id, rust_biguint};
+use pair::pair_actions::swap::SwapModule;
+
+#[test]
+fn setup_test() {
+    let _ = BuybackSetup::new(
+        buyback_and_burn::contract_obj,
+        pair::contract_obj,
+        fees_collector::contract_obj,
+    );
+}
+
+#[test]
+fn deposit_unknown_token_test() {
+    let mut setup = BuybackSetup::new(
+        buyback_and_burn::contract_obj,
+        pair::contract_obj,
+        fees_collector::contract_obj,
+    );
+
+    setup
+        .deposit(WEGLD_TOKEN_ID, 1_000)
+        .assert_user_error("Invalid payment token");
+
+    setup.add_swap_pair(WEGLD_TOKEN_ID, 1_000);
+    setup.deposit(WEGLD_TOKEN_ID, 1_000).assert_ok();
+}
+
+#[test]
+fn buyback_within_weekly_budget_test() {
+    let mut setup = BuybackSetup::new(
+        buyback_and_burn::contract_obj,
+        pair::contract_obj,
+        fees_collector::contract_obj,
+    );
+
+    let weekly_budget = 100_000;
+    setup.add_swap_pair(WEGLD_TOKEN_ID, weekly_budget);
+    setup.deposit(WEGLD_TOKEN_ID, 3 * weekly_budget).assert_ok();
+
+    // only the weekly budget is spent
+    setup
+        .execute_buyback(WEGLD_TOKEN_ID, 2 * weekly_budget)
+        .assert_ok();
+    setup
+        .execute_buyback(WEGLD_TOKEN_ID, weekly_budget)
+        .assert_user_error("Weekly budget exhausted");
+
+    // ~99_600 MEX bought from the balanced pool, half of which is deposited
+    let mex_deposited = 49_801u64;
+    setup
+        .b_mock
+        .execute_query(&setup.buyback_wrapper, |sc| {
+            assert_eq!(
+                sc.pending_fees(&managed_token_id!(WEGLD_TOKEN_ID)).get(),
+                managed_biguint!(2 * weekly_budget)
+            );
+            assert_eq!(
+                sc.get_remaining_budget(managed_token_id!(WEGLD_TOKEN_ID)),
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.fc_wrapper, |sc| {
+            assert_eq!(
+                sc.accumulated_fees(1, &managed_token_id!(MEX_TOKEN_ID))
+                    .get(),
+                managed_biguint!(mex_deposited)
+            );
+        })
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(
+        setup.buyback_wrapper.address_ref(),
+        MEX_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn buyback_price_deviation_test() {
+    let mut setup = BuybackSetup::new(
+        buyback_and_burn::contract_obj,
+        pair::contract_obj,
+        fees_collector::contract_obj,
+    );
+
+    setup.add_swap_pair(WEGLD_TOKEN_ID, 1_000_000);
+    setup.deposit(WEGLD_TOKEN_ID, 1_000_000).assert_ok();
+
+    // dump WEGLD into the pool, moving the spot price away from the TWAP
+    let user_address = setup.user_address.clone();
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &setup.pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(USER_BALANCE / 10),
+            |sc| {
+                let _ = sc
+                    .swap_tokens_fixed_input(managed_token_id!(MEX_TOKEN_ID), managed_biguint!(1));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .execute_buyback(WEGLD_TOKEN_ID, 1_000_000)
+        .assert_user_error("Price deviates too much from TWAP");
+}
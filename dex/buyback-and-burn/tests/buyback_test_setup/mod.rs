@@ -0,0 +1,234 @@
+#![allow(deprecated)]
+
+use multiversx_sc::types::{Address, EsdtLocalRole, ManagedAddress, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    whitebox_legacy::{TxResult, TxTokenTransfer, *},
+    DebugApi,
+};
+
+use buyback_and_burn::{config::ConfigModule, weekly_budget::WeeklyBudgetModule, *};
+use fees_collector::{config::ConfigModule as _, FeesCollector};
+use pair::{
+    config::ConfigModule as _,
+    pair_actions::{add_liq::AddLiquidityModule, swap::SwapModule},
+    Pair,
+};
+use pausable::{PausableModule, State};
+
+pub const MEX_TOKEN_ID: &[u8] = b"MEX-abcdef";
+pub const WEGLD_TOKEN_ID: &[u8] = b"WEGLD-abcdef";
+pub const LP_TOKEN_ID: &[u8] = b"LPTOK-abcdef";
+pub const LOCKED_TOKEN_ID: &[u8] = b"LOCKED-abcdef";
+
+pub const BURN_PERCENTAGE: u64 = 5_000;
+pub const MAX_PRICE_DEVIATION: u64 = 500;
+pub const USER_BALANCE: u64 = 1_000_000_000;
+pub const POOL_LIQUIDITY: u64 = 100_000_000;
+
+pub struct BuybackSetup<BuybackObjBuilder, PairObjBuilder, FeesCollectorObjBuilder>
+where
+    BuybackObjBuilder: 'static + Copy + Fn() -> buyback_and_burn::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+    FeesCollectorObjBuilder: 'static + Copy + Fn() -> fees_collector::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub user_address: Address,
+    pub buyback_wrapper:
+        ContractObjWrapper<buyback_and_burn::ContractObj<DebugApi>, BuybackObjBuilder>,
+    pub pair_wrapper: ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+    pub fc_wrapper:
+        ContractObjWrapper<fees_collector::ContractObj<DebugApi>, FeesCollectorObjBuilder>,
+}
+
+impl<BuybackObjBuilder, PairObjBuilder, FeesCollectorObjBuilder>
+    BuybackSetup<BuybackObjBuilder, PairObjBuilder, FeesCollectorObjBuilder>
+where
+    BuybackObjBuilder: 'static + Copy + Fn() -> buyback_and_burn::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+    FeesCollectorObjBuilder: 'static + Copy + Fn() -> fees_collector::ContractObj<DebugApi>,
+{
+    pub fn new(
+        buyback_builder: BuybackObjBuilder,
+        pair_builder: PairObjBuilder,
+        fc_builder: FeesCollectorObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let user_address = b_mock.create_user_account(&rust_zero);
+
+        let pair_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner_address), pair_builder, "pair path");
+        let fc_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            fc_builder,
+            "fees collector path",
+        );
+        let buyback_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            buyback_builder,
+            "buyback path",
+        );
+
+        b_mock.set_block_round(1);
+
+        b_mock
+            .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_address!(&owner_address),
+                    managed_address!(&owner_address),
+                    300,
+                    50,
+                    ManagedAddress::zero(),
+                    MultiValueEncoded::new(),
+                );
+                sc.lp_token_identifier()
+                    .set(&managed_token_id!(LP_TOKEN_ID));
+                sc.state().set(State::Active);
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            pair_wrapper.address_ref(),
+            LP_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        // the energy factory is never called when depositing fungible tokens
+        b_mock
+            .execute_tx(&owner_address, &fc_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(LOCKED_TOKEN_ID),
+                    managed_address!(pair_wrapper.address_ref()),
+                );
+
+                let mut tokens = MultiValueEncoded::new();
+                tokens.push(managed_token_id!(MEX_TOKEN_ID));
+                sc.add_known_tokens(tokens);
+
+                let mut contracts = MultiValueEncoded::new();
+                contracts.push(managed_address!(buyback_wrapper.address_ref()));
+                sc.add_known_contracts(contracts);
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_tx(&owner_address, &buyback_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_address!(fc_wrapper.address_ref()),
+                    BURN_PERCENTAGE,
+                    MAX_PRICE_DEVIATION,
+                    MultiValueEncoded::new(),
+                );
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            buyback_wrapper.address_ref(),
+            MEX_TOKEN_ID,
+            &[EsdtLocalRole::Burn],
+        );
+
+        b_mock.set_esdt_balance(
+            &owner_address,
+            WEGLD_TOKEN_ID,
+            &rust_biguint!(POOL_LIQUIDITY),
+        );
+        b_mock.set_esdt_balance(&owner_address, MEX_TOKEN_ID, &rust_biguint!(POOL_LIQUIDITY));
+        b_mock.set_esdt_balance(&user_address, WEGLD_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+        b_mock.set_esdt_balance(&user_address, MEX_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+
+        let payments = vec![
+            TxTokenTransfer {
+                token_identifier: WEGLD_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_LIQUIDITY),
+            },
+            TxTokenTransfer {
+                token_identifier: MEX_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_LIQUIDITY),
+            },
+        ];
+        b_mock
+            .execute_esdt_multi_transfer(&owner_address, &pair_wrapper, &payments, |sc| {
+                let _ = sc.add_liquidity(managed_biguint!(1), managed_biguint!(1));
+            })
+            .assert_ok();
+
+        // let the pair record enough price observations for the safe price
+        b_mock.set_block_round(2);
+        b_mock
+            .execute_esdt_transfer(
+                &user_address,
+                &pair_wrapper,
+                MEX_TOKEN_ID,
+                0,
+                &rust_biguint!(1_000),
+                |sc| {
+                    let _ = sc.swap_tokens_fixed_input(
+                        managed_token_id!(WEGLD_TOKEN_ID),
+                        managed_biguint!(1),
+                    );
+                },
+            )
+            .assert_ok();
+        b_mock.set_block_round(100);
+
+        BuybackSetup {
+            b_mock,
+            owner_address,
+            user_address,
+            buyback_wrapper,
+            pair_wrapper,
+            fc_wrapper,
+        }
+    }
+
+    pub fn add_swap_pair(&mut self, token_id: &[u8], weekly_budget: u64) {
+        let pair_address = self.pair_wrapper.address_ref().clone();
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.buyback_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.add_swap_pair(managed_token_id!(token_id), managed_address!(&pair_address));
+                    sc.set_weekly_budget(
+                        managed_token_id!(token_id),
+                        managed_biguint!(weekly_budget),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn deposit(&mut self, token_id: &[u8], amount: u64) -> TxResult {
+        self.b_mock.execute_esdt_transfer(
+            &self.user_address,
+            &self.buyback_wrapper,
+            token_id,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                sc.deposit_swap_fees();
+            },
+        )
+    }
+
+    pub fn execute_buyback(&mut self, token_id: &[u8], amount: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.buyback_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.execute_buyback(managed_token_id!(token_id), managed_biguint!(amount));
+            },
+        )
+    }
+}
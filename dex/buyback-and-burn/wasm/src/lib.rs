@@ -0,0 +1,49 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           23
+// Async Callback (empty):               1
+// Total number of exported functions:  26
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    buyback_and_burn
+    (
+        init => init
+        upgrade => upgrade
+        depositSwapFees => deposit_swap_fees
+        executeBuyback => execute_buyback
+        getPendingFees => pending_fees
+        setBurnPercentage => set_burn_percentage
+        setMaxPriceDeviation => set_max_price_deviation
+        setFeesCollectorAddress => set_fees_collector_address
+        addSwapPair => add_swap_pair
+        removeSwapPair => remove_swap_pair
+        getSwapPairs => get_swap_pairs
+        getMexTokenId => mex_token_id
+        getFeesCollectorAddress => fees_collector_address
+        getBurnPercentage => burn_percentage
+        getMaxPriceDeviation => max_price_deviation
+        setWeeklyBudget => set_weekly_budget
+        getRemainingBudget => get_remaining_budget
+        getWeeklyBudget => weekly_budget
+        getSpentBudget => spent_budget
+        addAdmin => add_admin_endpoint
+        removeAdmin => remove_admin_endpoint
+        updateOwnerOrAdmin => update_owner_or_admin_endpoint
+        getPermissions => permissions
+        getCurrentWeek => get_current_week
+        getFirstWeekStartEpoch => first_week_start_epoch
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -3,8 +3,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use common_structs::FarmTokenAttributes;
-use contexts::storage_cache::StorageCache;
+use common_structs::{Epoch, FarmTokenAttributes, PaymentAttributesPair};
+use contexts::{enter_farm_context::EnterFarmContext, storage_cache::StorageCache};
 use core::marker::PhantomData;
 use fixed_supply_token::FixedSupplyToken;
 
@@ -17,6 +17,9 @@ use farm::{
 };
 use farm_base_impl::base_traits_impl::FarmContract;
 
+pub type ExitFarmForMigrationResultType<M> =
+    MultiValue3<EsdtTokenPayment<M>, EsdtTokenPayment<M>, Epoch>;
+
 #[multiversx_sc::contract]
 pub trait Farm:
     rewards::RewardsModule
@@ -174,6 +177,143 @@ pub trait Farm:
         (exit_farm_result.farming_tokens, locked_rewards_payment).into()
     }
 
+    /// Exits the farm without the exit penalty, for positions migrated to another farm.
+    /// Only callable by whitelisted contracts.
+    ///
+    /// Returns the farming tokens, the rewards and the entering epoch of the position,
+    /// so the new farm position can keep it.
+    #[payable("*")]
+    #[endpoint(exitFarmForMigration)]
+    fn exit_farm_for_migration(
+        &self,
+        orig_caller: ManagedAddress,
+    ) -> ExitFarmForMigrationResultType<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        self.require_sc_address_whitelisted(&caller);
+
+        let payment = self.call_value().single_esdt();
+
+        let migrated_amount = self.migrate_old_farm_positions(&orig_caller);
+
+        let exit_farm_result =
+            self.exit_farm_base::<NoMintWrapper<Self>>(orig_caller.clone(), payment);
+        let entering_epoch = exit_farm_result
+            .context
+            .farm_token
+            .attributes
+            .entering_epoch;
+        let farming_tokens = exit_farm_result.farming_token_payment;
+        let rewards = exit_farm_result.reward_payment;
+
+        self.set_farm_supply_for_current_week(&exit_farm_result.storage_cache.farm_token_supply);
+        self.emit_exit_farm_event(
+            &orig_caller,
+            exit_farm_result.context,
+            farming_tokens.clone(),
+            rewards.clone(),
+            exit_farm_result.storage_cache,
+        );
+
+        self.decrease_old_farm_positions(migrated_amount, &orig_caller);
+
+        self.send_payment_non_zero(&caller, &farming_tokens);
+
+        let locked_rewards_payment = self.send_to_lock_contract_non_zero(
+            rewards.token_identifier,
+            rewards.amount,
+            caller,
+            orig_caller.clone(),
+        );
+
+        self.clear_user_energy_if_needed(&orig_caller);
+
+        (farming_tokens, locked_rewards_payment, entering_epoch).into()
+    }
+
+    /// Enters the farm with a position migrated from another farm, keeping its entering epoch.
+    /// Only callable by whitelisted contracts.
+    #[payable("*")]
+    #[endpoint(enterFarmForMigration)]
+    fn enter_farm_for_migration(
+        &self,
+        orig_caller: ManagedAddress,
+        entering_epoch: Epoch,
+    ) -> EnterFarmResultType<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        self.require_sc_address_whitelisted(&caller);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(entering_epoch <= current_epoch, "Invalid entering epoch");
+
+        let boosted_rewards = self.claim_only_boosted_payment(&orig_caller);
+        let boosted_rewards_payment = self.send_to_lock_contract_non_zero(
+            self.reward_token_id().get(),
+            boosted_rewards,
+            caller.clone(),
+            orig_caller.clone(),
+        );
+
+        let new_farm_token = self.enter_farm_with_entering_epoch(&orig_caller, entering_epoch);
+        self.send_payment_non_zero(&caller, &new_farm_token);
+
+        self.update_energy_and_progress(&orig_caller);
+
+        (new_farm_token, boosted_rewards_payment).into()
+    }
+
+    fn enter_farm_with_entering_epoch(
+        &self,
+        orig_caller: &ManagedAddress,
+        entering_epoch: Epoch,
+    ) -> EsdtTokenPayment {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        let mut storage_cache = StorageCache::new(self);
+        self.validate_contract_state(storage_cache.contract_state, &storage_cache.farm_token_id);
+
+        let enter_farm_context = EnterFarmContext::new(
+            payments,
+            &storage_cache.farming_token_id,
+            &storage_cache.farm_token_id,
+        );
+        require!(
+            enter_farm_context.additional_farm_tokens.is_empty(),
+            "Cannot merge migrated positions"
+        );
+
+        let farming_token_amount = &enter_farm_context.farming_token_payment.amount;
+        NoMintWrapper::<Self>::increase_user_farm_position(self, orig_caller, farming_token_amount);
+        NoMintWrapper::<Self>::generate_aggregated_rewards(self, &mut storage_cache);
+
+        storage_cache.farm_token_supply += farming_token_amount;
+
+        let mut attributes = NoMintWrapper::<Self>::create_enter_farm_initial_attributes(
+            self,
+            orig_caller.clone(),
+            farming_token_amount.clone(),
+            storage_cache.reward_per_share.clone(),
+        );
+        attributes.entering_epoch = entering_epoch;
+
+        let payment = self
+            .farm_token()
+            .nft_create(farming_token_amount.clone(), &attributes);
+        let new_farm_token = PaymentAttributesPair {
+            payment: payment.clone(),
+            attributes,
+        };
+
+        self.set_farm_supply_for_current_week(&storage_cache.farm_token_supply);
+        self.emit_enter_farm_event(
+            orig_caller,
+            enter_farm_context.farming_token_payment,
+            new_farm_token,
+            false,
+            storage_cache,
+        );
+
+        payment
+    }
+
     #[payable("*")]
     #[endpoint(mergeFarmTokens)]
     fn merge_farm_tokens_endpoint(
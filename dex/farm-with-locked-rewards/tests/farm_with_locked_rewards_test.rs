@@ -1,12 +1,15 @@
 #![allow(deprecated)]
 
 use common_structs::FarmTokenAttributes;
+use farm_with_locked_rewards::Farm;
 use multiversx_sc::codec::Empty;
-use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, DebugApi,
+};
 use simple_lock::locked_token::LockedTokenAttributes;
 
 use crate::farm_with_locked_rewards_setup::{
-    FarmSetup, BOOSTED_YIELDS_PERCENTAGE, FARM_TOKEN_ID, LOCKED_REWARD_TOKEN_ID,
+    FarmSetup, BOOSTED_YIELDS_PERCENTAGE, FARMING_TOKEN_ID, FARM_TOKEN_ID, LOCKED_REWARD_TOKEN_ID,
 };
 
 mod farm_with_locked_rewards_setup;
@@ -528,3 +531,111 @@ fn claim_boosted_rewards_with_zero_position_test() {
         None,
     );
 }
+
+#[test]
+fn migration_enter_and_exit_test() {
+    DebugApi::dummy();
+    let mut farm_setup = FarmSetup::new(
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+
+    let farm_token_amount = 100_000_000;
+    let first_user = farm_setup.first_user.clone();
+    let owner = farm_setup.owner.clone();
+    let entering_epoch = 2;
+    farm_setup.b_mock.set_block_epoch(4);
+
+    // only whitelisted contracts may migrate positions
+    farm_setup
+        .b_mock
+        .set_esdt_balance(&owner, FARMING_TOKEN_ID, &rust_biguint!(farm_token_amount));
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &farm_setup.farm_wrapper,
+            FARMING_TOKEN_ID,
+            0,
+            &rust_biguint!(farm_token_amount),
+            |sc| {
+                let _ = sc.enter_farm_for_migration(managed_address!(&first_user), entering_epoch);
+            },
+        )
+        .assert_user_error("Item not whitelisted");
+
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &farm_setup.farm_wrapper,
+            FARMING_TOKEN_ID,
+            0,
+            &rust_biguint!(farm_token_amount),
+            |sc| {
+                let _ = sc.enter_farm_for_migration(managed_address!(&first_user), 5);
+            },
+        )
+        .assert_user_error("Invalid entering epoch");
+
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &farm_setup.farm_wrapper,
+            FARMING_TOKEN_ID,
+            0,
+            &rust_biguint!(farm_token_amount),
+            |sc| {
+                let (farm_token, _) = sc
+                    .enter_farm_for_migration(managed_address!(&first_user), entering_epoch)
+                    .into_tuple();
+                assert_eq!(
+                    farm_token.token_identifier,
+                    managed_token_id!(FARM_TOKEN_ID)
+                );
+                assert_eq!(farm_token.amount, managed_biguint!(farm_token_amount));
+            },
+        )
+        .assert_ok();
+
+    farm_setup.b_mock.check_nft_balance(
+        &first_user,
+        FARM_TOKEN_ID,
+        1,
+        &rust_biguint!(farm_token_amount),
+        Some(&FarmTokenAttributes::<DebugApi> {
+            reward_per_share: managed_biguint!(0),
+            entering_epoch,
+            compounded_reward: managed_biguint!(0),
+            current_farm_amount: managed_biguint!(farm_token_amount),
+            original_owner: managed_address!(&first_user),
+        }),
+    );
+
+    // still inside the penalty window, the migration exit applies no penalty
+    farm_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &farm_setup.farm_wrapper,
+            FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(farm_token_amount),
+            |sc| {
+                let (farming_tokens, _, exit_entering_epoch) = sc
+                    .exit_farm_for_migration(managed_address!(&first_user))
+                    .into_tuple();
+                assert_eq!(farming_tokens.amount, managed_biguint!(farm_token_amount));
+                assert_eq!(exit_entering_epoch, entering_epoch);
+            },
+        )
+        .assert_ok();
+
+    farm_setup.b_mock.check_esdt_balance(
+        &first_user,
+        FARMING_TOKEN_ID,
+        &rust_biguint!(farm_token_amount),
+    );
+    farm_setup.check_farm_token_supply(0);
+}
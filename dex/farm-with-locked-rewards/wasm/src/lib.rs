@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           66
+// Endpoints:                           68
 // Async Callback:                       1
-// Total number of exported functions:  69
+// Total number of exported functions:  71
 
 #![no_std]
 
@@ -23,6 +23,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         enterFarm => enter_farm_endpoint
         claimRewards => claim_rewards_endpoint
         exitFarm => exit_farm_endpoint
+        exitFarmForMigration => exit_farm_for_migration
+        enterFarmForMigration => enter_farm_for_migration
         mergeFarmTokens => merge_farm_tokens_endpoint
         claimBoostedRewards => claim_boosted_rewards
         startProduceRewards => start_produce_rewards_endpoint
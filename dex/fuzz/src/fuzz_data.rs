@@ -12,6 +12,7 @@ pub mod fuzz_data_tests {
     use farm::exit_penalty::ExitPenaltyModule;
     use farm::*;
     use farm_token::FarmTokenModule;
+    use multiversx_sc::codec::multi_types::OptionalValue;
     use multiversx_sc::codec::Empty;
     use multiversx_sc::types::{Address, BigUint, EsdtLocalRole};
     use multiversx_sc_scenario::{
@@ -680,6 +681,7 @@ pub mod fuzz_data_tests {
                     managed_biguint!(MAX_PENALTY_PERCENTAGE),
                     managed_biguint!(FIXED_PENALTY_PERCENTAGE),
                     managed_address!(locking_sc_wrapper.address_ref()),
+                    OptionalValue::None,
                 );
 
                 sc.redeem_token()
@@ -50,6 +50,30 @@ pub trait Config {
         self.try_change_price_providers(price_providers);
     }
 
+    /// Stops new proposals, votes and executions, in preparation for the migration to governance-v2.
+    /// Deposited vote tokens can be redeemed at any time while in read-only mode.
+    #[endpoint(enableReadOnlyMode)]
+    fn enable_read_only_mode(&self) {
+        self.require_caller_self_or_owner();
+
+        self.read_only_mode().set(true);
+    }
+
+    fn require_not_read_only(&self) {
+        require!(!self.read_only_mode().get(), READ_ONLY_MODE);
+    }
+
+    fn require_caller_self_or_owner(&self) {
+        let caller = self.blockchain().get_caller();
+        let sc_address = self.blockchain().get_sc_address();
+        let owner = self.blockchain().get_owner_address();
+
+        require!(
+            caller == sc_address || caller == owner,
+            INVALID_CALLER_NOT_SELF_OR_OWNER
+        );
+    }
+
     fn require_caller_self(&self) {
         let caller = self.blockchain().get_caller();
         let sc_address = self.blockchain().get_sc_address();
@@ -154,6 +178,10 @@ pub trait Config {
     #[storage_mapper("mexTokenId")]
     fn mex_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
 
+    #[view(isReadOnlyMode)]
+    #[storage_mapper("readOnlyMode")]
+    fn read_only_mode(&self) -> SingleValueMapper<bool>;
+
     #[storage_mapper("price_providers")]
     fn price_providers(&self) -> MapMapper<TokenIdentifier, ManagedAddress>;
 }
@@ -8,3 +8,5 @@ pub const VOTING_PERIOD_NOT_ENDED: &[u8] = b"voting period not ended";
 pub const ERROR_ZERO_VALUE: &[u8] = b"value cannot be zero";
 pub const INVALID_ESDT: &[u8] = b"invalid esdt";
 pub const INVALID_CALLER_NOT_SELF: &[u8] = b"caller should be self";
+pub const INVALID_CALLER_NOT_SELF_OR_OWNER: &[u8] = b"caller should be self or owner";
+pub const READ_ONLY_MODE: &[u8] = b"contract is in read-only mode";
@@ -55,6 +55,8 @@ pub trait Governance:
     #[payable("*")]
     #[endpoint]
     fn propose(&self, args: ProposalCreationArgs<Self::Api>) -> u64 {
+        self.require_not_read_only();
+
         let payment = self.call_value().single_esdt();
         self.require_is_accepted_payment(&payment);
 
@@ -96,6 +98,7 @@ pub trait Governance:
 
     #[endpoint]
     fn execute(&self, proposal_id: u64) {
+        self.require_not_read_only();
         require!(!self.proposal(proposal_id).is_empty(), PROPOSAL_NOT_FOUND);
         let mut proposal = self.proposal(proposal_id).get();
 
@@ -110,6 +113,7 @@ pub trait Governance:
     }
 
     fn vote(&self, proposal_id: u64, vote_type: VoteType) {
+        self.require_not_read_only();
         require!(!self.proposal(proposal_id).is_empty(), PROPOSAL_NOT_FOUND);
         let mut proposal = self.proposal(proposal_id).get();
 
@@ -143,7 +147,33 @@ pub trait Governance:
     #[endpoint]
     fn redeem(&self) {
         let payment = self.call_value().single_esdt();
+        let attr = self.redeem_vote_nft(payment);
+
+        self.send_back(attr.payment);
+    }
+
+    /// Redeems any number of vote NFTs in a single transaction.
+    /// The deposited tokens are all sent back to the caller.
+    #[payable("*")]
+    #[endpoint(redeemBulk)]
+    fn redeem_bulk(&self) -> ManagedVec<EsdtTokenPayment> {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), BAD_PAYMENT_TOKEN);
+
+        let mut redeemed_payments = ManagedVec::new();
+        for payment in &payments {
+            let attr = self.redeem_vote_nft(payment);
+            redeemed_payments.push(attr.payment);
+        }
 
+        let caller = self.blockchain().get_caller();
+        self.send().direct_multi(&caller, &redeemed_payments);
+
+        redeemed_payments
+    }
+
+    /// In read-only mode, vote NFTs can be redeemed regardless of the proposal's status
+    fn redeem_vote_nft(&self, payment: EsdtTokenPayment) -> VoteNFTAttributes<Self::Api> {
         let vote_nft_id = self.vote_nft_id().get();
         require!(payment.token_identifier == vote_nft_id, BAD_PAYMENT_TOKEN);
 
@@ -152,15 +182,15 @@ pub trait Governance:
         let pstat = self.get_proposal_status(&proposal);
 
         match pstat {
-            ProposalStatus::Succeeded | ProposalStatus::Defeated | ProposalStatus::Executed => {
-                self.send_back(attr.payment.clone());
-                self.burn_vote_nft(payment.clone());
-            }
+            ProposalStatus::Succeeded | ProposalStatus::Defeated | ProposalStatus::Executed => {}
             ProposalStatus::Active | ProposalStatus::Pending => {
-                sc_panic!(VOTING_PERIOD_NOT_ENDED);
+                require!(self.read_only_mode().get(), VOTING_PERIOD_NOT_ENDED);
             }
         }
 
-        self.emit_redeem_event(proposal, payment, attr);
+        self.burn_vote_nft(payment.clone());
+        self.emit_redeem_event(proposal, payment, attr.clone());
+
+        attr
     }
 }
@@ -9,7 +9,7 @@ pub enum VoteType {
     DownVote = 2,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug)]
+#[derive(TypeAbi, TopEncode, TopDecode, PartialEq, Debug, Clone)]
 pub struct VoteNFTAttributes<M: ManagedTypeApi> {
     pub proposal_id: u64,
     pub vote_type: VoteType,
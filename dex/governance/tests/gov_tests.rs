@@ -678,3 +678,185 @@ fn test_basic_propose_with_lpmex() {
         })
         .assert_ok();
 }
+
+#[test]
+fn test_read_only_mode_and_bulk_redeem() {
+    let mut gov_setup = setup_gov(governance::contract_obj, pair_mock::contract_obj);
+
+    for _ in 0..2 {
+        gov_setup
+            .blockchain_wrapper
+            .execute_esdt_transfer(
+                &gov_setup.owner_address,
+                &gov_setup.gov_wrapper,
+                MEX_TOKEN_ID,
+                0,
+                &rust_biguint!(MIN_WEIGHT_FOR_PROPOSAL),
+                |sc| {
+                    sc.propose(ProposalCreationArgs {
+                        description: managed_buffer!(&b""[..]),
+                        actions: ManagedVec::from(Vec::<Action<DebugApi>>::new()),
+                    });
+                },
+            )
+            .assert_ok();
+    }
+
+    // only the owner or the contract itself can enable read-only mode
+    gov_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &gov_setup.user_address,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.enable_read_only_mode();
+            },
+        )
+        .assert_user_error(core::str::from_utf8(INVALID_CALLER_NOT_SELF_OR_OWNER).unwrap());
+
+    gov_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &gov_setup.owner_address,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.enable_read_only_mode();
+            },
+        )
+        .assert_ok();
+
+    gov_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &gov_setup.user_address,
+            &gov_setup.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            &rust_biguint!(MIN_WEIGHT_FOR_PROPOSAL),
+            |sc| {
+                sc.propose(ProposalCreationArgs {
+                    description: managed_buffer!(&b""[..]),
+                    actions: ManagedVec::from(Vec::<Action<DebugApi>>::new()),
+                });
+            },
+        )
+        .assert_user_error(core::str::from_utf8(READ_ONLY_MODE).unwrap());
+
+    gov_setup
+        .blockchain_wrapper
+        .set_block_nonce(VOTING_DELAY_IN_BLOCKS);
+
+    gov_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &gov_setup.user_address,
+            &gov_setup.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            &rust_biguint!(MIN_WEIGHT_FOR_PROPOSAL),
+            |sc| {
+                sc.upvote(0);
+            },
+        )
+        .assert_user_error(core::str::from_utf8(READ_ONLY_MODE).unwrap());
+
+    // vote NFTs of active proposals can be redeemed in read-only mode
+    let vote_nfts = [
+        TxTokenTransfer {
+            token_identifier: VOTE_NFT_ID.to_vec(),
+            nonce: 1,
+            value: rust_biguint!(1),
+        },
+        TxTokenTransfer {
+            token_identifier: VOTE_NFT_ID.to_vec(),
+            nonce: 2,
+            value: rust_biguint!(1),
+        },
+    ];
+    gov_setup
+        .blockchain_wrapper
+        .execute_esdt_multi_transfer(
+            &gov_setup.owner_address,
+            &gov_setup.gov_wrapper,
+            &vote_nfts,
+            |sc| {
+                let redeemed_payments = sc.redeem_bulk();
+                assert_eq!(redeemed_payments.len(), 2);
+            },
+        )
+        .assert_ok();
+
+    let owner_address = gov_setup.owner_address.clone();
+    gov_setup.blockchain_wrapper.check_esdt_balance(
+        &owner_address,
+        MEX_TOKEN_ID,
+        &rust_biguint!(USER_TOTAL_MEX_TOKENS),
+    );
+    gov_setup
+        .blockchain_wrapper
+        .check_nft_balance::<VoteNFTAttributes<DebugApi>>(
+            &owner_address,
+            VOTE_NFT_ID,
+            1,
+            &rust_biguint!(0),
+            None,
+        );
+}
+
+#[test]
+fn test_read_only_mode_blocks_execute() {
+    let mut gov_setup = setup_gov(governance::contract_obj, pair_mock::contract_obj);
+
+    gov_setup.blockchain_wrapper.set_block_nonce(0);
+
+    gov_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &gov_setup.owner_address,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let dummy_proposal = Proposal::<DebugApi> {
+                    actions: ManagedVec::from(Vec::<Action<DebugApi>>::new()),
+                    creation_block: 0,
+                    description: managed_buffer!(&[]),
+                    id: 0,
+                    num_downvotes: managed_biguint!(0),
+                    num_upvotes: managed_biguint!(QUORUM),
+                    proposer: managed_address!(&Address::zero()),
+                    was_executed: false,
+                };
+
+                sc.proposal(0).set(dummy_proposal);
+                sc.enable_read_only_mode();
+            },
+        )
+        .assert_ok();
+
+    gov_setup
+        .blockchain_wrapper
+        .set_block_nonce(VOTING_DELAY_IN_BLOCKS + VOTING_PERIOD_IN_BLOCKS);
+
+    gov_setup
+        .blockchain_wrapper
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let status = sc.get_proposal_status_view(0);
+            assert_eq!(ProposalStatus::Succeeded, status);
+        })
+        .assert_ok();
+
+    // proposals imported in governance-v2 cannot also be executed here
+    gov_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &gov_setup.owner_address,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute(0);
+            },
+        )
+        .assert_user_error(core::str::from_utf8(READ_ONLY_MODE).unwrap());
+}
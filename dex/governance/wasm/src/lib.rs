@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           21
+// Endpoints:                           24
 // Async Callback (empty):               1
-// Total number of exported functions:  24
+// Total number of exported functions:  27
 
 #![no_std]
 
@@ -25,12 +25,14 @@ multiversx_sc_wasm_adapter::endpoints! {
         downvote => downvote
         execute => execute
         redeem => redeem
+        redeemBulk => redeem_bulk
         changeQuorum => change_quorum
         changeMinTokenBalanceForProposing => change_min_weight_for_proposal
         changeVotingDelayInBlocks => change_voting_delay_in_blocks
         changeVotingPeriodInBlocks => change_voting_period_in_blocks
         changeGovernanceTokenIds => change_governance_token_ids
         changePriceProviders => change_price_providers
+        enableReadOnlyMode => enable_read_only_mode
         getGovernanceTokenId => governance_token_ids
         getQuorum => quorum
         getMinWeightForProposal => min_weight_for_proposal
@@ -40,6 +42,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getProposalIdCounter => proposal_id_counter
         getVoteNFTId => vote_nft_id
         getMexTokenId => mex_token_id
+        isReadOnlyMode => read_only_mode
         getProposalStatus => get_proposal_status_view
     )
 }
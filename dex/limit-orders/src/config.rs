@@ -0,0 +1,49 @@
+multiversx_sc::imports!();
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+pub const MAX_EXECUTION_FEE_PERCENTAGE: u64 = 500;
+pub const MAX_ORDER_SLICES: u32 = 100;
+
+#[multiversx_sc::module]
+pub trait ConfigModule {
+    #[only_owner]
+    #[endpoint(setRouterAddress)]
+    fn set_router_address(&self, router_address: ManagedAddress) {
+        self.set_router_address_unchecked(router_address);
+    }
+
+    /// Percentage of each swap output paid to the keeper executing the order, where 10_000 = 100%
+    #[only_owner]
+    #[endpoint(setExecutionFeePercentage)]
+    fn set_execution_fee_percentage(&self, execution_fee_percentage: u64) {
+        self.set_execution_fee_percentage_unchecked(execution_fee_percentage);
+    }
+
+    fn set_router_address_unchecked(&self, router_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid router address"
+        );
+        self.router_address().set(router_address);
+    }
+
+    fn set_execution_fee_percentage_unchecked(&self, execution_fee_percentage: u64) {
+        require!(
+            execution_fee_percentage <= MAX_EXECUTION_FEE_PERCENTAGE,
+            "Invalid execution fee percentage"
+        );
+        self.execution_fee_percentage()
+            .set(execution_fee_percentage);
+    }
+
+    #[proxy]
+    fn router_proxy(&self, sc_address: ManagedAddress) -> router::Proxy<Self::Api>;
+
+    #[view(getRouterAddress)]
+    #[storage_mapper("routerAddress")]
+    fn router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getExecutionFeePercentage)]
+    #[storage_mapper("executionFeePercentage")]
+    fn execution_fee_percentage(&self) -> SingleValueMapper<u64>;
+}
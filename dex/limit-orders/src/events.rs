@@ -0,0 +1,72 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::orders::{Order, OrderId};
+
+#[derive(TypeAbi, TopEncode)]
+pub struct ExecuteOrderEvent<M: ManagedTypeApi> {
+    input: EsdtTokenPayment<M>,
+    output: EsdtTokenPayment<M>,
+    execution_fee: BigUint<M>,
+    remaining_slices: u32,
+    block: u64,
+    epoch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_create_order_event(&self, order: &Order<Self::Api>) {
+        self.create_order_event(order.creator.clone(), order.id, order);
+    }
+
+    fn emit_execute_order_event(
+        &self,
+        order: &Order<Self::Api>,
+        input: EsdtTokenPayment,
+        output: EsdtTokenPayment,
+        execution_fee: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.execute_order_event(
+            caller,
+            order.id,
+            ExecuteOrderEvent {
+                input,
+                output,
+                execution_fee,
+                remaining_slices: order.remaining_slices,
+                block: self.blockchain().get_block_nonce(),
+                epoch: self.blockchain().get_block_epoch(),
+            },
+        );
+    }
+
+    fn emit_cancel_order_event(&self, order: &Order<Self::Api>) {
+        self.cancel_order_event(order.creator.clone(), order.id, &order.input_tokens);
+    }
+
+    #[event("createOrder")]
+    fn create_order_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] order_id: OrderId,
+        order: &Order<Self::Api>,
+    );
+
+    /// The output is the amount received by the order creator, after the execution fee
+    #[event("executeOrder")]
+    fn execute_order_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] order_id: OrderId,
+        execute_order_event: ExecuteOrderEvent<Self::Api>,
+    );
+
+    #[event("cancelOrder")]
+    fn cancel_order_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] order_id: OrderId,
+        refund: &EsdtTokenPayment,
+    );
+}
@@ -0,0 +1,24 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod config;
+pub mod events;
+pub mod orders;
+pub mod views;
+
+#[multiversx_sc::contract]
+pub trait LimitOrders:
+    config::ConfigModule + orders::OrdersModule + views::ViewsModule + events::EventsModule
+{
+    /// Orders are executed by keepers against the router pairs,
+    /// who receive `execution_fee_percentage` of each swap output.
+    #[init]
+    fn init(&self, router_address: ManagedAddress, execution_fee_percentage: u64) {
+        self.set_router_address_unchecked(router_address);
+        self.set_execution_fee_percentage_unchecked(execution_fee_percentage);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
@@ -0,0 +1,265 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use pair::pair_actions::{swap::ProxyTrait as _, views::ProxyTrait as _};
+use router::factory::ProxyTrait as _;
+
+use crate::config::{MAX_ORDER_SLICES, MAX_PERCENTAGE};
+
+pub type OrderId = u64;
+
+/// The input tokens and the minimum output are the remaining amounts, for all the remaining slices
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct Order<M: ManagedTypeApi> {
+    pub id: OrderId,
+    pub creator: ManagedAddress<M>,
+    pub pair_address: ManagedAddress<M>,
+    pub input_tokens: EsdtTokenPayment<M>,
+    pub output_token_id: TokenIdentifier<M>,
+    pub amount_out_min: BigUint<M>,
+    pub remaining_slices: u32,
+    pub blocks_between_slices: u64,
+    pub next_slice_block: u64,
+}
+
+impl<M: ManagedTypeApi> Order<M> {
+    /// Returns the input tokens of the next slice and the minimum output the creator must receive for them.
+    /// The last slice uses all the remaining tokens.
+    pub fn get_next_slice(&self) -> (EsdtTokenPayment<M>, BigUint<M>) {
+        if self.remaining_slices <= 1 {
+            return (self.input_tokens.clone(), self.amount_out_min.clone());
+        }
+
+        let slice_amount = &self.input_tokens.amount / self.remaining_slices;
+        let slice_amount_out_min = &self.amount_out_min * &slice_amount / &self.input_tokens.amount;
+        let slice_input =
+            EsdtTokenPayment::new(self.input_tokens.token_identifier.clone(), 0, slice_amount);
+
+        (slice_input, slice_amount_out_min)
+    }
+}
+
+#[multiversx_sc::module]
+pub trait OrdersModule: crate::config::ConfigModule + crate::events::EventsModule {
+    /// Swaps the payment into `token_out` through the router pair, once the pair's price
+    /// gives at least `amount_out_min`, after the execution fee.
+    /// If `opt_fee_tier` is provided, the pair with the given fee tier is used.
+    ///
+    /// Returns the order ID.
+    #[payable("*")]
+    #[endpoint(createLimitOrder)]
+    fn create_limit_order(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+        opt_fee_tier: OptionalValue<u64>,
+    ) -> OrderId {
+        self.create_order(token_out, amount_out_min, 1, 0, opt_fee_tier)
+    }
+
+    /// Same as a limit order, but the payment is split into `slices` equal parts.
+    /// Each slice must reach the proportional part of `amount_out_min`,
+    /// and at least `blocks_between_slices` blocks must pass between two executions.
+    ///
+    /// Returns the order ID.
+    #[payable("*")]
+    #[endpoint(createTwapOrder)]
+    fn create_twap_order(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+        slices: u32,
+        blocks_between_slices: u64,
+        opt_fee_tier: OptionalValue<u64>,
+    ) -> OrderId {
+        require!(
+            slices > 1 && slices <= MAX_ORDER_SLICES,
+            "Invalid number of slices"
+        );
+        require!(blocks_between_slices > 0, "Invalid blocks between slices");
+
+        self.create_order(
+            token_out,
+            amount_out_min,
+            slices,
+            blocks_between_slices,
+            opt_fee_tier,
+        )
+    }
+
+    /// Anyone can execute the next slice of an order, once the target price is reached.
+    /// The caller receives the execution fee, taken from the swap output.
+    ///
+    /// Returns the execution fee.
+    #[endpoint(executeOrder)]
+    fn execute_order(&self, order_id: OrderId) -> EsdtTokenPayment {
+        let order_mapper = self.orders(order_id);
+        require!(!order_mapper.is_empty(), "Order not found");
+
+        let mut order = order_mapper.get();
+        let current_block = self.blockchain().get_block_nonce();
+        require!(
+            current_block >= order.next_slice_block,
+            "Slice cannot be executed yet"
+        );
+
+        let (slice_input, slice_amount_out_min) = order.get_next_slice();
+        let amount_out = self.get_order_amount_out(&order.pair_address, &slice_input);
+        let execution_fee_amount = self.get_execution_fee_amount(&amount_out);
+        require!(
+            &amount_out - &execution_fee_amount >= slice_amount_out_min,
+            "Target price not reached"
+        );
+
+        let output: EsdtTokenPayment = self
+            .order_pair_proxy(order.pair_address.clone())
+            .swap_tokens_fixed_input(order.output_token_id.clone(), amount_out)
+            .with_esdt_transfer(slice_input.clone())
+            .execute_on_dest_context();
+
+        let execution_fee =
+            EsdtTokenPayment::new(output.token_identifier.clone(), 0, execution_fee_amount);
+        let creator_output = EsdtTokenPayment::new(
+            output.token_identifier,
+            0,
+            output.amount - &execution_fee.amount,
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.send()
+            .direct_non_zero_esdt_payment(&caller, &execution_fee);
+        self.send()
+            .direct_non_zero_esdt_payment(&order.creator, &creator_output);
+
+        order.input_tokens.amount -= &slice_input.amount;
+        order.amount_out_min -= slice_amount_out_min;
+        order.remaining_slices -= 1;
+        order.next_slice_block = current_block + order.blocks_between_slices;
+        if order.remaining_slices == 0 {
+            self.remove_order(&order);
+        } else {
+            order_mapper.set(&order);
+        }
+
+        self.emit_execute_order_event(
+            &order,
+            slice_input,
+            creator_output,
+            execution_fee.amount.clone(),
+        );
+
+        execution_fee
+    }
+
+    /// Refunds the remaining input tokens to the order creator
+    #[endpoint(cancelOrder)]
+    fn cancel_order(&self, order_id: OrderId) -> EsdtTokenPayment {
+        let order_mapper = self.orders(order_id);
+        require!(!order_mapper.is_empty(), "Order not found");
+
+        let order = order_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(caller == order.creator, "Only the order creator can cancel");
+
+        self.remove_order(&order);
+        self.send()
+            .direct_non_zero_esdt_payment(&caller, &order.input_tokens);
+
+        self.emit_cancel_order_event(&order);
+
+        order.input_tokens
+    }
+
+    fn create_order(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+        slices: u32,
+        blocks_between_slices: u64,
+        opt_fee_tier: OptionalValue<u64>,
+    ) -> OrderId {
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, "Only fungible tokens accepted");
+        require!(
+            payment.token_identifier != token_out,
+            "Input and output tokens must differ"
+        );
+        require!(amount_out_min > 0, "Invalid amount out min");
+        require!(payment.amount >= slices, "Order amount too low");
+
+        let pair_address: ManagedAddress = self
+            .router_proxy(self.router_address().get())
+            .get_pair(
+                payment.token_identifier.clone(),
+                token_out.clone(),
+                opt_fee_tier,
+            )
+            .execute_on_dest_context();
+        require!(
+            !pair_address.is_zero(),
+            "No router pair for the given tokens"
+        );
+
+        let caller = self.blockchain().get_caller();
+        let order_id = self.last_order_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let order = Order {
+            id: order_id,
+            creator: caller.clone(),
+            pair_address: pair_address.clone(),
+            input_tokens: payment,
+            output_token_id: token_out,
+            amount_out_min,
+            remaining_slices: slices,
+            blocks_between_slices,
+            next_slice_block: self.blockchain().get_block_nonce(),
+        };
+
+        self.orders(order_id).set(&order);
+        let _ = self.user_orders(&caller).insert(order_id);
+        let _ = self.pair_orders(&pair_address).insert(order_id);
+
+        self.emit_create_order_event(&order);
+
+        order_id
+    }
+
+    fn remove_order(&self, order: &Order<Self::Api>) {
+        self.orders(order.id).clear();
+        let _ = self.user_orders(&order.creator).swap_remove(&order.id);
+        let _ = self.pair_orders(&order.pair_address).swap_remove(&order.id);
+    }
+
+    fn get_order_amount_out(
+        &self,
+        pair_address: &ManagedAddress,
+        input: &EsdtTokenPayment,
+    ) -> BigUint {
+        self.order_pair_proxy(pair_address.clone())
+            .get_amount_out_view(input.token_identifier.clone(), input.amount.clone())
+            .execute_on_dest_context()
+    }
+
+    fn get_execution_fee_amount(&self, amount_out: &BigUint) -> BigUint {
+        amount_out * self.execution_fee_percentage().get() / MAX_PERCENTAGE
+    }
+
+    #[proxy]
+    fn order_pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+
+    #[view(getOrder)]
+    #[storage_mapper("orders")]
+    fn orders(&self, order_id: OrderId) -> SingleValueMapper<Order<Self::Api>>;
+
+    #[view(getLastOrderId)]
+    #[storage_mapper("lastOrderId")]
+    fn last_order_id(&self) -> SingleValueMapper<OrderId>;
+
+    #[storage_mapper("userOrders")]
+    fn user_orders(&self, user: &ManagedAddress) -> UnorderedSetMapper<OrderId>;
+
+    #[storage_mapper("pairOrders")]
+    fn pair_orders(&self, pair_address: &ManagedAddress) -> UnorderedSetMapper<OrderId>;
+}
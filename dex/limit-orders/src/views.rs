@@ -0,0 +1,50 @@
+multiversx_sc::imports!();
+
+use crate::orders::{Order, OrderId};
+
+#[multiversx_sc::module]
+pub trait ViewsModule:
+    crate::orders::OrdersModule + crate::config::ConfigModule + crate::events::EventsModule
+{
+    #[view(getUserOrders)]
+    fn get_user_orders(&self, user: ManagedAddress) -> MultiValueEncoded<Order<Self::Api>> {
+        self.get_orders(self.user_orders(&user).iter())
+    }
+
+    #[view(getPairOrders)]
+    fn get_pair_orders(&self, pair_address: ManagedAddress) -> MultiValueEncoded<Order<Self::Api>> {
+        self.get_orders(self.pair_orders(&pair_address).iter())
+    }
+
+    /// Keepers can use this to check if the next slice of the order can be executed at the current block
+    #[view(canExecuteOrder)]
+    fn can_execute_order(&self, order_id: OrderId) -> bool {
+        let order_mapper = self.orders(order_id);
+        if order_mapper.is_empty() {
+            return false;
+        }
+
+        let order = order_mapper.get();
+        if self.blockchain().get_block_nonce() < order.next_slice_block {
+            return false;
+        }
+
+        let (slice_input, slice_amount_out_min) = order.get_next_slice();
+        let amount_out = self.get_order_amount_out(&order.pair_address, &slice_input);
+        let execution_fee_amount = self.get_execution_fee_amount(&amount_out);
+
+        amount_out - execution_fee_amount >= slice_amount_out_min
+    }
+
+    fn get_orders(
+        &self,
+        order_ids: impl Iterator<Item = OrderId>,
+    ) -> MultiValueEncoded<Order<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for order_id in order_ids {
+            result.push(self.orders(order_id).get());
+        }
+
+        result
+    }
+}
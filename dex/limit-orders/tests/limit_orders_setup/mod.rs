@@ -0,0 +1,252 @@
+#![allow(dead_code)]
+#![allow(deprecated)]
+
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, EsdtLocalRole, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    whitebox_legacy::{TxResult, TxTokenTransfer, *},
+    DebugApi,
+};
+
+use limit_orders::{orders::OrdersModule, LimitOrders};
+use pair::{
+    config::ConfigModule as PairConfigModule, pair_actions::add_liq::AddLiquidityModule, Pair,
+};
+use pausable::{PausableModule, State};
+use router::{config::ConfigModule as RouterConfigModule, factory::PairTokens, Router};
+
+pub const MEX_TOKEN_ID: &[u8] = b"MEX-abcdef";
+pub const WEGLD_TOKEN_ID: &[u8] = b"WEGLD-abcdef";
+pub const LP_TOKEN_ID: &[u8] = b"LPTOK-abcdef";
+
+pub const POOL_LIQUIDITY: u64 = 1_000_000_000;
+pub const USER_BALANCE: u64 = 10_000_000;
+pub const EXECUTION_FEE_PERCENTAGE: u64 = 100;
+
+pub struct LimitOrdersSetup<LimitOrdersObjBuilder, RouterObjBuilder, PairObjBuilder>
+where
+    LimitOrdersObjBuilder: 'static + Copy + Fn() -> limit_orders::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub user_address: Address,
+    pub keeper_address: Address,
+    pub limit_orders_wrapper:
+        ContractObjWrapper<limit_orders::ContractObj<DebugApi>, LimitOrdersObjBuilder>,
+    pub router_wrapper: ContractObjWrapper<router::ContractObj<DebugApi>, RouterObjBuilder>,
+    pub pair_wrapper: ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+}
+
+impl<LimitOrdersObjBuilder, RouterObjBuilder, PairObjBuilder>
+    LimitOrdersSetup<LimitOrdersObjBuilder, RouterObjBuilder, PairObjBuilder>
+where
+    LimitOrdersObjBuilder: 'static + Copy + Fn() -> limit_orders::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    pub fn new(
+        limit_orders_builder: LimitOrdersObjBuilder,
+        router_builder: RouterObjBuilder,
+        pair_builder: PairObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let user_address = b_mock.create_user_account(&rust_zero);
+        let keeper_address = b_mock.create_user_account(&rust_zero);
+
+        let router_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            router_builder,
+            "router path",
+        );
+        let pair_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(router_wrapper.address_ref()),
+            pair_builder,
+            "pair path",
+        );
+        let limit_orders_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            limit_orders_builder,
+            "limit orders path",
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_address!(router_wrapper.address_ref()),
+                    managed_address!(&owner_address),
+                    300,
+                    50,
+                    ManagedAddress::zero(),
+                    MultiValueEncoded::new(),
+                );
+                sc.lp_token_identifier()
+                    .set(&managed_token_id!(LP_TOKEN_ID));
+                sc.state().set(State::Active);
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            pair_wrapper.address_ref(),
+            LP_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &router_wrapper, &rust_zero, |sc| {
+                sc.init(OptionalValue::None);
+                sc.pair_map().insert(
+                    PairTokens {
+                        first_token_id: managed_token_id!(WEGLD_TOKEN_ID),
+                        second_token_id: managed_token_id!(MEX_TOKEN_ID),
+                    },
+                    managed_address!(pair_wrapper.address_ref()),
+                );
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_tx(&owner_address, &limit_orders_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_address!(router_wrapper.address_ref()),
+                    EXECUTION_FEE_PERCENTAGE,
+                );
+            })
+            .assert_ok();
+
+        // 1 WEGLD = 1 MEX
+        b_mock.set_esdt_balance(
+            &owner_address,
+            WEGLD_TOKEN_ID,
+            &rust_biguint!(POOL_LIQUIDITY),
+        );
+        b_mock.set_esdt_balance(&owner_address, MEX_TOKEN_ID, &rust_biguint!(POOL_LIQUIDITY));
+        let payments = vec![
+            TxTokenTransfer {
+                token_identifier: WEGLD_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_LIQUIDITY),
+            },
+            TxTokenTransfer {
+                token_identifier: MEX_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_LIQUIDITY),
+            },
+        ];
+        b_mock
+            .execute_esdt_multi_transfer(&owner_address, &pair_wrapper, &payments, |sc| {
+                let _ = sc.add_liquidity(managed_biguint!(1), managed_biguint!(1));
+            })
+            .assert_ok();
+
+        b_mock.set_esdt_balance(&user_address, WEGLD_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+
+        LimitOrdersSetup {
+            b_mock,
+            owner_address,
+            user_address,
+            keeper_address,
+            limit_orders_wrapper,
+            router_wrapper,
+            pair_wrapper,
+        }
+    }
+
+    pub fn create_limit_order(&mut self, amount: u64, amount_out_min: u64) -> TxResult {
+        self.b_mock.execute_esdt_transfer(
+            &self.user_address,
+            &self.limit_orders_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                let _ = sc.create_limit_order(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(amount_out_min),
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+
+    pub fn create_twap_order(
+        &mut self,
+        amount: u64,
+        amount_out_min: u64,
+        slices: u32,
+        blocks_between_slices: u64,
+    ) -> TxResult {
+        self.b_mock.execute_esdt_transfer(
+            &self.user_address,
+            &self.limit_orders_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                let _ = sc.create_twap_order(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_biguint!(amount_out_min),
+                    slices,
+                    blocks_between_slices,
+                    OptionalValue::None,
+                );
+            },
+        )
+    }
+
+    pub fn execute_order(&mut self, caller: &Address, order_id: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            caller,
+            &self.limit_orders_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.execute_order(order_id);
+            },
+        )
+    }
+
+    pub fn cancel_order(&mut self, caller: &Address, order_id: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            caller,
+            &self.limit_orders_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.cancel_order(order_id);
+            },
+        )
+    }
+
+    /// Sells MEX into the pair, increasing the MEX price of WEGLD
+    pub fn sell_mex(&mut self, amount: u64) {
+        let owner_address = self.owner_address.clone();
+        self.b_mock
+            .set_esdt_balance(&owner_address, MEX_TOKEN_ID, &rust_biguint!(amount));
+        self.b_mock
+            .execute_esdt_transfer(
+                &owner_address,
+                &self.pair_wrapper,
+                MEX_TOKEN_ID,
+                0,
+                &rust_biguint!(amount),
+                |sc| {
+                    use pair::pair_actions::swap::SwapModule;
+
+                    let _ = sc.swap_tokens_fixed_input(
+                        managed_token_id!(WEGLD_TOKEN_ID),
+                        managed_biguint!(1),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+}
@@ -0,0 +1,262 @@
+#![allow(deprecated)]
+
+mod limit_orders_setup;
+
+use limit_orders::{orders::OrdersModule, views::ViewsModule};
+use limit_orders_setup::*;
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id, rust_biguint};
+
+#[test]
+fn limit_orders_setup_test() {
+    let _ = LimitOrdersSetup::new(
+        limit_orders::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+}
+
+#[test]
+fn create_order_validation_test() {
+    let mut setup = LimitOrdersSetup::new(
+        limit_orders::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+    let user_address = setup.user_address.clone();
+
+    setup
+        .create_limit_order(1_000_000, 0)
+        .assert_user_error("Invalid amount out min");
+    setup
+        .create_twap_order(1_000_000, 900_000, 1, 10)
+        .assert_user_error("Invalid number of slices");
+    setup
+        .create_twap_order(1_000_000, 900_000, 2, 0)
+        .assert_user_error("Invalid blocks between slices");
+    setup
+        .create_twap_order(2, 1, 3, 10)
+        .assert_user_error("Order amount too low");
+
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &setup.limit_orders_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000_000),
+            |sc| {
+                let _ = sc.create_limit_order(
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Input and output tokens must differ");
+
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &setup.limit_orders_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000_000),
+            |sc| {
+                let _ = sc.create_limit_order(
+                    managed_token_id!(LP_TOKEN_ID),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("No router pair for the given tokens");
+
+    setup
+        .b_mock
+        .check_esdt_balance(&user_address, WEGLD_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+}
+
+#[test]
+fn limit_order_execution_test() {
+    let mut setup = LimitOrdersSetup::new(
+        limit_orders::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+    let user_address = setup.user_address.clone();
+    let keeper_address = setup.keeper_address.clone();
+
+    // ~996_000 MEX at the current price, before the execution fee
+    setup.create_limit_order(1_000_000, 1_000_000).assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 1_000_000),
+    );
+
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            assert!(!sc.can_execute_order(1));
+            assert!(!sc.can_execute_order(2));
+        })
+        .assert_ok();
+    setup
+        .execute_order(&keeper_address, 1)
+        .assert_user_error("Target price not reached");
+    setup
+        .execute_order(&keeper_address, 2)
+        .assert_user_error("Order not found");
+
+    // WEGLD price goes up
+    setup.sell_mex(50_000_000);
+
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            assert!(sc.can_execute_order(1));
+        })
+        .assert_ok();
+    setup.execute_order(&keeper_address, 1).assert_ok();
+
+    let user_mex_balance = setup
+        .b_mock
+        .get_esdt_balance(&user_address, MEX_TOKEN_ID, 0);
+    let keeper_mex_balance = setup
+        .b_mock
+        .get_esdt_balance(&keeper_address, MEX_TOKEN_ID, 0);
+    assert!(user_mex_balance >= rust_biguint!(1_000_000));
+    assert_eq!(
+        keeper_mex_balance,
+        (&user_mex_balance + &keeper_mex_balance) * EXECUTION_FEE_PERCENTAGE / 10_000u64
+    );
+
+    // the order is removed once filled
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            assert!(sc.orders(1).is_empty());
+            assert_eq!(sc.get_user_orders(managed_address!(&user_address)).len(), 0);
+        })
+        .assert_ok();
+    setup
+        .execute_order(&keeper_address, 1)
+        .assert_user_error("Order not found");
+}
+
+#[test]
+fn twap_order_execution_test() {
+    let mut setup = LimitOrdersSetup::new(
+        limit_orders::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+    let user_address = setup.user_address.clone();
+    let keeper_address = setup.keeper_address.clone();
+    let limit_orders_address = setup.limit_orders_wrapper.address_ref().clone();
+
+    setup
+        .create_twap_order(1_000_000, 900_000, 3, 10)
+        .assert_ok();
+
+    // first slice
+    setup.execute_order(&keeper_address, 1).assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &limit_orders_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(666_667),
+    );
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            let order = sc.orders(1).get();
+            assert_eq!(order.remaining_slices, 2);
+            assert_eq!(order.amount_out_min, managed_biguint!(600_001));
+            assert_eq!(order.next_slice_block, 10);
+        })
+        .assert_ok();
+
+    setup
+        .execute_order(&keeper_address, 1)
+        .assert_user_error("Slice cannot be executed yet");
+
+    // second slice
+    setup.b_mock.set_block_nonce(10);
+    setup.execute_order(&keeper_address, 1).assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &limit_orders_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(333_334),
+    );
+
+    // last slice uses all the remaining tokens
+    setup.b_mock.set_block_nonce(20);
+    setup.execute_order(&keeper_address, 1).assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&limit_orders_address, WEGLD_TOKEN_ID, &rust_biguint!(0));
+
+    let user_mex_balance = setup
+        .b_mock
+        .get_esdt_balance(&user_address, MEX_TOKEN_ID, 0);
+    assert!(user_mex_balance >= rust_biguint!(900_000));
+
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            assert!(sc.orders(1).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn cancel_order_test() {
+    let mut setup = LimitOrdersSetup::new(
+        limit_orders::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+    let user_address = setup.user_address.clone();
+    let keeper_address = setup.keeper_address.clone();
+    let pair_address = setup.pair_wrapper.address_ref().clone();
+
+    setup.create_limit_order(1_000_000, 2_000_000).assert_ok();
+    setup
+        .create_twap_order(3_000_000, 6_000_000, 3, 10)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            assert_eq!(sc.get_user_orders(managed_address!(&user_address)).len(), 2);
+            assert_eq!(sc.get_pair_orders(managed_address!(&pair_address)).len(), 2);
+        })
+        .assert_ok();
+
+    setup
+        .cancel_order(&keeper_address, 2)
+        .assert_user_error("Only the order creator can cancel");
+    setup.cancel_order(&user_address, 2).assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &user_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 1_000_000),
+    );
+
+    setup
+        .b_mock
+        .execute_query(&setup.limit_orders_wrapper, |sc| {
+            let user_orders = sc.get_user_orders(managed_address!(&user_address));
+            assert_eq!(user_orders.len(), 1);
+            assert_eq!(user_orders.into_iter().next().unwrap().id, 1);
+            assert_eq!(sc.get_pair_orders(managed_address!(&pair_address)).len(), 1);
+        })
+        .assert_ok();
+
+    setup
+        .cancel_order(&user_address, 2)
+        .assert_user_error("Order not found");
+}
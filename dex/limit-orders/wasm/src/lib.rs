@@ -0,0 +1,39 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           13
+// Async Callback (empty):               1
+// Total number of exported functions:  16
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    limit_orders
+    (
+        init => init
+        upgrade => upgrade
+        setRouterAddress => set_router_address
+        setExecutionFeePercentage => set_execution_fee_percentage
+        getRouterAddress => router_address
+        getExecutionFeePercentage => execution_fee_percentage
+        createLimitOrder => create_limit_order
+        createTwapOrder => create_twap_order
+        executeOrder => execute_order
+        cancelOrder => cancel_order
+        getOrder => orders
+        getLastOrderId => last_order_id
+        getUserOrders => get_user_orders
+        getPairOrders => get_pair_orders
+        canExecuteOrder => can_execute_order
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
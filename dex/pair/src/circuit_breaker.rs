@@ -0,0 +1,170 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use pausable::State;
+
+use crate::{
+    amm, config,
+    config::MAX_PERCENTAGE,
+    errors::{ERROR_CIRCUIT_BREAKER_TRIPPED, ERROR_INVALID_ARGS},
+    events, read_pair_storage, safe_price, safe_price_view,
+    safe_price_view::PriceObservationWeightedAmounts,
+};
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
+pub enum CircuitBreakerAction {
+    RejectSwap,
+    DisableSwaps,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct CircuitBreakerConfig {
+    pub max_price_deviation: u64,
+    pub twap_window_rounds: u64,
+    pub action: CircuitBreakerAction,
+}
+
+#[multiversx_sc::module]
+pub trait CircuitBreakerModule:
+    safe_price::SafePriceModule
+    + safe_price_view::SafePriceViewModule
+    + config::ConfigModule
+    + token_send::TokenSendModule
+    + amm::AmmModule
+    + permissions_module::PermissionsModule
+    + pausable::PausableModule
+    + read_pair_storage::ReadPairStorageModule
+    + events::EventsModule
+{
+    /// After each swap, the new spot price is compared with the TWAP over the last twap_window_rounds.
+    /// If it deviates by more than max_price_deviation (out of MAX_PERCENTAGE),
+    /// the swap is either rejected, or it goes through and the pair is moved into PartialActive,
+    /// which disables further swaps until the pair is resumed.
+    #[endpoint(setCircuitBreaker)]
+    fn set_circuit_breaker(
+        &self,
+        max_price_deviation: u64,
+        twap_window_rounds: u64,
+        action: CircuitBreakerAction,
+    ) {
+        self.require_caller_has_owner_or_admin_permissions();
+        require!(
+            max_price_deviation > 0 && max_price_deviation <= MAX_PERCENTAGE,
+            ERROR_INVALID_ARGS
+        );
+        require!(twap_window_rounds > 0, ERROR_INVALID_ARGS);
+
+        self.circuit_breaker_config().set(CircuitBreakerConfig {
+            max_price_deviation,
+            twap_window_rounds,
+            action,
+        });
+    }
+
+    #[endpoint(removeCircuitBreaker)]
+    fn remove_circuit_breaker(&self) {
+        self.require_caller_has_owner_or_admin_permissions();
+        self.circuit_breaker_config().clear();
+    }
+
+    /// The check is skipped while there aren't enough price observations for the TWAP window
+    fn check_circuit_breaker(&self, first_token_reserve: &BigUint, second_token_reserve: &BigUint) {
+        let config_mapper = self.circuit_breaker_config();
+        if config_mapper.is_empty() {
+            return;
+        }
+
+        let config = config_mapper.get();
+        let opt_twap_amounts = self.get_twap_weighted_amounts(config.twap_window_rounds);
+        let twap_amounts = match opt_twap_amounts {
+            Some(twap_amounts) => twap_amounts,
+            None => return,
+        };
+        if twap_amounts.weighted_first_token_reserve == 0
+            || twap_amounts.weighted_second_token_reserve == 0
+            || first_token_reserve == &0u64
+        {
+            return;
+        }
+
+        // both prices are scaled by first_token_reserve * weighted_first_token_reserve
+        let spot_price_scaled = second_token_reserve * &twap_amounts.weighted_first_token_reserve;
+        let twap_price_scaled = &twap_amounts.weighted_second_token_reserve * first_token_reserve;
+        let price_diff = if spot_price_scaled > twap_price_scaled {
+            &spot_price_scaled - &twap_price_scaled
+        } else {
+            &twap_price_scaled - &spot_price_scaled
+        };
+        let price_deviation = price_diff * MAX_PERCENTAGE / twap_price_scaled;
+        if price_deviation <= config.max_price_deviation {
+            return;
+        }
+
+        match config.action {
+            CircuitBreakerAction::RejectSwap => sc_panic!(ERROR_CIRCUIT_BREAKER_TRIPPED),
+            CircuitBreakerAction::DisableSwaps => {
+                self.state().set(State::PartialActive);
+                self.emit_circuit_breaker_tripped_event(
+                    first_token_reserve,
+                    second_token_reserve,
+                    twap_amounts.weighted_first_token_reserve,
+                    twap_amounts.weighted_second_token_reserve,
+                    price_deviation,
+                );
+            }
+        }
+    }
+
+    fn get_twap_weighted_amounts(
+        &self,
+        twap_window_rounds: u64,
+    ) -> Option<PriceObservationWeightedAmounts<Self::Api>> {
+        let pair_address = self.blockchain().get_sc_address();
+        let price_observations = self.get_price_observation_mapper(pair_address.clone());
+        if price_observations.is_empty() {
+            return None;
+        }
+
+        let current_round = self.blockchain().get_block_round();
+        if twap_window_rounds >= current_round {
+            return None;
+        }
+
+        let start_round = current_round - twap_window_rounds;
+        let safe_price_current_index = self
+            .get_safe_price_current_index_mapper(pair_address.clone())
+            .get();
+        let oldest_price_observation =
+            self.get_oldest_price_observation(safe_price_current_index, &price_observations);
+        if oldest_price_observation.recording_round > start_round {
+            return None;
+        }
+
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        let first_price_observation = self.get_price_observation(
+            &pair_address,
+            &first_token_id,
+            &second_token_id,
+            safe_price_current_index,
+            &price_observations,
+            start_round,
+        );
+        let last_price_observation = self.get_price_observation(
+            &pair_address,
+            &first_token_id,
+            &second_token_id,
+            safe_price_current_index,
+            &price_observations,
+            current_round,
+        );
+
+        Some(self.compute_weighted_amounts(&first_price_observation, &last_price_observation))
+    }
+
+    #[view(getCircuitBreakerConfig)]
+    #[storage_mapper("circuit_breaker_config")]
+    fn circuit_breaker_config(&self) -> SingleValueMapper<CircuitBreakerConfig>;
+}
@@ -68,6 +68,20 @@ pub struct RemoveLiquidityEvent<M: ManagedTypeApi> {
     timestamp: u64,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct CircuitBreakerTrippedEvent<M: ManagedTypeApi> {
+    first_token_id: TokenIdentifier<M>,
+    second_token_id: TokenIdentifier<M>,
+    first_token_reserve: BigUint<M>,
+    second_token_reserve: BigUint<M>,
+    twap_first_token_reserve: BigUint<M>,
+    twap_second_token_reserve: BigUint<M>,
+    price_deviation: BigUint<M>,
+    block: u64,
+    epoch: u64,
+    timestamp: u64,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule:
     crate::config::ConfigModule
@@ -188,6 +202,36 @@ pub trait EventsModule:
         )
     }
 
+    fn emit_circuit_breaker_tripped_event(
+        &self,
+        first_token_reserve: &BigUint,
+        second_token_reserve: &BigUint,
+        twap_first_token_reserve: BigUint,
+        twap_second_token_reserve: BigUint,
+        price_deviation: BigUint,
+    ) {
+        let epoch = self.blockchain().get_block_epoch();
+        let first_token_id = self.first_token_id().get();
+        let second_token_id = self.second_token_id().get();
+        self.circuit_breaker_tripped_event(
+            &first_token_id,
+            &second_token_id,
+            epoch,
+            &CircuitBreakerTrippedEvent {
+                first_token_id: first_token_id.clone(),
+                second_token_id: second_token_id.clone(),
+                first_token_reserve: first_token_reserve.clone(),
+                second_token_reserve: second_token_reserve.clone(),
+                twap_first_token_reserve,
+                twap_second_token_reserve,
+                price_deviation,
+                block: self.blockchain().get_block_nonce(),
+                epoch,
+                timestamp: self.blockchain().get_block_timestamp(),
+            },
+        )
+    }
+
     #[event("swap")]
     fn swap_event(
         &self,
@@ -226,4 +270,13 @@ pub trait EventsModule:
         #[indexed] epoch: u64,
         remove_liquidity_event: &RemoveLiquidityEvent<Self::Api>,
     );
+
+    #[event("circuit_breaker_tripped")]
+    fn circuit_breaker_tripped_event(
+        &self,
+        #[indexed] first_token: &TokenIdentifier,
+        #[indexed] second_token: &TokenIdentifier,
+        #[indexed] epoch: u64,
+        circuit_breaker_tripped_event: &CircuitBreakerTrippedEvent<Self::Api>,
+    );
 }
@@ -4,6 +4,7 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 mod amm;
+pub mod circuit_breaker;
 pub mod config;
 mod contexts;
 pub mod errors;
@@ -37,6 +38,7 @@ pub trait Pair<ContractReader>:
     + read_pair_storage::ReadPairStorageModule
     + safe_price::SafePriceModule
     + safe_price_view::SafePriceViewModule
+    + circuit_breaker::CircuitBreakerModule
     + contexts::output_builder::OutputBuilderModule
     + locking_wrapper::LockingWrapperModule
     + permissions_module::PermissionsModule
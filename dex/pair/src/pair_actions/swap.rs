@@ -23,6 +23,9 @@ pub trait SwapModule:
     + crate::locking_wrapper::LockingWrapperModule
     + crate::events::EventsModule
     + crate::safe_price::SafePriceModule
+    + crate::safe_price_view::SafePriceViewModule
+    + crate::read_pair_storage::ReadPairStorageModule
+    + crate::circuit_breaker::CircuitBreakerModule
     + crate::fee::FeeModule
     + crate::config::ConfigModule
     + token_send::TokenSendModule
@@ -147,6 +150,11 @@ pub trait SwapModule:
             );
         }
 
+        self.check_circuit_breaker(
+            &storage_cache.first_token_reserve,
+            &storage_cache.second_token_reserve,
+        );
+
         let caller = self.blockchain().get_caller();
         let output_payments = self.build_swap_output_payments(&swap_context);
 
@@ -219,6 +227,11 @@ pub trait SwapModule:
             );
         }
 
+        self.check_circuit_breaker(
+            &storage_cache.first_token_reserve,
+            &storage_cache.second_token_reserve,
+        );
+
         let caller = self.blockchain().get_caller();
         let output_payments = self.build_swap_output_payments(&swap_context);
 
@@ -13,10 +13,10 @@ use crate::{
 pub const DEFAULT_SAFE_PRICE_ROUNDS_OFFSET: u64 = 10 * 60;
 pub const SECONDS_PER_ROUND: u64 = 6;
 
-struct PriceObservationWeightedAmounts<M: ManagedTypeApi> {
-    weighted_first_token_reserve: BigUint<M>,
-    weighted_second_token_reserve: BigUint<M>,
-    weighted_lp_supply: BigUint<M>,
+pub struct PriceObservationWeightedAmounts<M: ManagedTypeApi> {
+    pub weighted_first_token_reserve: BigUint<M>,
+    pub weighted_second_token_reserve: BigUint<M>,
+    pub weighted_lp_supply: BigUint<M>,
 }
 
 #[multiversx_sc::module]
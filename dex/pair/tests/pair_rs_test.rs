@@ -20,6 +20,7 @@ use multiversx_sc_scenario::{
     whitebox_legacy::TxTokenTransfer, DebugApi,
 };
 use pair::{
+    circuit_breaker::{CircuitBreakerAction, CircuitBreakerModule},
     config::MAX_PERCENTAGE,
     fee::FeeModule,
     locking_wrapper::LockingWrapperModule,
@@ -27,6 +28,7 @@ use pair::{
     safe_price::{PriceObservation, Round, SafePriceModule},
 };
 use pair_setup::*;
+use pausable::{PausableModule, State};
 use simple_lock::{
     locked_token::{LockedTokenAttributes, LockedTokenModule},
     proxy_lp::{LpProxyTokenAttributes, ProxyLpModule},
@@ -1650,3 +1652,89 @@ fn fees_collector_pair_test() {
         })
         .assert_ok();
 }
+
+#[test]
+fn test_circuit_breaker() {
+    let mut pair_setup = PairSetup::new(pair::contract_obj);
+    let rust_zero = rust_biguint!(0);
+
+    pair_setup.b_mock.set_block_round(1_001);
+    pair_setup.add_liquidity(
+        1_001_000, 1_000_000, 1_001_000, 1_000_000, 1_000_000, 1_001_000, 1_001_000,
+    );
+
+    // record some price observations
+    pair_setup.b_mock.set_block_round(1_011);
+    pair_setup.swap_fixed_input(WEGLD_TOKEN_ID, 1_000, MEX_TOKEN_ID, 900, 996);
+    pair_setup.b_mock.set_block_round(1_021);
+    pair_setup.swap_fixed_input(MEX_TOKEN_ID, 1_000, WEGLD_TOKEN_ID, 900, 997);
+
+    // 5% max deviation from the TWAP over 10 rounds
+    let owner_address = pair_setup.owner_address.clone();
+    pair_setup
+        .b_mock
+        .execute_tx(&owner_address, &pair_setup.pair_wrapper, &rust_zero, |sc| {
+            sc.set_circuit_breaker(5_000, 10, CircuitBreakerAction::RejectSwap);
+        })
+        .assert_ok();
+
+    // big swap is rejected
+    pair_setup.b_mock.set_block_round(1_031);
+    let user_address = pair_setup.user_address.clone();
+    pair_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &pair_setup.pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(200_000),
+            |sc| {
+                let _ = sc
+                    .swap_tokens_fixed_input(managed_token_id!(MEX_TOKEN_ID), managed_biguint!(1));
+            },
+        )
+        .assert_user_error("Price deviation from TWAP too high");
+
+    // small swap goes through
+    pair_setup.swap_fixed_input(WEGLD_TOKEN_ID, 1_000, MEX_TOKEN_ID, 900, 996);
+
+    pair_setup
+        .b_mock
+        .execute_tx(&owner_address, &pair_setup.pair_wrapper, &rust_zero, |sc| {
+            sc.set_circuit_breaker(5_000, 10, CircuitBreakerAction::DisableSwaps);
+        })
+        .assert_ok();
+
+    // big swap goes through, but swaps are disabled afterwards
+    pair_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &pair_setup.pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(200_000),
+            |sc| {
+                let _ = sc
+                    .swap_tokens_fixed_input(managed_token_id!(MEX_TOKEN_ID), managed_biguint!(1));
+                assert_eq!(sc.state().get(), State::PartialActive);
+            },
+        )
+        .assert_ok();
+
+    pair_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &pair_setup.pair_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                let _ = sc
+                    .swap_tokens_fixed_input(managed_token_id!(MEX_TOKEN_ID), managed_biguint!(1));
+            },
+        )
+        .assert_user_error("Swap is not enabled");
+}
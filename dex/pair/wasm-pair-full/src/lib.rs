@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           63
+// Endpoints:                           66
 // Async Callback (empty):               1
-// Total number of exported functions:  66
+// Total number of exported functions:  69
 
 #![no_std]
 
@@ -47,6 +47,9 @@ multiversx_sc_wasm_adapter::endpoints! {
         getSafePriceCurrentIndex => safe_price_current_index
         updateAndGetTokensForGivenPositionWithSafePrice => update_and_get_tokens_for_given_position_with_safe_price
         updateAndGetSafePrice => update_and_get_safe_price
+        setCircuitBreaker => set_circuit_breaker
+        removeCircuitBreaker => remove_circuit_breaker
+        getCircuitBreakerConfig => circuit_breaker_config
         setLockingDeadlineEpoch => set_locking_deadline_epoch
         setLockingScAddress => set_locking_sc_address
         setUnlockEpoch => set_unlock_epoch
@@ -0,0 +1,730 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    phase::Phase,
+    redeem_token::{ACCEPTED_TOKEN_REDEEM_NONCE, LAUNCHED_TOKEN_REDEEM_NONCE},
+    BELOW_MIN_PRICE_ERR_MSG, INVALID_PAYMENT_ERR_MSG,
+};
+
+pub const MAX_BATCH_PRICE_LEVELS: usize = 50;
+
+static AUCTION_NOT_ACTIVE_ERR_MSG: &[u8] = b"Auction not active";
+static AUCTION_SOLD_OUT_ERR_MSG: &[u8] = b"Auction sold out";
+static NOT_AUCTION_LAUNCH_ERR_MSG: &[u8] = b"Not an auction launch";
+static NOT_BATCH_AUCTION_ERR_MSG: &[u8] = b"Not a batch auction";
+
+/// How the launched tokens are sold.
+/// Both auction types run between the start and the end block,
+/// and end with every bidder paying the same clearing price.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum LaunchMechanism<M: ManagedTypeApi> {
+    PriceDiscovery,
+    /// The price decreases linearly from start_price to end_price.
+    /// The auction closes early once the commitments buy all the launched tokens
+    /// or reach max_raise.
+    DutchAuction {
+        start_price: BigUint<M>,
+        end_price: BigUint<M>,
+        min_raise: BigUint<M>,
+        max_raise: BigUint<M>,
+    },
+    /// Sealed bids with a max price, cleared at a single price after the auction ends.
+    /// Bids opening a new price level must be at least min_price_level_bid,
+    /// so the limited price levels cannot be filled with dust bids.
+    BatchAuction {
+        min_raise: BigUint<M>,
+        max_raise: BigUint<M>,
+        min_price_level_bid: BigUint<M>,
+    },
+}
+
+impl<M: ManagedTypeApi> LaunchMechanism<M> {
+    pub fn is_auction(&self) -> bool {
+        !matches!(self, LaunchMechanism::PriceDiscovery)
+    }
+
+    pub fn get_min_raise(&self) -> BigUint<M> {
+        match self {
+            LaunchMechanism::PriceDiscovery => BigUint::zero(),
+            LaunchMechanism::DutchAuction { min_raise, .. }
+            | LaunchMechanism::BatchAuction { min_raise, .. } => min_raise.clone(),
+        }
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct BidAttributes<M: ManagedTypeApi> {
+    pub max_price: BigUint<M>,
+}
+
+/// Bids exactly at the clearing price are filled in the
+/// marginal_fill_numerator / marginal_fill_denominator ratio. Higher bids are filled entirely.
+/// If the raise is below min_raise, the auction is not successful and everyone is refunded.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct AuctionResult<M: ManagedTypeApi> {
+    pub clearing_price: BigUint<M>,
+    pub marginal_fill_numerator: BigUint<M>,
+    pub marginal_fill_denominator: BigUint<M>,
+    pub launched_tokens_sold: BigUint<M>,
+    pub accepted_tokens_raised: BigUint<M>,
+    pub successful: bool,
+}
+
+impl<M: ManagedTypeApi> AuctionResult<M> {
+    pub fn new_full_fill(
+        clearing_price: BigUint<M>,
+        launched_tokens_sold: BigUint<M>,
+        accepted_tokens_raised: BigUint<M>,
+    ) -> Self {
+        AuctionResult {
+            clearing_price,
+            marginal_fill_numerator: BigUint::from(1u32),
+            marginal_fill_denominator: BigUint::from(1u32),
+            launched_tokens_sold,
+            accepted_tokens_raised,
+            successful: false,
+        }
+    }
+}
+
+pub struct AuctionRedeemShares<M: ManagedTypeApi> {
+    pub bought_tokens: EgldOrEsdtTokenPayment<M>,
+    pub refund: EgldOrEsdtTokenPayment<M>,
+}
+
+#[multiversx_sc::module]
+pub trait AuctionModule:
+    crate::common_storage::CommonStorageModule
+    + crate::events::EventsModule
+    + crate::phase::PhaseModule
+    + crate::redeem_token::RedeemTokenModule
+    + crate::participation::ParticipationModule
+    + crate::vesting::VestingModule
+    + locking_module::locking_module::LockingModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    fn set_launch_mechanism(&self, launch_mechanism: LaunchMechanism<Self::Api>) {
+        match &launch_mechanism {
+            LaunchMechanism::PriceDiscovery => {}
+            LaunchMechanism::DutchAuction {
+                start_price,
+                end_price,
+                min_raise,
+                max_raise,
+            } => {
+                require!(
+                    start_price >= end_price && *end_price > 0,
+                    "Invalid auction prices"
+                );
+                require!(
+                    *end_price >= self.min_launched_token_price().get(),
+                    BELOW_MIN_PRICE_ERR_MSG
+                );
+                require!(
+                    *max_raise > 0 && min_raise <= max_raise,
+                    "Invalid raise limits"
+                );
+            }
+            LaunchMechanism::BatchAuction {
+                min_raise,
+                max_raise,
+                min_price_level_bid,
+            } => {
+                require!(
+                    *max_raise > 0 && min_raise <= max_raise,
+                    "Invalid raise limits"
+                );
+                require!(*min_price_level_bid > 0, "Invalid min price level bid");
+            }
+        }
+        if launch_mechanism.is_auction() {
+            require!(
+                self.end_block().get() > self.start_block().get(),
+                "Invalid auction duration"
+            );
+        }
+
+        self.launch_mechanism().set(launch_mechanism);
+    }
+
+    /// Launched tokens may only be deposited before the auction starts.
+    /// During a dutch auction, accepted tokens are committed at the current price,
+    /// and the part over the remaining allocation is refunded.
+    fn deposit_into_auction(
+        &self,
+        launch_mechanism: &LaunchMechanism<Self::Api>,
+        phase: Phase<Self::Api>,
+    ) -> EsdtTokenPayment<Self::Api> {
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        let caller = self.blockchain().get_caller();
+        let launched_token_id = self.launched_token_id().get();
+        let accepted_token_id = self.accepted_token_id().get();
+        let (redeem_token_nonce, redeem_token_amount, current_price) =
+            if payment_token == launched_token_id {
+                require!(
+                    phase == Phase::Idle,
+                    "Launched tokens must be deposited before the auction"
+                );
+
+                self.launched_token_balance()
+                    .update(|balance| *balance += &payment_amount);
+
+                (
+                    LAUNCHED_TOKEN_REDEEM_NONCE,
+                    payment_amount.clone(),
+                    BigUint::zero(),
+                )
+            } else if payment_token == accepted_token_id {
+                require!(phase == Phase::Auction, AUCTION_NOT_ACTIVE_ERR_MSG);
+
+                let (start_price, end_price, max_raise) = match launch_mechanism {
+                    LaunchMechanism::DutchAuction {
+                        start_price,
+                        end_price,
+                        max_raise,
+                        ..
+                    } => (start_price, end_price, max_raise),
+                    _ => sc_panic!("Bids must be placed through placeBid"),
+                };
+
+                let current_price = self.get_dutch_auction_price(start_price, end_price);
+                let committed_amount = self.commit_to_dutch_auction(
+                    &caller,
+                    &payment_token,
+                    &payment_amount,
+                    &current_price,
+                    max_raise,
+                );
+
+                (ACCEPTED_TOKEN_REDEEM_NONCE, committed_amount, current_price)
+            } else {
+                sc_panic!(INVALID_PAYMENT_ERR_MSG);
+            };
+
+        let payment_result = self.mint_and_send_redeem_token(
+            &caller,
+            redeem_token_nonce,
+            redeem_token_amount.clone(),
+        );
+
+        self.emit_deposit_event(
+            payment_token,
+            payment_amount,
+            payment_result.token_identifier.clone(),
+            redeem_token_nonce,
+            redeem_token_amount,
+            current_price,
+            phase,
+        );
+
+        payment_result
+    }
+
+    fn commit_to_dutch_auction(
+        &self,
+        caller: &ManagedAddress,
+        accepted_token_id: &EgldOrEsdtTokenIdentifier,
+        payment_amount: &BigUint,
+        current_price: &BigUint,
+        max_raise: &BigUint,
+    ) -> BigUint {
+        let closing_price_mapper = self.dutch_auction_closing_price();
+        require!(closing_price_mapper.is_empty(), AUCTION_SOLD_OUT_ERR_MSG);
+
+        let launched_token_balance = self.launched_token_balance().get();
+        require!(launched_token_balance > 0, "No launched tokens available");
+
+        let price_precision = self.price_precision().get();
+        let sold_out_amount = &launched_token_balance * current_price / price_precision;
+        let max_commit_amount = core::cmp::min(sold_out_amount, max_raise.clone());
+        let committed_amount = self.accepted_token_balance().get();
+        require!(
+            committed_amount < max_commit_amount,
+            AUCTION_SOLD_OUT_ERR_MSG
+        );
+
+        let available_amount = max_commit_amount - committed_amount;
+        let accepted_amount = if *payment_amount >= available_amount {
+            closing_price_mapper.set(current_price);
+            available_amount
+        } else {
+            payment_amount.clone()
+        };
+
+        self.add_user_contribution(caller, &accepted_amount);
+        self.accepted_token_balance()
+            .update(|balance| *balance += &accepted_amount);
+
+        let refund_amount = payment_amount - &accepted_amount;
+        if refund_amount > 0 {
+            self.send()
+                .direct(caller, accepted_token_id, 0, &refund_amount);
+        }
+
+        accepted_amount
+    }
+
+    /// Places a sealed bid in a batch auction.
+    /// The received SFT holds the bid's max price, and is used to redeem the bought tokens
+    /// and the refund after the auction ends.
+    #[payable("*")]
+    #[endpoint(placeBid)]
+    fn place_bid(&self, max_price: BigUint) -> EsdtTokenPayment<Self::Api> {
+        let phase = self.get_current_phase();
+        require!(phase == Phase::Auction, AUCTION_NOT_ACTIVE_ERR_MSG);
+        let min_price_level_bid = match self.launch_mechanism().get() {
+            LaunchMechanism::BatchAuction {
+                min_price_level_bid,
+                ..
+            } => min_price_level_bid,
+            _ => sc_panic!(NOT_BATCH_AUCTION_ERR_MSG),
+        };
+
+        let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
+        require!(
+            payment_token == self.accepted_token_id().get(),
+            INVALID_PAYMENT_ERR_MSG
+        );
+        require!(
+            max_price > 0 && max_price >= self.min_launched_token_price().get(),
+            BELOW_MIN_PRICE_ERR_MSG
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.add_user_contribution(&caller, &payment_amount);
+        self.add_batch_demand(&max_price, &payment_amount, &min_price_level_bid);
+        self.accepted_token_balance()
+            .update(|balance| *balance += &payment_amount);
+
+        let attributes = BidAttributes {
+            max_price: max_price.clone(),
+        };
+        let bid_token =
+            self.redeem_token()
+                .nft_create_and_send(&caller, payment_amount.clone(), &attributes);
+
+        self.emit_deposit_event(
+            payment_token,
+            payment_amount,
+            bid_token.token_identifier.clone(),
+            bid_token.token_nonce,
+            bid_token.amount.clone(),
+            max_price,
+            phase,
+        );
+
+        bid_token
+    }
+
+    fn add_batch_demand(&self, price: &BigUint, amount: &BigUint, min_price_level_bid: &BigUint) {
+        let demand_mapper = self.batch_demand(price);
+        if demand_mapper.is_empty() {
+            require!(
+                amount >= min_price_level_bid,
+                "Bid too low for a new price level"
+            );
+
+            self.batch_price_levels().update(|price_levels| {
+                require!(
+                    price_levels.len() < MAX_BATCH_PRICE_LEVELS,
+                    "Too many price levels"
+                );
+
+                // price levels are kept in descending order
+                let mut new_price_levels = ManagedVec::new();
+                let mut inserted = false;
+                for level in price_levels.iter() {
+                    if !inserted && *price > *level {
+                        new_price_levels.push(price.clone());
+                        inserted = true;
+                    }
+                    new_price_levels.push(level.clone_value());
+                }
+                if !inserted {
+                    new_price_levels.push(price.clone());
+                }
+
+                *price_levels = new_price_levels;
+            });
+        }
+
+        demand_mapper.update(|demand| *demand += amount);
+    }
+
+    /// After the auction ends, bidders receive their bought tokens locked (or vested),
+    /// and the refund for the unfilled part of their bid.
+    /// Users that deposited launched tokens receive their share of the raised tokens locked,
+    /// their share of the unsold launched tokens, and their share of the seeded pool's LP tokens.
+    /// If the auction was not successful, everyone gets their deposit back.
+    #[payable("*")]
+    #[endpoint(redeemAuction)]
+    fn redeem_auction(&self) -> MultiValueEncoded<EgldOrEsdtTokenPayment<Self::Api>> {
+        let phase = self.get_current_phase();
+        self.require_redeem_allowed(&phase);
+
+        let launch_mechanism = self.launch_mechanism().get();
+        require!(launch_mechanism.is_auction(), NOT_AUCTION_LAUNCH_ERR_MSG);
+
+        let (payment_token, payment_nonce, payment_amount) =
+            self.call_value().single_esdt().into_tuple();
+        let redeem_token_id = self.redeem_token().get_token_id();
+        require!(payment_token == redeem_token_id, INVALID_PAYMENT_ERR_MSG);
+
+        let auction_result = self.get_or_compute_auction_result(&launch_mechanism);
+        let caller = self.blockchain().get_caller();
+        let mut opt_lp_share = None;
+        let shares = match payment_nonce {
+            LAUNCHED_TOKEN_REDEEM_NONCE => {
+                let shares = self.compute_seller_shares(&auction_result, &payment_amount);
+                opt_lp_share = self.send_seeded_pool_lp_share(caller.clone(), &payment_amount);
+                self.burn_redeem_token_without_supply_decrease(payment_nonce, &payment_amount);
+
+                shares
+            }
+            ACCEPTED_TOKEN_REDEEM_NONCE => {
+                self.burn_redeem_token(payment_nonce, &payment_amount);
+
+                self.compute_bid_shares(&auction_result, &payment_amount, None)
+            }
+            _ => {
+                require!(
+                    matches!(launch_mechanism, LaunchMechanism::BatchAuction { .. }),
+                    INVALID_PAYMENT_ERR_MSG
+                );
+
+                let attributes: BidAttributes<Self::Api> =
+                    self.redeem_token().get_token_attributes(payment_nonce);
+                self.redeem_token().nft_burn(payment_nonce, &payment_amount);
+
+                self.compute_bid_shares(
+                    &auction_result,
+                    &payment_amount,
+                    Some(&attributes.max_price),
+                )
+            }
+        };
+
+        let mut output_payments = MultiValueEncoded::new();
+        let is_vested = payment_nonce != LAUNCHED_TOKEN_REDEEM_NONCE && self.is_vesting_enabled();
+        if shares.bought_tokens.amount > 0 && is_vested {
+            self.add_vested_tokens(&caller, &shares.bought_tokens.amount);
+        } else if shares.bought_tokens.amount > 0 {
+            let locked_tokens = self.lock_tokens_and_forward(
+                caller.clone(),
+                shares.bought_tokens.token_identifier.clone(),
+                shares.bought_tokens.amount.clone(),
+            );
+            output_payments.push(locked_tokens);
+        }
+        if shares.refund.amount > 0 {
+            self.send().direct(
+                &caller,
+                &shares.refund.token_identifier,
+                0,
+                &shares.refund.amount,
+            );
+            output_payments.push(shares.refund);
+        }
+        if let Some(lp_share) = opt_lp_share {
+            output_payments.push(lp_share);
+        }
+
+        self.emit_redeem_event(
+            payment_token,
+            payment_nonce,
+            payment_amount,
+            shares.bought_tokens.token_identifier,
+            shares.bought_tokens.amount,
+        );
+
+        output_payments
+    }
+
+    fn compute_seller_shares(
+        &self,
+        auction_result: &AuctionResult<Self::Api>,
+        redeem_token_amount: &BigUint,
+    ) -> AuctionRedeemShares<Self::Api> {
+        let redeem_token_supply = self
+            .redeem_token_total_circulating_supply(LAUNCHED_TOKEN_REDEEM_NONCE)
+            .get();
+        let launched_token_id = EgldOrEsdtTokenIdentifier::esdt(self.launched_token_id().get());
+        let accepted_token_id = self.accepted_token_id().get();
+        let launched_token_balance = self.launched_token_balance().get();
+
+        let (raised_amount, unsold_amount) = if auction_result.successful {
+            (
+                &auction_result.accepted_tokens_raised - &self.get_pool_seeded_accepted_amount(),
+                launched_token_balance - &auction_result.launched_tokens_sold,
+            )
+        } else {
+            (BigUint::zero(), launched_token_balance)
+        };
+
+        AuctionRedeemShares {
+            bought_tokens: EgldOrEsdtTokenPayment::new(
+                accepted_token_id,
+                0,
+                raised_amount * redeem_token_amount / &redeem_token_supply,
+            ),
+            refund: EgldOrEsdtTokenPayment::new(
+                launched_token_id,
+                0,
+                unsold_amount * redeem_token_amount / &redeem_token_supply,
+            ),
+        }
+    }
+
+    /// Dutch auction commitments have no max price, as they are always filled entirely.
+    /// The paid amount of partially filled bids is rounded up,
+    /// while the bought tokens are rounded down, so the SC never pays out more than it holds.
+    fn compute_bid_shares(
+        &self,
+        auction_result: &AuctionResult<Self::Api>,
+        bid_amount: &BigUint,
+        opt_max_price: Option<&BigUint>,
+    ) -> AuctionRedeemShares<Self::Api> {
+        let launched_token_id = EgldOrEsdtTokenIdentifier::esdt(self.launched_token_id().get());
+        let accepted_token_id = self.accepted_token_id().get();
+        let clearing_price = &auction_result.clearing_price;
+
+        let (paid_amount, bought_amount) = match opt_max_price {
+            _ if !auction_result.successful => (BigUint::zero(), BigUint::zero()),
+            Some(max_price) if max_price < clearing_price => (BigUint::zero(), BigUint::zero()),
+            Some(max_price) if max_price == clearing_price => {
+                let numerator = &auction_result.marginal_fill_numerator;
+                let denominator = &auction_result.marginal_fill_denominator;
+                let filled_amount = bid_amount * numerator / denominator;
+                let paid_amount = (bid_amount * numerator + denominator - 1u32) / denominator;
+
+                (
+                    paid_amount,
+                    self.get_launched_amount(&filled_amount, clearing_price),
+                )
+            }
+            _ => (
+                bid_amount.clone(),
+                self.get_launched_amount(bid_amount, clearing_price),
+            ),
+        };
+
+        AuctionRedeemShares {
+            bought_tokens: EgldOrEsdtTokenPayment::new(launched_token_id, 0, bought_amount),
+            refund: EgldOrEsdtTokenPayment::new(accepted_token_id, 0, bid_amount - &paid_amount),
+        }
+    }
+
+    fn get_or_compute_auction_result(
+        &self,
+        launch_mechanism: &LaunchMechanism<Self::Api>,
+    ) -> AuctionResult<Self::Api> {
+        let result_mapper = self.auction_result();
+        if !result_mapper.is_empty() {
+            return result_mapper.get();
+        }
+
+        let auction_result = self.compute_auction_result(launch_mechanism);
+        result_mapper.set(&auction_result);
+
+        auction_result
+    }
+
+    fn compute_auction_result(
+        &self,
+        launch_mechanism: &LaunchMechanism<Self::Api>,
+    ) -> AuctionResult<Self::Api> {
+        let mut auction_result = match launch_mechanism {
+            LaunchMechanism::PriceDiscovery => sc_panic!(NOT_AUCTION_LAUNCH_ERR_MSG),
+            LaunchMechanism::DutchAuction { end_price, .. } => {
+                self.compute_dutch_auction_result(end_price)
+            }
+            LaunchMechanism::BatchAuction { max_raise, .. } => {
+                self.compute_batch_auction_result(max_raise)
+            }
+        };
+
+        let min_raise = launch_mechanism.get_min_raise();
+        auction_result.successful = auction_result.accepted_tokens_raised > 0
+            && auction_result.accepted_tokens_raised >= min_raise;
+
+        auction_result
+    }
+
+    /// If the auction did not close early, but the commitments were still enough
+    /// to buy all the launched tokens at a price above end_price, that price is used instead.
+    fn compute_dutch_auction_result(&self, end_price: &BigUint) -> AuctionResult<Self::Api> {
+        let launched_token_balance = self.launched_token_balance().get();
+        let committed_amount = self.accepted_token_balance().get();
+        let price_precision = self.price_precision().get();
+
+        let closing_price_mapper = self.dutch_auction_closing_price();
+        let clearing_price = if !closing_price_mapper.is_empty() {
+            closing_price_mapper.get()
+        } else if launched_token_balance > 0 {
+            let sold_out_price = (&committed_amount * price_precision + &launched_token_balance
+                - 1u32)
+                / &launched_token_balance;
+            core::cmp::max(sold_out_price, end_price.clone())
+        } else {
+            end_price.clone()
+        };
+
+        let launched_tokens_sold = self.get_launched_amount(&committed_amount, &clearing_price);
+
+        AuctionResult::new_full_fill(clearing_price, launched_tokens_sold, committed_amount)
+    }
+
+    /// Fills the bids from the highest price down, until either all the launched tokens
+    /// are sold or max_raise is reached. The bids at the last reached level are filled pro-rata.
+    /// If the higher bids already buy all the tokens at a price above the next level,
+    /// the clearing price is raised to exactly sell out to them.
+    fn compute_batch_auction_result(&self, max_raise: &BigUint) -> AuctionResult<Self::Api> {
+        let launched_token_balance = self.launched_token_balance().get();
+        let price_levels = self.batch_price_levels().get();
+        if launched_token_balance == 0 || price_levels.is_empty() {
+            return AuctionResult::new_full_fill(BigUint::zero(), BigUint::zero(), BigUint::zero());
+        }
+
+        let price_precision = self.price_precision().get();
+        let mut higher_levels_demand = BigUint::zero();
+        let mut last_price = BigUint::zero();
+        for price in price_levels.iter() {
+            let price = price.clone_value();
+            let level_demand = self.batch_demand(&price).get();
+            let total_demand = &higher_levels_demand + &level_demand;
+            let supply_reached = &total_demand * price_precision / &price >= launched_token_balance;
+            if !supply_reached && total_demand < *max_raise {
+                higher_levels_demand = total_demand;
+                last_price = price;
+                continue;
+            }
+
+            if supply_reached && higher_levels_demand > 0 {
+                let sold_out_price =
+                    (&higher_levels_demand * price_precision + &launched_token_balance - 1u32)
+                        / &launched_token_balance;
+                if sold_out_price > price {
+                    let launched_tokens_sold =
+                        self.get_launched_amount(&higher_levels_demand, &sold_out_price);
+
+                    return AuctionResult::new_full_fill(
+                        sold_out_price,
+                        launched_tokens_sold,
+                        higher_levels_demand,
+                    );
+                }
+            }
+
+            let higher_levels_tokens = &higher_levels_demand * price_precision / &price;
+            let supply_fill_amount =
+                (&launched_token_balance - &higher_levels_tokens) * &price / price_precision;
+            let cap_fill_amount = max_raise - &higher_levels_demand;
+            let filled_amount = core::cmp::min(
+                core::cmp::min(supply_fill_amount, cap_fill_amount),
+                level_demand.clone(),
+            );
+
+            let accepted_tokens_raised = higher_levels_demand + &filled_amount;
+            let launched_tokens_sold = self.get_launched_amount(&accepted_tokens_raised, &price);
+
+            return AuctionResult {
+                clearing_price: price,
+                marginal_fill_numerator: filled_amount,
+                marginal_fill_denominator: level_demand,
+                launched_tokens_sold,
+                accepted_tokens_raised,
+                successful: false,
+            };
+        }
+
+        // not enough demand, so all bids are filled at the lowest bid price
+        let launched_tokens_sold = self.get_launched_amount(&higher_levels_demand, &last_price);
+
+        AuctionResult::new_full_fill(last_price, launched_tokens_sold, higher_levels_demand)
+    }
+
+    fn get_launched_amount(&self, accepted_amount: &BigUint, price: &BigUint) -> BigUint {
+        if *price == 0 {
+            return BigUint::zero();
+        }
+
+        let price_precision = self.price_precision().get();
+        accepted_amount * price_precision / price
+    }
+
+    fn get_dutch_auction_price(&self, start_price: &BigUint, end_price: &BigUint) -> BigUint {
+        let current_block = self.blockchain().get_block_nonce();
+        let start_block = self.start_block().get();
+        let end_block = self.end_block().get();
+        if current_block <= start_block {
+            return start_price.clone();
+        }
+        if current_block >= end_block {
+            return end_price.clone();
+        }
+
+        let price_decrease =
+            (start_price - end_price) * (current_block - start_block) / (end_block - start_block);
+        start_price - &price_decrease
+    }
+
+    #[view(getDutchAuctionPrice)]
+    fn get_dutch_auction_price_view(&self) -> BigUint {
+        match self.launch_mechanism().get() {
+            LaunchMechanism::DutchAuction {
+                start_price,
+                end_price,
+                ..
+            } => {
+                let closing_price_mapper = self.dutch_auction_closing_price();
+                if !closing_price_mapper.is_empty() {
+                    return closing_price_mapper.get();
+                }
+
+                self.get_dutch_auction_price(&start_price, &end_price)
+            }
+            _ => sc_panic!("Not a dutch auction"),
+        }
+    }
+
+    /// Available once the auction has ended.
+    #[view(getAuctionResult)]
+    fn get_auction_result_view(&self) -> OptionalValue<AuctionResult<Self::Api>> {
+        let launch_mechanism = self.launch_mechanism().get();
+        if !launch_mechanism.is_auction() || self.get_current_phase() != Phase::Redeem {
+            return OptionalValue::None;
+        }
+
+        let result_mapper = self.auction_result();
+        if !result_mapper.is_empty() {
+            return OptionalValue::Some(result_mapper.get());
+        }
+
+        OptionalValue::Some(self.compute_auction_result(&launch_mechanism))
+    }
+
+    #[view(getBatchAuctionDemand)]
+    fn get_batch_auction_demand(&self) -> MultiValueEncoded<MultiValue2<BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for price in self.batch_price_levels().get().iter() {
+            let price = price.clone_value();
+            let demand = self.batch_demand(&price).get();
+            result.push((price, demand).into());
+        }
+
+        result
+    }
+
+    #[storage_mapper("dutchAuctionClosingPrice")]
+    fn dutch_auction_closing_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("batchPriceLevels")]
+    fn batch_price_levels(&self) -> SingleValueMapper<ManagedVec<BigUint>>;
+
+    #[storage_mapper("batchDemand")]
+    fn batch_demand(&self, price: &BigUint) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("auctionResult")]
+    fn auction_result(&self) -> SingleValueMapper<AuctionResult<Self::Api>>;
+}
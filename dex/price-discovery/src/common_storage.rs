@@ -1,9 +1,29 @@
 multiversx_sc::imports!();
 
+use crate::{
+    auction::LaunchMechanism,
+    pool_seeding::{PoolSeedingConfig, SeededPoolInfo},
+};
+
 pub const MAX_PERCENTAGE: u64 = 10_000_000_000_000; // 100%
 
 #[multiversx_sc::module]
 pub trait CommonStorageModule {
+    /// The accepted tokens added to the pool are no longer available to the launched token depositors
+    fn get_pool_seeded_accepted_amount(&self) -> BigUint {
+        if self.pool_seeding_config().is_empty() {
+            return BigUint::zero();
+        }
+
+        let seeded_pool_info_mapper = self.seeded_pool_info();
+        require!(
+            !seeded_pool_info_mapper.is_empty(),
+            "Pool must be seeded first"
+        );
+
+        seeded_pool_info_mapper.get().accepted_token_amount
+    }
+
     #[view(getLaunchedTokenId)]
     #[storage_mapper("launchedTokenId")]
     fn launched_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
@@ -27,4 +47,24 @@ pub trait CommonStorageModule {
     #[view(getEndBlock)]
     #[storage_mapper("endBlock")]
     fn end_block(&self) -> SingleValueMapper<u64>;
+
+    #[view(getMinLaunchedTokenPrice)]
+    #[storage_mapper("minLaunchedTokenPrice")]
+    fn min_launched_token_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getPricePrecision)]
+    #[storage_mapper("pricePrecision")]
+    fn price_precision(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLaunchMechanism)]
+    #[storage_mapper("launchMechanism")]
+    fn launch_mechanism(&self) -> SingleValueMapper<LaunchMechanism<Self::Api>>;
+
+    #[view(getPoolSeedingConfig)]
+    #[storage_mapper("poolSeedingConfig")]
+    fn pool_seeding_config(&self) -> SingleValueMapper<PoolSeedingConfig<Self::Api>>;
+
+    #[view(getSeededPoolInfo)]
+    #[storage_mapper("seededPoolInfo")]
+    fn seeded_pool_info(&self) -> SingleValueMapper<SeededPoolInfo<Self::Api>>;
 }
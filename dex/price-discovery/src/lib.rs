@@ -3,14 +3,19 @@
 multiversx_sc::imports!();
 
 use crate::{
+    auction::LaunchMechanism,
     common_storage::MAX_PERCENTAGE,
     redeem_token::{ACCEPTED_TOKEN_REDEEM_NONCE, LAUNCHED_TOKEN_REDEEM_NONCE},
 };
 
+pub mod auction;
 pub mod common_storage;
 pub mod events;
+pub mod participation;
 pub mod phase;
+pub mod pool_seeding;
 pub mod redeem_token;
+pub mod vesting;
 
 static INVALID_PAYMENT_ERR_MSG: &[u8] = b"Invalid payment token";
 static BELOW_MIN_PRICE_ERR_MSG: &[u8] = b"Launched token below min price";
@@ -18,11 +23,15 @@ const MAX_TOKEN_DECIMALS: u32 = 18;
 
 #[multiversx_sc::contract]
 pub trait PriceDiscovery:
-    common_storage::CommonStorageModule
+    auction::AuctionModule
+    + common_storage::CommonStorageModule
     + events::EventsModule
     + locking_module::locking_module::LockingModule
+    + participation::ParticipationModule
     + phase::PhaseModule
+    + pool_seeding::PoolSeedingModule
     + redeem_token::RedeemTokenModule
+    + vesting::VestingModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     /// For explanations regarding what each parameter means, please refer to docs/setup.md
@@ -42,6 +51,7 @@ pub trait PriceDiscovery:
         penalty_max_percentage: BigUint,
         fixed_penalty_percentage: BigUint,
         locking_sc_address: ManagedAddress,
+        opt_launch_mechanism: OptionalValue<LaunchMechanism<Self::Api>>,
     ) {
         require!(
             launched_token_id.is_valid_esdt_identifier(),
@@ -111,17 +121,30 @@ pub trait PriceDiscovery:
             .set(&fixed_penalty_percentage);
 
         self.set_locking_sc_address(locking_sc_address);
+
+        let launch_mechanism = match opt_launch_mechanism {
+            OptionalValue::Some(launch_mechanism) => launch_mechanism,
+            OptionalValue::None => LaunchMechanism::PriceDiscovery,
+        };
+        self.set_launch_mechanism(launch_mechanism);
     }
 
     #[upgrade]
     fn upgrade(&self) {}
 
     /// Users can deposit either launched_token or accepted_token.
-    /// They will receive an SFT that can be used to withdraw said tokens
+    /// They will receive an SFT that can be used to withdraw said tokens.
+    /// For auction launches, launched tokens are deposited before the start block,
+    /// and accepted tokens are committed to dutch auctions at the current auction price
     #[payable("*")]
     #[endpoint]
     fn deposit(&self) -> EsdtTokenPayment<Self::Api> {
         let phase = self.get_current_phase();
+        let launch_mechanism = self.launch_mechanism().get();
+        if launch_mechanism.is_auction() {
+            return self.deposit_into_auction(&launch_mechanism, phase);
+        }
+
         self.require_deposit_allowed(&phase);
 
         let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
@@ -135,6 +158,11 @@ pub trait PriceDiscovery:
             sc_panic!(INVALID_PAYMENT_ERR_MSG);
         };
 
+        let caller = self.blockchain().get_caller();
+        if redeem_token_nonce == ACCEPTED_TOKEN_REDEEM_NONCE {
+            self.add_user_contribution(&caller, &payment_amount);
+        }
+
         self.increase_balance(balance_mapper, &payment_amount);
 
         let current_price = self.calculate_price();
@@ -144,7 +172,6 @@ pub trait PriceDiscovery:
             BELOW_MIN_PRICE_ERR_MSG
         );
 
-        let caller = self.blockchain().get_caller();
         let payment_result =
             self.mint_and_send_redeem_token(&caller, redeem_token_nonce, payment_amount.clone());
 
@@ -200,6 +227,10 @@ pub trait PriceDiscovery:
         require!(current_price >= min_price, BELOW_MIN_PRICE_ERR_MSG);
 
         let caller = self.blockchain().get_caller();
+        if payment_nonce == ACCEPTED_TOKEN_REDEEM_NONCE {
+            self.remove_user_contribution(&caller, &payment_amount);
+        }
+
         self.send()
             .direct(&caller, &refund_token_id, 0, &withdraw_amount);
 
@@ -219,8 +250,10 @@ pub trait PriceDiscovery:
     /// After all phases have ended,
     /// users can withdraw their fair share of either accepted or launched tokens,
     /// depending on which token they deposited initially.
-    /// Users that deposited accepted tokens will receive Locked launched tokens.
-    /// Users that deposited launched tokens will receive Locked accepted tokens.
+    /// Users that deposited accepted tokens will receive Locked launched tokens,
+    /// or vested launched tokens, if a vesting schedule is set.
+    /// Users that deposited launched tokens will receive Locked accepted tokens,
+    /// and their share of the seeded pool's LP tokens, locked until the LP unlock epoch.
     /// The users can unlock said tokens at the configured unlock_epoch,
     /// through the SC at locking_sc_address
     #[payable("*")]
@@ -228,6 +261,10 @@ pub trait PriceDiscovery:
     fn redeem(&self) -> EgldOrEsdtTokenPayment<Self::Api> {
         let phase = self.get_current_phase();
         self.require_redeem_allowed(&phase);
+        require!(
+            !self.launch_mechanism().get().is_auction(),
+            "Auction launches are redeemed through redeemAuction"
+        );
 
         let (payment_token, payment_nonce, payment_amount) =
             self.call_value().single_esdt().into_tuple();
@@ -235,15 +272,22 @@ pub trait PriceDiscovery:
         require!(payment_token == redeem_token_id, INVALID_PAYMENT_ERR_MSG);
 
         let bought_tokens = self.compute_bought_tokens(payment_nonce, &payment_amount);
+        let caller = self.blockchain().get_caller();
+        if payment_nonce == LAUNCHED_TOKEN_REDEEM_NONCE {
+            let _ = self.send_seeded_pool_lp_share(caller.clone(), &payment_amount);
+        }
         self.burn_redeem_token_without_supply_decrease(payment_nonce, &payment_amount);
 
         if bought_tokens.amount > 0 {
-            let caller = self.blockchain().get_caller();
-            let _ = self.lock_tokens_and_forward(
-                caller,
-                bought_tokens.token_identifier.clone(),
-                bought_tokens.amount.clone(),
-            );
+            if payment_nonce == ACCEPTED_TOKEN_REDEEM_NONCE && self.is_vesting_enabled() {
+                self.add_vested_tokens(&caller, &bought_tokens.amount);
+            } else {
+                let _ = self.lock_tokens_and_forward(
+                    caller,
+                    bought_tokens.token_identifier.clone(),
+                    bought_tokens.amount.clone(),
+                );
+            }
         }
 
         self.emit_redeem_event(
@@ -276,7 +320,7 @@ pub trait PriceDiscovery:
             ),
             LAUNCHED_TOKEN_REDEEM_NONCE => (
                 self.accepted_token_id().get(),
-                self.accepted_token_balance().get(),
+                self.accepted_token_balance().get() - self.get_pool_seeded_accepted_amount(),
             ),
             _ => sc_panic!(INVALID_PAYMENT_ERR_MSG),
         };
@@ -303,12 +347,4 @@ pub trait PriceDiscovery:
     fn decrease_balance(&self, mapper: SingleValueMapper<BigUint>, amount: &BigUint) {
         mapper.update(|b| *b -= amount);
     }
-
-    #[view(getMinLaunchedTokenPrice)]
-    #[storage_mapper("minLaunchedTokenPrice")]
-    fn min_launched_token_price(&self) -> SingleValueMapper<BigUint>;
-
-    #[view(getPricePrecision)]
-    #[storage_mapper("pricePrecision")]
-    fn price_precision(&self) -> SingleValueMapper<u64>;
 }
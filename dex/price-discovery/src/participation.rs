@@ -0,0 +1,146 @@
+multiversx_sc::imports!();
+
+pub const HASH_LEN: usize = 32;
+
+pub type Hash<M> = ManagedByteArray<M, HASH_LEN>;
+
+static NOT_ALLOWLISTED_ERR_MSG: &[u8] = b"Address not allowlisted";
+
+#[multiversx_sc::module]
+pub trait ParticipationModule: crate::common_storage::CommonStorageModule {
+    /// Limits for the total accepted tokens each address can deposit.
+    /// A max_contribution of 0 means there is no upper limit.
+    #[only_owner]
+    #[endpoint(setContributionLimits)]
+    fn set_contribution_limits(&self, min_contribution: BigUint, max_contribution: BigUint) {
+        require!(
+            max_contribution == 0 || min_contribution <= max_contribution,
+            "Invalid contribution limits"
+        );
+
+        self.min_contribution().set(min_contribution);
+        self.max_contribution().set(max_contribution);
+    }
+
+    /// When enabled, only the allowlisted addresses may deposit accepted tokens.
+    /// Addresses are either added by the owner, or add themselves with a proof for the Merkle root.
+    #[only_owner]
+    #[endpoint(setAllowlistEnabled)]
+    fn set_allowlist_enabled(&self, enabled: bool) {
+        self.allowlist_enabled().set(enabled);
+    }
+
+    #[only_owner]
+    #[endpoint(addToAllowlist)]
+    fn add_to_allowlist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let mut allowlist = self.allowlist();
+        for address in addresses {
+            let _ = allowlist.insert(address);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeFromAllowlist)]
+    fn remove_from_allowlist(&self, addresses: MultiValueEncoded<ManagedAddress>) {
+        let mut allowlist = self.allowlist();
+        for address in addresses {
+            let _ = allowlist.swap_remove(&address);
+        }
+    }
+
+    /// The leaves are the keccak256 hashes of the allowlisted addresses.
+    /// The pairs of nodes are sorted before being hashed together.
+    #[only_owner]
+    #[endpoint(setAllowlistMerkleRoot)]
+    fn set_allowlist_merkle_root(&self, merkle_root: Hash<Self::Api>) {
+        self.allowlist_merkle_root().set(merkle_root);
+    }
+
+    #[endpoint(proveAllowlisted)]
+    fn prove_allowlisted(&self, proof: MultiValueEncoded<Hash<Self::Api>>) {
+        let root_mapper = self.allowlist_merkle_root();
+        require!(!root_mapper.is_empty(), "No Merkle root set");
+
+        let caller = self.blockchain().get_caller();
+        let mut computed_hash = self.crypto().keccak256(caller.as_managed_buffer());
+        for proof_node in proof {
+            computed_hash = self.hash_merkle_pair(&computed_hash, &proof_node);
+        }
+        require!(computed_hash == root_mapper.get(), "Invalid Merkle proof");
+
+        let _ = self.allowlist().insert(caller);
+    }
+
+    fn hash_merkle_pair(
+        &self,
+        first: &Hash<Self::Api>,
+        second: &Hash<Self::Api>,
+    ) -> Hash<Self::Api> {
+        let (low, high) = if first.to_byte_array() <= second.to_byte_array() {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        let mut pair_buffer = low.as_managed_buffer().clone();
+        pair_buffer.append(high.as_managed_buffer());
+
+        self.crypto().keccak256(pair_buffer)
+    }
+
+    fn add_user_contribution(&self, user: &ManagedAddress, amount: &BigUint) {
+        if self.allowlist_enabled().get() {
+            require!(self.allowlist().contains(user), NOT_ALLOWLISTED_ERR_MSG);
+        }
+
+        let contribution_mapper = self.user_contribution(user);
+        let new_contribution = contribution_mapper.get() + amount;
+        require!(
+            new_contribution >= self.min_contribution().get(),
+            "Contribution below min"
+        );
+
+        let max_contribution = self.max_contribution().get();
+        require!(
+            max_contribution == 0 || new_contribution <= max_contribution,
+            "Contribution above max"
+        );
+
+        contribution_mapper.set(new_contribution);
+    }
+
+    /// Redeem tokens can be transferred, so the withdrawer might not have deposited anything
+    fn remove_user_contribution(&self, user: &ManagedAddress, amount: &BigUint) {
+        self.user_contribution(user).update(|contribution| {
+            if *contribution > *amount {
+                *contribution -= amount;
+            } else {
+                *contribution = BigUint::zero();
+            }
+        });
+    }
+
+    #[view(getMinContribution)]
+    #[storage_mapper("minContribution")]
+    fn min_contribution(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxContribution)]
+    #[storage_mapper("maxContribution")]
+    fn max_contribution(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUserContribution)]
+    #[storage_mapper("userContribution")]
+    fn user_contribution(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(isAllowlistEnabled)]
+    #[storage_mapper("allowlistEnabled")]
+    fn allowlist_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getAllowlist)]
+    #[storage_mapper("allowlist")]
+    fn allowlist(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getAllowlistMerkleRoot)]
+    #[storage_mapper("allowlistMerkleRoot")]
+    fn allowlist_merkle_root(&self) -> SingleValueMapper<Hash<Self::Api>>;
+}
@@ -8,6 +8,7 @@ pub enum Phase<M: ManagedTypeApi> {
     LinearIncreasingPenalty { penalty_percentage: BigUint<M> },
     OnlyWithdrawFixedPenalty { penalty_percentage: BigUint<M> },
     Redeem,
+    Auction,
 }
 
 impl<M: ManagedTypeApi> Phase<M> {
@@ -32,6 +33,15 @@ pub trait PhaseModule:
             return Phase::Idle;
         }
 
+        if self.launch_mechanism().get().is_auction() {
+            let end_block = self.end_block().get();
+            if current_block < end_block {
+                return Phase::Auction;
+            }
+
+            return Phase::Redeem;
+        }
+
         let no_limit_phase_duration_blocks = self.no_limit_phase_duration_blocks().get();
         let no_limit_phase_end = start_block + no_limit_phase_duration_blocks;
         if current_block < no_limit_phase_end {
@@ -80,7 +90,8 @@ pub trait PhaseModule:
             | Phase::OnlyWithdrawFixedPenalty {
                 penalty_percentage: _,
             }
-            | Phase::Redeem => {
+            | Phase::Redeem
+            | Phase::Auction => {
                 sc_panic!("Deposit not allowed in this phase")
             }
             _ => {}
@@ -89,7 +100,7 @@ pub trait PhaseModule:
 
     fn require_withdraw_allowed(&self, phase: &Phase<Self::Api>) {
         match phase {
-            Phase::Idle | Phase::Redeem => {
+            Phase::Idle | Phase::Redeem | Phase::Auction => {
                 sc_panic!("Withdraw not allowed in this phase")
             }
             _ => {}
@@ -0,0 +1,436 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::{
+    common_storage::MAX_PERCENTAGE, redeem_token::LAUNCHED_TOKEN_REDEEM_NONCE,
+    INVALID_PAYMENT_ERR_MSG,
+};
+
+pub type AddLiquidityResultType<M> =
+    MultiValue3<EsdtTokenPayment<M>, EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
+
+static POOL_SEEDING_NOT_CONFIGURED_ERR_MSG: &[u8] = b"Pool seeding not configured";
+static POOL_ALREADY_SEEDED_ERR_MSG: &[u8] = b"Pool already seeded";
+
+// Manually declared, to avoid adding the router and pair code to this contract
+pub mod router_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait RouterProxy {
+        #[endpoint(createPair)]
+        fn create_pair(
+            &self,
+            first_token_id: TokenIdentifier,
+            second_token_id: TokenIdentifier,
+            initial_liquidity_adder: ManagedAddress,
+            opt_fee_percents: OptionalValue<MultiValue2<u64, u64>>,
+            admins: MultiValueEncoded<ManagedAddress>,
+        ) -> ManagedAddress;
+
+        #[payable("EGLD")]
+        #[endpoint(issueLpToken)]
+        fn issue_lp_token(
+            &self,
+            pair_address: ManagedAddress,
+            lp_token_display_name: ManagedBuffer,
+            lp_token_ticker: ManagedBuffer,
+        );
+
+        #[view(getPair)]
+        fn get_pair(
+            &self,
+            first_token_id: TokenIdentifier,
+            second_token_id: TokenIdentifier,
+        ) -> ManagedAddress;
+    }
+}
+
+pub mod pair_proxy {
+    multiversx_sc::imports!();
+    use super::AddLiquidityResultType;
+
+    #[multiversx_sc::proxy]
+    pub trait PairProxy {
+        #[payable("*")]
+        #[endpoint(addInitialLiquidity)]
+        fn add_initial_liquidity(&self) -> AddLiquidityResultType<Self::Api>;
+
+        #[view(getFirstTokenId)]
+        fn get_first_token_id(&self) -> TokenIdentifier;
+
+        #[view(getInitialLiquidtyAdder)]
+        fn get_initial_liquidity_adder(&self) -> Option<ManagedAddress>;
+
+        #[view(getTotalSupply)]
+        fn get_total_supply(&self) -> BigUint;
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct PoolSeedingConfig<M: ManagedTypeApi> {
+    pub router_address: ManagedAddress<M>,
+    pub liquidity_percentage: BigUint<M>,
+    pub lp_lock_epochs: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct SeededPoolInfo<M: ManagedTypeApi> {
+    pub pair_address: ManagedAddress<M>,
+    pub launched_token_amount: BigUint<M>,
+    pub accepted_token_amount: BigUint<M>,
+    pub price: BigUint<M>,
+    pub depositors_lp_tokens: EsdtTokenPayment<M>,
+    pub lp_unlock_epoch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait PoolSeedingModule:
+    crate::auction::AuctionModule
+    + crate::common_storage::CommonStorageModule
+    + crate::events::EventsModule
+    + crate::phase::PhaseModule
+    + crate::redeem_token::RedeemTokenModule
+    + crate::participation::ParticipationModule
+    + crate::vesting::VestingModule
+    + locking_module::locking_module::LockingModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// After the launch ends, liquidity_percentage of the raised accepted tokens
+    /// are added as initial liquidity to the router pair, at the discovered price.
+    /// The accepted tokens belong to the users that deposited launched tokens,
+    /// so they get their part of the LP tokens when redeeming, and the owner gets the rest.
+    /// The LP tokens are locked for lp_lock_epochs.
+    /// Users that deposited launched tokens can only redeem after the pool is seeded.
+    #[only_owner]
+    #[endpoint(setPoolSeedingConfig)]
+    fn set_pool_seeding_config(
+        &self,
+        router_address: ManagedAddress,
+        liquidity_percentage: BigUint,
+        lp_lock_epochs: u64,
+    ) {
+        self.require_pool_not_seeded();
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid router address"
+        );
+        require!(
+            liquidity_percentage > 0 && liquidity_percentage <= MAX_PERCENTAGE,
+            "Invalid liquidity percentage"
+        );
+        require!(
+            self.accepted_token_id().get().is_esdt(),
+            "Accepted token must be an ESDT"
+        );
+
+        self.pool_seeding_config().set(PoolSeedingConfig {
+            router_address,
+            liquidity_percentage,
+            lp_lock_epochs,
+        });
+    }
+
+    /// Also returns the launched tokens reserved for the pool.
+    #[only_owner]
+    #[endpoint(removePoolSeedingConfig)]
+    fn remove_pool_seeding_config(&self) {
+        self.require_pool_not_seeded();
+
+        self.pool_seeding_config().clear();
+        self.send_pool_seeding_launched_tokens_to_owner();
+    }
+
+    /// The launched tokens added to the pool are taken from this reserve.
+    /// Whatever is not needed at the discovered price is sent back to the owner when seeding.
+    #[only_owner]
+    #[payable("*")]
+    #[endpoint(depositPoolSeedingTokens)]
+    fn deposit_pool_seeding_tokens(&self) {
+        self.require_pool_not_seeded();
+
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.launched_token_id().get(),
+            INVALID_PAYMENT_ERR_MSG
+        );
+
+        self.pool_seeding_launched_tokens()
+            .update(|amount| *amount += payment.amount);
+    }
+
+    #[only_owner]
+    #[endpoint(withdrawPoolSeedingTokens)]
+    fn withdraw_pool_seeding_tokens(&self) {
+        self.require_pool_not_seeded();
+        self.send_pool_seeding_launched_tokens_to_owner();
+    }
+
+    /// Creates the launched token - accepted token pair through the router,
+    /// with this contract as the initial liquidity adder.
+    /// If the router already has the pair, it is used instead,
+    /// as long as this contract is its initial liquidity adder and it has no liquidity yet.
+    #[only_owner]
+    #[endpoint(createSeedingPair)]
+    fn create_seeding_pair(&self) -> ManagedAddress {
+        self.require_pool_not_seeded();
+        let config = self.get_pool_seeding_config();
+        let pair_mapper = self.seeding_pair_address();
+        require!(pair_mapper.is_empty(), "Seeding pair already created");
+
+        let launched_token_id = self.launched_token_id().get();
+        let accepted_token_id = self.accepted_token_id().get().unwrap_esdt();
+        let mut pair_address = self.get_router_pair(
+            &config.router_address,
+            &launched_token_id,
+            &accepted_token_id,
+        );
+        if !pair_address.is_zero() {
+            self.require_can_seed_pair(&pair_address);
+        } else {
+            pair_address = self
+                .router_proxy(config.router_address)
+                .create_pair(
+                    launched_token_id,
+                    accepted_token_id,
+                    self.blockchain().get_sc_address(),
+                    OptionalValue::<MultiValue2<u64, u64>>::None,
+                    MultiValueEncoded::new(),
+                )
+                .execute_on_dest_context();
+        }
+
+        pair_mapper.set(&pair_address);
+
+        pair_address
+    }
+
+    /// This contract is the temporary owner of the pairs it creates,
+    /// so the LP token is issued through it. The roles are then set through the router.
+    #[only_owner]
+    #[payable("EGLD")]
+    #[endpoint(issueSeedingPairLpToken)]
+    fn issue_seeding_pair_lp_token(
+        &self,
+        lp_token_display_name: ManagedBuffer,
+        lp_token_ticker: ManagedBuffer,
+    ) {
+        let config = self.get_pool_seeding_config();
+        let pair_mapper = self.seeding_pair_address();
+        require!(!pair_mapper.is_empty(), "Seeding pair not created");
+
+        let issue_cost = self.call_value().egld_value().clone_value();
+        self.router_proxy(config.router_address)
+            .issue_lp_token(pair_mapper.get(), lp_token_display_name, lp_token_ticker)
+            .with_egld_transfer(issue_cost)
+            .async_call_and_exit()
+    }
+
+    /// Adds the configured share of the raised tokens as initial liquidity,
+    /// at the price discovered by the launch.
+    #[only_owner]
+    #[endpoint(seedPool)]
+    fn seed_pool(&self) -> EgldOrEsdtTokenPayment<Self::Api> {
+        self.require_pool_not_seeded();
+        let config = self.get_pool_seeding_config();
+        let phase = self.get_current_phase();
+        self.require_redeem_allowed(&phase);
+
+        let launched_token_id = self.launched_token_id().get();
+        let accepted_token_id = self.accepted_token_id().get().unwrap_esdt();
+        let pair_address = self.get_seeding_pair_address(
+            &config.router_address,
+            &launched_token_id,
+            &accepted_token_id,
+        );
+
+        let (raised_amount, price) = self.get_raised_amount_and_price();
+        require!(price > 0, "No price discovered");
+
+        let accepted_token_amount = raised_amount * &config.liquidity_percentage / MAX_PERCENTAGE;
+        let price_precision = self.price_precision().get();
+        let launched_token_amount = &accepted_token_amount * price_precision / &price;
+        require!(
+            accepted_token_amount > 0 && launched_token_amount > 0,
+            "Nothing to seed"
+        );
+
+        let reserve_amount = self.pool_seeding_launched_tokens().get();
+        require!(
+            reserve_amount >= launched_token_amount,
+            "Not enough launched tokens for pool seeding"
+        );
+
+        let launched_payment =
+            EsdtTokenPayment::new(launched_token_id.clone(), 0, launched_token_amount.clone());
+        let accepted_payment =
+            EsdtTokenPayment::new(accepted_token_id.clone(), 0, accepted_token_amount.clone());
+        let first_token_id: TokenIdentifier = self
+            .pair_proxy(pair_address.clone())
+            .get_first_token_id()
+            .execute_on_dest_context();
+        let mut payments = ManagedVec::new();
+        if first_token_id == launched_token_id {
+            payments.push(launched_payment);
+            payments.push(accepted_payment);
+        } else {
+            payments.push(accepted_payment);
+            payments.push(launched_payment);
+        }
+
+        let add_liq_result: AddLiquidityResultType<Self::Api> = self
+            .pair_proxy(pair_address.clone())
+            .add_initial_liquidity()
+            .with_multi_token_transfer(payments)
+            .execute_on_dest_context();
+        let (lp_tokens, _, _) = add_liq_result.into_tuple();
+
+        // both sides have the same value at the discovered price
+        let depositors_lp_amount = if self
+            .redeem_token_total_circulating_supply(LAUNCHED_TOKEN_REDEEM_NONCE)
+            .get()
+            > 0
+        {
+            &lp_tokens.amount / 2u32
+        } else {
+            BigUint::zero()
+        };
+        let owner_lp_amount = &lp_tokens.amount - &depositors_lp_amount;
+        let lp_unlock_epoch = self.blockchain().get_block_epoch() + config.lp_lock_epochs;
+        self.seeded_pool_info().set(SeededPoolInfo {
+            pair_address,
+            launched_token_amount: launched_token_amount.clone(),
+            accepted_token_amount,
+            price,
+            depositors_lp_tokens: EsdtTokenPayment::new(
+                lp_tokens.token_identifier.clone(),
+                0,
+                depositors_lp_amount,
+            ),
+            lp_unlock_epoch,
+        });
+        self.pool_seeding_launched_tokens()
+            .set(reserve_amount - launched_token_amount);
+        self.send_pool_seeding_launched_tokens_to_owner();
+
+        let owner = self.blockchain().get_owner_address();
+        self.lock_tokens_until_and_forward(
+            owner,
+            EgldOrEsdtTokenIdentifier::esdt(lp_tokens.token_identifier),
+            owner_lp_amount,
+            lp_unlock_epoch,
+        )
+    }
+
+    fn get_raised_amount_and_price(&self) -> (BigUint, BigUint) {
+        let launch_mechanism = self.launch_mechanism().get();
+        if launch_mechanism.is_auction() {
+            let auction_result = self.get_or_compute_auction_result(&launch_mechanism);
+            require!(auction_result.successful, "Auction was not successful");
+
+            return (
+                auction_result.accepted_tokens_raised,
+                auction_result.clearing_price,
+            );
+        }
+
+        let launched_token_balance = self.launched_token_balance().get();
+        let accepted_token_balance = self.accepted_token_balance().get();
+        require!(launched_token_balance > 0, "No launched tokens available");
+
+        let price_precision = self.price_precision().get();
+        let price = &accepted_token_balance * price_precision / &launched_token_balance;
+
+        (accepted_token_balance, price)
+    }
+
+    fn get_seeding_pair_address(
+        &self,
+        router_address: &ManagedAddress,
+        launched_token_id: &TokenIdentifier,
+        accepted_token_id: &TokenIdentifier,
+    ) -> ManagedAddress {
+        let pair_mapper = self.seeding_pair_address();
+        if !pair_mapper.is_empty() {
+            return pair_mapper.get();
+        }
+
+        let pair_address =
+            self.get_router_pair(router_address, launched_token_id, accepted_token_id);
+        require!(!pair_address.is_zero(), "Seeding pair not created");
+        self.require_can_seed_pair(&pair_address);
+
+        pair_address
+    }
+
+    fn require_can_seed_pair(&self, pair_address: &ManagedAddress) {
+        let opt_initial_liquidity_adder: Option<ManagedAddress> = self
+            .pair_proxy(pair_address.clone())
+            .get_initial_liquidity_adder()
+            .execute_on_dest_context();
+        require!(
+            opt_initial_liquidity_adder == Some(self.blockchain().get_sc_address()),
+            "Not the initial liquidity adder of the pair"
+        );
+
+        let lp_token_supply: BigUint = self
+            .pair_proxy(pair_address.clone())
+            .get_total_supply()
+            .execute_on_dest_context();
+        require!(lp_token_supply == 0, "Pair already has liquidity");
+    }
+
+    fn get_router_pair(
+        &self,
+        router_address: &ManagedAddress,
+        launched_token_id: &TokenIdentifier,
+        accepted_token_id: &TokenIdentifier,
+    ) -> ManagedAddress {
+        self.router_proxy(router_address.clone())
+            .get_pair(launched_token_id.clone(), accepted_token_id.clone())
+            .execute_on_dest_context()
+    }
+
+    fn send_pool_seeding_launched_tokens_to_owner(&self) {
+        let amount = self.pool_seeding_launched_tokens().take();
+        if amount == 0 {
+            return;
+        }
+
+        let owner = self.blockchain().get_owner_address();
+        let launched_token_id = self.launched_token_id().get();
+        self.send()
+            .direct_esdt(&owner, &launched_token_id, 0, &amount);
+    }
+
+    fn get_pool_seeding_config(&self) -> PoolSeedingConfig<Self::Api> {
+        let config_mapper = self.pool_seeding_config();
+        require!(
+            !config_mapper.is_empty(),
+            POOL_SEEDING_NOT_CONFIGURED_ERR_MSG
+        );
+
+        config_mapper.get()
+    }
+
+    fn require_pool_not_seeded(&self) {
+        require!(
+            self.seeded_pool_info().is_empty(),
+            POOL_ALREADY_SEEDED_ERR_MSG
+        );
+    }
+
+    #[proxy]
+    fn router_proxy(&self, sc_address: ManagedAddress) -> router_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn pair_proxy(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;
+
+    #[view(getPoolSeedingLaunchedTokens)]
+    #[storage_mapper("poolSeedingLaunchedTokens")]
+    fn pool_seeding_launched_tokens(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getSeedingPairAddress)]
+    #[storage_mapper("seedingPairAddress")]
+    fn seeding_pair_address(&self) -> SingleValueMapper<ManagedAddress>;
+}
@@ -8,6 +8,7 @@ pub const ACCEPTED_TOKEN_REDEEM_NONCE: u64 = 2;
 #[multiversx_sc::module]
 pub trait RedeemTokenModule:
     crate::common_storage::CommonStorageModule
+    + locking_module::locking_module::LockingModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[only_owner]
@@ -69,6 +70,37 @@ pub trait RedeemTokenModule:
             .update(|supply| *supply -= amount);
     }
 
+    /// Users that deposited launched tokens get their share of the LP tokens of the seeded pool,
+    /// locked until the LP unlock epoch.
+    fn send_seeded_pool_lp_share(
+        &self,
+        to: ManagedAddress,
+        redeem_token_amount: &BigUint,
+    ) -> Option<EgldOrEsdtTokenPayment<Self::Api>> {
+        if self.seeded_pool_info().is_empty() {
+            return None;
+        }
+
+        let seeded_pool_info = self.seeded_pool_info().get();
+        let redeem_token_supply = self
+            .redeem_token_total_circulating_supply(LAUNCHED_TOKEN_REDEEM_NONCE)
+            .get();
+        let lp_tokens = seeded_pool_info.depositors_lp_tokens;
+        let lp_share = lp_tokens.amount * redeem_token_amount / redeem_token_supply;
+        if lp_share == 0 {
+            return None;
+        }
+
+        let locked_lp_tokens = self.lock_tokens_until_and_forward(
+            to,
+            EgldOrEsdtTokenIdentifier::esdt(lp_tokens.token_identifier),
+            lp_share,
+            seeded_pool_info.lp_unlock_epoch,
+        );
+
+        Some(locked_lp_tokens)
+    }
+
     #[inline]
     fn burn_redeem_token_without_supply_decrease(&self, nonce: u64, amount: &BigUint) {
         self.redeem_token().nft_burn(nonce, amount);
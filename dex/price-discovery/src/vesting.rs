@@ -0,0 +1,131 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::phase::Phase;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingSchedule {
+    pub start_epoch: u64,
+    pub duration_epochs: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct VestingPosition<M: ManagedTypeApi> {
+    pub total_amount: BigUint<M>,
+    pub claimed_amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait VestingModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule + crate::phase::PhaseModule
+{
+    /// When set, the bought launched tokens are not locked, but vest linearly
+    /// over duration_epochs, starting from start_epoch. They are then claimed through claimVestedTokens.
+    /// The schedule cannot be changed once the redeem phase starts.
+    #[only_owner]
+    #[endpoint(setVestingSchedule)]
+    fn set_vesting_schedule(&self, start_epoch: u64, duration_epochs: u64) {
+        self.require_before_redeem();
+        require!(duration_epochs > 0, "Invalid vesting duration");
+
+        self.vesting_schedule().set(VestingSchedule {
+            start_epoch,
+            duration_epochs,
+        });
+    }
+
+    #[only_owner]
+    #[endpoint(removeVestingSchedule)]
+    fn remove_vesting_schedule(&self) {
+        self.require_before_redeem();
+        self.vesting_schedule().clear();
+    }
+
+    #[endpoint(claimVestedTokens)]
+    fn claim_vested_tokens(&self) -> EsdtTokenPayment<Self::Api> {
+        let caller = self.blockchain().get_caller();
+        let position_mapper = self.user_vesting_position(&caller);
+        require!(!position_mapper.is_empty(), "Nothing to claim");
+
+        let mut position = position_mapper.get();
+        let claimable_amount = self.get_claimable_amount(&position);
+        position.claimed_amount += &claimable_amount;
+        if position.claimed_amount == position.total_amount {
+            position_mapper.clear();
+        } else {
+            position_mapper.set(position);
+        }
+
+        let launched_token_id = self.launched_token_id().get();
+        if claimable_amount > 0 {
+            self.send()
+                .direct_esdt(&caller, &launched_token_id, 0, &claimable_amount);
+        }
+
+        EsdtTokenPayment::new(launched_token_id, 0, claimable_amount)
+    }
+
+    #[view(getClaimableVestedTokens)]
+    fn get_claimable_vested_tokens(&self, user: ManagedAddress) -> BigUint {
+        let position_mapper = self.user_vesting_position(&user);
+        if position_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        self.get_claimable_amount(&position_mapper.get())
+    }
+
+    #[inline]
+    fn is_vesting_enabled(&self) -> bool {
+        !self.vesting_schedule().is_empty()
+    }
+
+    fn add_vested_tokens(&self, user: &ManagedAddress, amount: &BigUint) {
+        let position_mapper = self.user_vesting_position(user);
+        let mut position = if !position_mapper.is_empty() {
+            position_mapper.get()
+        } else {
+            VestingPosition {
+                total_amount: BigUint::zero(),
+                claimed_amount: BigUint::zero(),
+            }
+        };
+        position.total_amount += amount;
+
+        position_mapper.set(position);
+    }
+
+    fn get_claimable_amount(&self, position: &VestingPosition<Self::Api>) -> BigUint {
+        let schedule = self.vesting_schedule().get();
+        let current_epoch = self.blockchain().get_block_epoch();
+        if current_epoch <= schedule.start_epoch {
+            return BigUint::zero();
+        }
+
+        let epochs_passed = core::cmp::min(
+            current_epoch - schedule.start_epoch,
+            schedule.duration_epochs,
+        );
+        let vested_amount = &position.total_amount * epochs_passed / schedule.duration_epochs;
+
+        vested_amount - &position.claimed_amount
+    }
+
+    fn require_before_redeem(&self) {
+        require!(
+            self.get_current_phase() != Phase::Redeem,
+            "Cannot change vesting in this phase"
+        );
+    }
+
+    #[view(getVestingSchedule)]
+    #[storage_mapper("vestingSchedule")]
+    fn vesting_schedule(&self) -> SingleValueMapper<VestingSchedule>;
+
+    #[view(getUserVestingPosition)]
+    #[storage_mapper("userVestingPosition")]
+    fn user_vesting_position(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<VestingPosition<Self::Api>>;
+}
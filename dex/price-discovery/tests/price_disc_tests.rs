@@ -1,10 +1,19 @@
 #![allow(deprecated)]
 
 use multiversx_sc::codec::Empty;
-use multiversx_sc_scenario::{managed_biguint, managed_token_id_wrapped};
+use multiversx_sc::contract_base::ContractBase;
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id_wrapped};
 use multiversx_sc_scenario::{rust_biguint, DebugApi};
+use pair::config::ConfigModule as PairConfigModule;
+use price_discovery::auction::{
+    AuctionModule, AuctionResult, BidAttributes, LaunchMechanism, MAX_BATCH_PRICE_LEVELS,
+};
 use price_discovery::common_storage::*;
+use price_discovery::participation::ParticipationModule;
+use price_discovery::pool_seeding::PoolSeedingModule;
 use price_discovery::redeem_token::*;
+use price_discovery::vesting::VestingModule;
 use price_discovery::PriceDiscovery;
 
 mod tests_common;
@@ -12,6 +21,7 @@ use simple_lock::locked_token::LockedTokenAttributes;
 use tests_common::*;
 
 const MIN_PRICE_PRECISION: u64 = 1_000_000_000_000_000_000;
+const MIN_PRICE_LEVEL_BID: u64 = 1_000_000;
 
 #[test]
 fn test_init() {
@@ -429,3 +439,875 @@ fn redeem_too_early() {
     )
     .assert_user_error("Redeem not allowed in this phase");
 }
+
+#[test]
+fn dutch_auction_sold_out_test() {
+    let mut pd_setup = init_with_launch_mechanism(price_discovery::contract_obj, || {
+        LaunchMechanism::DutchAuction {
+            start_price: managed_biguint!(MIN_PRICE_PRECISION),
+            end_price: managed_biguint!(MIN_PRICE_PRECISION / 10),
+            min_raise: managed_biguint!(100_000_000),
+            max_raise: managed_biguint!(800_000_000),
+        }
+    });
+
+    let launched_tokens_amount = rust_biguint!(1_000_000_000);
+    call_deposit_initial_tokens(&mut pd_setup, &launched_tokens_amount);
+
+    // withdraw is not possible in auctions
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    let first_user_address = pd_setup.first_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_ok();
+    call_withdraw(&mut pd_setup, &first_user_address, &rust_biguint!(1))
+        .assert_user_error("Withdraw not allowed in this phase");
+
+    // price is 0.58 - only 280_000_000 more are needed to buy all the launched tokens
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK + 7);
+    let second_user_address = pd_setup.second_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(900_000_000),
+    )
+    .assert_ok();
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(720_000_000),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance::<Empty>(
+        &second_user_address,
+        REDEEM_TOKEN_ID,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(280_000_000),
+        None,
+    );
+
+    call_deposit(&mut pd_setup, &first_user_address, &rust_biguint!(1_000))
+        .assert_user_error("Auction sold out");
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK);
+    call_redeem_auction(
+        &mut pd_setup,
+        &first_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_ok();
+    call_redeem_auction(
+        &mut pd_setup,
+        &second_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(280_000_000),
+    )
+    .assert_ok();
+
+    let owner_address = pd_setup.owner_address.clone();
+    call_redeem(
+        &mut pd_setup,
+        &owner_address,
+        LAUNCHED_TOKEN_REDEEM_NONCE,
+        &launched_tokens_amount,
+    )
+    .assert_user_error("Auction launches are redeemed through redeemAuction");
+    call_redeem_auction(
+        &mut pd_setup,
+        &owner_address,
+        LAUNCHED_TOKEN_REDEEM_NONCE,
+        &launched_tokens_amount,
+    )
+    .assert_ok();
+
+    // everyone pays the clearing price of 0.58
+    DebugApi::dummy();
+    let locked_launched_attributes = LockedTokenAttributes::<DebugApi> {
+        original_token_id: managed_token_id_wrapped!(LAUNCHED_TOKEN_ID),
+        original_token_nonce: 0,
+        unlock_epoch: UNLOCK_EPOCH,
+    };
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &first_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(300_000_000u64 * 100 / 58),
+        Some(&locked_launched_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &second_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(280_000_000u64 * 100 / 58),
+        Some(&locked_launched_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        2,
+        &rust_biguint!(580_000_000),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(ACCEPTED_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: UNLOCK_EPOCH,
+        }),
+    );
+}
+
+#[test]
+fn batch_auction_partial_fill_test() {
+    let mut pd_setup = init_with_launch_mechanism(price_discovery::contract_obj, || {
+        LaunchMechanism::BatchAuction {
+            min_raise: managed_biguint!(100_000_000),
+            max_raise: managed_biguint!(1_000_000_000),
+            min_price_level_bid: managed_biguint!(MIN_PRICE_LEVEL_BID),
+        }
+    });
+
+    let launched_tokens_amount = rust_biguint!(1_000_000_000);
+    call_deposit_initial_tokens(&mut pd_setup, &launched_tokens_amount);
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_user_error("Bids must be placed through placeBid");
+    call_place_bid(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(300_000_000),
+        MIN_PRICE_PRECISION,
+    )
+    .assert_ok();
+    call_place_bid(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(600_000_000),
+        MIN_PRICE_PRECISION / 2,
+    )
+    .assert_ok();
+
+    let first_bid_nonce = ACCEPTED_TOKEN_REDEEM_NONCE + 1;
+    let second_bid_nonce = ACCEPTED_TOKEN_REDEEM_NONCE + 2;
+    DebugApi::dummy();
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &second_user_address,
+        REDEEM_TOKEN_ID,
+        second_bid_nonce,
+        &rust_biguint!(600_000_000),
+        Some(&BidAttributes::<DebugApi> {
+            max_price: managed_biguint!(MIN_PRICE_PRECISION / 2),
+        }),
+    );
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK);
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            let auction_result = sc.get_auction_result_view().into_option().unwrap();
+            assert_eq!(
+                auction_result,
+                AuctionResult {
+                    clearing_price: managed_biguint!(MIN_PRICE_PRECISION / 2),
+                    marginal_fill_numerator: managed_biguint!(200_000_000),
+                    marginal_fill_denominator: managed_biguint!(600_000_000),
+                    launched_tokens_sold: managed_biguint!(1_000_000_000),
+                    accepted_tokens_raised: managed_biguint!(500_000_000),
+                    successful: true,
+                }
+            );
+        })
+        .assert_ok();
+
+    // the second bid is at the clearing price, so it is filled for a third
+    call_redeem_auction(
+        &mut pd_setup,
+        &first_user_address,
+        first_bid_nonce,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_ok();
+    call_redeem_auction(
+        &mut pd_setup,
+        &second_user_address,
+        second_bid_nonce,
+        &rust_biguint!(600_000_000),
+    )
+    .assert_ok();
+
+    let owner_address = pd_setup.owner_address.clone();
+    call_redeem_auction(
+        &mut pd_setup,
+        &owner_address,
+        LAUNCHED_TOKEN_REDEEM_NONCE,
+        &launched_tokens_amount,
+    )
+    .assert_ok();
+
+    let locked_launched_attributes = LockedTokenAttributes::<DebugApi> {
+        original_token_id: managed_token_id_wrapped!(LAUNCHED_TOKEN_ID),
+        original_token_nonce: 0,
+        unlock_epoch: UNLOCK_EPOCH,
+    };
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &first_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(600_000_000),
+        Some(&locked_launched_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &first_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(700_000_000),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &second_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(400_000_000),
+        Some(&locked_launched_attributes),
+    );
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(800_000_000),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        2,
+        &rust_biguint!(500_000_000),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(ACCEPTED_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: UNLOCK_EPOCH,
+        }),
+    );
+}
+
+#[test]
+fn batch_auction_below_min_raise_test() {
+    let mut pd_setup = init_with_launch_mechanism(price_discovery::contract_obj, || {
+        LaunchMechanism::BatchAuction {
+            min_raise: managed_biguint!(500_000_000),
+            max_raise: managed_biguint!(1_000_000_000),
+            min_price_level_bid: managed_biguint!(MIN_PRICE_LEVEL_BID),
+        }
+    });
+
+    let launched_tokens_amount = rust_biguint!(1_000_000_000);
+    call_deposit_initial_tokens(&mut pd_setup, &launched_tokens_amount);
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    let first_user_address = pd_setup.first_user_address.clone();
+    call_place_bid(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(300_000_000),
+        MIN_PRICE_PRECISION,
+    )
+    .assert_ok();
+
+    // not enough was raised, so everyone gets their tokens back
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK);
+    call_redeem_auction(
+        &mut pd_setup,
+        &first_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE + 1,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_ok();
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &first_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_000_000_000),
+    );
+
+    let owner_address = pd_setup.owner_address.clone();
+    call_redeem_auction(
+        &mut pd_setup,
+        &owner_address,
+        LAUNCHED_TOKEN_REDEEM_NONCE,
+        &launched_tokens_amount,
+    )
+    .assert_ok();
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(5_000_000_000),
+    );
+}
+
+#[test]
+fn batch_auction_dust_bids_test() {
+    let mut pd_setup = init_with_launch_mechanism(price_discovery::contract_obj, || {
+        LaunchMechanism::BatchAuction {
+            min_raise: managed_biguint!(100_000_000),
+            max_raise: managed_biguint!(1_000_000_000),
+            min_price_level_bid: managed_biguint!(MIN_PRICE_LEVEL_BID),
+        }
+    });
+
+    let launched_tokens_amount = rust_biguint!(1_000_000_000);
+    call_deposit_initial_tokens(&mut pd_setup, &launched_tokens_amount);
+
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+
+    // dust bids cannot open new price levels
+    for i in 0..MAX_BATCH_PRICE_LEVELS as u64 {
+        call_place_bid(
+            &mut pd_setup,
+            &first_user_address,
+            &rust_biguint!(1),
+            MIN_PRICE_PRECISION + i,
+        )
+        .assert_user_error("Bid too low for a new price level");
+    }
+
+    for i in 0..MAX_BATCH_PRICE_LEVELS as u64 {
+        call_place_bid(
+            &mut pd_setup,
+            &first_user_address,
+            &rust_biguint!(MIN_PRICE_LEVEL_BID),
+            MIN_PRICE_PRECISION + i,
+        )
+        .assert_ok();
+    }
+    call_place_bid(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(MIN_PRICE_LEVEL_BID),
+        MIN_PRICE_PRECISION / 2,
+    )
+    .assert_user_error("Too many price levels");
+
+    // bids at an existing price level have no minimum
+    call_place_bid(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(1),
+        MIN_PRICE_PRECISION,
+    )
+    .assert_ok();
+}
+
+#[test]
+fn seed_pool_test() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    let (router_address, pair_wrapper) = setup_seeding_pair_and_router(&mut pd_setup);
+    let pair_address = pair_wrapper.address_ref().clone();
+    user_deposit_ok_steps(&mut pd_setup);
+
+    // half of the raised tokens go to the pool
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_pool_seeding_config(
+                    managed_address!(&router_address),
+                    managed_biguint!(MAX_PERCENTAGE / 2),
+                    10,
+                );
+            },
+        )
+        .assert_ok();
+
+    let reserve_amount = rust_biguint!(3_000_000_000);
+    pd_setup.blockchain_wrapper.set_esdt_balance(
+        &owner_address,
+        LAUNCHED_TOKEN_ID,
+        &reserve_amount,
+    );
+    pd_setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            LAUNCHED_TOKEN_ID,
+            0,
+            &reserve_amount,
+            |sc| {
+                sc.deposit_pool_seeding_tokens();
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK + 1);
+    let owner_redeem_amount = rust_biguint!(5_000_000_000);
+    call_redeem(
+        &mut pd_setup,
+        &owner_address,
+        LAUNCHED_TOKEN_REDEEM_NONCE,
+        &owner_redeem_amount,
+    )
+    .assert_user_error("Pool must be seeded first");
+
+    // price is 0.3, so 750_000_000 accepted tokens are paired with 2_500_000_000 launched tokens
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.seed_pool();
+            },
+        )
+        .assert_ok();
+
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &pair_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(2_500_000_000),
+    );
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &pair_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(750_000_000),
+    );
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(500_000_000),
+    );
+
+    // the LP tokens are split between the owner and the launched token depositors
+    DebugApi::dummy();
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(374_999_500),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(LP_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: 10,
+        }),
+    );
+
+    // the launched token depositors get their LP tokens and the rest of the raised tokens,
+    // and the locked LP tokens are merged with the owner's, as they have the same unlock epoch
+    call_redeem(
+        &mut pd_setup,
+        &owner_address,
+        LAUNCHED_TOKEN_REDEEM_NONCE,
+        &owner_redeem_amount,
+    )
+    .assert_ok();
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(749_999_000),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(LP_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: 10,
+        }),
+    );
+    pd_setup.blockchain_wrapper.check_nft_balance(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        2,
+        &rust_biguint!(750_000_000),
+        Some(&LockedTokenAttributes::<DebugApi> {
+            original_token_id: managed_token_id_wrapped!(ACCEPTED_TOKEN_ID),
+            original_token_nonce: 0,
+            unlock_epoch: UNLOCK_EPOCH,
+        }),
+    );
+}
+
+#[test]
+fn seed_existing_pair_checks_test() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    let (router_address, pair_wrapper) = setup_seeding_pair_and_router(&mut pd_setup);
+    let owner_address = pd_setup.owner_address.clone();
+    let rust_zero = rust_biguint!(0);
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pd_setup.pd_wrapper, &rust_zero, |sc| {
+            sc.set_pool_seeding_config(
+                managed_address!(&router_address),
+                managed_biguint!(MAX_PERCENTAGE / 2),
+                10,
+            );
+        })
+        .assert_ok();
+
+    // another address is the initial liquidity adder
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+            sc.initial_liquidity_adder()
+                .set(Some(managed_address!(&owner_address)));
+        })
+        .assert_ok();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pd_setup.pd_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_seeding_pair();
+        })
+        .assert_user_error("Not the initial liquidity adder of the pair");
+
+    // the pair already has liquidity
+    let pd_address = pd_setup.pd_wrapper.address_ref().clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+            sc.initial_liquidity_adder()
+                .set(Some(managed_address!(&pd_address)));
+            sc.lp_token_supply().set(managed_biguint!(1_000));
+        })
+        .assert_ok();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pd_setup.pd_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_seeding_pair();
+        })
+        .assert_user_error("Pair already has liquidity");
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+            sc.lp_token_supply().clear();
+        })
+        .assert_ok();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(&owner_address, &pd_setup.pd_wrapper, &rust_zero, |sc| {
+            let pair_address = sc.create_seeding_pair();
+            assert_eq!(pair_address, managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn contribution_limits_test() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(5_000_000_000));
+
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_contribution_limits(
+                    managed_biguint!(100_000_000),
+                    managed_biguint!(600_000_000),
+                );
+            },
+        )
+        .assert_ok();
+
+    let first_user_address = pd_setup.first_user_address.clone();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(50_000_000),
+    )
+    .assert_user_error("Contribution below min");
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(700_000_000),
+    )
+    .assert_user_error("Contribution above max");
+
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(400_000_000),
+    )
+    .assert_ok();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_user_error("Contribution above max");
+
+    // withdrawing frees up the contribution
+    call_withdraw(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_ok();
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(300_000_000),
+    )
+    .assert_ok();
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_query(&pd_setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.user_contribution(&managed_address!(&first_user_address))
+                    .get(),
+                managed_biguint!(600_000_000)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn allowlist_test() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+    pd_setup.blockchain_wrapper.set_block_nonce(START_BLOCK);
+    call_deposit_initial_tokens(&mut pd_setup, &rust_biguint!(5_000_000_000));
+
+    let owner_address = pd_setup.owner_address.clone();
+    let first_user_address = pd_setup.first_user_address.clone();
+    let second_user_address = pd_setup.second_user_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_allowlist_enabled(true);
+
+                // both users are leaves of the tree
+                let first_leaf = sc
+                    .crypto()
+                    .keccak256(managed_address!(&first_user_address).as_managed_buffer());
+                let second_leaf = sc
+                    .crypto()
+                    .keccak256(managed_address!(&second_user_address).as_managed_buffer());
+                let merkle_root = sc.hash_merkle_pair(&first_leaf, &second_leaf);
+                sc.set_allowlist_merkle_root(merkle_root);
+            },
+        )
+        .assert_ok();
+
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_user_error("Address not allowlisted");
+
+    // wrong proof
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let wrong_node = sc
+                    .crypto()
+                    .keccak256(managed_address!(&owner_address).as_managed_buffer());
+                let mut proof = MultiValueEncoded::new();
+                proof.push(wrong_node);
+                sc.prove_allowlisted(proof);
+            },
+        )
+        .assert_user_error("Invalid Merkle proof");
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let sibling_node = sc
+                    .crypto()
+                    .keccak256(managed_address!(&second_user_address).as_managed_buffer());
+                let mut proof = MultiValueEncoded::new();
+                proof.push(sibling_node);
+                sc.prove_allowlisted(proof);
+            },
+        )
+        .assert_ok();
+
+    call_deposit(
+        &mut pd_setup,
+        &first_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_ok();
+
+    // second user is added by the owner, then removed
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut addresses = MultiValueEncoded::new();
+                addresses.push(managed_address!(&second_user_address));
+                sc.add_to_allowlist(addresses);
+            },
+        )
+        .assert_ok();
+
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_ok();
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut addresses = MultiValueEncoded::new();
+                addresses.push(managed_address!(&second_user_address));
+                sc.remove_from_allowlist(addresses);
+            },
+        )
+        .assert_ok();
+
+    call_deposit(
+        &mut pd_setup,
+        &second_user_address,
+        &rust_biguint!(100_000_000),
+    )
+    .assert_user_error("Address not allowlisted");
+}
+
+#[test]
+fn vesting_test() {
+    let mut pd_setup = init(price_discovery::contract_obj);
+
+    let owner_address = pd_setup.owner_address.clone();
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_vesting_schedule(5, 10);
+            },
+        )
+        .assert_ok();
+
+    user_deposit_ok_steps(&mut pd_setup);
+    withdraw_ok_steps(&mut pd_setup, 0);
+
+    pd_setup.blockchain_wrapper.set_block_nonce(END_BLOCK + 1);
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &owner_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_vesting_schedule();
+            },
+        )
+        .assert_user_error("Cannot change vesting in this phase");
+
+    let first_user_address = pd_setup.first_user_address.clone();
+    call_redeem(
+        &mut pd_setup,
+        &first_user_address,
+        ACCEPTED_TOKEN_REDEEM_NONCE,
+        &rust_biguint!(600_000_000),
+    )
+    .assert_ok();
+
+    // nothing is locked, the tokens vest instead
+    pd_setup.blockchain_wrapper.check_nft_balance::<Empty>(
+        &first_user_address,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(0),
+        None,
+    );
+
+    let total_vested_amount = 5_000_000_000u64 * 600_000_000 / 1_100_000_000;
+    pd_setup.blockchain_wrapper.set_block_epoch(5);
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let payment = sc.claim_vested_tokens();
+                assert_eq!(payment.amount, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    // half of the duration passed
+    pd_setup.blockchain_wrapper.set_block_epoch(10);
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_vested_tokens();
+            },
+        )
+        .assert_ok();
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(total_vested_amount / 2),
+    );
+
+    pd_setup.blockchain_wrapper.set_block_epoch(30);
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_vested_tokens();
+            },
+        )
+        .assert_ok();
+    pd_setup.blockchain_wrapper.check_esdt_balance(
+        &first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(total_vested_amount),
+    );
+
+    pd_setup
+        .blockchain_wrapper
+        .execute_tx(
+            &first_user_address,
+            &pd_setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.claim_vested_tokens();
+            },
+        )
+        .assert_user_error("Nothing to claim");
+}
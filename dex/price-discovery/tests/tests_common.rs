@@ -1,17 +1,23 @@
 #![allow(deprecated)]
 
-use multiversx_sc::codec::Empty;
-use multiversx_sc::types::{Address, EsdtLocalRole};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{Address, EsdtLocalRole, MultiValueEncoded};
 use multiversx_sc_scenario::whitebox_legacy::TxResult;
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id_wrapped, whitebox_legacy::*,
 };
 use multiversx_sc_scenario::{managed_token_id, rust_biguint, DebugApi};
 
+use price_discovery::auction::{AuctionModule, LaunchMechanism};
 use price_discovery::redeem_token::*;
 use price_discovery::*;
 
 use multiversx_sc::storage::mappers::StorageTokenWrapper;
+use pair::config::ConfigModule as PairConfigModule;
+use pair::Pair;
+use router::config::ConfigModule as RouterConfigModule;
+use router::factory::PairTokens;
+use router::Router;
 use simple_lock::locked_token::LockedTokenModule;
 use simple_lock::SimpleLock;
 
@@ -21,6 +27,7 @@ pub const LAUNCHED_TOKEN_ID: &[u8] = b"SOCOOLWOW-123456";
 pub const ACCEPTED_TOKEN_ID: &[u8] = b"USDC-123456";
 pub const REDEEM_TOKEN_ID: &[u8] = b"GIBREWARDS-123456";
 pub const LOCKED_TOKEN_ID: &[u8] = b"NOOO0-123456";
+pub const LP_TOKEN_ID: &[u8] = b"LPTOK-123456";
 pub const OWNER_EGLD_BALANCE: u64 = 100_000_000;
 
 pub const START_BLOCK: u64 = 10;
@@ -54,6 +61,17 @@ pub fn init<PriceDiscObjBuilder>(
 ) -> PriceDiscSetup<PriceDiscObjBuilder>
 where
     PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+{
+    init_with_launch_mechanism(pd_builder, || LaunchMechanism::PriceDiscovery)
+}
+
+pub fn init_with_launch_mechanism<PriceDiscObjBuilder, LaunchMechanismBuilder>(
+    pd_builder: PriceDiscObjBuilder,
+    launch_mechanism_builder: LaunchMechanismBuilder,
+) -> PriceDiscSetup<PriceDiscObjBuilder>
+where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+    LaunchMechanismBuilder: Fn() -> LaunchMechanism<DebugApi>,
 {
     let rust_zero = rust_biguint!(0u64);
     let mut blockchain_wrapper = BlockchainStateWrapper::new();
@@ -87,7 +105,7 @@ where
         &rust_biguint!(1_000_000_000),
     );
 
-    // set sc roles and initial minted SFTs (only needed for the purpose of SFT add quantity)
+    // set sc roles, the initial SFTs are minted after init
     blockchain_wrapper.set_esdt_local_roles(
         pd_wrapper.address_ref(),
         REDEEM_TOKEN_ID,
@@ -97,20 +115,6 @@ where
             EsdtLocalRole::NftAddQuantity,
         ],
     );
-    blockchain_wrapper.set_nft_balance(
-        pd_wrapper.address_ref(),
-        REDEEM_TOKEN_ID,
-        LAUNCHED_TOKEN_REDEEM_NONCE,
-        &rust_biguint!(1),
-        &Empty,
-    );
-    blockchain_wrapper.set_nft_balance(
-        pd_wrapper.address_ref(),
-        REDEEM_TOKEN_ID,
-        ACCEPTED_TOKEN_REDEEM_NONCE,
-        &rust_biguint!(1),
-        &Empty,
-    );
 
     blockchain_wrapper.set_block_nonce(START_BLOCK - 1);
 
@@ -157,10 +161,14 @@ where
                 managed_biguint!(MAX_PENALTY_PERCENTAGE),
                 managed_biguint!(FIXED_PENALTY_PERCENTAGE),
                 managed_address!(locking_sc_wrapper.address_ref()),
+                OptionalValue::Some(launch_mechanism_builder()),
             );
 
             sc.redeem_token()
                 .set_token_id(managed_token_id!(REDEEM_TOKEN_ID));
+
+            // created through NFTCreate, so the batch auction bids get the next nonces
+            sc.create_initial_redeem_tokens();
         })
         .assert_ok();
 
@@ -258,3 +266,116 @@ where
         },
     )
 }
+
+pub fn call_place_bid<PriceDiscObjBuilder>(
+    pd_setup: &mut PriceDiscSetup<PriceDiscObjBuilder>,
+    caller: &Address,
+    amount: &num_bigint::BigUint,
+    max_price: u64,
+) -> TxResult
+where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+{
+    let b_wrapper = &mut pd_setup.blockchain_wrapper;
+    b_wrapper.execute_esdt_transfer(
+        caller,
+        &pd_setup.pd_wrapper,
+        ACCEPTED_TOKEN_ID,
+        0,
+        amount,
+        |sc| {
+            let _ = sc.place_bid(managed_biguint!(max_price));
+        },
+    )
+}
+
+pub fn call_redeem_auction<PriceDiscObjBuilder>(
+    pd_setup: &mut PriceDiscSetup<PriceDiscObjBuilder>,
+    caller: &Address,
+    sft_nonce: u64,
+    amount: &num_bigint::BigUint,
+) -> TxResult
+where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+{
+    let b_wrapper = &mut pd_setup.blockchain_wrapper;
+    b_wrapper.execute_esdt_transfer(
+        caller,
+        &pd_setup.pd_wrapper,
+        REDEEM_TOKEN_ID,
+        sft_nonce,
+        amount,
+        |sc| {
+            let _ = sc.redeem_auction();
+        },
+    )
+}
+
+// the pair already exists in the router, with the price discovery SC as initial liquidity adder
+pub fn setup_seeding_pair_and_router<PriceDiscObjBuilder>(
+    pd_setup: &mut PriceDiscSetup<PriceDiscObjBuilder>,
+) -> (
+    Address,
+    ContractObjWrapper<
+        pair::ContractObj<DebugApi>,
+        impl 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+    >,
+)
+where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+{
+    let rust_zero = rust_biguint!(0u64);
+    let b_wrapper = &mut pd_setup.blockchain_wrapper;
+    let owner_address = pd_setup.owner_address.clone();
+    let pd_address = pd_setup.pd_wrapper.address_ref().clone();
+
+    let router_wrapper = b_wrapper.create_sc_account(
+        &rust_zero,
+        Some(&owner_address),
+        router::contract_obj,
+        "router.wasm",
+    );
+    let pair_wrapper = b_wrapper.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair::contract_obj,
+        "pair.wasm",
+    );
+
+    b_wrapper
+        .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(LAUNCHED_TOKEN_ID),
+                managed_token_id!(ACCEPTED_TOKEN_ID),
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(&owner_address),
+                300,
+                50,
+                managed_address!(&pd_address),
+                MultiValueEncoded::new(),
+            );
+            sc.lp_token_identifier()
+                .set(&managed_token_id!(LP_TOKEN_ID));
+        })
+        .assert_ok();
+    b_wrapper.set_esdt_local_roles(
+        pair_wrapper.address_ref(),
+        LP_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    b_wrapper
+        .execute_tx(&owner_address, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::None);
+            sc.pair_map().insert(
+                PairTokens {
+                    first_token_id: managed_token_id!(LAUNCHED_TOKEN_ID),
+                    second_token_id: managed_token_id!(ACCEPTED_TOKEN_ID),
+                },
+                managed_address!(pair_wrapper.address_ref()),
+            );
+        })
+        .assert_ok();
+
+    (router_wrapper.address_ref().clone(), pair_wrapper)
+}
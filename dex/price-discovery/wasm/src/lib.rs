@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           27
+// Endpoints:                           62
 // Async Callback:                       1
-// Total number of exported functions:  30
+// Total number of exported functions:  65
 
 #![no_std]
 
@@ -24,18 +24,38 @@ multiversx_sc_wasm_adapter::endpoints! {
         withdraw => withdraw
         redeem => redeem
         getCurrentPrice => calculate_price
-        getMinLaunchedTokenPrice => min_launched_token_price
-        getPricePrecision => price_precision
+        placeBid => place_bid
+        redeemAuction => redeem_auction
+        getDutchAuctionPrice => get_dutch_auction_price_view
+        getAuctionResult => get_auction_result_view
+        getBatchAuctionDemand => get_batch_auction_demand
         getLaunchedTokenId => launched_token_id
         getAcceptedTokenId => accepted_token_id
         getLaunchedTokenBalance => launched_token_balance
         getAcceptedTokenBalance => accepted_token_balance
         getStartBlock => start_block
         getEndBlock => end_block
+        getMinLaunchedTokenPrice => min_launched_token_price
+        getPricePrecision => price_precision
+        getLaunchMechanism => launch_mechanism
+        getPoolSeedingConfig => pool_seeding_config
+        getSeededPoolInfo => seeded_pool_info
         setLockingScAddress => set_locking_sc_address
         setUnlockEpoch => set_unlock_epoch
         getLockingScAddress => locking_sc_address
         getUnlockEpoch => unlock_epoch
+        setContributionLimits => set_contribution_limits
+        setAllowlistEnabled => set_allowlist_enabled
+        addToAllowlist => add_to_allowlist
+        removeFromAllowlist => remove_from_allowlist
+        setAllowlistMerkleRoot => set_allowlist_merkle_root
+        proveAllowlisted => prove_allowlisted
+        getMinContribution => min_contribution
+        getMaxContribution => max_contribution
+        getUserContribution => user_contribution
+        isAllowlistEnabled => allowlist_enabled
+        getAllowlist => allowlist
+        getAllowlistMerkleRoot => allowlist_merkle_root
         getCurrentPhase => get_current_phase
         getNoLimitPhaseDurationBlocks => no_limit_phase_duration_blocks
         getLinearPenaltyPhaseDurationBlocks => linear_penalty_phase_duration_blocks
@@ -43,10 +63,25 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPenaltyMinPercentage => penalty_min_percentage
         getPenaltyMaxPercentage => penalty_max_percentage
         getFixedPenaltyPercentage => fixed_penalty_percentage
+        setPoolSeedingConfig => set_pool_seeding_config
+        removePoolSeedingConfig => remove_pool_seeding_config
+        depositPoolSeedingTokens => deposit_pool_seeding_tokens
+        withdrawPoolSeedingTokens => withdraw_pool_seeding_tokens
+        createSeedingPair => create_seeding_pair
+        issueSeedingPairLpToken => issue_seeding_pair_lp_token
+        seedPool => seed_pool
+        getPoolSeedingLaunchedTokens => pool_seeding_launched_tokens
+        getSeedingPairAddress => seeding_pair_address
         issueRedeemToken => issue_redeem_token
         createInitialRedeemTokens => create_initial_redeem_tokens
         getRedeemTokenId => redeem_token
         getRedeemTokenTotalCirculatingSupply => redeem_token_total_circulating_supply
+        setVestingSchedule => set_vesting_schedule
+        removeVestingSchedule => remove_vesting_schedule
+        claimVestedTokens => claim_vested_tokens
+        getClaimableVestedTokens => get_claimable_vested_tokens
+        getVestingSchedule => vesting_schedule
+        getUserVestingPosition => user_vesting_position
     )
 }
 
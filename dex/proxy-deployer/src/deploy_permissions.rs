@@ -0,0 +1,97 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait DeployPermissionsModule {
+    /// Non-owner deployers have to pay this fee, which is sent to the owner.
+    #[only_owner]
+    #[endpoint(setDeployFee)]
+    fn set_deploy_fee(&self, token_id: TokenIdentifier, amount: BigUint) {
+        require!(token_id.is_valid_esdt_identifier(), "Invalid token ID");
+        require!(amount > 0, "Invalid fee amount");
+
+        self.deploy_fee()
+            .set(EsdtTokenPayment::new(token_id, 0, amount));
+    }
+
+    #[only_owner]
+    #[endpoint(removeDeployFee)]
+    fn remove_deploy_fee(&self) {
+        self.deploy_fee().clear();
+    }
+
+    /// When enabled, only the owner and the whitelisted addresses can deploy farms.
+    #[only_owner]
+    #[endpoint(setDeployerWhitelistEnabled)]
+    fn set_deployer_whitelist_enabled(&self, enabled: bool) {
+        self.deployer_whitelist_enabled().set(enabled);
+    }
+
+    #[only_owner]
+    #[endpoint(addDeployersToWhitelist)]
+    fn add_deployers_to_whitelist(&self, deployers: MultiValueEncoded<ManagedAddress>) {
+        let mut whitelist = self.deployer_whitelist();
+        for deployer in deployers {
+            let _ = whitelist.insert(deployer);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeDeployersFromWhitelist)]
+    fn remove_deployers_from_whitelist(&self, deployers: MultiValueEncoded<ManagedAddress>) {
+        let mut whitelist = self.deployer_whitelist();
+        for deployer in deployers {
+            let _ = whitelist.swap_remove(&deployer);
+        }
+    }
+
+    fn require_can_deploy_and_pay_fee(&self, caller: &ManagedAddress) {
+        let owner = self.blockchain().get_owner_address();
+        let fee_mapper = self.deploy_fee();
+        if caller == &owner || fee_mapper.is_empty() {
+            require!(
+                self.call_value().all_esdt_transfers().is_empty(),
+                "No payment expected"
+            );
+        }
+        if caller == &owner {
+            return;
+        }
+
+        if self.deployer_whitelist_enabled().get() {
+            require!(
+                self.deployer_whitelist().contains(caller),
+                "Caller may not deploy farms"
+            );
+        }
+
+        if fee_mapper.is_empty() {
+            return;
+        }
+
+        let fee = fee_mapper.get();
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == fee.token_identifier && payment.amount == fee.amount,
+            "Invalid deploy fee payment"
+        );
+
+        self.send().direct_esdt(
+            &owner,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &payment.amount,
+        );
+    }
+
+    #[view(getDeployFee)]
+    #[storage_mapper("deployFee")]
+    fn deploy_fee(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    #[view(isDeployerWhitelistEnabled)]
+    #[storage_mapper("deployerWhitelistEnabled")]
+    fn deployer_whitelist_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getDeployerWhitelist)]
+    #[storage_mapper("deployerWhitelist")]
+    fn deployer_whitelist(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
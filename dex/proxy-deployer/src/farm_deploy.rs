@@ -1,37 +1,136 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
+use config::ProxyTrait as _;
 use farm::ProxyTrait as _;
+use farm_staking::ProxyTrait as _;
+use farm_with_locked_rewards::ProxyTrait as _;
+use multiversx_sc_modules::ongoing_operation::{CONTINUE_OP, STOP_OP};
 
-const DIVISION_SAFETY_CONST: u64 = 1_000_000_000_000_000_000;
+use crate::{
+    farm_templates::{
+        FarmTemplate, FarmTemplateInitArgs, FarmTemplateType, TemplateId, TemplateVersion,
+        FIRST_TEMPLATE_VERSION, LEGACY_FARMS_TEMPLATE_ID,
+    },
+    ongoing_upgrade_operation::{OngoingOperation, MIN_GAS_TO_SAVE_PROGRESS},
+};
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DeployedFarmInfo<M: ManagedTypeApi> {
+    pub deployer: ManagedAddress<M>,
+    pub template_id: TemplateId,
+    pub template_version: TemplateVersion,
+    pub farming_token_id: TokenIdentifier<M>,
+    pub reward_token_id: TokenIdentifier<M>,
+}
 
 #[multiversx_sc::module]
-pub trait FarmDeployModule {
+pub trait FarmDeployModule:
+    crate::farm_templates::FarmTemplatesModule
+    + crate::deploy_permissions::DeployPermissionsModule
+    + crate::ongoing_upgrade_operation::OngoingUpgradeOperationModule
+    + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+{
+    /// Deploys a farm from the given template.
+    /// For farm staking templates, the reward token must be the same as the farming token,
+    /// and no pair address is needed.
+    #[payable("*")]
     #[endpoint(deployFarm)]
     fn deploy_farm(
         &self,
+        template_id: TemplateId,
         reward_token_id: TokenIdentifier,
         farming_token_id: TokenIdentifier,
-        pair_contract_address: ManagedAddress,
+        opt_pair_contract_address: OptionalValue<ManagedAddress>,
     ) -> ManagedAddress {
-        let owner = self.blockchain().get_owner_address();
         let caller = self.blockchain().get_caller();
+        self.require_can_deploy_and_pay_fee(&caller);
+
+        let owner = self.blockchain().get_owner_address();
         let mut admins_list = MultiValueEncoded::new();
         admins_list.push(caller.clone());
 
-        let farm_template = self.farm_template_address().get();
-        let code_metadata =
-            CodeMetadata::PAYABLE_BY_SC | CodeMetadata::READABLE | CodeMetadata::UPGRADEABLE;
-        let (new_farm_address, ()) = self
-            .farm_deploy_proxy()
-            .init(
-                reward_token_id,
+        let farm_template = self.get_farm_template_or_panic(template_id);
+        let new_farm_address = match farm_template.init_args {
+            FarmTemplateInitArgs::Farm {
+                division_safety_constant,
+            } => {
+                let pair_contract_address =
+                    self.get_pair_address_or_zero(opt_pair_contract_address);
+                let (new_farm_address, ()) = self
+                    .farm_deploy_proxy()
+                    .init(
+                        reward_token_id.clone(),
+                        farming_token_id.clone(),
+                        division_safety_constant,
+                        pair_contract_address,
+                        owner,
+                        admins_list,
+                    )
+                    .deploy_from_source(&farm_template.template_address, self.get_code_metadata());
+
+                new_farm_address
+            }
+            FarmTemplateInitArgs::FarmWithLockedRewards {
+                division_safety_constant,
+            } => {
+                let pair_contract_address =
+                    self.get_pair_address_or_zero(opt_pair_contract_address);
+                let (new_farm_address, ()) = self
+                    .farm_with_locked_rewards_deploy_proxy()
+                    .init(
+                        reward_token_id.clone(),
+                        farming_token_id.clone(),
+                        division_safety_constant,
+                        pair_contract_address,
+                        owner,
+                        admins_list,
+                    )
+                    .deploy_from_source(&farm_template.template_address, self.get_code_metadata());
+
+                new_farm_address
+            }
+            FarmTemplateInitArgs::FarmStaking {
+                division_safety_constant,
+                max_apr,
+                min_unbond_epochs,
+            } => {
+                require!(
+                    reward_token_id == farming_token_id,
+                    "Reward token must be the farming token"
+                );
+                require!(
+                    opt_pair_contract_address.is_none(),
+                    "No pair address expected"
+                );
+
+                let (new_farm_address, ()) = self
+                    .farm_staking_deploy_proxy()
+                    .init(
+                        farming_token_id.clone(),
+                        division_safety_constant,
+                        max_apr,
+                        min_unbond_epochs,
+                        owner,
+                        admins_list,
+                    )
+                    .deploy_from_source(&farm_template.template_address, self.get_code_metadata());
+
+                new_farm_address
+            }
+        };
+
+        self.deployed_farm_info(&new_farm_address)
+            .set(DeployedFarmInfo {
+                deployer: caller.clone(),
+                template_id,
+                template_version: farm_template.version,
                 farming_token_id,
-                DIVISION_SAFETY_CONST,
-                pair_contract_address,
-                owner,
-                admins_list,
-            )
-            .deploy_from_source(&farm_template, code_metadata);
+                reward_token_id,
+            });
+        let _ = self
+            .template_farms(template_id)
+            .insert(new_farm_address.clone());
 
         self.deployer_farm_addresses(&caller)
             .update(|farm_addresses| {
@@ -42,6 +141,147 @@ pub trait FarmDeployModule {
         new_farm_address
     }
 
+    /// Adds farms deployed before the templates registry to the registry.
+    /// They were deployed from the legacy farm template, which is registered as the first template,
+    /// so they can then be upgraded through `upgradeFarmsFromTemplate`.
+    #[only_owner]
+    #[endpoint(registerLegacyFarms)]
+    fn register_legacy_farms(
+        &self,
+        deployer: ManagedAddress,
+        farm_addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let legacy_template = self.get_farm_template_or_panic(LEGACY_FARMS_TEMPLATE_ID);
+        require!(
+            legacy_template.init_args.get_template_type() == FarmTemplateType::Farm,
+            "Invalid legacy farms template"
+        );
+
+        let deployer_farms = self.deployer_farm_addresses(&deployer).get();
+        let mut template_farms = self.template_farms(LEGACY_FARMS_TEMPLATE_ID);
+        for farm_address in farm_addresses {
+            require!(
+                deployer_farms.contains(&farm_address),
+                "Farm not deployed by the deployer"
+            );
+
+            let farm_info_mapper = self.deployed_farm_info(&farm_address);
+            require!(farm_info_mapper.is_empty(), "Farm already registered");
+
+            let farming_token_id: TokenIdentifier = self
+                .farm_proxy(farm_address.clone())
+                .farming_token_id()
+                .execute_on_dest_context();
+            let reward_token_id: TokenIdentifier = self
+                .farm_proxy(farm_address.clone())
+                .reward_token_id()
+                .execute_on_dest_context();
+            farm_info_mapper.set(DeployedFarmInfo {
+                deployer: deployer.clone(),
+                template_id: LEGACY_FARMS_TEMPLATE_ID,
+                template_version: FIRST_TEMPLATE_VERSION,
+                farming_token_id,
+                reward_token_id,
+            });
+            let _ = template_farms.insert(farm_address);
+        }
+    }
+
+    /// Upgrades the next farm deployed from an older version of the template to its current source.
+    /// The upgrade is an async call which ends the transaction,
+    /// so this has to be called once for each outdated farm.
+    /// Returns "completed" once there are no more outdated farms.
+    /// Upgrades for other templates are blocked until then.
+    #[only_owner]
+    #[endpoint(upgradeFarmsFromTemplate)]
+    fn upgrade_farms_from_template(&self, template_id: TemplateId) -> OperationCompletionStatus {
+        let farm_template = self.get_farm_template_or_panic(template_id);
+        let mut current_index = self.load_upgrade_farms_operation(template_id);
+        let template_farms = self.template_farms(template_id);
+        let template_farms_len = template_farms.len();
+
+        let mut opt_outdated_farm = None;
+        let run_result = self.run_while_it_has_gas(MIN_GAS_TO_SAVE_PROGRESS, || {
+            if current_index > template_farms_len {
+                return STOP_OP;
+            }
+
+            let farm_address = template_farms.get_by_index(current_index);
+            current_index += 1;
+            if self.is_farm_outdated(&farm_address, &farm_template) {
+                opt_outdated_farm = Some(farm_address);
+                return STOP_OP;
+            }
+
+            CONTINUE_OP
+        });
+
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas
+            || opt_outdated_farm.is_some()
+        {
+            self.save_progress(&OngoingOperation::UpgradeFarms {
+                template_id,
+                farm_index: current_index,
+            });
+        }
+
+        if let Some(farm_address) = opt_outdated_farm {
+            self.upgrade_farm(farm_address, &farm_template);
+        }
+
+        run_result
+    }
+
+    fn is_farm_outdated(
+        &self,
+        farm_address: &ManagedAddress,
+        farm_template: &FarmTemplate<Self::Api>,
+    ) -> bool {
+        self.deployed_farm_info(farm_address).get().template_version < farm_template.version
+    }
+
+    fn upgrade_farm(&self, farm_address: ManagedAddress, farm_template: &FarmTemplate<Self::Api>) {
+        // upgrade calls cannot take a callback, so the callback closure is saved as for async calls
+        self.callbacks()
+            .upgrade_farm_callback(&farm_address, farm_template.version)
+            .save_to_storage::<Self::Api>();
+
+        // all the farm templates have an upgrade endpoint without arguments
+        self.tx()
+            .to(farm_address)
+            .raw_upgrade()
+            .from_source(farm_template.template_address.clone())
+            .code_metadata(self.get_code_metadata())
+            .upgrade_async_call_and_exit();
+    }
+
+    #[callback]
+    fn upgrade_farm_callback(
+        &self,
+        farm_address: &ManagedAddress,
+        template_version: u32,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        if let ManagedAsyncCallResult::Ok(()) = result {
+            self.deployed_farm_info(farm_address)
+                .update(|farm_info| farm_info.template_version = template_version);
+        }
+    }
+
+    fn get_pair_address_or_zero(
+        &self,
+        opt_pair_contract_address: OptionalValue<ManagedAddress>,
+    ) -> ManagedAddress {
+        match opt_pair_contract_address {
+            OptionalValue::Some(pair_contract_address) => pair_contract_address,
+            OptionalValue::None => ManagedAddress::zero(),
+        }
+    }
+
+    fn get_code_metadata(&self) -> CodeMetadata {
+        CodeMetadata::PAYABLE_BY_SC | CodeMetadata::READABLE | CodeMetadata::UPGRADEABLE
+    }
+
     #[only_owner]
     #[endpoint(callFarmEndpoint)]
     fn call_farm_endpoint(
@@ -71,9 +311,35 @@ pub trait FarmDeployModule {
         all_farm_addresses
     }
 
+    /// Lists the farms deployed from templates, with their template version and tokens.
+    #[view(getFarmRegistry)]
+    fn get_farm_registry(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, DeployedFarmInfo<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        let last_template_id = self.last_template_id().get();
+        for template_id in 1..=last_template_id {
+            for farm_address in self.template_farms(template_id).iter() {
+                let farm_info = self.deployed_farm_info(&farm_address).get();
+                result.push((farm_address, farm_info).into());
+            }
+        }
+
+        result
+    }
+
     #[proxy]
     fn farm_deploy_proxy(&self) -> farm::Proxy<Self::Api>;
 
+    #[proxy]
+    fn farm_proxy(&self, to: ManagedAddress) -> farm::Proxy<Self::Api>;
+
+    #[proxy]
+    fn farm_with_locked_rewards_deploy_proxy(&self) -> farm_with_locked_rewards::Proxy<Self::Api>;
+
+    #[proxy]
+    fn farm_staking_deploy_proxy(&self) -> farm_staking::Proxy<Self::Api>;
+
     #[storage_mapper("farmTemplateAddress")]
     fn farm_template_address(&self) -> SingleValueMapper<ManagedAddress>;
 
@@ -86,4 +352,15 @@ pub trait FarmDeployModule {
         &self,
         deployer_address: &ManagedAddress,
     ) -> SingleValueMapper<ManagedVec<ManagedAddress>>;
+
+    #[view(getDeployedFarmInfo)]
+    #[storage_mapper("deployedFarmInfo")]
+    fn deployed_farm_info(
+        &self,
+        farm_address: &ManagedAddress,
+    ) -> SingleValueMapper<DeployedFarmInfo<Self::Api>>;
+
+    #[view(getTemplateFarms)]
+    #[storage_mapper("templateFarms")]
+    fn template_farms(&self, template_id: TemplateId) -> UnorderedSetMapper<ManagedAddress>;
 }
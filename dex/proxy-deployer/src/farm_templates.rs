@@ -0,0 +1,179 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type TemplateId = u32;
+pub type TemplateVersion = u32;
+
+pub const FIRST_TEMPLATE_VERSION: TemplateVersion = 1;
+pub const LEGACY_FARMS_TEMPLATE_ID: TemplateId = 1;
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
+pub enum FarmTemplateType {
+    Farm,
+    FarmWithLockedRewards,
+    FarmStaking,
+}
+
+/// The init arguments that are fixed per template.
+/// The tokens and the pair address are given by the deployer.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum FarmTemplateInitArgs<M: ManagedTypeApi> {
+    Farm {
+        division_safety_constant: BigUint<M>,
+    },
+    FarmWithLockedRewards {
+        division_safety_constant: BigUint<M>,
+    },
+    FarmStaking {
+        division_safety_constant: BigUint<M>,
+        max_apr: BigUint<M>,
+        min_unbond_epochs: u64,
+    },
+}
+
+impl<M: ManagedTypeApi> FarmTemplateInitArgs<M> {
+    pub fn get_template_type(&self) -> FarmTemplateType {
+        match self {
+            FarmTemplateInitArgs::Farm { .. } => FarmTemplateType::Farm,
+            FarmTemplateInitArgs::FarmWithLockedRewards { .. } => {
+                FarmTemplateType::FarmWithLockedRewards
+            }
+            FarmTemplateInitArgs::FarmStaking { .. } => FarmTemplateType::FarmStaking,
+        }
+    }
+
+    pub fn get_division_safety_constant(&self) -> &BigUint<M> {
+        match self {
+            FarmTemplateInitArgs::Farm {
+                division_safety_constant,
+            }
+            | FarmTemplateInitArgs::FarmWithLockedRewards {
+                division_safety_constant,
+            }
+            | FarmTemplateInitArgs::FarmStaking {
+                division_safety_constant,
+                ..
+            } => division_safety_constant,
+        }
+    }
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FarmTemplate<M: ManagedTypeApi> {
+    pub template_address: ManagedAddress<M>,
+    pub version: TemplateVersion,
+    pub init_args: FarmTemplateInitArgs<M>,
+}
+
+#[multiversx_sc::module]
+pub trait FarmTemplatesModule {
+    #[only_owner]
+    #[endpoint(addFarmTemplate)]
+    fn add_farm_template(
+        &self,
+        template_address: ManagedAddress,
+        init_args: FarmTemplateInitArgs<Self::Api>,
+    ) -> TemplateId {
+        self.require_valid_template_address(&template_address);
+        self.require_valid_init_args(&init_args);
+
+        let template_id = self.last_template_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.farm_template(template_id).set(FarmTemplate {
+            template_address,
+            version: FIRST_TEMPLATE_VERSION,
+            init_args,
+        });
+
+        template_id
+    }
+
+    /// Sets a new source contract for the template, and increases its version.
+    /// Farms deployed from older versions can then be upgraded through `upgradeFarmsFromTemplate`.
+    #[only_owner]
+    #[endpoint(updateFarmTemplateAddress)]
+    fn update_farm_template_address(
+        &self,
+        template_id: TemplateId,
+        template_address: ManagedAddress,
+    ) -> TemplateVersion {
+        self.require_valid_template_address(&template_address);
+
+        let mapper = self.farm_template(template_id);
+        require!(!mapper.is_empty(), "Unknown template");
+
+        mapper.update(|template| {
+            template.template_address = template_address;
+            template.version += 1;
+            template.version
+        })
+    }
+
+    /// The new init arguments only apply to farms deployed afterwards.
+    #[only_owner]
+    #[endpoint(setFarmTemplateInitArgs)]
+    fn set_farm_template_init_args(
+        &self,
+        template_id: TemplateId,
+        init_args: FarmTemplateInitArgs<Self::Api>,
+    ) {
+        self.require_valid_init_args(&init_args);
+
+        let mapper = self.farm_template(template_id);
+        require!(!mapper.is_empty(), "Unknown template");
+
+        mapper.update(|template| {
+            require!(
+                template.init_args.get_template_type() == init_args.get_template_type(),
+                "Cannot change template type"
+            );
+            template.init_args = init_args;
+        });
+    }
+
+    fn require_valid_template_address(&self, template_address: &ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(template_address),
+            "Invalid farm template address"
+        );
+    }
+
+    fn require_valid_init_args(&self, init_args: &FarmTemplateInitArgs<Self::Api>) {
+        require!(
+            *init_args.get_division_safety_constant() > 0,
+            "Invalid division safety constant"
+        );
+    }
+
+    fn get_farm_template_or_panic(&self, template_id: TemplateId) -> FarmTemplate<Self::Api> {
+        let mapper = self.farm_template(template_id);
+        require!(!mapper.is_empty(), "Unknown template");
+
+        mapper.get()
+    }
+
+    #[view(getAllFarmTemplates)]
+    fn get_all_farm_templates(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<TemplateId, FarmTemplate<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        let last_template_id = self.last_template_id().get();
+        for template_id in 1..=last_template_id {
+            let template = self.farm_template(template_id).get();
+            result.push((template_id, template).into());
+        }
+
+        result
+    }
+
+    #[view(getFarmTemplate)]
+    #[storage_mapper("farmTemplate")]
+    fn farm_template(&self, template_id: TemplateId) -> SingleValueMapper<FarmTemplate<Self::Api>>;
+
+    #[storage_mapper("lastTemplateId")]
+    fn last_template_id(&self) -> SingleValueMapper<TemplateId>;
+}
@@ -2,20 +2,52 @@
 
 multiversx_sc::imports!();
 
+pub mod deploy_permissions;
 pub mod farm_deploy;
+pub mod farm_templates;
+pub mod ongoing_upgrade_operation;
+
+use farm_templates::FarmTemplateInitArgs;
+
+pub const DEFAULT_DIVISION_SAFETY_CONST: u64 = 1_000_000_000_000_000_000;
 
 #[multiversx_sc::contract]
-pub trait ProxyDeployer: farm_deploy::FarmDeployModule {
+pub trait ProxyDeployer:
+    farm_deploy::FarmDeployModule
+    + farm_templates::FarmTemplatesModule
+    + deploy_permissions::DeployPermissionsModule
+    + ongoing_upgrade_operation::OngoingUpgradeOperationModule
+    + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+{
+    /// The given farm template is registered as the first template.
     #[init]
     fn init(&self, farm_template_address: ManagedAddress) {
-        require!(
-            self.blockchain().is_smart_contract(&farm_template_address),
-            "Invalid farm template address"
-        );
-
-        self.farm_template_address().set(&farm_template_address);
+        self.add_default_farm_template(farm_template_address);
     }
 
+    /// Registers the farm template used before the templates registry, if needed.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        let legacy_template_mapper = self.farm_template_address();
+        if self.last_template_id().is_empty() && !legacy_template_mapper.is_empty() {
+            self.add_default_farm_template(legacy_template_mapper.take());
+        }
+    }
+
+    fn add_default_farm_template(&self, farm_template_address: ManagedAddress) {
+        self.require_valid_template_address(&farm_template_address);
+
+        let template_id = self.last_template_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        self.farm_template(template_id)
+            .set(farm_templates::FarmTemplate {
+                template_address: farm_template_address,
+                version: farm_templates::FIRST_TEMPLATE_VERSION,
+                init_args: FarmTemplateInitArgs::Farm {
+                    division_safety_constant: BigUint::from(DEFAULT_DIVISION_SAFETY_CONST),
+                },
+            });
+    }
 }
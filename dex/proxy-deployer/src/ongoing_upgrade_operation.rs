@@ -0,0 +1,40 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::farm_templates::TemplateId;
+
+pub const MIN_GAS_TO_SAVE_PROGRESS: u64 = 30_000_000;
+const FIRST_INDEX: usize = 1;
+
+#[derive(TopEncode, TopDecode, Default)]
+pub enum OngoingOperation {
+    #[default]
+    None,
+    UpgradeFarms {
+        template_id: TemplateId,
+        farm_index: usize,
+    },
+}
+
+#[multiversx_sc::module]
+pub trait OngoingUpgradeOperationModule:
+    multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+{
+    fn load_upgrade_farms_operation(&self, template_id: TemplateId) -> usize {
+        let current_op: OngoingOperation = self.load_operation();
+        match current_op {
+            OngoingOperation::None => FIRST_INDEX,
+            OngoingOperation::UpgradeFarms {
+                template_id: op_template_id,
+                farm_index,
+            } => {
+                require!(
+                    op_template_id == template_id,
+                    "Upgrade for another template in progress"
+                );
+
+                farm_index
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+#![allow(dead_code)]
+#![allow(deprecated)]
+
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    contract_base::{CallableContract, ContractBase},
+    types::{Address, ManagedAsyncCallError, ManagedAsyncCallResult, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
+    whitebox_legacy::{BlockchainStateWrapper, ContractObjWrapper},
+    DebugApi,
+};
+
+use proxy_deployer::{
+    deploy_permissions::DeployPermissionsModule,
+    farm_deploy::FarmDeployModule,
+    farm_templates::{FarmTemplateInitArgs, FarmTemplatesModule, TemplateId},
+    ProxyDeployer,
+};
+
+pub static REWARD_TOKEN_ID: &[u8] = b"REW-123456";
+pub static FARMING_TOKEN_ID: &[u8] = b"LPTOK-123456";
+pub static STAKING_TOKEN_ID: &[u8] = b"STAKE-123456";
+pub static FEE_TOKEN_ID: &[u8] = b"FEE-123456";
+
+pub const DIV_SAFETY: u64 = 1_000_000_000_000;
+pub const MAX_APR: u64 = 2_500;
+pub const MIN_UNBOND_EPOCHS: u64 = 5;
+pub const DEPLOY_FEE: u64 = 1_000;
+pub const USER_FEE_BALANCE: u64 = 10_000;
+
+pub const FARM_TEMPLATE_ID: TemplateId = 1;
+pub const FARM_WITH_LOCKED_REWARDS_TEMPLATE_ID: TemplateId = 2;
+pub const FARM_STAKING_TEMPLATE_ID: TemplateId = 3;
+
+pub const PROXY_DEPLOYER_WASM_PATH: &str = "proxy-deployer/output/proxy-deployer.wasm";
+pub const FARM_WASM_PATH: &str = "farm/output/farm.wasm";
+pub const FARM_V2_WASM_PATH: &str = "farm/output/farm-v2.wasm";
+pub const FARM_WITH_LOCKED_REWARDS_WASM_PATH: &str =
+    "farm-with-locked-rewards/output/farm-with-locked-rewards.wasm";
+pub const FARM_STAKING_WASM_PATH: &str = "farm-staking/output/farm-staking.wasm";
+
+pub struct ProxyDeployerSetup<ProxyDeployerObjBuilder>
+where
+    ProxyDeployerObjBuilder: 'static + Copy + Fn() -> proxy_deployer::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner: Address,
+    pub user: Address,
+    pub pair_address: Address,
+    pub farm_template_address: Address,
+    pub proxy_deployer_wrapper:
+        ContractObjWrapper<proxy_deployer::ContractObj<DebugApi>, ProxyDeployerObjBuilder>,
+}
+
+impl<ProxyDeployerObjBuilder> ProxyDeployerSetup<ProxyDeployerObjBuilder>
+where
+    ProxyDeployerObjBuilder: 'static + Copy + Fn() -> proxy_deployer::ContractObj<DebugApi>,
+{
+    pub fn new(proxy_deployer_builder: ProxyDeployerObjBuilder) -> Self {
+        let rust_zero = rust_biguint!(0);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner = b_mock.create_user_account(&rust_zero);
+        let user = b_mock.create_user_account(&rust_zero);
+        let pair_address = b_mock.create_user_account(&rust_zero);
+        b_mock.set_esdt_balance(&user, FEE_TOKEN_ID, &rust_biguint!(USER_FEE_BALANCE));
+
+        let farm_template_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner), farm::contract_obj, FARM_WASM_PATH);
+        let farm_with_locked_rewards_template_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner),
+            farm_with_locked_rewards::contract_obj,
+            FARM_WITH_LOCKED_REWARDS_WASM_PATH,
+        );
+        let farm_staking_template_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner),
+            farm_staking::contract_obj,
+            FARM_STAKING_WASM_PATH,
+        );
+        let proxy_deployer_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner),
+            proxy_deployer_builder,
+            PROXY_DEPLOYER_WASM_PATH,
+        );
+
+        b_mock
+            .execute_tx(&owner, &proxy_deployer_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(farm_template_wrapper.address_ref()));
+
+                let template_id = sc.add_farm_template(
+                    managed_address!(farm_with_locked_rewards_template_wrapper.address_ref()),
+                    FarmTemplateInitArgs::FarmWithLockedRewards {
+                        division_safety_constant: managed_biguint!(DIV_SAFETY),
+                    },
+                );
+                assert_eq!(template_id, FARM_WITH_LOCKED_REWARDS_TEMPLATE_ID);
+
+                let template_id = sc.add_farm_template(
+                    managed_address!(farm_staking_template_wrapper.address_ref()),
+                    FarmTemplateInitArgs::FarmStaking {
+                        division_safety_constant: managed_biguint!(DIV_SAFETY),
+                        max_apr: managed_biguint!(MAX_APR),
+                        min_unbond_epochs: MIN_UNBOND_EPOCHS,
+                    },
+                );
+                assert_eq!(template_id, FARM_STAKING_TEMPLATE_ID);
+            })
+            .assert_ok();
+
+        ProxyDeployerSetup {
+            b_mock,
+            owner,
+            user,
+            pair_address,
+            farm_template_address: farm_template_wrapper.address_ref().clone(),
+            proxy_deployer_wrapper,
+        }
+    }
+
+    pub fn set_deploy_fee(&mut self, amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner,
+                &self.proxy_deployer_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_deploy_fee(managed_token_id!(FEE_TOKEN_ID), managed_biguint!(amount));
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Runs the farm upgrade callback with a failed upgrade result
+    pub fn fail_farm_upgrade(&mut self, farm_address: &Address, template_version: u32) {
+        self.b_mock
+            .execute_tx(
+                &self.owner,
+                &self.proxy_deployer_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.upgrade_farm_callback(
+                        &managed_address!(farm_address),
+                        template_version,
+                        ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                            err_code: 4,
+                            err_msg: managed_buffer!(b"upgrade failed"),
+                        }),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn enable_whitelist(&mut self, whitelisted: &Address) {
+        self.b_mock
+            .execute_tx(
+                &self.owner,
+                &self.proxy_deployer_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_deployer_whitelist_enabled(true);
+
+                    let mut deployers = MultiValueEncoded::new();
+                    deployers.push(managed_address!(whitelisted));
+                    sc.add_deployers_to_whitelist(deployers);
+                },
+            )
+            .assert_ok();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deploy_farm<CB, ContractObjBuilder>(
+        &mut self,
+        caller: &Address,
+        farm_builder: ContractObjBuilder,
+        template_id: TemplateId,
+        reward_token_id: &[u8],
+        farming_token_id: &[u8],
+        with_pair: bool,
+        fee_amount: u64,
+    ) -> ContractObjWrapper<CB, ContractObjBuilder>
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        let farm_wrapper = self
+            .b_mock
+            .prepare_deploy_from_sc(self.proxy_deployer_wrapper.address_ref(), farm_builder);
+        let pair_address = self.pair_address.clone();
+        let expected_farm_address = farm_wrapper.address_ref().clone();
+
+        let deploy = |sc: proxy_deployer::ContractObj<DebugApi>| {
+            let opt_pair_address = if with_pair {
+                OptionalValue::Some(managed_address!(&pair_address))
+            } else {
+                OptionalValue::None
+            };
+            let new_farm_address = sc.deploy_farm(
+                template_id,
+                managed_token_id!(reward_token_id),
+                managed_token_id!(farming_token_id),
+                opt_pair_address,
+            );
+            assert_eq!(new_farm_address, managed_address!(&expected_farm_address));
+        };
+        if fee_amount > 0 {
+            self.b_mock
+                .execute_esdt_transfer(
+                    caller,
+                    &self.proxy_deployer_wrapper,
+                    FEE_TOKEN_ID,
+                    0,
+                    &rust_biguint!(fee_amount),
+                    deploy,
+                )
+                .assert_ok();
+        } else {
+            self.b_mock
+                .execute_tx(
+                    caller,
+                    &self.proxy_deployer_wrapper,
+                    &rust_biguint!(0),
+                    deploy,
+                )
+                .assert_ok();
+        }
+
+        farm_wrapper
+    }
+}
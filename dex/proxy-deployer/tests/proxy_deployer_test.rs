@@ -0,0 +1,628 @@
+#![allow(deprecated)]
+
+mod proxy_deployer_setup;
+
+use config::ConfigModule;
+use farm::exit_penalty::ExitPenaltyModule;
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{MultiValueEncoded, OperationCompletionStatus},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_token_id, rust_biguint, whitebox_legacy::TxTokenTransfer,
+};
+use proxy_deployer::{
+    deploy_permissions::DeployPermissionsModule,
+    farm_deploy::FarmDeployModule,
+    farm_templates::{FarmTemplatesModule, FIRST_TEMPLATE_VERSION},
+};
+use proxy_deployer_setup::*;
+
+#[test]
+fn deploy_farm_from_each_template_test() {
+    let mut setup = ProxyDeployerSetup::new(proxy_deployer::contract_obj);
+    let user = setup.user.clone();
+    let rust_zero = rust_biguint!(0);
+
+    let farm_wrapper = setup.deploy_farm(
+        &user,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        true,
+        0,
+    );
+    let farm_with_locked_rewards_wrapper = setup.deploy_farm(
+        &user,
+        farm_with_locked_rewards::contract_obj,
+        FARM_WITH_LOCKED_REWARDS_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        true,
+        0,
+    );
+    let farm_staking_wrapper = setup.deploy_farm(
+        &user,
+        farm_staking::contract_obj,
+        FARM_STAKING_TEMPLATE_ID,
+        STAKING_TOKEN_ID,
+        STAKING_TOKEN_ID,
+        false,
+        0,
+    );
+
+    let pair_address = setup.pair_address.clone();
+    setup
+        .b_mock
+        .execute_query(&farm_wrapper, |sc| {
+            assert_eq!(
+                sc.reward_token_id().get(),
+                managed_token_id!(REWARD_TOKEN_ID)
+            );
+            assert_eq!(
+                sc.farming_token_id().get(),
+                managed_token_id!(FARMING_TOKEN_ID)
+            );
+            assert_eq!(
+                sc.pair_contract_address().get(),
+                managed_address!(&pair_address)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&farm_with_locked_rewards_wrapper, |sc| {
+            assert_eq!(
+                sc.reward_token_id().get(),
+                managed_token_id!(REWARD_TOKEN_ID)
+            );
+            assert_eq!(
+                sc.farming_token_id().get(),
+                managed_token_id!(FARMING_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&farm_staking_wrapper, |sc| {
+            assert_eq!(
+                sc.reward_token_id().get(),
+                managed_token_id!(STAKING_TOKEN_ID)
+            );
+            assert_eq!(
+                sc.farming_token_id().get(),
+                managed_token_id!(STAKING_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+
+    let farm_address = farm_wrapper.address_ref().clone();
+    let farm_staking_address = farm_staking_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_deployer_wrapper, |sc| {
+            assert_eq!(sc.get_farm_registry().len(), 3);
+            assert_eq!(sc.get_all_deployed_farms().len(), 3);
+
+            let farm_info = sc
+                .deployed_farm_info(&managed_address!(&farm_address))
+                .get();
+            assert_eq!(farm_info.deployer, managed_address!(&user));
+            assert_eq!(farm_info.template_id, FARM_TEMPLATE_ID);
+            assert_eq!(farm_info.template_version, FIRST_TEMPLATE_VERSION);
+
+            let farm_staking_info = sc
+                .deployed_farm_info(&managed_address!(&farm_staking_address))
+                .get();
+            assert_eq!(farm_staking_info.template_id, FARM_STAKING_TEMPLATE_ID);
+            assert_eq!(
+                farm_staking_info.farming_token_id,
+                managed_token_id!(STAKING_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+
+    // farm staking requires the same reward and farming token, and no pair
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_farm(
+                FARM_STAKING_TEMPLATE_ID,
+                managed_token_id!(REWARD_TOKEN_ID),
+                managed_token_id!(STAKING_TOKEN_ID),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Reward token must be the farming token");
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_farm(
+                FARM_STAKING_TEMPLATE_ID,
+                managed_token_id!(STAKING_TOKEN_ID),
+                managed_token_id!(STAKING_TOKEN_ID),
+                OptionalValue::Some(managed_address!(&pair_address)),
+            );
+        })
+        .assert_user_error("No pair address expected");
+
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_farm(
+                FARM_STAKING_TEMPLATE_ID + 1,
+                managed_token_id!(STAKING_TOKEN_ID),
+                managed_token_id!(STAKING_TOKEN_ID),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("Unknown template");
+}
+
+#[test]
+fn deploy_fee_test() {
+    let mut setup = ProxyDeployerSetup::new(proxy_deployer::contract_obj);
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let rust_zero = rust_biguint!(0);
+
+    setup.set_deploy_fee(DEPLOY_FEE);
+
+    // no payment
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_farm(
+                FARM_TEMPLATE_ID,
+                managed_token_id!(REWARD_TOKEN_ID),
+                managed_token_id!(FARMING_TOKEN_ID),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("incorrect number of ESDT transfers");
+
+    // wrong amount
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user,
+            &setup.proxy_deployer_wrapper,
+            FEE_TOKEN_ID,
+            0,
+            &rust_biguint!(DEPLOY_FEE - 1),
+            |sc| {
+                let _ = sc.deploy_farm(
+                    FARM_TEMPLATE_ID,
+                    managed_token_id!(REWARD_TOKEN_ID),
+                    managed_token_id!(FARMING_TOKEN_ID),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Invalid deploy fee payment");
+
+    // the fee goes to the owner
+    let _ = setup.deploy_farm(
+        &user,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        false,
+        DEPLOY_FEE,
+    );
+    setup.b_mock.check_esdt_balance(
+        &user,
+        FEE_TOKEN_ID,
+        &rust_biguint!(USER_FEE_BALANCE - DEPLOY_FEE),
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&owner, FEE_TOKEN_ID, &rust_biguint!(DEPLOY_FEE));
+
+    // the owner does not pay the fee
+    let _ = setup.deploy_farm(
+        &owner,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        false,
+        0,
+    );
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner,
+            &setup.proxy_deployer_wrapper,
+            FEE_TOKEN_ID,
+            0,
+            &rust_biguint!(DEPLOY_FEE),
+            |sc| {
+                let _ = sc.deploy_farm(
+                    FARM_TEMPLATE_ID,
+                    managed_token_id!(REWARD_TOKEN_ID),
+                    managed_token_id!(FARMING_TOKEN_ID),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("No payment expected");
+
+    // no payment is accepted once the fee is removed
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            sc.remove_deploy_fee();
+        })
+        .assert_ok();
+
+    let payments = [TxTokenTransfer {
+        token_identifier: FEE_TOKEN_ID.to_vec(),
+        nonce: 0,
+        value: rust_biguint!(DEPLOY_FEE),
+    }];
+    setup
+        .b_mock
+        .execute_esdt_multi_transfer(&user, &setup.proxy_deployer_wrapper, &payments, |sc| {
+            let _ = sc.deploy_farm(
+                FARM_TEMPLATE_ID,
+                managed_token_id!(REWARD_TOKEN_ID),
+                managed_token_id!(FARMING_TOKEN_ID),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("No payment expected");
+
+    let _ = setup.deploy_farm(
+        &user,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        false,
+        0,
+    );
+}
+
+#[test]
+fn deployer_whitelist_test() {
+    let mut setup = ProxyDeployerSetup::new(proxy_deployer::contract_obj);
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let other_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let rust_zero = rust_biguint!(0);
+
+    setup.enable_whitelist(&user);
+
+    setup
+        .b_mock
+        .execute_tx(
+            &other_user,
+            &setup.proxy_deployer_wrapper,
+            &rust_zero,
+            |sc| {
+                let _ = sc.deploy_farm(
+                    FARM_TEMPLATE_ID,
+                    managed_token_id!(REWARD_TOKEN_ID),
+                    managed_token_id!(FARMING_TOKEN_ID),
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Caller may not deploy farms");
+
+    // the whitelisted user and the owner can deploy
+    let _ = setup.deploy_farm(
+        &user,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        false,
+        0,
+    );
+    let _ = setup.deploy_farm(
+        &owner,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        false,
+        0,
+    );
+
+    // whitelisted users still pay the fee
+    setup.set_deploy_fee(DEPLOY_FEE);
+    setup
+        .b_mock
+        .execute_tx(&user, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.deploy_farm(
+                FARM_TEMPLATE_ID,
+                managed_token_id!(REWARD_TOKEN_ID),
+                managed_token_id!(FARMING_TOKEN_ID),
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error("incorrect number of ESDT transfers");
+
+    // once disabled, anyone can deploy
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            sc.set_deployer_whitelist_enabled(false);
+            sc.remove_deploy_fee();
+        })
+        .assert_ok();
+
+    let _ = setup.deploy_farm(
+        &other_user,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        false,
+        0,
+    );
+}
+
+#[test]
+fn interrupted_upgrade_from_template_test() {
+    let mut setup = ProxyDeployerSetup::new(proxy_deployer::contract_obj);
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let rust_zero = rust_biguint!(0);
+
+    let mut farm_addresses = Vec::new();
+    for _ in 0..3 {
+        let farm_wrapper = setup.deploy_farm(
+            &user,
+            farm::contract_obj,
+            FARM_TEMPLATE_ID,
+            REWARD_TOKEN_ID,
+            FARMING_TOKEN_ID,
+            true,
+            0,
+        );
+        farm_addresses.push(farm_wrapper.address_ref().clone());
+    }
+    let farm_staking_wrapper = setup.deploy_farm(
+        &user,
+        farm_staking::contract_obj,
+        FARM_STAKING_TEMPLATE_ID,
+        STAKING_TOKEN_ID,
+        STAKING_TOKEN_ID,
+        false,
+        0,
+    );
+
+    let new_template_wrapper = setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        farm::contract_obj,
+        FARM_V2_WASM_PATH,
+    );
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let new_version = sc.update_farm_template_address(
+                FARM_TEMPLATE_ID,
+                managed_address!(new_template_wrapper.address_ref()),
+            );
+            assert_eq!(new_version, FIRST_TEMPLATE_VERSION + 1);
+        })
+        .assert_ok();
+
+    // each call upgrades one farm and ends the transaction
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.upgrade_farms_from_template(FARM_TEMPLATE_ID);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_deployer_wrapper, |sc| {
+            let upgraded_count = farm_addresses
+                .iter()
+                .filter(|farm_address| {
+                    sc.deployed_farm_info(&managed_address!(*farm_address))
+                        .get()
+                        .template_version
+                        == FIRST_TEMPLATE_VERSION + 1
+                })
+                .count();
+            assert_eq!(upgraded_count, 1);
+        })
+        .assert_ok();
+
+    // another template cannot be upgraded until the ongoing upgrade is done
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.upgrade_farms_from_template(FARM_STAKING_TEMPLATE_ID);
+        })
+        .assert_user_error("Upgrade for another template in progress");
+
+    // a failed upgrade does not bump the template version
+    setup.fail_farm_upgrade(&farm_addresses[1], FIRST_TEMPLATE_VERSION + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_deployer_wrapper, |sc| {
+            let farm_info = sc
+                .deployed_farm_info(&managed_address!(&farm_addresses[1]))
+                .get();
+            assert_eq!(farm_info.template_version, FIRST_TEMPLATE_VERSION);
+        })
+        .assert_ok();
+
+    // resume
+    for _ in 1..farm_addresses.len() {
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+                let _ = sc.upgrade_farms_from_template(FARM_TEMPLATE_ID);
+            })
+            .assert_ok();
+    }
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let result = sc.upgrade_farms_from_template(FARM_TEMPLATE_ID);
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        })
+        .assert_ok();
+
+    let farm_staking_address = farm_staking_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_deployer_wrapper, |sc| {
+            for farm_address in farm_addresses.iter() {
+                let farm_info = sc.deployed_farm_info(&managed_address!(farm_address)).get();
+                assert_eq!(farm_info.template_version, FIRST_TEMPLATE_VERSION + 1);
+            }
+
+            let farm_staking_info = sc
+                .deployed_farm_info(&managed_address!(&farm_staking_address))
+                .get();
+            assert_eq!(farm_staking_info.template_version, FIRST_TEMPLATE_VERSION);
+        })
+        .assert_ok();
+
+    // the other template can now be upgraded
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let result = sc.upgrade_farms_from_template(FARM_STAKING_TEMPLATE_ID);
+            assert_eq!(result, OperationCompletionStatus::Completed);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn register_legacy_farms_test() {
+    let mut setup = ProxyDeployerSetup::new(proxy_deployer::contract_obj);
+    let owner = setup.owner.clone();
+    let user = setup.user.clone();
+    let rust_zero = rust_biguint!(0);
+
+    let legacy_farm_wrapper = setup.deploy_farm(
+        &user,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        true,
+        0,
+    );
+    let other_farm_wrapper = setup.deploy_farm(
+        &owner,
+        farm::contract_obj,
+        FARM_TEMPLATE_ID,
+        REWARD_TOKEN_ID,
+        FARMING_TOKEN_ID,
+        true,
+        0,
+    );
+    let legacy_farm_address = legacy_farm_wrapper.address_ref().clone();
+    let other_farm_address = other_farm_wrapper.address_ref().clone();
+
+    // farms deployed before the templates registry are only in the deployers' lists
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let farm_address = managed_address!(&legacy_farm_address);
+            sc.deployed_farm_info(&farm_address).clear();
+            let _ = sc
+                .template_farms(FARM_TEMPLATE_ID)
+                .swap_remove(&farm_address);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let mut farm_addresses = MultiValueEncoded::new();
+            farm_addresses.push(managed_address!(&other_farm_address));
+            sc.register_legacy_farms(managed_address!(&user), farm_addresses);
+        })
+        .assert_user_error("Farm not deployed by the deployer");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let mut farm_addresses = MultiValueEncoded::new();
+            farm_addresses.push(managed_address!(&other_farm_address));
+            sc.register_legacy_farms(managed_address!(&owner), farm_addresses);
+        })
+        .assert_user_error("Farm already registered");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let mut farm_addresses = MultiValueEncoded::new();
+            farm_addresses.push(managed_address!(&legacy_farm_address));
+            sc.register_legacy_farms(managed_address!(&user), farm_addresses);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_deployer_wrapper, |sc| {
+            let farm_address = managed_address!(&legacy_farm_address);
+            assert!(sc.template_farms(FARM_TEMPLATE_ID).contains(&farm_address));
+
+            let farm_info = sc.deployed_farm_info(&farm_address).get();
+            assert_eq!(farm_info.deployer, managed_address!(&user));
+            assert_eq!(farm_info.template_id, FARM_TEMPLATE_ID);
+            assert_eq!(farm_info.template_version, FIRST_TEMPLATE_VERSION);
+            assert_eq!(
+                farm_info.reward_token_id,
+                managed_token_id!(REWARD_TOKEN_ID)
+            );
+            assert_eq!(
+                farm_info.farming_token_id,
+                managed_token_id!(FARMING_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+
+    // registered farms are upgraded with the template
+    let new_template_wrapper = setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        farm::contract_obj,
+        FARM_V2_WASM_PATH,
+    );
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+            let _ = sc.update_farm_template_address(
+                FARM_TEMPLATE_ID,
+                managed_address!(new_template_wrapper.address_ref()),
+            );
+        })
+        .assert_ok();
+
+    for _ in 0..2 {
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.proxy_deployer_wrapper, &rust_zero, |sc| {
+                let _ = sc.upgrade_farms_from_template(FARM_TEMPLATE_ID);
+            })
+            .assert_ok();
+    }
+
+    setup
+        .b_mock
+        .execute_query(&setup.proxy_deployer_wrapper, |sc| {
+            let farm_info = sc
+                .deployed_farm_info(&managed_address!(&legacy_farm_address))
+                .get();
+            assert_eq!(farm_info.template_version, FIRST_TEMPLATE_VERSION + 1);
+        })
+        .assert_ok();
+}
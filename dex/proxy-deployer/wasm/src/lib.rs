@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            4
-// Async Callback (empty):               1
-// Total number of exported functions:   7
+// Endpoints:                           22
+// Async Callback:                       1
+// Total number of exported functions:  25
 
 #![no_std]
 
@@ -21,10 +21,28 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         deployFarm => deploy_farm
+        registerLegacyFarms => register_legacy_farms
+        upgradeFarmsFromTemplate => upgrade_farms_from_template
         callFarmEndpoint => call_farm_endpoint
         getAllDeployedFarms => get_all_deployed_farms
+        getFarmRegistry => get_farm_registry
         getDeployerFarmAddresses => deployer_farm_addresses
+        getDeployedFarmInfo => deployed_farm_info
+        getTemplateFarms => template_farms
+        addFarmTemplate => add_farm_template
+        updateFarmTemplateAddress => update_farm_template_address
+        setFarmTemplateInitArgs => set_farm_template_init_args
+        getAllFarmTemplates => get_all_farm_templates
+        getFarmTemplate => farm_template
+        setDeployFee => set_deploy_fee
+        removeDeployFee => remove_deploy_fee
+        setDeployerWhitelistEnabled => set_deployer_whitelist_enabled
+        addDeployersToWhitelist => add_deployers_to_whitelist
+        removeDeployersFromWhitelist => remove_deployers_from_whitelist
+        getDeployFee => deploy_fee
+        isDeployerWhitelistEnabled => deployer_whitelist_enabled
+        getDeployerWhitelist => deployer_whitelist
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { proxy_deployer }
@@ -0,0 +1,364 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use pair::{
+    pair_actions::{common_result_types::AddLiquidityResultType, initial_liq::ProxyTrait as _},
+    read_pair_storage, ProxyTrait as _,
+};
+
+use crate::{
+    config, factory::PairTokens, DEFAULT_SPECIAL_FEE_PERCENT, LP_TOKEN_DECIMALS,
+    LP_TOKEN_INITIAL_SUPPLY, USER_DEFINED_TOTAL_FEE_PERCENT,
+};
+
+const ISSUE_LP_TOKEN_GAS_LIMIT: u64 = 60_000_000;
+const ISSUE_CALLBACK_GAS: u64 = 40_000_000;
+const SET_LP_ROLES_GAS_LIMIT: u64 = 10_000_000;
+const SET_LP_ROLES_CALLBACK_GAS: u64 = 5_000_000;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct BondedPairConfig<M: ManagedTypeApi> {
+    pub bond_amount: BigUint<M>,
+    pub min_liquidity_value: BigUint<M>,
+    pub bond_holding_period_epochs: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct BondedPairInfo<M: ManagedTypeApi> {
+    pub creator: ManagedAddress<M>,
+    pub bond_amount: BigUint<M>,
+    pub common_token_id: TokenIdentifier<M>,
+    pub creation_epoch: u64,
+    pub opt_activation_epoch: Option<u64>,
+    pub lp_roles_set: bool,
+}
+
+#[multiversx_sc::module]
+pub trait BondedPairsModule:
+    config::ConfigModule
+    + read_pair_storage::ReadPairStorageModule
+    + crate::factory::FactoryModule
+    + crate::events::EventsModule
+    + crate::enable_swap_by_user::EnableSwapByUserModule
+{
+    /// The bond is paid in EGLD. The minimum liquidity value is denominated in the common token of the pair,
+    /// and is computed as twice the common token reserve.
+    #[only_owner]
+    #[endpoint(setBondedPairConfig)]
+    fn set_bonded_pair_config(
+        &self,
+        bond_amount: BigUint,
+        min_liquidity_value: BigUint,
+        bond_holding_period_epochs: u64,
+    ) {
+        require!(bond_amount > 0, "Invalid bond amount");
+
+        self.bonded_pair_config().set(BondedPairConfig {
+            bond_amount,
+            min_liquidity_value,
+            bond_holding_period_epochs,
+        });
+    }
+
+    /// Deploys the pair and issues its LP token, which is given the mint and burn roles
+    /// once the issue completes. The payment is the bond plus the LP token issue cost.
+    /// The caller adds the initial liquidity through addBondedPairInitialLiquidity.
+    #[payable("EGLD")]
+    #[endpoint(createBondedPair)]
+    fn create_bonded_pair(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        lp_token_display_name: ManagedBuffer,
+        lp_token_ticker: ManagedBuffer,
+    ) -> ManagedAddress {
+        require!(self.is_active(), "Not active");
+        require!(
+            !self.bonded_pair_config().is_empty(),
+            "Bonded pairs not configured"
+        );
+
+        require!(first_token_id != second_token_id, "Identical tokens");
+        require!(
+            first_token_id.is_valid_esdt_identifier(),
+            "First Token ID is not a valid esdt token ID"
+        );
+        require!(
+            second_token_id.is_valid_esdt_identifier(),
+            "Second Token ID is not a valid esdt token ID"
+        );
+        let pair_address = self.get_pair(
+            first_token_id.clone(),
+            second_token_id.clone(),
+            OptionalValue::None,
+        );
+        require!(pair_address.is_zero(), "Pair already exists");
+
+        let common_tokens = self.common_tokens_for_user_pairs();
+        let common_token_id = if common_tokens.contains(&first_token_id) {
+            first_token_id.clone()
+        } else if common_tokens.contains(&second_token_id) {
+            second_token_id.clone()
+        } else {
+            sc_panic!("No common token in pair");
+        };
+
+        let config = self.bonded_pair_config().get();
+        let payment_amount = self.call_value().egld_value().clone_value();
+        require!(
+            payment_amount > config.bond_amount,
+            "Payment must cover the bond and the issue cost"
+        );
+        let issue_cost = &payment_amount - &config.bond_amount;
+
+        let caller = self.blockchain().get_caller();
+        let owner = self.owner().get();
+        let own_sc_address = self.blockchain().get_sc_address();
+        let mut admins = MultiValueEncoded::new();
+        admins.push(caller.clone());
+
+        // the initial liquidity goes through the router, which enables the swaps afterwards
+        let pair_address = self.create_pair(
+            &first_token_id,
+            &second_token_id,
+            &owner,
+            USER_DEFINED_TOTAL_FEE_PERCENT,
+            DEFAULT_SPECIAL_FEE_PERCENT,
+            &own_sc_address,
+            admins,
+        );
+        self.bonded_pair_info(&pair_address).set(BondedPairInfo {
+            creator: caller.clone(),
+            bond_amount: config.bond_amount,
+            common_token_id,
+            creation_epoch: self.blockchain().get_block_epoch(),
+            opt_activation_epoch: None,
+            lp_roles_set: false,
+        });
+
+        self.emit_create_pair_event(
+            caller.clone(),
+            first_token_id,
+            second_token_id,
+            USER_DEFINED_TOTAL_FEE_PERCENT,
+            DEFAULT_SPECIAL_FEE_PERCENT,
+            pair_address.clone(),
+        );
+
+        self.send()
+            .esdt_system_sc_proxy()
+            .issue_fungible(
+                issue_cost,
+                &lp_token_display_name,
+                &lp_token_ticker,
+                &BigUint::from(LP_TOKEN_INITIAL_SUPPLY),
+                FungibleTokenProperties {
+                    num_decimals: LP_TOKEN_DECIMALS,
+                    can_freeze: true,
+                    can_wipe: true,
+                    can_pause: true,
+                    can_mint: true,
+                    can_burn: true,
+                    can_change_owner: true,
+                    can_upgrade: true,
+                    can_add_special_roles: true,
+                },
+            )
+            .with_gas_limit(ISSUE_LP_TOKEN_GAS_LIMIT)
+            .async_call_promise()
+            .with_callback(
+                self.callbacks()
+                    .bonded_pair_lp_issue_callback(&caller, &pair_address),
+            )
+            .with_extra_gas_for_callback(ISSUE_CALLBACK_GAS)
+            .register_promise();
+
+        pair_address
+    }
+
+    #[promises_callback]
+    fn bonded_pair_lp_issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        pair_address: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        let (token_id, returned_tokens) = self.call_value().egld_or_single_fungible_esdt();
+        match result {
+            ManagedAsyncCallResult::Ok(()) => {
+                let lp_token_id = token_id.unwrap_esdt();
+                self.pair_temporary_owner().remove(pair_address);
+                let _: IgnoreValue = self
+                    .user_pair_proxy(pair_address.clone())
+                    .set_lp_token_identifier(lp_token_id.clone())
+                    .execute_on_dest_context();
+
+                let roles = [EsdtLocalRole::Mint, EsdtLocalRole::Burn];
+                self.send()
+                    .esdt_system_sc_proxy()
+                    .set_special_roles(pair_address, &lp_token_id, roles.iter().cloned())
+                    .with_gas_limit(SET_LP_ROLES_GAS_LIMIT)
+                    .async_call_promise()
+                    .with_callback(self.callbacks().bonded_pair_lp_roles_callback(pair_address))
+                    .with_extra_gas_for_callback(SET_LP_ROLES_CALLBACK_GAS)
+                    .register_promise();
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                if token_id.is_egld() && returned_tokens > 0u64 {
+                    self.send().direct_egld(caller, &returned_tokens);
+                }
+            }
+        }
+    }
+
+    /// If setting the roles failed, they can still be set through setLocalRoles.
+    #[promises_callback]
+    fn bonded_pair_lp_roles_callback(
+        &self,
+        pair_address: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<()>,
+    ) {
+        let info_mapper = self.bonded_pair_info(pair_address);
+        if let ManagedAsyncCallResult::Ok(()) = result {
+            if !info_mapper.is_empty() {
+                info_mapper.update(|info| info.lp_roles_set = true);
+            }
+        }
+    }
+
+    /// The pair creator adds the initial liquidity through the router, which enables the swaps
+    /// in the same transaction. The liquidity value (twice the common token reserve)
+    /// must reach the configured minimum.
+    /// The pair itself cannot enable the swaps from addInitialLiquidity, as activating a pair
+    /// requires the router to call back into it.
+    #[payable("*")]
+    #[endpoint(addBondedPairInitialLiquidity)]
+    fn add_bonded_pair_initial_liquidity(
+        &self,
+        pair_address: ManagedAddress,
+    ) -> AddLiquidityResultType<Self::Api> {
+        require!(self.is_active(), "Not active");
+        self.check_is_pair_sc(&pair_address);
+
+        let info_mapper = self.bonded_pair_info(&pair_address);
+        require!(!info_mapper.is_empty(), "Not a bonded pair");
+
+        let mut info = info_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == info.creator,
+            "Only the pair creator may add the initial liquidity"
+        );
+
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        let output: AddLiquidityResultType<Self::Api> = self
+            .user_pair_proxy(pair_address.clone())
+            .add_initial_liquidity()
+            .with_multi_token_transfer(payments)
+            .execute_on_dest_context();
+        let (lp_payment, _, _) = output.clone().into_tuple();
+        self.send()
+            .direct_non_zero_esdt_payment(&caller, &lp_payment);
+
+        self.require_state_active_no_swaps(&pair_address);
+        self.require_min_liquidity_value(&pair_address, &info.common_token_id);
+        self.pair_resume(pair_address.clone());
+
+        info.opt_activation_epoch = Some(self.blockchain().get_block_epoch());
+        info_mapper.set(&info);
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        self.emit_user_swaps_enabled_event(caller, first_token_id, second_token_id, pair_address);
+
+        output
+    }
+
+    /// The creator gets the bond back after the holding period,
+    /// as long as the pair still holds the minimum liquidity value.
+    #[endpoint(claimPairBond)]
+    fn claim_pair_bond(&self, pair_address: ManagedAddress) {
+        let info_mapper = self.bonded_pair_info(&pair_address);
+        require!(!info_mapper.is_empty(), "Not a bonded pair");
+
+        let info = info_mapper.get();
+        let caller = self.blockchain().get_caller();
+        require!(caller == info.creator, "Only the pair creator may claim");
+
+        let activation_epoch = match info.opt_activation_epoch {
+            Some(activation_epoch) => activation_epoch,
+            None => sc_panic!("Pair not activated"),
+        };
+        let holding_period_epochs = self.bonded_pair_config().get().bond_holding_period_epochs;
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch >= activation_epoch + holding_period_epochs,
+            "Holding period not passed"
+        );
+        self.require_min_liquidity_value(&pair_address, &info.common_token_id);
+
+        info_mapper.clear();
+        self.send().direct_egld(&caller, &info.bond_amount);
+    }
+
+    /// The owner takes the bond of a pair that was not activated
+    /// within the holding period from its creation.
+    /// The pair is removed from the router, so the tokens can be paired again.
+    #[only_owner]
+    #[endpoint(slashPairBond)]
+    fn slash_pair_bond(&self, pair_address: ManagedAddress) {
+        let info_mapper = self.bonded_pair_info(&pair_address);
+        require!(!info_mapper.is_empty(), "Not a bonded pair");
+
+        let info = info_mapper.get();
+        require!(
+            info.opt_activation_epoch.is_none(),
+            "Pair already activated"
+        );
+        let holding_period_epochs = self.bonded_pair_config().get().bond_holding_period_epochs;
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(
+            current_epoch >= info.creation_epoch + holding_period_epochs,
+            "Holding period not passed"
+        );
+
+        info_mapper.clear();
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        let _ = self.pair_map().remove(&PairTokens {
+            first_token_id,
+            second_token_id,
+        });
+        self.pair_temporary_owner().remove(&pair_address);
+
+        let caller = self.blockchain().get_caller();
+        self.send().direct_egld(&caller, &info.bond_amount);
+    }
+
+    fn require_min_liquidity_value(
+        &self,
+        pair_address: &ManagedAddress,
+        common_token_id: &TokenIdentifier,
+    ) {
+        let common_token_reserve = self
+            .get_pair_reserve_mapper(pair_address.clone(), common_token_id)
+            .get();
+        let liquidity_value = common_token_reserve * 2u32;
+        let min_liquidity_value = self.bonded_pair_config().get().min_liquidity_value;
+        require!(
+            liquidity_value >= min_liquidity_value,
+            "Not enough liquidity"
+        );
+    }
+
+    #[view(getBondedPairConfig)]
+    #[storage_mapper("bondedPairConfig")]
+    fn bonded_pair_config(&self) -> SingleValueMapper<BondedPairConfig<Self::Api>>;
+
+    #[view(getBondedPairInfo)]
+    #[storage_mapper("bondedPairInfo")]
+    fn bonded_pair_info(
+        &self,
+        pair_address: &ManagedAddress,
+    ) -> SingleValueMapper<BondedPairInfo<Self::Api>>;
+}
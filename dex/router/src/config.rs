@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::{enable_swap_by_user::EnableSwapByUserConfig, factory::PairTokens};
+use crate::{
+    enable_swap_by_user::EnableSwapByUserConfig,
+    factory::{FeeTierPairTokens, PairTokens},
+};
 use pair::read_pair_storage;
 
 #[multiversx_sc::module]
@@ -14,19 +17,30 @@ pub trait ConfigModule: read_pair_storage::ReadPairStorageModule {
         let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
         let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
 
-        let pair_tokens = PairTokens {
-            first_token_id: first_token_id.clone(),
-            second_token_id: second_token_id.clone(),
-        };
-
-        let mut pair_map_address_opt = self.pair_map().get(&pair_tokens);
-        if pair_map_address_opt.is_none() {
-            let reverse_pair_tokens = PairTokens {
-                first_token_id: second_token_id.clone(),
-                second_token_id: first_token_id.clone(),
+        let pair_fee_tier_mapper = self.pair_fee_tier(pair_address);
+        let pair_map_address_opt = if pair_fee_tier_mapper.is_empty() {
+            let pair_tokens = PairTokens {
+                first_token_id: first_token_id.clone(),
+                second_token_id: second_token_id.clone(),
             };
-            pair_map_address_opt = self.pair_map().get(&reverse_pair_tokens);
-        }
+
+            let mut pair_map_address_opt = self.pair_map().get(&pair_tokens);
+            if pair_map_address_opt.is_none() {
+                let reverse_pair_tokens = PairTokens {
+                    first_token_id: second_token_id.clone(),
+                    second_token_id: first_token_id.clone(),
+                };
+                pair_map_address_opt = self.pair_map().get(&reverse_pair_tokens);
+            }
+
+            pair_map_address_opt
+        } else {
+            self.get_fee_tier_pair(
+                &first_token_id,
+                &second_token_id,
+                pair_fee_tier_mapper.get(),
+            )
+        };
 
         require!(pair_map_address_opt.is_some(), "Not a pair SC");
 
@@ -36,6 +50,28 @@ pub trait ConfigModule: read_pair_storage::ReadPairStorageModule {
         }
     }
 
+    fn get_fee_tier_pair(
+        &self,
+        first_token_id: &TokenIdentifier,
+        second_token_id: &TokenIdentifier,
+        fee_tier: u64,
+    ) -> Option<ManagedAddress> {
+        let pair_address_opt = self.fee_tier_pair_map().get(&FeeTierPairTokens {
+            first_token_id: first_token_id.clone(),
+            second_token_id: second_token_id.clone(),
+            fee_tier,
+        });
+        if pair_address_opt.is_some() {
+            return pair_address_opt;
+        }
+
+        self.fee_tier_pair_map().get(&FeeTierPairTokens {
+            first_token_id: second_token_id.clone(),
+            second_token_id: first_token_id.clone(),
+            fee_tier,
+        })
+    }
+
     #[view(getPairCreationEnabled)]
     #[storage_mapper("pair_creation_enabled")]
     fn pair_creation_enabled(&self) -> SingleValueMapper<bool>;
@@ -63,6 +99,18 @@ pub trait ConfigModule: read_pair_storage::ReadPairStorageModule {
     #[storage_mapper("pair_map")]
     fn pair_map(&self) -> MapMapper<PairTokens<Self::Api>, ManagedAddress>;
 
+    #[storage_mapper("feeTierPairMap")]
+    fn fee_tier_pair_map(&self) -> MapMapper<FeeTierPairTokens<Self::Api>, ManagedAddress>;
+
+    /// Empty for the pairs not created at a fee tier
+    #[view(getPairFeeTier)]
+    #[storage_mapper("pairFeeTier")]
+    fn pair_fee_tier(&self, pair_address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// Maps the total fee percent of each tier to its special fee percent
+    #[storage_mapper("feeTiers")]
+    fn fee_tiers(&self) -> MapMapper<u64, u64>;
+
     #[view(getPairTemplateAddress)]
     #[storage_mapper("pair_template_address")]
     fn pair_template_address(&self) -> SingleValueMapper<ManagedAddress>;
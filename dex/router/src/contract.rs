@@ -4,13 +4,15 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod bonded_pairs;
 pub mod config;
 pub mod enable_swap_by_user;
 mod events;
 pub mod factory;
+pub mod fee_tiers;
 pub mod multi_pair_swap;
 
-use factory::PairTokens;
+use factory::{FeeTierPairTokens, PairTokens};
 use pair::config::ProxyTrait as _;
 use pair::fee::ProxyTrait as _;
 use pair::{read_pair_storage, ProxyTrait as _};
@@ -33,6 +35,8 @@ pub trait Router:
     + multi_pair_swap::MultiPairSwap
     + token_send::TokenSendModule
     + enable_swap_by_user::EnableSwapByUserModule
+    + bonded_pairs::BondedPairsModule
+    + fee_tiers::FeeTiersModule
 {
     #[init]
     fn init(&self, pair_template_address_opt: OptionalValue<ManagedAddress>) {
@@ -106,7 +110,11 @@ pub trait Router:
             second_token_id.is_valid_esdt_identifier(),
             "Second Token ID is not a valid esdt token ID"
         );
-        let pair_address = self.get_pair(first_token_id.clone(), second_token_id.clone());
+        let pair_address = self.get_pair(
+            first_token_id.clone(),
+            second_token_id.clone(),
+            OptionalValue::None,
+        );
         require!(pair_address.is_zero(), "Pair already exists");
 
         let mut total_fee_percent_requested = DEFAULT_TOTAL_FEE_PERCENT;
@@ -157,6 +165,7 @@ pub trait Router:
         &self,
         first_token_id: TokenIdentifier,
         second_token_id: TokenIdentifier,
+        opt_fee_tier: OptionalValue<u64>,
     ) {
         require!(self.is_active(), "Not active");
 
@@ -169,7 +178,7 @@ pub trait Router:
             second_token_id.is_valid_esdt_identifier(),
             "Second Token ID is not a valid esdt token ID"
         );
-        let pair_address = self.get_pair(first_token_id.clone(), second_token_id.clone());
+        let pair_address = self.get_pair(first_token_id, second_token_id, opt_fee_tier);
         require!(!pair_address.is_zero(), "Pair does not exists");
 
         self.upgrade_pair(pair_address);
@@ -232,10 +241,7 @@ pub trait Router:
                 },
             )
             .async_call()
-            .with_callback(
-                self.callbacks()
-                    .lp_token_issue_callback(&caller, &pair_address),
-            )
+            .with_callback(Router::callbacks(self).lp_token_issue_callback(&caller, &pair_address))
             .call_and_exit()
     }
 
@@ -265,6 +271,7 @@ pub trait Router:
         &self,
         first_token_id: TokenIdentifier,
         second_token_id: TokenIdentifier,
+        opt_fee_tier: OptionalValue<u64>,
     ) -> ManagedAddress {
         require!(self.is_active(), "Not active");
 
@@ -277,7 +284,16 @@ pub trait Router:
             second_token_id.is_valid_esdt_identifier(),
             "Second Token ID is not a valid esdt token ID"
         );
-        let mut pair_address = self.get_pair(first_token_id.clone(), second_token_id.clone());
+
+        if let Some(fee_tier) = opt_fee_tier.into_option() {
+            return self.remove_fee_tier_pair(first_token_id, second_token_id, fee_tier);
+        }
+
+        let mut pair_address = self.get_pair(
+            first_token_id.clone(),
+            second_token_id.clone(),
+            OptionalValue::None,
+        );
         require!(!pair_address.is_zero(), "Pair does not exists");
 
         pair_address = self
@@ -301,6 +317,34 @@ pub trait Router:
         pair_address
     }
 
+    fn remove_fee_tier_pair(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        fee_tier: u64,
+    ) -> ManagedAddress {
+        let mut pair_address_opt = self.fee_tier_pair_map().remove(&FeeTierPairTokens {
+            first_token_id: first_token_id.clone(),
+            second_token_id: second_token_id.clone(),
+            fee_tier,
+        });
+        if pair_address_opt.is_none() {
+            pair_address_opt = self.fee_tier_pair_map().remove(&FeeTierPairTokens {
+                first_token_id: second_token_id,
+                second_token_id: first_token_id,
+                fee_tier,
+            });
+        }
+
+        let pair_address = match pair_address_opt {
+            Some(pair_address) => pair_address,
+            None => sc_panic!("Pair does not exists"),
+        };
+        self.pair_fee_tier(&pair_address).clear();
+
+        pair_address
+    }
+
     #[only_owner]
     #[endpoint(setFeeOn)]
     fn set_fee_on(
@@ -134,7 +134,10 @@ pub trait EnableSwapByUserModule:
         let caller = self.blockchain().get_caller();
         self.require_caller_initial_liquidity_adder(&pair_address, &caller);
 
-        self.set_fee_percents(pair_address.clone());
+        // pairs created at a fee tier keep the fees of their tier
+        if self.pair_fee_tier(&pair_address).is_empty() {
+            self.set_fee_percents(pair_address.clone());
+        }
         self.pair_resume(pair_address.clone());
 
         self.send().direct_esdt(
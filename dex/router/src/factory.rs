@@ -12,6 +12,13 @@ pub struct PairTokens<M: ManagedTypeApi> {
     pub second_token_id: TokenIdentifier<M>,
 }
 
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi)]
+pub struct FeeTierPairTokens<M: ManagedTypeApi> {
+    pub first_token_id: TokenIdentifier<M>,
+    pub second_token_id: TokenIdentifier<M>,
+    pub fee_tier: u64,
+}
+
 #[derive(ManagedVecItem, TopEncode, TopDecode, PartialEq, TypeAbi)]
 pub struct PairContractMetadata<M: ManagedTypeApi> {
     first_token_id: TokenIdentifier<M>,
@@ -42,6 +49,69 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
         special_fee_percent: u64,
         initial_liquidity_adder: &ManagedAddress,
         admins: MultiValueEncoded<ManagedAddress>,
+    ) -> ManagedAddress {
+        let new_address = self.deploy_pair(
+            first_token_id,
+            second_token_id,
+            owner,
+            total_fee_percent,
+            special_fee_percent,
+            initial_liquidity_adder,
+            admins,
+        );
+
+        self.pair_map().insert(
+            PairTokens {
+                first_token_id: first_token_id.clone(),
+                second_token_id: second_token_id.clone(),
+            },
+            new_address.clone(),
+        );
+        new_address
+    }
+
+    /// The fee tier is the total fee percent of the pair
+    fn create_fee_tier_pair(
+        &self,
+        first_token_id: &TokenIdentifier,
+        second_token_id: &TokenIdentifier,
+        owner: &ManagedAddress,
+        fee_tier: u64,
+        special_fee_percent: u64,
+        initial_liquidity_adder: &ManagedAddress,
+        admins: MultiValueEncoded<ManagedAddress>,
+    ) -> ManagedAddress {
+        let new_address = self.deploy_pair(
+            first_token_id,
+            second_token_id,
+            owner,
+            fee_tier,
+            special_fee_percent,
+            initial_liquidity_adder,
+            admins,
+        );
+
+        self.fee_tier_pair_map().insert(
+            FeeTierPairTokens {
+                first_token_id: first_token_id.clone(),
+                second_token_id: second_token_id.clone(),
+                fee_tier,
+            },
+            new_address.clone(),
+        );
+        self.pair_fee_tier(&new_address).set(fee_tier);
+        new_address
+    }
+
+    fn deploy_pair(
+        &self,
+        first_token_id: &TokenIdentifier,
+        second_token_id: &TokenIdentifier,
+        owner: &ManagedAddress,
+        total_fee_percent: u64,
+        special_fee_percent: u64,
+        initial_liquidity_adder: &ManagedAddress,
+        admins: MultiValueEncoded<ManagedAddress>,
     ) -> ManagedAddress {
         require!(
             !self.pair_template_address().is_empty(),
@@ -65,13 +135,6 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
                 CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE | CodeMetadata::PAYABLE_BY_SC,
             );
 
-        self.pair_map().insert(
-            PairTokens {
-                first_token_id: first_token_id.clone(),
-                second_token_id: second_token_id.clone(),
-            },
-            new_address.clone(),
-        );
         self.pair_temporary_owner().insert(
             new_address.clone(),
             (
@@ -100,6 +163,9 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
         for pair in self.pair_map().values() {
             result.push(pair);
         }
+        for pair in self.fee_tier_pair_map().values() {
+            result.push(pair);
+        }
         result
     }
 
@@ -109,6 +175,12 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
         for pair in self.pair_map().keys() {
             result.push(pair);
         }
+        for pair in self.fee_tier_pair_map().keys() {
+            result.push(PairTokens {
+                first_token_id: pair.first_token_id,
+                second_token_id: pair.second_token_id,
+            });
+        }
         result
     }
 
@@ -123,15 +195,32 @@ pub trait FactoryModule: config::ConfigModule + read_pair_storage::ReadPairStora
             };
             result.push(pair_metadata);
         }
+        for (k, v) in self.fee_tier_pair_map().iter() {
+            let pair_metadata = PairContractMetadata {
+                first_token_id: k.first_token_id,
+                second_token_id: k.second_token_id,
+                address: v,
+            };
+            result.push(pair_metadata);
+        }
         result
     }
 
+    /// Without a fee tier, returns the pair created through createPair.
+    /// Returns the zero address if there is no such pair.
     #[view(getPair)]
     fn get_pair(
         &self,
         first_token_id: TokenIdentifier,
         second_token_id: TokenIdentifier,
+        opt_fee_tier: OptionalValue<u64>,
     ) -> ManagedAddress {
+        if let Some(fee_tier) = opt_fee_tier.into_option() {
+            return self
+                .get_fee_tier_pair(&first_token_id, &second_token_id, fee_tier)
+                .unwrap_or_else(ManagedAddress::zero);
+        }
+
         let mut address = self
             .pair_map()
             .get(&PairTokens {
@@ -0,0 +1,113 @@
+multiversx_sc::imports!();
+
+use pair::read_pair_storage;
+
+use crate::{config, MAX_TOTAL_FEE_PERCENT};
+
+#[multiversx_sc::module]
+pub trait FeeTiersModule:
+    config::ConfigModule
+    + read_pair_storage::ReadPairStorageModule
+    + crate::factory::FactoryModule
+    + crate::events::EventsModule
+{
+    /// A fee tier is identified by its non-zero total fee percent.
+    /// Removing a tier does not affect the pairs already created at that tier.
+    #[only_owner]
+    #[endpoint(addFeeTier)]
+    fn add_fee_tier(&self, total_fee_percent: u64, special_fee_percent: u64) {
+        require!(
+            total_fee_percent > 0
+                && total_fee_percent >= special_fee_percent
+                && total_fee_percent < MAX_TOTAL_FEE_PERCENT,
+            "Bad percents"
+        );
+
+        let _ = self
+            .fee_tiers()
+            .insert(total_fee_percent, special_fee_percent);
+    }
+
+    #[only_owner]
+    #[endpoint(removeFeeTier)]
+    fn remove_fee_tier(&self, total_fee_percent: u64) {
+        let removed = self.fee_tiers().remove(&total_fee_percent);
+        require!(removed.is_some(), "Fee tier does not exist");
+    }
+
+    /// Creates a pair with the fees of the given tier.
+    /// There can be one pair for each tier, besides the one created through createPair.
+    #[allow_multiple_var_args]
+    #[endpoint(createPairWithFeeTier)]
+    fn create_pair_with_fee_tier(
+        &self,
+        first_token_id: TokenIdentifier,
+        second_token_id: TokenIdentifier,
+        initial_liquidity_adder: ManagedAddress,
+        fee_tier: u64,
+        mut admins: MultiValueEncoded<ManagedAddress>,
+    ) -> ManagedAddress {
+        require!(self.is_active(), "Not active");
+        let owner = self.owner().get();
+        let caller = self.blockchain().get_caller();
+
+        if caller != owner {
+            require!(
+                self.pair_creation_enabled().get(),
+                "Pair creation is disabled"
+            );
+        }
+
+        require!(first_token_id != second_token_id, "Identical tokens");
+        require!(
+            first_token_id.is_valid_esdt_identifier(),
+            "First Token ID is not a valid esdt token ID"
+        );
+        require!(
+            second_token_id.is_valid_esdt_identifier(),
+            "Second Token ID is not a valid esdt token ID"
+        );
+        let pair_address = self.get_pair(
+            first_token_id.clone(),
+            second_token_id.clone(),
+            OptionalValue::Some(fee_tier),
+        );
+        require!(pair_address.is_zero(), "Pair already exists");
+
+        let special_fee_percent = match self.fee_tiers().get(&fee_tier) {
+            Some(special_fee_percent) => special_fee_percent,
+            None => sc_panic!("Fee tier does not exist"),
+        };
+
+        admins.push(caller.clone());
+
+        let address = self.create_fee_tier_pair(
+            &first_token_id,
+            &second_token_id,
+            &owner,
+            fee_tier,
+            special_fee_percent,
+            &initial_liquidity_adder,
+            admins,
+        );
+
+        self.emit_create_pair_event(
+            caller,
+            first_token_id,
+            second_token_id,
+            fee_tier,
+            special_fee_percent,
+            address.clone(),
+        );
+        address
+    }
+
+    #[view(getFeeTiers)]
+    fn get_fee_tiers(&self) -> MultiValueEncoded<MultiValue2<u64, u64>> {
+        let mut result = MultiValueEncoded::new();
+        for (total_fee_percent, special_fee_percent) in self.fee_tiers().iter() {
+            result.push((total_fee_percent, special_fee_percent).into());
+        }
+        result
+    }
+}
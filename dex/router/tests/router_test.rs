@@ -5,8 +5,8 @@ use multiversx_sc::{
     codec::multi_types::OptionalValue,
     storage::mappers::StorageTokenWrapper,
     types::{
-        Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAddress, ManagedVec,
-        MultiValueEncoded,
+        Address, EgldOrEsdtTokenIdentifier, EsdtLocalRole, ManagedAddress, ManagedAsyncCallResult,
+        ManagedVec, MultiValueEncoded,
     },
 };
 use pair::{
@@ -15,14 +15,21 @@ use pair::{
 };
 use pausable::{PausableModule, State};
 use router::{
-    config::ConfigModule, enable_swap_by_user::EnableSwapByUserModule, factory::PairTokens,
-    multi_pair_swap::SWAP_TOKENS_FIXED_INPUT_FUNC_NAME, Router,
+    bonded_pairs::BondedPairsModule,
+    config::ConfigModule,
+    enable_swap_by_user::EnableSwapByUserModule,
+    factory::{FactoryModule, PairTokens},
+    fee_tiers::FeeTiersModule,
+    multi_pair_swap::SWAP_TOKENS_FIXED_INPUT_FUNC_NAME,
+    Router,
 };
 use router_setup::*;
 
 use multiversx_sc_scenario::{
-    managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
-    whitebox_legacy::BlockchainStateWrapper, whitebox_legacy::TxTokenTransfer, DebugApi,
+    managed_address, managed_biguint, managed_buffer, managed_token_id, managed_token_id_wrapped,
+    rust_biguint,
+    whitebox_legacy::{BlockchainStateWrapper, ContractObjWrapper, TxTokenTransfer},
+    DebugApi,
 };
 use simple_lock::{
     locked_token::{LockedTokenAttributes, LockedTokenModule},
@@ -113,7 +120,7 @@ fn test_router_upgrade_pair() {
         .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
             let first_token_id = managed_token_id!(CUSTOM_TOKEN_ID);
             let second_token_id = managed_token_id!(USDC_TOKEN_ID);
-            sc.upgrade_pair_endpoint(first_token_id, second_token_id);
+            sc.upgrade_pair_endpoint(first_token_id, second_token_id, OptionalValue::None);
         })
         .assert_ok();
 
@@ -558,3 +565,595 @@ fn user_enable_pair_swaps_fail_test() {
         }),
     );
 }
+
+const BOND_AMOUNT: u64 = 1_000;
+const LP_ISSUE_COST: u64 = 50;
+const BOND_HOLDING_PERIOD_EPOCHS: u64 = 10;
+const BONDED_PAIR_CREATION_EPOCH: u64 = 5;
+
+type RouterWrapper =
+    ContractObjWrapper<router::ContractObj<DebugApi>, fn() -> router::ContractObj<DebugApi>>;
+type PairWrapper =
+    ContractObjWrapper<pair::ContractObj<DebugApi>, fn() -> pair::ContractObj<DebugApi>>;
+
+// the pair is created through createBondedPair, and the LP token is issued and given its roles
+fn setup_bonded_pair() -> (
+    BlockchainStateWrapper,
+    Address,
+    Address,
+    RouterWrapper,
+    PairWrapper,
+) {
+    let rust_zero = rust_biguint!(0u64);
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_biguint!(BOND_AMOUNT + LP_ISSUE_COST));
+    let esdt_system_sc = b_mock.create_user_account(&rust_zero);
+
+    b_mock.set_esdt_balance(
+        &user,
+        CUSTOM_TOKEN_ID,
+        &rust_biguint!(USER_CUSTOM_TOKEN_BALANCE),
+    );
+    b_mock.set_esdt_balance(&user, USDC_TOKEN_ID, &rust_biguint!(USER_USDC_BALANCE));
+    b_mock.set_esdt_balance(&esdt_system_sc, LPUSDC_TOKEN_ID, &rust_biguint!(1_000));
+
+    let router_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        router::contract_obj as fn() -> router::ContractObj<DebugApi>,
+        ROUTER_WASM_PATH,
+    );
+    let pair_template_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair::contract_obj,
+        PAIR_WASM_PATH,
+    );
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::Some(managed_address!(
+                pair_template_wrapper.address_ref()
+            )));
+            sc.add_common_tokens_for_user_pairs(MultiValueEncoded::from(ManagedVec::from(vec![
+                managed_token_id!(USDC_TOKEN_ID),
+            ])));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(
+            &user,
+            &router_wrapper,
+            &rust_biguint!(BOND_AMOUNT + LP_ISSUE_COST),
+            |sc| {
+                let _ = sc.create_bonded_pair(
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_buffer!(b"LPUSDC"),
+                    managed_buffer!(b"LPUSDC"),
+                );
+            },
+        )
+        .assert_user_error("Bonded pairs not configured");
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.set_bonded_pair_config(
+                managed_biguint!(BOND_AMOUNT),
+                managed_biguint!(2 * USER_USDC_BALANCE + 1),
+                BOND_HOLDING_PERIOD_EPOCHS,
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_biguint!(BOND_AMOUNT), |sc| {
+            let _ = sc.create_bonded_pair(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_buffer!(b"LPUSDC"),
+                managed_buffer!(b"LPUSDC"),
+            );
+        })
+        .assert_user_error("Payment must cover the bond and the issue cost");
+
+    let pair_wrapper = b_mock.prepare_deploy_from_sc(
+        router_wrapper.address_ref(),
+        pair::contract_obj as fn() -> pair::ContractObj<DebugApi>,
+    );
+    b_mock.set_block_epoch(BONDED_PAIR_CREATION_EPOCH);
+    b_mock
+        .execute_tx(
+            &user,
+            &router_wrapper,
+            &rust_biguint!(BOND_AMOUNT + LP_ISSUE_COST),
+            |sc| {
+                let pair_address = sc.create_bonded_pair(
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_buffer!(b"LPUSDC"),
+                    managed_buffer!(b"LPUSDC"),
+                );
+                assert_eq!(pair_address, managed_address!(pair_wrapper.address_ref()));
+
+                let info = sc.bonded_pair_info(&pair_address).get();
+                assert_eq!(info.creator, managed_address!(&user));
+                assert_eq!(info.bond_amount, managed_biguint!(BOND_AMOUNT));
+                assert_eq!(info.common_token_id, managed_token_id!(USDC_TOKEN_ID));
+                assert_eq!(info.creation_epoch, BONDED_PAIR_CREATION_EPOCH);
+                assert_eq!(info.opt_activation_epoch, None);
+                assert!(!info.lp_roles_set);
+            },
+        )
+        .assert_ok();
+
+    // the issue callback, with the issued tokens sent by the system SC
+    let user_address = user.clone();
+    b_mock
+        .execute_esdt_transfer(
+            &esdt_system_sc,
+            &router_wrapper,
+            LPUSDC_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.bonded_pair_lp_issue_callback(
+                    &managed_address!(&user_address),
+                    &managed_address!(pair_wrapper.address_ref()),
+                    ManagedAsyncCallResult::Ok(()),
+                );
+            },
+        )
+        .assert_ok();
+
+    b_mock
+        .execute_query(&pair_wrapper, |sc| {
+            assert_eq!(
+                sc.lp_token_identifier().get(),
+                managed_token_id!(LPUSDC_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+
+    // the issue callback sets the roles, and the roles callback records it
+    b_mock.set_esdt_local_roles(
+        pair_wrapper.address_ref(),
+        LPUSDC_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+    b_mock
+        .execute_tx(&esdt_system_sc, &router_wrapper, &rust_zero, |sc| {
+            let pair_address = managed_address!(pair_wrapper.address_ref());
+            sc.bonded_pair_lp_roles_callback(&pair_address, ManagedAsyncCallResult::Ok(()));
+
+            assert!(sc.bonded_pair_info(&pair_address).get().lp_roles_set);
+        })
+        .assert_ok();
+
+    (b_mock, owner, user, router_wrapper, pair_wrapper)
+}
+
+#[test]
+fn bonded_pair_activation_and_bond_claim_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let bond_amount = BOND_AMOUNT;
+    let bond_holding_period_epochs = BOND_HOLDING_PERIOD_EPOCHS;
+    let min_liquidity_value = 2 * USER_USDC_BALANCE;
+
+    let (mut b_mock, owner, user, router_wrapper, pair_wrapper) = setup_bonded_pair();
+    let current_epoch = BONDED_PAIR_CREATION_EPOCH;
+
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: CUSTOM_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(USER_CUSTOM_TOKEN_BALANCE),
+        },
+        TxTokenTransfer {
+            token_identifier: USDC_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(USER_USDC_BALANCE),
+        },
+    ];
+
+    // the initial liquidity can only be added through the router
+    b_mock
+        .execute_esdt_multi_transfer(&user, &pair_wrapper, &payments, |sc| {
+            let _ = sc.add_initial_liquidity();
+        })
+        .assert_user_error("Permission denied");
+
+    // liquidity below the min value
+    b_mock
+        .execute_esdt_multi_transfer(&user, &router_wrapper, &payments, |sc| {
+            let _ =
+                sc.add_bonded_pair_initial_liquidity(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Not enough liquidity");
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.set_bonded_pair_config(
+                managed_biguint!(bond_amount),
+                managed_biguint!(min_liquidity_value),
+                bond_holding_period_epochs,
+            );
+        })
+        .assert_ok();
+
+    // claiming the bond requires the pair to be active
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            sc.claim_pair_bond(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Pair not activated");
+
+    // adding the initial liquidity enables the swaps
+    b_mock
+        .execute_esdt_multi_transfer(&user, &router_wrapper, &payments, |sc| {
+            let _ =
+                sc.add_bonded_pair_initial_liquidity(managed_address!(pair_wrapper.address_ref()));
+
+            let info = sc
+                .bonded_pair_info(&managed_address!(pair_wrapper.address_ref()))
+                .get();
+            assert_eq!(info.opt_activation_epoch, Some(current_epoch));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&pair_wrapper, |sc| {
+            assert_eq!(sc.state().get(), State::Active);
+        })
+        .assert_ok();
+    b_mock.check_esdt_balance(
+        &user,
+        LPUSDC_TOKEN_ID,
+        &(rust_biguint!(USER_USDC_BALANCE) - 1_000u64),
+    );
+
+    // claim bond
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.claim_pair_bond(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Only the pair creator may claim");
+
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            sc.claim_pair_bond(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Holding period not passed");
+
+    b_mock.set_block_epoch(current_epoch + bond_holding_period_epochs);
+
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            sc.claim_pair_bond(managed_address!(pair_wrapper.address_ref()));
+
+            assert!(sc
+                .bonded_pair_info(&managed_address!(pair_wrapper.address_ref()))
+                .is_empty());
+        })
+        .assert_ok();
+
+    b_mock.check_egld_balance(&user, &rust_biguint!(bond_amount));
+    b_mock.check_egld_balance(router_wrapper.address_ref(), &rust_zero);
+}
+
+#[test]
+fn bonded_pair_bond_slash_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let (mut b_mock, owner, user, router_wrapper, pair_wrapper) = setup_bonded_pair();
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.slash_pair_bond(managed_address!(&owner));
+        })
+        .assert_user_error("Not a bonded pair");
+
+    b_mock.set_block_epoch(BONDED_PAIR_CREATION_EPOCH + BOND_HOLDING_PERIOD_EPOCHS - 1);
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.slash_pair_bond(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Holding period not passed");
+
+    // the pair was not activated within the holding period
+    b_mock.set_block_epoch(BONDED_PAIR_CREATION_EPOCH + BOND_HOLDING_PERIOD_EPOCHS);
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.slash_pair_bond(managed_address!(pair_wrapper.address_ref()));
+
+            assert!(sc
+                .bonded_pair_info(&managed_address!(pair_wrapper.address_ref()))
+                .is_empty());
+        })
+        .assert_ok();
+
+    b_mock.check_egld_balance(&owner, &rust_biguint!(BOND_AMOUNT));
+    b_mock.check_egld_balance(&user, &rust_zero);
+    b_mock.check_egld_balance(router_wrapper.address_ref(), &rust_zero);
+
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            sc.claim_pair_bond(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Not a bonded pair");
+
+    // the pair was removed, so the tokens can be paired again
+    b_mock
+        .execute_query(&router_wrapper, |sc| {
+            let pair_address = sc.get_pair(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                OptionalValue::None,
+            );
+            assert!(pair_address.is_zero());
+            assert!(sc
+                .pair_temporary_owner()
+                .get(&managed_address!(pair_wrapper.address_ref()))
+                .is_none());
+        })
+        .assert_ok();
+
+    b_mock.set_egld_balance(&user, &rust_biguint!(BOND_AMOUNT + LP_ISSUE_COST));
+    let new_pair_wrapper = b_mock.prepare_deploy_from_sc(
+        router_wrapper.address_ref(),
+        pair::contract_obj as fn() -> pair::ContractObj<DebugApi>,
+    );
+    b_mock
+        .execute_tx(
+            &user,
+            &router_wrapper,
+            &rust_biguint!(BOND_AMOUNT + LP_ISSUE_COST),
+            |sc| {
+                let pair_address = sc.create_bonded_pair(
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    managed_token_id!(USDC_TOKEN_ID),
+                    managed_buffer!(b"LPUSDC"),
+                    managed_buffer!(b"LPUSDC"),
+                );
+                assert_eq!(
+                    pair_address,
+                    managed_address!(new_pair_wrapper.address_ref())
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn activated_bonded_pair_bond_slash_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let (mut b_mock, owner, user, router_wrapper, pair_wrapper) = setup_bonded_pair();
+
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: CUSTOM_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(USER_CUSTOM_TOKEN_BALANCE),
+        },
+        TxTokenTransfer {
+            token_identifier: USDC_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: rust_biguint!(USER_USDC_BALANCE),
+        },
+    ];
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.set_bonded_pair_config(
+                managed_biguint!(BOND_AMOUNT),
+                managed_biguint!(2 * USER_USDC_BALANCE),
+                BOND_HOLDING_PERIOD_EPOCHS,
+            );
+        })
+        .assert_ok();
+    b_mock
+        .execute_esdt_multi_transfer(&user, &router_wrapper, &payments, |sc| {
+            let _ =
+                sc.add_bonded_pair_initial_liquidity(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_ok();
+
+    b_mock.set_block_epoch(BONDED_PAIR_CREATION_EPOCH + BOND_HOLDING_PERIOD_EPOCHS);
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.slash_pair_bond(managed_address!(pair_wrapper.address_ref()));
+        })
+        .assert_user_error("Pair already activated");
+}
+
+#[test]
+fn fee_tier_pairs_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let low_fee_tier = 50u64;
+    let high_fee_tier = 1_000u64;
+
+    let mut b_mock = BlockchainStateWrapper::new();
+    let owner = b_mock.create_user_account(&rust_zero);
+    let user = b_mock.create_user_account(&rust_zero);
+
+    let router_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner),
+        router::contract_obj,
+        ROUTER_WASM_PATH,
+    );
+    let pair_template_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair::contract_obj,
+        PAIR_WASM_PATH,
+    );
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::Some(managed_address!(
+                pair_template_wrapper.address_ref()
+            )));
+            sc.set_pair_creation_enabled(true);
+
+            sc.add_fee_tier(low_fee_tier, 5);
+            sc.add_fee_tier(high_fee_tier, 100);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.add_fee_tier(100, 200);
+        })
+        .assert_user_error("Bad percents");
+
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.add_fee_tier(0, 0);
+        })
+        .assert_user_error("Bad percents");
+
+    // create the default pair and a pair for each tier
+    let default_pair_wrapper =
+        b_mock.prepare_deploy_from_sc(router_wrapper.address_ref(), pair::contract_obj);
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_endpoint(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_address!(&user),
+                OptionalValue::None,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+
+    let low_fee_pair_wrapper =
+        b_mock.prepare_deploy_from_sc(router_wrapper.address_ref(), pair::contract_obj);
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_address!(&user),
+                low_fee_tier,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+
+    let high_fee_pair_wrapper =
+        b_mock.prepare_deploy_from_sc(router_wrapper.address_ref(), pair::contract_obj);
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_address!(&user),
+                high_fee_tier,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+
+    // one pair per tier
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_address!(&user),
+                low_fee_tier,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("Pair already exists");
+
+    b_mock
+        .execute_tx(&user, &router_wrapper, &rust_zero, |sc| {
+            let _ = sc.create_pair_with_fee_tier(
+                managed_token_id!(USDC_TOKEN_ID),
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_address!(&user),
+                300,
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_user_error("Fee tier does not exist");
+
+    b_mock
+        .execute_query(&router_wrapper, |sc| {
+            let first_token_id = managed_token_id!(CUSTOM_TOKEN_ID);
+            let second_token_id = managed_token_id!(USDC_TOKEN_ID);
+            assert_eq!(
+                sc.get_pair(
+                    first_token_id.clone(),
+                    second_token_id.clone(),
+                    OptionalValue::None
+                ),
+                managed_address!(default_pair_wrapper.address_ref())
+            );
+            assert_eq!(
+                sc.get_pair(
+                    second_token_id.clone(),
+                    first_token_id.clone(),
+                    OptionalValue::Some(low_fee_tier)
+                ),
+                managed_address!(low_fee_pair_wrapper.address_ref())
+            );
+            assert_eq!(
+                sc.get_pair(
+                    first_token_id.clone(),
+                    second_token_id.clone(),
+                    OptionalValue::Some(high_fee_tier)
+                ),
+                managed_address!(high_fee_pair_wrapper.address_ref())
+            );
+            assert_eq!(sc.get_all_pairs_addresses().to_vec().len(), 3);
+
+            sc.check_is_pair_sc(&managed_address!(low_fee_pair_wrapper.address_ref()));
+            sc.check_is_pair_sc(&managed_address!(high_fee_pair_wrapper.address_ref()));
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(&high_fee_pair_wrapper, |sc| {
+            assert_eq!(sc.total_fee_percent().get(), high_fee_tier);
+            assert_eq!(sc.special_fee_percent().get(), 100);
+        })
+        .assert_ok();
+
+    // removing a tier keeps its pairs
+    b_mock
+        .execute_tx(&owner, &router_wrapper, &rust_zero, |sc| {
+            sc.remove_fee_tier(high_fee_tier);
+
+            let fee_tiers: Vec<(u64, u64)> = sc
+                .get_fee_tiers()
+                .into_iter()
+                .map(|fee_tier| fee_tier.into_tuple())
+                .collect();
+            assert_eq!(fee_tiers, vec![(low_fee_tier, 5)]);
+
+            let pair_address = sc.remove_pair(
+                managed_token_id!(CUSTOM_TOKEN_ID),
+                managed_token_id!(USDC_TOKEN_ID),
+                OptionalValue::Some(low_fee_tier),
+            );
+            assert_eq!(
+                pair_address,
+                managed_address!(low_fee_pair_wrapper.address_ref())
+            );
+            assert!(sc.pair_fee_tier(&pair_address).is_empty());
+
+            assert_eq!(
+                sc.get_pair(
+                    managed_token_id!(CUSTOM_TOKEN_ID),
+                    managed_token_id!(USDC_TOKEN_ID),
+                    OptionalValue::Some(high_fee_tier)
+                ),
+                managed_address!(high_fee_pair_wrapper.address_ref())
+            );
+        })
+        .assert_ok();
+}
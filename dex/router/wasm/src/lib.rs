@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           29
+// Endpoints:                           41
 // Async Callback:                       1
-// Total number of exported functions:  32
+// Promise callbacks:                    2
+// Total number of exported functions:  46
 
 #![no_std]
 
@@ -35,6 +36,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getOwner => owner
         setTemporaryOwnerPeriod => set_temporary_owner_period
         setPairTemplateAddress => set_pair_template_address
+        getPairFeeTier => pair_fee_tier
         getPairTemplateAddress => pair_template_address
         getTemporaryOwnerPeriod => temporary_owner_period
         getCommonTokensForUserPairs => common_tokens_for_user_pairs
@@ -49,6 +51,19 @@ multiversx_sc_wasm_adapter::endpoints! {
         removeCommonTokensForUserPairs => remove_common_tokens_for_user_pairs
         setSwapEnabledByUser => set_swap_enabled_by_user
         getEnableSwapByUserConfig => try_get_config
+        setBondedPairConfig => set_bonded_pair_config
+        createBondedPair => create_bonded_pair
+        addBondedPairInitialLiquidity => add_bonded_pair_initial_liquidity
+        claimPairBond => claim_pair_bond
+        slashPairBond => slash_pair_bond
+        getBondedPairConfig => bonded_pair_config
+        getBondedPairInfo => bonded_pair_info
+        addFeeTier => add_fee_tier
+        removeFeeTier => remove_fee_tier
+        createPairWithFeeTier => create_pair_with_fee_tier
+        getFeeTiers => get_fee_tiers
+        bonded_pair_lp_issue_callback => bonded_pair_lp_issue_callback
+        bonded_pair_lp_roles_callback => bonded_pair_lp_roles_callback
     )
 }
 
@@ -0,0 +1,143 @@
+multiversx_sc::imports!();
+
+use pair::read_pair_storage;
+use router::factory::ProxyTrait as _;
+
+#[multiversx_sc::module]
+pub trait ConfigModule:
+    crate::external_storage::ExternalStorageModule
+    + read_pair_storage::ReadPairStorageModule
+    + permissions_module::PermissionsModule
+{
+    #[endpoint(setRouterAddress)]
+    fn set_router_address(&self, router_address: ManagedAddress) {
+        self.require_caller_has_owner_permissions();
+        self.set_router_address_unchecked(router_address);
+    }
+
+    /// Only pairs deployed through the router can be whitelisted
+    #[endpoint(addPairs)]
+    fn add_pairs(&self, pair_addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_caller_has_owner_permissions();
+
+        let mut whitelisted_pairs = self.whitelisted_pairs();
+        for pair_address in pair_addresses {
+            self.require_router_pair(&pair_address);
+            let _ = whitelisted_pairs.insert(pair_address);
+        }
+    }
+
+    #[endpoint(removePairs)]
+    fn remove_pairs(&self, pair_addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_caller_has_owner_permissions();
+
+        let mut whitelisted_pairs = self.whitelisted_pairs();
+        for pair_address in pair_addresses {
+            let _ = whitelisted_pairs.swap_remove(&pair_address);
+        }
+    }
+
+    /// The farming token of the farm must be the LP token of the given whitelisted pair
+    #[endpoint(addFarm)]
+    fn add_farm(&self, farm_address: ManagedAddress, pair_address: ManagedAddress) {
+        self.require_caller_has_owner_permissions();
+        self.require_whitelisted_pair(&pair_address);
+        require!(
+            self.blockchain().is_smart_contract(&farm_address),
+            "Invalid farm address"
+        );
+
+        let farming_token_id = self.get_farming_token_id_mapper(farm_address.clone()).get();
+        let lp_token_id = self.get_pair_lp_token_id_mapper(pair_address.clone()).get();
+        require!(
+            farming_token_id == lp_token_id,
+            "Farm does not accept the pair LP token"
+        );
+
+        let _ = self.whitelisted_farms().insert(farm_address, pair_address);
+    }
+
+    #[endpoint(removeFarm)]
+    fn remove_farm(&self, farm_address: ManagedAddress) {
+        self.require_caller_has_owner_permissions();
+        require!(
+            self.farm_position(&farm_address).is_empty(),
+            "Must exit the farm first"
+        );
+
+        let removed = self.whitelisted_farms().remove(&farm_address);
+        require!(removed.is_some(), "Farm not whitelisted");
+    }
+
+    fn set_router_address_unchecked(&self, router_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid router address"
+        );
+        self.router_address().set(router_address);
+    }
+
+    fn require_router_pair(&self, pair_address: &ManagedAddress) {
+        let router_address = self.router_address().get();
+        let fee_tier_mapper =
+            self.get_router_pair_fee_tier_mapper(router_address.clone(), pair_address);
+        let opt_fee_tier = if fee_tier_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(fee_tier_mapper.get())
+        };
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        let router_pair_address: ManagedAddress = self
+            .router_proxy(router_address)
+            .get_pair(first_token_id, second_token_id, opt_fee_tier)
+            .execute_on_dest_context();
+        require!(&router_pair_address == pair_address, "Not a router pair");
+    }
+
+    fn require_whitelisted_pair(&self, pair_address: &ManagedAddress) {
+        require!(
+            self.whitelisted_pairs().contains(pair_address),
+            "Pair not whitelisted"
+        );
+    }
+
+    fn get_farm_pair(&self, farm_address: &ManagedAddress) -> ManagedAddress {
+        match self.whitelisted_farms().get(farm_address) {
+            Some(pair_address) => pair_address,
+            None => sc_panic!("Farm not whitelisted"),
+        }
+    }
+
+    #[view(getWhitelistedFarms)]
+    fn get_whitelisted_farms(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedAddress>> {
+        let mut result = MultiValueEncoded::new();
+        for (farm_address, pair_address) in self.whitelisted_farms().iter() {
+            result.push((farm_address, pair_address).into());
+        }
+
+        result
+    }
+
+    #[proxy]
+    fn router_proxy(&self, sc_address: ManagedAddress) -> router::Proxy<Self::Api>;
+
+    #[view(getRouterAddress)]
+    #[storage_mapper("routerAddress")]
+    fn router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getWhitelistedPairs)]
+    #[storage_mapper("whitelistedPairs")]
+    fn whitelisted_pairs(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Maps each farm to the pair of its farming token
+    #[storage_mapper("whitelistedFarms")]
+    fn whitelisted_farms(&self) -> MapMapper<ManagedAddress, ManagedAddress>;
+
+    #[view(getFarmPosition)]
+    #[storage_mapper("farmPosition")]
+    fn farm_position(&self, farm_address: &ManagedAddress) -> SingleValueMapper<EsdtTokenPayment>;
+}
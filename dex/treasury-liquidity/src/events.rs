@@ -0,0 +1,126 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(TypeAbi, TopEncode)]
+pub struct LiquidityEvent<M: ManagedTypeApi> {
+    first_tokens: EsdtTokenPayment<M>,
+    second_tokens: EsdtTokenPayment<M>,
+    lp_tokens: EsdtTokenPayment<M>,
+    block: u64,
+    epoch: u64,
+}
+
+#[derive(TypeAbi, TopEncode)]
+pub struct FarmEvent<M: ManagedTypeApi> {
+    farming_tokens: EsdtTokenPayment<M>,
+    rewards: EsdtTokenPayment<M>,
+    farm_position: EsdtTokenPayment<M>,
+    block: u64,
+    epoch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_deposit_event(&self, caller: ManagedAddress, payment: EsdtTokenPayment) {
+        self.deposit_event(caller, payment);
+    }
+
+    fn emit_withdraw_event(&self, destination: ManagedAddress, payment: EsdtTokenPayment) {
+        let caller = self.blockchain().get_caller();
+        self.withdraw_event(caller, destination, payment);
+    }
+
+    fn build_liquidity_event(
+        &self,
+        first_tokens: EsdtTokenPayment,
+        second_tokens: EsdtTokenPayment,
+        lp_tokens: EsdtTokenPayment,
+    ) -> LiquidityEvent<Self::Api> {
+        LiquidityEvent {
+            first_tokens,
+            second_tokens,
+            lp_tokens,
+            block: self.blockchain().get_block_nonce(),
+            epoch: self.blockchain().get_block_epoch(),
+        }
+    }
+
+    fn build_farm_event(
+        &self,
+        farming_tokens: EsdtTokenPayment,
+        rewards: EsdtTokenPayment,
+        farm_position: EsdtTokenPayment,
+    ) -> FarmEvent<Self::Api> {
+        FarmEvent {
+            farming_tokens,
+            rewards,
+            farm_position,
+            block: self.blockchain().get_block_nonce(),
+            epoch: self.blockchain().get_block_epoch(),
+        }
+    }
+
+    #[event("deposit")]
+    fn deposit_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] payment: EsdtTokenPayment,
+    );
+
+    #[event("withdraw")]
+    fn withdraw_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] destination: ManagedAddress,
+        #[indexed] payment: EsdtTokenPayment,
+    );
+
+    #[event("addLiquidity")]
+    fn add_liquidity_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] pair_address: ManagedAddress,
+        liquidity_event: LiquidityEvent<Self::Api>,
+    );
+
+    #[event("removeLiquidity")]
+    fn remove_liquidity_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] pair_address: ManagedAddress,
+        liquidity_event: LiquidityEvent<Self::Api>,
+    );
+
+    #[event("enterFarm")]
+    fn enter_farm_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] farm_address: ManagedAddress,
+        farm_event: FarmEvent<Self::Api>,
+    );
+
+    #[event("exitFarm")]
+    fn exit_farm_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] farm_address: ManagedAddress,
+        farm_event: FarmEvent<Self::Api>,
+    );
+
+    #[event("claimRewards")]
+    fn claim_rewards_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] farm_address: ManagedAddress,
+        farm_event: FarmEvent<Self::Api>,
+    );
+
+    /// The farming tokens are the LP tokens added to the farm position
+    #[event("compoundRewards")]
+    fn compound_rewards_event(
+        &self,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] farm_address: ManagedAddress,
+        farm_event: FarmEvent<Self::Api>,
+    );
+}
@@ -0,0 +1,52 @@
+use multiversx_sc::storage::StorageKey;
+
+multiversx_sc::imports!();
+
+pub static PAIR_LP_TOKEN_ID_STORAGE_KEY: &[u8] = b"lpTokenIdentifier";
+pub static FARM_FARMING_TOKEN_ID_STORAGE_KEY: &[u8] = b"farming_token_id";
+pub static FARM_REWARD_TOKEN_ID_STORAGE_KEY: &[u8] = b"reward_token_id";
+pub static ROUTER_PAIR_FEE_TIER_STORAGE_KEY: &[u8] = b"pairFeeTier";
+
+#[multiversx_sc::module]
+pub trait ExternalStorageModule {
+    fn get_pair_lp_token_id_mapper(
+        &self,
+        pair_address: ManagedAddress,
+    ) -> SingleValueMapper<TokenIdentifier, ManagedAddress> {
+        SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+            pair_address,
+            StorageKey::new(PAIR_LP_TOKEN_ID_STORAGE_KEY),
+        )
+    }
+
+    fn get_farming_token_id_mapper(
+        &self,
+        farm_address: ManagedAddress,
+    ) -> SingleValueMapper<TokenIdentifier, ManagedAddress> {
+        SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+            farm_address,
+            StorageKey::new(FARM_FARMING_TOKEN_ID_STORAGE_KEY),
+        )
+    }
+
+    fn get_farm_reward_token_id_mapper(
+        &self,
+        farm_address: ManagedAddress,
+    ) -> SingleValueMapper<TokenIdentifier, ManagedAddress> {
+        SingleValueMapper::<_, _, ManagedAddress>::new_from_address(
+            farm_address,
+            StorageKey::new(FARM_REWARD_TOKEN_ID_STORAGE_KEY),
+        )
+    }
+
+    fn get_router_pair_fee_tier_mapper(
+        &self,
+        router_address: ManagedAddress,
+        pair_address: &ManagedAddress,
+    ) -> SingleValueMapper<u64, ManagedAddress> {
+        let mut storage_key = StorageKey::new(ROUTER_PAIR_FEE_TIER_STORAGE_KEY);
+        storage_key.append_item(pair_address);
+
+        SingleValueMapper::<_, _, ManagedAddress>::new_from_address(router_address, storage_key)
+    }
+}
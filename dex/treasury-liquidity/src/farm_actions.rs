@@ -0,0 +1,231 @@
+multiversx_sc::imports!();
+
+use farm::{
+    base_functions::{ClaimRewardsResultType, ExitFarmResultType},
+    EnterFarmResultType, ProxyTrait as _,
+};
+use pair::{pair_actions::swap::ProxyTrait as _, read_pair_storage};
+
+#[multiversx_sc::module]
+pub trait FarmActionsModule:
+    crate::config::ConfigModule
+    + crate::external_storage::ExternalStorageModule
+    + read_pair_storage::ReadPairStorageModule
+    + crate::liquidity_actions::LiquidityActionsModule
+    + crate::events::EventsModule
+    + permissions_module::PermissionsModule
+{
+    /// Stakes LP tokens from the treasury funds. The treasury keeps a single position for each farm.
+    ///
+    /// Returns the farm position
+    #[endpoint(enterFarm)]
+    fn enter_farm_endpoint(
+        &self,
+        farm_address: ManagedAddress,
+        lp_token_amount: BigUint,
+    ) -> EsdtTokenPayment {
+        self.require_caller_has_admin_permissions();
+        let pair_address = self.get_farm_pair(&farm_address);
+        require!(lp_token_amount > 0, "Invalid amount");
+
+        let lp_token_id = self.get_pair_lp_token_id_mapper(pair_address).get();
+        let lp_tokens = EsdtTokenPayment::new(lp_token_id, 0, lp_token_amount);
+        let (farm_position, boosted_rewards) = self.enter_farm(&farm_address, lp_tokens.clone());
+
+        let caller = self.blockchain().get_caller();
+        self.enter_farm_event(
+            caller,
+            farm_address,
+            self.build_farm_event(lp_tokens, boosted_rewards, farm_position.clone()),
+        );
+
+        farm_position
+    }
+
+    /// Exits the given amount of the farm position.
+    ///
+    /// Returns the LP tokens and the rewards
+    #[endpoint(exitFarm)]
+    fn exit_farm_endpoint(
+        &self,
+        farm_address: ManagedAddress,
+        farm_token_amount: BigUint,
+    ) -> ExitFarmResultType<Self::Api> {
+        self.require_caller_has_admin_permissions();
+        let position_mapper = self.farm_position(&farm_address);
+        require!(!position_mapper.is_empty(), "No farm position");
+
+        let mut farm_position = position_mapper.get();
+        require!(
+            farm_token_amount > 0 && farm_token_amount <= farm_position.amount,
+            "Invalid amount"
+        );
+
+        let exit_payment = EsdtTokenPayment::new(
+            farm_position.token_identifier.clone(),
+            farm_position.token_nonce,
+            farm_token_amount.clone(),
+        );
+        let exit_farm_result: ExitFarmResultType<Self::Api> = self
+            .treasury_farm_proxy(farm_address.clone())
+            .exit_farm_endpoint(OptionalValue::<ManagedAddress>::None)
+            .with_esdt_transfer(exit_payment)
+            .execute_on_dest_context();
+
+        farm_position.amount -= farm_token_amount;
+        if farm_position.amount == 0 {
+            position_mapper.clear();
+        } else {
+            position_mapper.set(&farm_position);
+        }
+
+        let (lp_tokens, rewards) = exit_farm_result.clone().into_tuple();
+        let caller = self.blockchain().get_caller();
+        self.exit_farm_event(
+            caller,
+            farm_address,
+            self.build_farm_event(lp_tokens, rewards, farm_position),
+        );
+
+        exit_farm_result
+    }
+
+    /// Returns the claimed rewards
+    #[endpoint(claimFarmRewards)]
+    fn claim_farm_rewards_endpoint(&self, farm_address: ManagedAddress) -> EsdtTokenPayment {
+        self.require_caller_has_admin_permissions();
+        let _ = self.get_farm_pair(&farm_address);
+
+        let (farm_position, rewards) = self.claim_farm_rewards(&farm_address);
+
+        let farming_tokens = EsdtTokenPayment::new(
+            self.get_farming_token_id_mapper(farm_address.clone()).get(),
+            0,
+            BigUint::zero(),
+        );
+        let caller = self.blockchain().get_caller();
+        self.claim_rewards_event(
+            caller,
+            farm_address,
+            self.build_farm_event(farming_tokens, rewards.clone(), farm_position),
+        );
+
+        rewards
+    }
+
+    /// Claims the rewards and stakes them back into the farm. The reward token must be one of the pair tokens.
+    /// Half of the rewards are swapped into the other pair token, then liquidity is added
+    /// and the LP tokens are added to the farm position. The unused tokens are kept by the treasury.
+    ///
+    /// Returns the farm position
+    #[endpoint(compoundFarmRewards)]
+    fn compound_farm_rewards(
+        &self,
+        farm_address: ManagedAddress,
+        swap_amount_out_min: BigUint,
+    ) -> EsdtTokenPayment {
+        self.require_caller_has_admin_permissions();
+        let pair_address = self.get_farm_pair(&farm_address);
+
+        let (_, rewards) = self.claim_farm_rewards(&farm_address);
+        require!(rewards.amount > 1u32, "Not enough rewards to compound");
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+        let rewards_are_first_token = rewards.token_identifier == first_token_id;
+        let other_token_id = if rewards_are_first_token {
+            second_token_id
+        } else if rewards.token_identifier == second_token_id {
+            first_token_id
+        } else {
+            sc_panic!("Reward token not in pair");
+        };
+
+        let swap_amount = &rewards.amount / 2u32;
+        let kept_rewards = EsdtTokenPayment::new(
+            rewards.token_identifier.clone(),
+            0,
+            &rewards.amount - &swap_amount,
+        );
+        let swapped_tokens: EsdtTokenPayment = self
+            .treasury_pair_proxy(pair_address.clone())
+            .swap_tokens_fixed_input(other_token_id, swap_amount_out_min)
+            .with_esdt_transfer((rewards.token_identifier.clone(), 0, swap_amount))
+            .execute_on_dest_context();
+
+        // the swap output is already bounded, and the reserves cannot change in between
+        let (first_tokens, second_tokens) = if rewards_are_first_token {
+            (kept_rewards, swapped_tokens)
+        } else {
+            (swapped_tokens, kept_rewards)
+        };
+        let (lp_tokens, _, _) = self
+            .add_liquidity_to_pair(
+                pair_address,
+                first_tokens,
+                second_tokens,
+                BigUint::from(1u32),
+                BigUint::from(1u32),
+            )
+            .into_tuple();
+
+        let (farm_position, _) = self.enter_farm(&farm_address, lp_tokens.clone());
+
+        let caller = self.blockchain().get_caller();
+        self.compound_rewards_event(
+            caller,
+            farm_address,
+            self.build_farm_event(lp_tokens, rewards, farm_position.clone()),
+        );
+
+        farm_position
+    }
+
+    /// Merges the current farm position into the new one
+    fn enter_farm(
+        &self,
+        farm_address: &ManagedAddress,
+        lp_tokens: EsdtTokenPayment,
+    ) -> (EsdtTokenPayment, EsdtTokenPayment) {
+        let position_mapper = self.farm_position(farm_address);
+
+        let mut payments = ManagedVec::new();
+        payments.push(lp_tokens);
+        if !position_mapper.is_empty() {
+            payments.push(position_mapper.get());
+        }
+
+        let enter_farm_result: EnterFarmResultType<Self::Api> = self
+            .treasury_farm_proxy(farm_address.clone())
+            .enter_farm_endpoint(OptionalValue::<ManagedAddress>::None)
+            .with_multi_token_transfer(payments)
+            .execute_on_dest_context();
+
+        let (farm_position, boosted_rewards) = enter_farm_result.into_tuple();
+        position_mapper.set(&farm_position);
+
+        (farm_position, boosted_rewards)
+    }
+
+    fn claim_farm_rewards(
+        &self,
+        farm_address: &ManagedAddress,
+    ) -> (EsdtTokenPayment, EsdtTokenPayment) {
+        let position_mapper = self.farm_position(farm_address);
+        require!(!position_mapper.is_empty(), "No farm position");
+
+        let claim_rewards_result: ClaimRewardsResultType<Self::Api> = self
+            .treasury_farm_proxy(farm_address.clone())
+            .claim_rewards_endpoint(OptionalValue::<ManagedAddress>::None)
+            .with_esdt_transfer(position_mapper.get())
+            .execute_on_dest_context();
+
+        let (farm_position, rewards) = claim_rewards_result.into_tuple();
+        position_mapper.set(&farm_position);
+
+        (farm_position, rewards)
+    }
+
+    #[proxy]
+    fn treasury_farm_proxy(&self, sc_address: ManagedAddress) -> farm::Proxy<Self::Api>;
+}
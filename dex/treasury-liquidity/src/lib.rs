@@ -0,0 +1,84 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod config;
+pub mod events;
+pub mod external_storage;
+pub mod farm_actions;
+pub mod liquidity_actions;
+
+use pair::read_pair_storage;
+use permissions_module::Permissions;
+
+#[multiversx_sc::contract]
+pub trait TreasuryLiquidity:
+    config::ConfigModule
+    + external_storage::ExternalStorageModule
+    + read_pair_storage::ReadPairStorageModule
+    + liquidity_actions::LiquidityActionsModule
+    + farm_actions::FarmActionsModule
+    + events::EventsModule
+    + permissions_module::PermissionsModule
+{
+    /// The admins are the only addresses that can operate the treasury funds,
+    /// i.e. the governance-v2 contract and the multisig.
+    /// The owner manages the whitelisted pairs and farms.
+    #[init]
+    fn init(&self, router_address: ManagedAddress, admins: MultiValueEncoded<ManagedAddress>) {
+        self.set_router_address_unchecked(router_address);
+
+        let caller = self.blockchain().get_caller();
+        self.add_permissions(caller, Permissions::OWNER);
+        self.add_permissions_for_all(admins, Permissions::ADMIN);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Anyone can fund the treasury with fungible tokens
+    #[payable("*")]
+    #[endpoint]
+    fn deposit(&self) {
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "No payments");
+
+        let caller = self.blockchain().get_caller();
+        for payment in &payments {
+            require!(payment.token_nonce == 0, "Only fungible tokens accepted");
+            self.emit_deposit_event(caller.clone(), payment);
+        }
+    }
+
+    /// Farm positions can only be withdrawn after exiting the farm.
+    /// The token nonce is 0 for fungible tokens, e.g. the locked rewards (XMEX) have a nonce.
+    #[endpoint]
+    fn withdraw(
+        &self,
+        destination: ManagedAddress,
+        token_id: TokenIdentifier,
+        token_nonce: u64,
+        amount: BigUint,
+    ) {
+        self.require_caller_has_admin_permissions();
+        require!(amount > 0, "Invalid amount");
+        require!(
+            amount <= self.get_token_balance(token_id.clone(), token_nonce),
+            "Not enough funds"
+        );
+
+        self.send()
+            .direct_esdt(&destination, &token_id, token_nonce, &amount);
+
+        self.emit_withdraw_event(
+            destination,
+            EsdtTokenPayment::new(token_id, token_nonce, amount),
+        );
+    }
+
+    #[view(getTokenBalance)]
+    fn get_token_balance(&self, token_id: TokenIdentifier, token_nonce: u64) -> BigUint {
+        self.blockchain()
+            .get_sc_balance(&EgldOrEsdtTokenIdentifier::esdt(token_id), token_nonce)
+    }
+}
@@ -0,0 +1,110 @@
+multiversx_sc::imports!();
+
+use pair::{
+    pair_actions::{
+        add_liq::ProxyTrait as _,
+        common_result_types::{AddLiquidityResultType, RemoveLiquidityResultType},
+        remove_liq::ProxyTrait as _,
+    },
+    read_pair_storage,
+};
+
+#[multiversx_sc::module]
+pub trait LiquidityActionsModule:
+    crate::config::ConfigModule
+    + crate::external_storage::ExternalStorageModule
+    + read_pair_storage::ReadPairStorageModule
+    + crate::events::EventsModule
+    + permissions_module::PermissionsModule
+{
+    /// Adds liquidity from the treasury funds. The unused tokens are kept by the treasury.
+    ///
+    /// Returns the LP tokens and the used amounts of the first and second tokens.
+    #[endpoint(addLiquidity)]
+    fn add_liquidity_endpoint(
+        &self,
+        pair_address: ManagedAddress,
+        first_token_amount: BigUint,
+        second_token_amount: BigUint,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> AddLiquidityResultType<Self::Api> {
+        self.require_caller_has_admin_permissions();
+        self.require_whitelisted_pair(&pair_address);
+
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address.clone()).get();
+
+        self.add_liquidity_to_pair(
+            pair_address,
+            EsdtTokenPayment::new(first_token_id, 0, first_token_amount),
+            EsdtTokenPayment::new(second_token_id, 0, second_token_amount),
+            first_token_amount_min,
+            second_token_amount_min,
+        )
+    }
+
+    /// Returns the received first and second tokens
+    #[endpoint(removeLiquidity)]
+    fn remove_liquidity_endpoint(
+        &self,
+        pair_address: ManagedAddress,
+        lp_token_amount: BigUint,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> RemoveLiquidityResultType<Self::Api> {
+        self.require_caller_has_admin_permissions();
+        self.require_whitelisted_pair(&pair_address);
+
+        let lp_token_id = self.get_pair_lp_token_id_mapper(pair_address.clone()).get();
+        let lp_tokens = EsdtTokenPayment::new(lp_token_id, 0, lp_token_amount);
+        let remove_liq_result: RemoveLiquidityResultType<Self::Api> = self
+            .treasury_pair_proxy(pair_address.clone())
+            .remove_liquidity(first_token_amount_min, second_token_amount_min)
+            .with_esdt_transfer(lp_tokens.clone())
+            .execute_on_dest_context();
+
+        let (first_tokens, second_tokens) = remove_liq_result.clone().into_tuple();
+        let caller = self.blockchain().get_caller();
+        self.remove_liquidity_event(
+            caller,
+            pair_address,
+            self.build_liquidity_event(first_tokens, second_tokens, lp_tokens),
+        );
+
+        remove_liq_result
+    }
+
+    fn add_liquidity_to_pair(
+        &self,
+        pair_address: ManagedAddress,
+        first_tokens: EsdtTokenPayment,
+        second_tokens: EsdtTokenPayment,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> AddLiquidityResultType<Self::Api> {
+        let mut payments = ManagedVec::new();
+        payments.push(first_tokens);
+        payments.push(second_tokens);
+
+        let add_liq_result: AddLiquidityResultType<Self::Api> = self
+            .treasury_pair_proxy(pair_address.clone())
+            .add_liquidity(first_token_amount_min, second_token_amount_min)
+            .with_multi_token_transfer(payments)
+            .execute_on_dest_context();
+
+        let (lp_tokens, first_tokens_used, second_tokens_used) =
+            add_liq_result.clone().into_tuple();
+        let caller = self.blockchain().get_caller();
+        self.add_liquidity_event(
+            caller,
+            pair_address,
+            self.build_liquidity_event(first_tokens_used, second_tokens_used, lp_tokens),
+        );
+
+        add_liq_result
+    }
+
+    #[proxy]
+    fn treasury_pair_proxy(&self, sc_address: ManagedAddress) -> pair::Proxy<Self::Api>;
+}
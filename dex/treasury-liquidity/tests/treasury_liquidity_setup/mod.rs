@@ -0,0 +1,285 @@
+#![allow(deprecated)]
+
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    storage::mappers::StorageTokenWrapper,
+    types::{Address, EsdtLocalRole, ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint,
+    whitebox_legacy::{TxResult, TxTokenTransfer, *},
+    DebugApi,
+};
+
+use ::config::ConfigModule as FarmConfigModule;
+use farm::Farm;
+use farm_boosted_yields::boosted_yields_factors::BoostedYieldsFactorsModule;
+use farm_token::FarmTokenModule;
+use pair::{
+    config::ConfigModule as PairConfigModule, pair_actions::add_liq::AddLiquidityModule, Pair,
+};
+use pausable::{PausableModule, State};
+use router::{config::ConfigModule as RouterConfigModule, factory::PairTokens, Router};
+use treasury_liquidity::{
+    config::ConfigModule, farm_actions::FarmActionsModule,
+    liquidity_actions::LiquidityActionsModule, TreasuryLiquidity,
+};
+
+pub const MEX_TOKEN_ID: &[u8] = b"MEX-abcdef";
+pub const WEGLD_TOKEN_ID: &[u8] = b"WEGLD-abcdef";
+pub const LP_TOKEN_ID: &[u8] = b"LPTOK-abcdef";
+pub const FARM_TOKEN_ID: &[u8] = b"FARM-abcdef";
+pub const LOCKED_TOKEN_ID: &[u8] = b"XMEX-abcdef";
+pub const LOCKED_TOKEN_NONCE: u64 = 3;
+
+pub const DIVISION_SAFETY_CONSTANT: u64 = 1_000_000_000_000;
+pub const PER_BLOCK_REWARD_AMOUNT: u64 = 5_000;
+pub const POOL_LIQUIDITY: u64 = 100_000_000;
+pub const TREASURY_BALANCE: u64 = 10_000_000;
+
+pub struct TreasuryLiquiditySetup<
+    TreasuryObjBuilder,
+    RouterObjBuilder,
+    PairObjBuilder,
+    FarmObjBuilder,
+> where
+    TreasuryObjBuilder: 'static + Copy + Fn() -> treasury_liquidity::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+    FarmObjBuilder: 'static + Copy + Fn() -> farm::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub governance_address: Address,
+    pub treasury_wrapper:
+        ContractObjWrapper<treasury_liquidity::ContractObj<DebugApi>, TreasuryObjBuilder>,
+    pub router_wrapper: ContractObjWrapper<router::ContractObj<DebugApi>, RouterObjBuilder>,
+    pub pair_wrapper: ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+    pub farm_wrapper: ContractObjWrapper<farm::ContractObj<DebugApi>, FarmObjBuilder>,
+}
+
+impl<TreasuryObjBuilder, RouterObjBuilder, PairObjBuilder, FarmObjBuilder>
+    TreasuryLiquiditySetup<TreasuryObjBuilder, RouterObjBuilder, PairObjBuilder, FarmObjBuilder>
+where
+    TreasuryObjBuilder: 'static + Copy + Fn() -> treasury_liquidity::ContractObj<DebugApi>,
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+    FarmObjBuilder: 'static + Copy + Fn() -> farm::ContractObj<DebugApi>,
+{
+    pub fn new(
+        treasury_builder: TreasuryObjBuilder,
+        router_builder: RouterObjBuilder,
+        pair_builder: PairObjBuilder,
+        farm_builder: FarmObjBuilder,
+    ) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let governance_address = b_mock.create_user_account(&rust_zero);
+
+        let router_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            router_builder,
+            "router path",
+        );
+        let pair_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(router_wrapper.address_ref()),
+            pair_builder,
+            "pair path",
+        );
+        let farm_wrapper =
+            b_mock.create_sc_account(&rust_zero, Some(&owner_address), farm_builder, "farm path");
+        let treasury_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            treasury_builder,
+            "treasury path",
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &pair_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_address!(router_wrapper.address_ref()),
+                    managed_address!(&owner_address),
+                    300,
+                    50,
+                    ManagedAddress::zero(),
+                    MultiValueEncoded::new(),
+                );
+                sc.lp_token_identifier()
+                    .set(&managed_token_id!(LP_TOKEN_ID));
+                sc.state().set(State::Active);
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            pair_wrapper.address_ref(),
+            LP_TOKEN_ID,
+            &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &router_wrapper, &rust_zero, |sc| {
+                sc.init(OptionalValue::None);
+                sc.pair_map().insert(
+                    PairTokens {
+                        first_token_id: managed_token_id!(WEGLD_TOKEN_ID),
+                        second_token_id: managed_token_id!(MEX_TOKEN_ID),
+                    },
+                    managed_address!(pair_wrapper.address_ref()),
+                );
+            })
+            .assert_ok();
+
+        b_mock
+            .execute_tx(&owner_address, &farm_wrapper, &rust_zero, |sc| {
+                sc.init(
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_token_id!(LP_TOKEN_ID),
+                    managed_biguint!(DIVISION_SAFETY_CONSTANT),
+                    ManagedAddress::zero(),
+                    ManagedAddress::zero(),
+                    MultiValueEncoded::new(),
+                );
+                sc.farm_token()
+                    .set_token_id(managed_token_id!(FARM_TOKEN_ID));
+                sc.per_block_reward_amount()
+                    .set(&managed_biguint!(PER_BLOCK_REWARD_AMOUNT));
+                sc.state().set(State::Active);
+                sc.produce_rewards_enabled().set(true);
+
+                sc.set_boosted_yields_factors(
+                    managed_biguint!(10),
+                    managed_biguint!(3),
+                    managed_biguint!(2),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+            })
+            .assert_ok();
+        b_mock.set_esdt_local_roles(
+            farm_wrapper.address_ref(),
+            FARM_TOKEN_ID,
+            &[
+                EsdtLocalRole::NftCreate,
+                EsdtLocalRole::NftAddQuantity,
+                EsdtLocalRole::NftBurn,
+            ],
+        );
+        b_mock.set_esdt_local_roles(
+            farm_wrapper.address_ref(),
+            LP_TOKEN_ID,
+            &[EsdtLocalRole::Burn],
+        );
+        b_mock.set_esdt_local_roles(
+            farm_wrapper.address_ref(),
+            MEX_TOKEN_ID,
+            &[EsdtLocalRole::Mint],
+        );
+
+        b_mock
+            .execute_tx(&owner_address, &treasury_wrapper, &rust_zero, |sc| {
+                let mut admins = MultiValueEncoded::new();
+                admins.push(managed_address!(&governance_address));
+
+                sc.init(managed_address!(router_wrapper.address_ref()), admins);
+            })
+            .assert_ok();
+
+        // initial pool liquidity, provided by the owner
+        b_mock.set_esdt_balance(
+            &owner_address,
+            WEGLD_TOKEN_ID,
+            &rust_biguint!(POOL_LIQUIDITY),
+        );
+        b_mock.set_esdt_balance(&owner_address, MEX_TOKEN_ID, &rust_biguint!(POOL_LIQUIDITY));
+        let payments = vec![
+            TxTokenTransfer {
+                token_identifier: WEGLD_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_LIQUIDITY),
+            },
+            TxTokenTransfer {
+                token_identifier: MEX_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(POOL_LIQUIDITY),
+            },
+        ];
+        b_mock
+            .execute_esdt_multi_transfer(&owner_address, &pair_wrapper, &payments, |sc| {
+                let _ = sc.add_liquidity(managed_biguint!(1), managed_biguint!(1));
+            })
+            .assert_ok();
+
+        b_mock.set_esdt_balance(
+            treasury_wrapper.address_ref(),
+            WEGLD_TOKEN_ID,
+            &rust_biguint!(TREASURY_BALANCE),
+        );
+        b_mock.set_esdt_balance(
+            treasury_wrapper.address_ref(),
+            MEX_TOKEN_ID,
+            &rust_biguint!(TREASURY_BALANCE),
+        );
+
+        TreasuryLiquiditySetup {
+            b_mock,
+            owner_address,
+            governance_address,
+            treasury_wrapper,
+            router_wrapper,
+            pair_wrapper,
+            farm_wrapper,
+        }
+    }
+
+    pub fn whitelist_pair_and_farm(&mut self) {
+        let pair_address = self.pair_wrapper.address_ref().clone();
+        let farm_address = self.farm_wrapper.address_ref().clone();
+        self.b_mock
+            .execute_tx(
+                &self.owner_address,
+                &self.treasury_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut pairs = MultiValueEncoded::new();
+                    pairs.push(managed_address!(&pair_address));
+                    sc.add_pairs(pairs);
+
+                    sc.add_farm(
+                        managed_address!(&farm_address),
+                        managed_address!(&pair_address),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn add_liquidity(&mut self, caller: &Address, amount: u64) -> TxResult {
+        let pair_address = self.pair_wrapper.address_ref().clone();
+        self.b_mock
+            .execute_tx(caller, &self.treasury_wrapper, &rust_biguint!(0), |sc| {
+                let _ = sc.add_liquidity_endpoint(
+                    managed_address!(&pair_address),
+                    managed_biguint!(amount),
+                    managed_biguint!(amount),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+            })
+    }
+
+    pub fn enter_farm(&mut self, caller: &Address, lp_token_amount: u64) -> TxResult {
+        let farm_address = self.farm_wrapper.address_ref().clone();
+        self.b_mock
+            .execute_tx(caller, &self.treasury_wrapper, &rust_biguint!(0), |sc| {
+                let _ = sc.enter_farm_endpoint(
+                    managed_address!(&farm_address),
+                    managed_biguint!(lp_token_amount),
+                );
+            })
+    }
+}
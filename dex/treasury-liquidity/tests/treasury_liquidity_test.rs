@@ -0,0 +1,452 @@
+#![allow(deprecated)]
+
+mod treasury_liquidity_setup;
+
+use multiversx_sc::{
+    codec::Empty,
+    types::{ManagedAddress, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id, rust_biguint};
+use treasury_liquidity::{
+    config::ConfigModule, farm_actions::FarmActionsModule,
+    liquidity_actions::LiquidityActionsModule, TreasuryLiquidity,
+};
+use treasury_liquidity_setup::*;
+
+#[test]
+fn treasury_liquidity_setup_test() {
+    let _ = TreasuryLiquiditySetup::new(
+        treasury_liquidity::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+        farm::contract_obj,
+    );
+}
+
+#[test]
+fn treasury_whitelist_test() {
+    let mut setup = TreasuryLiquiditySetup::new(
+        treasury_liquidity::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+        farm::contract_obj,
+    );
+
+    let pair_address = setup.pair_wrapper.address_ref().clone();
+    let farm_address = setup.farm_wrapper.address_ref().clone();
+    let governance_address = setup.governance_address.clone();
+
+    // only the owner can whitelist pairs
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut pairs = MultiValueEncoded::new();
+                pairs.push(managed_address!(&pair_address));
+                sc.add_pairs(pairs);
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    // pairs unknown to the router are rejected
+    let other_pair_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&setup.owner_address),
+        pair::contract_obj,
+        "other pair path",
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner_address,
+            &other_pair_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                use pair::Pair;
+
+                sc.init(
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_token_id!(MEX_TOKEN_ID),
+                    managed_address!(setup.router_wrapper.address_ref()),
+                    managed_address!(&setup.owner_address),
+                    300,
+                    50,
+                    ManagedAddress::zero(),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_ok();
+    let other_pair_address = other_pair_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut pairs = MultiValueEncoded::new();
+                pairs.push(managed_address!(&other_pair_address));
+                sc.add_pairs(pairs);
+            },
+        )
+        .assert_user_error("Not a router pair");
+
+    // the farm pair must be whitelisted first
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_farm(
+                    managed_address!(&farm_address),
+                    managed_address!(&pair_address),
+                );
+            },
+        )
+        .assert_user_error("Pair not whitelisted");
+
+    setup.whitelist_pair_and_farm();
+
+    setup
+        .b_mock
+        .execute_query(&setup.treasury_wrapper, |sc| {
+            assert!(sc
+                .whitelisted_pairs()
+                .contains(&managed_address!(&pair_address)));
+            assert_eq!(
+                sc.whitelisted_farms().get(&managed_address!(&farm_address)),
+                Some(managed_address!(&pair_address))
+            );
+        })
+        .assert_ok();
+
+    // liquidity can only be added to whitelisted pairs
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.add_liquidity_endpoint(
+                    managed_address!(&other_pair_address),
+                    managed_biguint!(1_000),
+                    managed_biguint!(1_000),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+            },
+        )
+        .assert_user_error("Pair not whitelisted");
+}
+
+#[test]
+fn treasury_liquidity_and_farm_test() {
+    let mut setup = TreasuryLiquiditySetup::new(
+        treasury_liquidity::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+        farm::contract_obj,
+    );
+    setup.whitelist_pair_and_farm();
+
+    let owner_address = setup.owner_address.clone();
+    let governance_address = setup.governance_address.clone();
+    let treasury_address = setup.treasury_wrapper.address_ref().clone();
+    let pair_address = setup.pair_wrapper.address_ref().clone();
+    let farm_address = setup.farm_wrapper.address_ref().clone();
+
+    // only admins can manage the treasury funds
+    setup
+        .add_liquidity(&owner_address, 1_000_000)
+        .assert_user_error("Permission denied");
+
+    setup
+        .add_liquidity(&governance_address, 1_000_000)
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &treasury_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(TREASURY_BALANCE - 1_000_000),
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&treasury_address, LP_TOKEN_ID, &rust_biguint!(1_000_000));
+
+    setup.enter_farm(&governance_address, 600_000).assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&treasury_address, LP_TOKEN_ID, &rust_biguint!(400_000));
+
+    setup
+        .b_mock
+        .execute_query(&setup.treasury_wrapper, |sc| {
+            let farm_position = sc.farm_position(&managed_address!(&farm_address)).get();
+            assert_eq!(
+                farm_position.token_identifier,
+                managed_token_id!(FARM_TOKEN_ID)
+            );
+            assert_eq!(farm_position.amount, managed_biguint!(600_000));
+        })
+        .assert_ok();
+
+    // the existing position is merged
+    setup.enter_farm(&governance_address, 400_000).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.treasury_wrapper, |sc| {
+            let farm_position = sc.farm_position(&managed_address!(&farm_address)).get();
+            assert_eq!(farm_position.amount, managed_biguint!(1_000_000));
+        })
+        .assert_ok();
+
+    // claim rewards
+    setup.b_mock.set_block_nonce(10);
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let rewards = sc.claim_farm_rewards_endpoint(managed_address!(&farm_address));
+                assert_eq!(rewards.token_identifier, managed_token_id!(MEX_TOKEN_ID));
+                assert!(rewards.amount > 0);
+            },
+        )
+        .assert_ok();
+
+    // compound rewards
+    setup.b_mock.set_block_nonce(20);
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let farm_position =
+                    sc.compound_farm_rewards(managed_address!(&farm_address), managed_biguint!(1));
+                assert!(farm_position.amount > 1_000_000);
+            },
+        )
+        .assert_ok();
+
+    // partial exit, after the minimum farming epochs
+    setup.b_mock.set_block_epoch(10);
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (lp_tokens, _) = sc
+                    .exit_farm_endpoint(managed_address!(&farm_address), managed_biguint!(500_000))
+                    .into_tuple();
+                assert_eq!(lp_tokens.amount, managed_biguint!(500_000));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&treasury_address, LP_TOKEN_ID, &rust_biguint!(500_000));
+
+    // the farm cannot be removed while the treasury has a position
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_farm(managed_address!(&farm_address));
+            },
+        )
+        .assert_user_error("Must exit the farm first");
+
+    // remove liquidity
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (first_tokens, second_tokens) = sc
+                    .remove_liquidity_endpoint(
+                        managed_address!(&pair_address),
+                        managed_biguint!(500_000),
+                        managed_biguint!(1),
+                        managed_biguint!(1),
+                    )
+                    .into_tuple();
+                assert_eq!(
+                    first_tokens.token_identifier,
+                    managed_token_id!(WEGLD_TOKEN_ID)
+                );
+                assert_eq!(
+                    second_tokens.token_identifier,
+                    managed_token_id!(MEX_TOKEN_ID)
+                );
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&treasury_address, LP_TOKEN_ID, &rust_biguint!(0));
+}
+
+#[test]
+fn treasury_deposit_and_withdraw_test() {
+    let mut setup = TreasuryLiquiditySetup::new(
+        treasury_liquidity::contract_obj,
+        router::contract_obj,
+        pair::contract_obj,
+        farm::contract_obj,
+    );
+
+    let owner_address = setup.owner_address.clone();
+    let governance_address = setup.governance_address.clone();
+    let treasury_address = setup.treasury_wrapper.address_ref().clone();
+
+    // anyone can deposit
+    setup
+        .b_mock
+        .set_esdt_balance(&owner_address, WEGLD_TOKEN_ID, &rust_biguint!(1_000));
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &owner_address,
+            &setup.treasury_wrapper,
+            WEGLD_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.deposit();
+            },
+        )
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &treasury_address,
+        WEGLD_TOKEN_ID,
+        &rust_biguint!(TREASURY_BALANCE + 1_000),
+    );
+
+    // only admins can withdraw
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw(
+                    managed_address!(&owner_address),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    0,
+                    managed_biguint!(1_000),
+                );
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw(
+                    managed_address!(&owner_address),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    0,
+                    managed_biguint!(TREASURY_BALANCE + 1_001),
+                );
+            },
+        )
+        .assert_user_error("Not enough funds");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw(
+                    managed_address!(&owner_address),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    0,
+                    managed_biguint!(1_000),
+                );
+
+                let balance = sc.get_token_balance(managed_token_id!(WEGLD_TOKEN_ID), 0);
+                assert_eq!(balance, managed_biguint!(TREASURY_BALANCE));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .check_esdt_balance(&owner_address, WEGLD_TOKEN_ID, &rust_biguint!(1_000));
+
+    // tokens with a nonce, e.g. the locked rewards
+    setup.b_mock.set_nft_balance(
+        &treasury_address,
+        LOCKED_TOKEN_ID,
+        LOCKED_TOKEN_NONCE,
+        &rust_biguint!(500),
+        &Empty,
+    );
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw(
+                    managed_address!(&owner_address),
+                    managed_token_id!(LOCKED_TOKEN_ID),
+                    LOCKED_TOKEN_NONCE + 1,
+                    managed_biguint!(500),
+                );
+            },
+        )
+        .assert_user_error("Not enough funds");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &governance_address,
+            &setup.treasury_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.withdraw(
+                    managed_address!(&owner_address),
+                    managed_token_id!(LOCKED_TOKEN_ID),
+                    LOCKED_TOKEN_NONCE,
+                    managed_biguint!(500),
+                );
+
+                let balance =
+                    sc.get_token_balance(managed_token_id!(LOCKED_TOKEN_ID), LOCKED_TOKEN_NONCE);
+                assert_eq!(balance, managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+    setup.b_mock.check_nft_balance::<Empty>(
+        &owner_address,
+        LOCKED_TOKEN_ID,
+        LOCKED_TOKEN_NONCE,
+        &rust_biguint!(500),
+        None,
+    );
+}
@@ -0,0 +1,48 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           22
+// Async Callback (empty):               1
+// Total number of exported functions:  25
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    treasury_liquidity
+    (
+        init => init
+        upgrade => upgrade
+        deposit => deposit
+        withdraw => withdraw
+        getTokenBalance => get_token_balance
+        setRouterAddress => set_router_address
+        addPairs => add_pairs
+        removePairs => remove_pairs
+        addFarm => add_farm
+        removeFarm => remove_farm
+        getWhitelistedFarms => get_whitelisted_farms
+        getRouterAddress => router_address
+        getWhitelistedPairs => whitelisted_pairs
+        getFarmPosition => farm_position
+        addLiquidity => add_liquidity_endpoint
+        removeLiquidity => remove_liquidity_endpoint
+        enterFarm => enter_farm_endpoint
+        exitFarm => exit_farm_endpoint
+        claimFarmRewards => claim_farm_rewards_endpoint
+        compoundFarmRewards => compound_farm_rewards
+        addAdmin => add_admin_endpoint
+        removeAdmin => remove_admin_endpoint
+        updateOwnerOrAdmin => update_owner_or_admin_endpoint
+        getPermissions => permissions
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
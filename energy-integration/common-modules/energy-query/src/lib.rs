@@ -3,9 +3,13 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-pub use energy_factory::energy::Energy;
+pub use energy_factory::energy::{Energy, EnergySnapshot};
 
 static USER_ENERGY_STORAGE_KEY: &[u8] = b"userEnergy";
+static USER_ENERGY_SNAPSHOTS_STORAGE_KEY: &[u8] = b"userEnergySnapshots";
+static USER_ENERGY_SNAPSHOTS_FIRST_INDEX_STORAGE_KEY: &[u8] = b"userEnergySnapshotsFirstIndex";
+static VEC_MAPPER_LEN_SUFFIX: &[u8] = b".len";
+static VEC_MAPPER_ITEM_SUFFIX: &[u8] = b".item";
 static LOCKED_TOKEN_ID_STORAGE_KEY: &[u8] = b"lockedTokenId";
 static BASE_TOKEN_ID_STORAGE_KEY: &[u8] = b"baseAssetTokenId";
 
@@ -52,6 +56,89 @@ pub trait EnergyQueryModule {
         }
     }
 
+    /// The user's energy at the end of the given epoch, based on the energy factory's snapshots.
+    /// Users without snapshots only have their current entry taken into account,
+    /// and only if it was last updated at or before the given epoch.
+    fn get_energy_entry_at_epoch(&self, user: &ManagedAddress, epoch: u64) -> Energy<Self::Api> {
+        if self.energy_factory_address().is_empty() {
+            return Energy::new_zero_energy(epoch);
+        }
+
+        let snapshots_len: usize = self.read_raw_storage_from_energy_factory(
+            self.get_energy_snapshots_key(user, VEC_MAPPER_LEN_SUFFIX),
+        );
+        if snapshots_len == 0 {
+            let energy_buffer: ManagedBuffer = self.read_energy_from_factory(user);
+            if energy_buffer.is_empty() {
+                return Energy::new_zero_energy(epoch);
+            }
+
+            let mut user_energy: Energy<Self::Api> = Energy::top_decode(energy_buffer)
+                .unwrap_or_else(|_| sc_panic!("Failed decoding result from energy factory"));
+            if user_energy.get_last_update_epoch() > epoch {
+                return Energy::new_zero_energy(epoch);
+            }
+
+            user_energy.deplete(epoch);
+
+            return user_energy;
+        }
+
+        // older snapshots are pruned by the energy factory
+        let mut first_index_key =
+            ManagedBuffer::new_from_bytes(USER_ENERGY_SNAPSHOTS_FIRST_INDEX_STORAGE_KEY);
+        first_index_key.append(user.as_managed_buffer());
+        let first_index: usize = self.read_raw_storage_from_energy_factory(first_index_key);
+
+        let mut low = core::cmp::max(first_index, 1);
+        let mut high = snapshots_len;
+        let mut opt_found_snapshot = None;
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let snapshot = self.read_energy_snapshot_from_factory(user, mid);
+            if snapshot.epoch <= epoch {
+                opt_found_snapshot = Some(snapshot);
+                low = mid + 1;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        match opt_found_snapshot {
+            Some(snapshot) => {
+                let mut user_energy = snapshot.energy;
+                user_energy.deplete(epoch);
+
+                user_energy
+            }
+            None => Energy::new_zero_energy(epoch),
+        }
+    }
+
+    fn get_energy_amount_at_epoch(&self, user: &ManagedAddress, epoch: u64) -> BigUint {
+        let user_energy = self.get_energy_entry_at_epoch(user, epoch);
+        user_energy.get_energy_amount()
+    }
+
+    fn read_energy_snapshot_from_factory(
+        &self,
+        user: &ManagedAddress,
+        index: usize,
+    ) -> EnergySnapshot<Self::Api> {
+        let mut key_buffer = self.get_energy_snapshots_key(user, VEC_MAPPER_ITEM_SUFFIX);
+        key_buffer.append_bytes(&(index as u32).to_be_bytes());
+
+        self.read_raw_storage_from_energy_factory(key_buffer)
+    }
+
+    fn get_energy_snapshots_key(&self, user: &ManagedAddress, suffix: &[u8]) -> ManagedBuffer {
+        let mut key_buffer = ManagedBuffer::new_from_bytes(USER_ENERGY_SNAPSHOTS_STORAGE_KEY);
+        key_buffer.append(user.as_managed_buffer());
+        key_buffer.append_bytes(suffix);
+
+        key_buffer
+    }
+
     fn get_base_token_id(&self) -> TokenIdentifier {
         self.read_raw_storage_from_energy_factory(ManagedBuffer::new_from_bytes(
             BASE_TOKEN_ID_STORAGE_KEY,
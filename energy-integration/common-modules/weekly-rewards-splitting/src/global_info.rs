@@ -80,9 +80,10 @@ pub trait WeeklyRewardsGlobalInfo:
 
         // clear entries that are not accessible anymore
         // users can claim only for weeks of
-        // (current_week - 1), (current_week - 2), ... (current_week - USER_MAX_CLAIM_WEEKS)
-        if current_week > USER_MAX_CLAIM_WEEKS + 1 {
-            let inaccessible_week = current_week - USER_MAX_CLAIM_WEEKS - 1;
+        // (current_week - 1), (current_week - 2), ... (current_week - user_max_claim_weeks)
+        let user_max_claim_weeks = self.get_user_max_claim_weeks(current_week);
+        if current_week > user_max_claim_weeks + 1 {
+            let inaccessible_week = current_week - user_max_claim_weeks - 1;
             self.total_rewards_for_week(inaccessible_week).clear();
             self.total_energy_for_week(inaccessible_week).clear();
         }
@@ -141,6 +142,67 @@ pub trait WeeklyRewardsGlobalInfo:
             })
     }
 
+    /// Weeks outside the previous horizon were already cleared,
+    /// so a raised horizon only grows by one week for each week after the change
+    fn get_user_max_claim_weeks(&self, current_week: Week) -> usize {
+        let mapper = self.user_max_claim_weeks();
+        if mapper.is_empty() {
+            return USER_MAX_CLAIM_WEEKS;
+        }
+
+        let user_max_claim_weeks = mapper.get();
+        let change_week = self.user_max_claim_weeks_change_week().get();
+        if current_week <= change_week {
+            return core::cmp::min(
+                user_max_claim_weeks,
+                self.previous_user_max_claim_weeks().get(),
+            );
+        }
+
+        let weeks_since_change = current_week - change_week;
+        core::cmp::min(
+            user_max_claim_weeks,
+            self.previous_user_max_claim_weeks().get() + weeks_since_change,
+        )
+    }
+
+    /// The weekly update only clears the week that just left the horizon,
+    /// so lowering the horizon clears the weeks between the previous and the new horizon
+    fn set_user_max_claim_weeks_from_week(&self, user_max_claim_weeks: usize, current_week: Week) {
+        self.perform_weekly_update(current_week);
+
+        let previous_user_max_claim_weeks = self.get_user_max_claim_weeks(current_week);
+        self.previous_user_max_claim_weeks()
+            .set(previous_user_max_claim_weeks);
+        self.user_max_claim_weeks_change_week().set(current_week);
+        self.user_max_claim_weeks().set(user_max_claim_weeks);
+
+        if user_max_claim_weeks >= previous_user_max_claim_weeks
+            || current_week <= user_max_claim_weeks + 1
+        {
+            return;
+        }
+
+        let first_inaccessible_week = core::cmp::max(
+            current_week.saturating_sub(previous_user_max_claim_weeks + 1),
+            1,
+        );
+        let last_inaccessible_week = current_week - user_max_claim_weeks - 1;
+        for week in first_inaccessible_week..=last_inaccessible_week {
+            self.total_rewards_for_week(week).clear();
+            self.total_energy_for_week(week).clear();
+        }
+    }
+
+    #[storage_mapper("userMaxClaimWeeks")]
+    fn user_max_claim_weeks(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("previousUserMaxClaimWeeks")]
+    fn previous_user_max_claim_weeks(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("userMaxClaimWeeksChangeWeek")]
+    fn user_max_claim_weeks_change_week(&self) -> SingleValueMapper<Week>;
+
     #[view(getLastGlobalUpdateWeek)]
     #[storage_mapper("lastGlobalUpdateWeek")]
     fn last_global_update_week(&self) -> SingleValueMapper<Week>;
@@ -84,13 +84,7 @@ pub trait WeeklyRewardsSplittingModule:
 
         let mut all_rewards = ManagedVec::new();
 
-        let total_weeks_to_claim = current_week - claim_progress.week;
-        if total_weeks_to_claim > USER_MAX_CLAIM_WEEKS {
-            let extra_weeks = total_weeks_to_claim - USER_MAX_CLAIM_WEEKS;
-            claim_progress.advance_multiple_weeks(extra_weeks);
-        }
-
-        let weeks_to_claim = core::cmp::min(total_weeks_to_claim, USER_MAX_CLAIM_WEEKS);
+        let weeks_to_claim = self.skip_unclaimable_weeks(current_week, &mut claim_progress);
         for _ in 0..weeks_to_claim {
             let rewards_for_week = self.claim_single(wrapper, &mut claim_progress);
             if !rewards_for_week.is_empty() {
@@ -117,6 +111,68 @@ pub trait WeeklyRewardsSplittingModule:
         all_rewards
     }
 
+    /// Claims at most `max_weeks` weeks, persisting the progress, so users with many
+    /// unclaimed weeks can catch up over multiple transactions.
+    /// Once the remaining weeks fit in `max_weeks`, this is the same as `claim_multi`.
+    fn claim_multi_paginated<
+        WRSM: WeeklyRewardsSplittingTraitsModule<WeeklyRewardsSplittingMod = Self>,
+    >(
+        &self,
+        wrapper: &WRSM,
+        user: &ManagedAddress,
+        max_weeks: usize,
+    ) -> PaymentsVec<Self::Api> {
+        require!(max_weeks > 0, "Invalid number of weeks");
+
+        let claim_progress_mapper = wrapper.get_claim_progress_mapper(self, user);
+        if claim_progress_mapper.is_empty() {
+            return self.claim_multi(wrapper, user);
+        }
+
+        let current_week = self.get_current_week();
+        let mut claim_progress = claim_progress_mapper.get();
+        let weeks_to_claim = self.skip_unclaimable_weeks(current_week, &mut claim_progress);
+        if weeks_to_claim <= max_weeks {
+            return self.claim_multi(wrapper, user);
+        }
+
+        let mut all_rewards = ManagedVec::new();
+        for _ in 0..max_weeks {
+            let rewards_for_week = self.claim_single(wrapper, &mut claim_progress);
+            if !rewards_for_week.is_empty() {
+                all_rewards.append_vec(rewards_for_week);
+            }
+        }
+
+        claim_progress_mapper.set(&claim_progress);
+
+        self.emit_claim_multi_event(
+            user,
+            claim_progress.week,
+            &claim_progress.energy,
+            &all_rewards,
+        );
+
+        all_rewards
+    }
+
+    /// Advances the claim progress past the weeks that are outside the claim horizon,
+    /// and returns the number of weeks that can still be claimed
+    fn skip_unclaimable_weeks(
+        &self,
+        current_week: Week,
+        claim_progress: &mut ClaimProgress<Self::Api>,
+    ) -> usize {
+        let user_max_claim_weeks = self.get_user_max_claim_weeks(current_week);
+        let total_weeks_to_claim = current_week - claim_progress.week;
+        if total_weeks_to_claim > user_max_claim_weeks {
+            let extra_weeks = total_weeks_to_claim - user_max_claim_weeks;
+            claim_progress.advance_multiple_weeks(extra_weeks);
+        }
+
+        core::cmp::min(total_weeks_to_claim, user_max_claim_weeks)
+    }
+
     fn claim_single<WRSM: WeeklyRewardsSplittingTraitsModule<WeeklyRewardsSplittingMod = Self>>(
         &self,
         wrapper: &WRSM,
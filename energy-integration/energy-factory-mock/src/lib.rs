@@ -2,7 +2,7 @@
 
 multiversx_sc::imports!();
 
-use energy_query::Energy;
+use energy_query::{Energy, EnergySnapshot};
 
 #[multiversx_sc::contract]
 pub trait EnergyFactoryMock {
@@ -17,11 +17,36 @@ pub trait EnergyFactoryMock {
         total_locked_tokens: BigUint,
     ) {
         let current_epoch = self.blockchain().get_block_epoch();
-        self.user_energy(&user).set(&Energy::new(
+        let energy = Energy::new(
             BigInt::from(energy_amount),
             current_epoch,
             total_locked_tokens,
-        ));
+        );
+
+        let mut snapshots_mapper = self.user_energy_snapshots(&user);
+        if snapshots_mapper.is_empty() && !self.user_energy(&user).is_empty() {
+            let prev_energy = self.user_energy(&user).get();
+            let prev_epoch = prev_energy.get_last_update_epoch();
+            if prev_epoch < current_epoch {
+                let _ = snapshots_mapper.push(&EnergySnapshot {
+                    epoch: prev_epoch,
+                    energy: prev_energy,
+                });
+            }
+        }
+
+        let snapshot = EnergySnapshot {
+            epoch: current_epoch,
+            energy: energy.clone(),
+        };
+        let snapshots_len = snapshots_mapper.len();
+        if snapshots_len > 0 && snapshots_mapper.get(snapshots_len).epoch == current_epoch {
+            snapshots_mapper.set(snapshots_len, &snapshot);
+        } else {
+            let _ = snapshots_mapper.push(&snapshot);
+        }
+
+        self.user_energy(&user).set(&energy);
     }
 
     #[view(getEnergyAmountForUser)]
@@ -55,6 +80,9 @@ pub trait EnergyFactoryMock {
     #[storage_mapper("userEnergy")]
     fn user_energy(&self, user: &ManagedAddress) -> SingleValueMapper<Energy<Self::Api>>;
 
+    #[storage_mapper("userEnergySnapshots")]
+    fn user_energy_snapshots(&self, user: &ManagedAddress) -> VecMapper<EnergySnapshot<Self::Api>>;
+
     #[storage_mapper("lockedTokenId")]
     fn locked_token(&self) -> NonFungibleTokenMapper;
 }
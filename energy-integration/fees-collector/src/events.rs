@@ -13,6 +13,15 @@ pub trait FeesCollectorEventsModule {
         self.deposit_swap_fees_event(caller, current_week, payment);
     }
 
+    fn emit_convert_fees_event(
+        self,
+        week: Week,
+        input: EsdtTokenPayment<Self::Api>,
+        output: EsdtTokenPayment<Self::Api>,
+    ) {
+        self.convert_fees_event(week, input, output);
+    }
+
     #[event("deposit_swap_fees_event")]
     fn deposit_swap_fees_event(
         &self,
@@ -20,4 +29,12 @@ pub trait FeesCollectorEventsModule {
         #[indexed] current_week: Week,
         #[indexed] payment: EsdtTokenPayment<Self::Api>,
     );
+
+    #[event("convert_fees_event")]
+    fn convert_fees_event(
+        &self,
+        #[indexed] week: Week,
+        #[indexed] input: EsdtTokenPayment<Self::Api>,
+        #[indexed] output: EsdtTokenPayment<Self::Api>,
+    );
 }
@@ -0,0 +1,284 @@
+multiversx_sc::imports!();
+
+use common_types::{PaymentsVec, Week};
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+pub const DEFAULT_MAX_CONVERSION_SLIPPAGE: u64 = 100; // 1%
+
+// Must manually declare, as Pair SC already depends on fees-collector
+// This avoids circular dependency
+pub mod pair_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait PairProxy {
+        #[payable("*")]
+        #[endpoint(swapTokensFixedInput)]
+        fn swap_tokens_fixed_input(
+            &self,
+            token_out: TokenIdentifier,
+            amount_out_min: BigUint,
+        ) -> EsdtTokenPayment;
+
+        #[view(getSafePriceByDefaultOffset)]
+        fn get_safe_price_by_default_offset(
+            &self,
+            pair_address: ManagedAddress,
+            input_payment: EsdtTokenPayment,
+        ) -> EsdtTokenPayment;
+    }
+}
+
+pub mod router_proxy {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::proxy]
+    pub trait RouterProxy {
+        #[view(getPair)]
+        fn get_pair(
+            &self,
+            first_token_id: TokenIdentifier,
+            second_token_id: TokenIdentifier,
+        ) -> ManagedAddress;
+    }
+}
+
+#[multiversx_sc::module]
+pub trait FeesConversionModule:
+    crate::config::ConfigModule
+    + crate::fees_accumulation::FeesAccumulationModule
+    + crate::events::FeesCollectorEventsModule
+    + weekly_rewards_splitting::global_info::WeeklyRewardsGlobalInfo
+    + weekly_rewards_splitting::events::WeeklyRewardsSplittingEventsModule
+    + weekly_rewards_splitting::locked_token_buckets::WeeklyRewardsLockedTokenBucketsModule
+    + week_timekeeping::WeekTimekeepingModule
+{
+    #[only_owner]
+    #[endpoint(setConversionBaseToken)]
+    fn set_conversion_base_token(&self, token_id: TokenIdentifier) {
+        require!(
+            self.known_tokens().contains(&token_id),
+            "Base token must be a known token"
+        );
+        require!(
+            token_id != self.locked_token_id().get(),
+            "Cannot convert into locked token"
+        );
+
+        self.conversion_base_token().set(token_id);
+    }
+
+    #[only_owner]
+    #[endpoint(setConversionRouterAddress)]
+    fn set_conversion_router_address(&self, router_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&router_address),
+            "Invalid SC address"
+        );
+
+        self.conversion_router_address().set(router_address);
+    }
+
+    /// The pair must be the router's pair of the given token and the base token
+    #[only_owner]
+    #[endpoint(setConversionPair)]
+    fn set_conversion_pair(&self, token_id: TokenIdentifier, pair_address: ManagedAddress) {
+        require!(
+            self.known_tokens().contains(&token_id),
+            "Invalid payment token"
+        );
+        require!(
+            self.blockchain().is_smart_contract(&pair_address),
+            "Invalid SC address"
+        );
+
+        let router_mapper = self.conversion_router_address();
+        require!(!router_mapper.is_empty(), "Router address not set");
+
+        let base_token_id = self.get_conversion_base_token_id();
+        let router_pair_address: ManagedAddress = self
+            .conversion_router_proxy(router_mapper.get())
+            .get_pair(token_id.clone(), base_token_id)
+            .execute_on_dest_context();
+        require!(
+            router_pair_address == pair_address,
+            "Not the router pair for the token"
+        );
+
+        self.conversion_pair(&token_id).set(pair_address);
+    }
+
+    #[only_owner]
+    #[endpoint(removeConversionPair)]
+    fn remove_conversion_pair(&self, token_id: TokenIdentifier) {
+        self.conversion_pair(&token_id).clear();
+    }
+
+    /// Maximum accepted deviation from the safe price, where 10_000 = 100%
+    #[only_owner]
+    #[endpoint(setMaxConversionSlippage)]
+    fn set_max_conversion_slippage(&self, max_slippage: u64) {
+        require!(max_slippage < MAX_PERCENTAGE, "Invalid slippage value");
+
+        self.max_conversion_slippage().set(max_slippage);
+    }
+
+    /// Users that enable this receive all their non-locked rewards converted to the base token
+    #[endpoint(setClaimConversion)]
+    fn set_claim_conversion(&self, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+        self.claim_conversion_enabled(&caller).set(enabled);
+    }
+
+    /// Converts the fees accumulated for the given week into the base token.
+    /// Only weeks whose rewards were not yet collected for distribution can be converted.
+    #[only_owner]
+    #[endpoint(convertAccumulatedFees)]
+    fn convert_accumulated_fees(
+        &self,
+        week: Week,
+        tokens: MultiValueEncoded<TokenIdentifier>,
+    ) -> EsdtTokenPayment {
+        let current_week = self.get_current_week();
+        require!(week <= current_week, "Invalid week");
+        require!(
+            self.total_rewards_for_week(week).is_empty(),
+            "Rewards already collected for week"
+        );
+
+        let base_token_id = self.get_conversion_base_token_id();
+        let locked_token_id = self.locked_token_id().get();
+        let mut total_output = BigUint::zero();
+        for token_id in tokens {
+            require!(
+                token_id != base_token_id && token_id != locked_token_id,
+                "Token cannot be converted"
+            );
+
+            let pair_mapper = self.conversion_pair(&token_id);
+            require!(!pair_mapper.is_empty(), "No conversion pair for token");
+
+            let opt_accumulated_fees = self.get_and_clear_accumulated_fees(week, &token_id);
+            let accumulated_fees = match opt_accumulated_fees {
+                Some(fees) => fees,
+                None => continue,
+            };
+
+            let input = EsdtTokenPayment::new(token_id, 0, accumulated_fees);
+            let output = self.swap_with_safe_price_limit(pair_mapper.get(), &base_token_id, &input);
+            require!(output.amount > 0, "Conversion output too low");
+
+            total_output += &output.amount;
+
+            self.emit_convert_fees_event(week, input, output);
+        }
+
+        self.accumulated_fees(week, &base_token_id)
+            .update(|amt| *amt += &total_output);
+
+        EsdtTokenPayment::new(base_token_id, 0, total_output)
+    }
+
+    fn convert_rewards_to_base_token(
+        &self,
+        rewards: PaymentsVec<Self::Api>,
+    ) -> PaymentsVec<Self::Api> {
+        let base_token_id = self.get_conversion_base_token_id();
+        let mut total_base_token_amount = BigUint::zero();
+        let mut results = ManagedVec::new();
+        for reward in &rewards {
+            if reward.token_identifier == base_token_id {
+                total_base_token_amount += reward.amount;
+                continue;
+            }
+
+            let pair_mapper = self.conversion_pair(&reward.token_identifier);
+            if pair_mapper.is_empty() {
+                results.push(reward);
+                continue;
+            }
+
+            let output =
+                self.swap_with_safe_price_limit(pair_mapper.get(), &base_token_id, &reward);
+            if output.amount == 0 {
+                results.push(reward);
+                continue;
+            }
+
+            total_base_token_amount += output.amount;
+        }
+
+        if total_base_token_amount > 0 {
+            results.push(EsdtTokenPayment::new(
+                base_token_id,
+                0,
+                total_base_token_amount,
+            ));
+        }
+
+        results
+    }
+
+    /// Returns a zero-amount payment, without swapping, if the safe price output is too small
+    fn swap_with_safe_price_limit(
+        &self,
+        pair_address: ManagedAddress,
+        token_out: &TokenIdentifier,
+        input: &EsdtTokenPayment,
+    ) -> EsdtTokenPayment {
+        let safe_price_output: EsdtTokenPayment = self
+            .conversion_pair_proxy(pair_address.clone())
+            .get_safe_price_by_default_offset(pair_address.clone(), input.clone())
+            .execute_on_dest_context();
+        require!(
+            &safe_price_output.token_identifier == token_out,
+            "Invalid conversion pair"
+        );
+
+        let max_slippage = self.max_conversion_slippage().get();
+        let amount_out_min =
+            safe_price_output.amount * (MAX_PERCENTAGE - max_slippage) / MAX_PERCENTAGE;
+        if amount_out_min == 0 {
+            return EsdtTokenPayment::new(token_out.clone(), 0, BigUint::zero());
+        }
+
+        self.conversion_pair_proxy(pair_address)
+            .swap_tokens_fixed_input(token_out.clone(), amount_out_min)
+            .with_esdt_transfer(input.clone())
+            .execute_on_dest_context()
+    }
+
+    fn get_conversion_base_token_id(&self) -> TokenIdentifier {
+        let mapper = self.conversion_base_token();
+        require!(!mapper.is_empty(), "Conversion base token not set");
+
+        mapper.get()
+    }
+
+    #[proxy]
+    fn conversion_pair_proxy(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;
+
+    #[proxy]
+    fn conversion_router_proxy(&self, sc_address: ManagedAddress)
+        -> router_proxy::Proxy<Self::Api>;
+
+    #[view(getConversionRouterAddress)]
+    #[storage_mapper("conversionRouterAddress")]
+    fn conversion_router_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getConversionBaseToken)]
+    #[storage_mapper("conversionBaseToken")]
+    fn conversion_base_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getConversionPair)]
+    #[storage_mapper("conversionPair")]
+    fn conversion_pair(&self, token_id: &TokenIdentifier) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getMaxConversionSlippage)]
+    #[storage_mapper("maxConversionSlippage")]
+    fn max_conversion_slippage(&self) -> SingleValueMapper<u64>;
+
+    #[view(isClaimConversionEnabled)]
+    #[storage_mapper("claimConversionEnabled")]
+    fn claim_conversion_enabled(&self, user: &ManagedAddress) -> SingleValueMapper<bool>;
+}
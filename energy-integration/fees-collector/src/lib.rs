@@ -4,12 +4,17 @@ multiversx_sc::imports!();
 
 use common_types::{PaymentsVec, Week};
 use core::marker::PhantomData;
-use weekly_rewards_splitting::base_impl::WeeklyRewardsSplittingTraitsModule;
+use weekly_rewards_splitting::{
+    base_impl::WeeklyRewardsSplittingTraitsModule, USER_MAX_CLAIM_WEEKS,
+};
 
 pub mod additional_locked_tokens;
 pub mod config;
 pub mod events;
 pub mod fees_accumulation;
+pub mod fees_conversion;
+
+pub const MAX_CLAIM_WEEKS_HORIZON: usize = 52;
 
 #[multiversx_sc::contract]
 pub trait FeesCollector:
@@ -21,6 +26,7 @@ pub trait FeesCollector:
     + weekly_rewards_splitting::locked_token_buckets::WeeklyRewardsLockedTokenBucketsModule
     + weekly_rewards_splitting::update_claim_progress_energy::UpdateClaimProgressEnergyModule
     + fees_accumulation::FeesAccumulationModule
+    + fees_conversion::FeesConversionModule
     + additional_locked_tokens::AdditionalLockedTokensModule
     + locking_module::lock_with_energy_module::LockWithEnergyModule
     + energy_query::EnergyQueryModule
@@ -42,10 +48,15 @@ pub trait FeesCollector:
 
         self.locked_token_id().set_if_empty(locked_token_id);
         self.energy_factory_address().set(&energy_factory_address);
+        self.max_conversion_slippage()
+            .set_if_empty(fees_conversion::DEFAULT_MAX_CONVERSION_SLIPPAGE);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.max_conversion_slippage()
+            .set_if_empty(fees_conversion::DEFAULT_MAX_CONVERSION_SLIPPAGE);
+    }
 
     #[endpoint(claimRewards)]
     fn claim_rewards_endpoint(
@@ -57,7 +68,23 @@ pub trait FeesCollector:
         let caller = self.blockchain().get_caller();
         let original_caller = self.get_orig_caller_from_opt(&caller, opt_original_caller);
 
-        self.claim_rewards(caller, original_caller)
+        self.claim_rewards(caller, original_caller, None)
+    }
+
+    /// Claims rewards for at most `max_weeks` weeks.
+    /// Users that were inactive for longer can call this repeatedly to catch up.
+    #[endpoint(claimRewardsPaginated)]
+    fn claim_rewards_paginated_endpoint(
+        &self,
+        max_weeks: usize,
+        opt_original_caller: OptionalValue<ManagedAddress>,
+    ) -> PaymentsVec<Self::Api> {
+        require!(self.not_paused(), "Cannot claim while paused");
+
+        let caller = self.blockchain().get_caller();
+        let original_caller = self.get_orig_caller_from_opt(&caller, opt_original_caller);
+
+        self.claim_rewards(caller, original_caller, Some(max_weeks))
     }
 
     #[endpoint(claimBoostedRewards)]
@@ -78,18 +105,46 @@ pub trait FeesCollector:
             OptionalValue::None => self.blockchain().get_caller(),
         };
 
-        self.claim_rewards(original_caller.clone(), original_caller)
+        self.claim_rewards(original_caller.clone(), original_caller, None)
+    }
+
+    /// Number of past weeks users can claim rewards for. Older rewards are lost.
+    ///
+    /// Raising the horizon does not restore the weeks that were already cleared.
+    /// The horizon grows by one week for each week after the change, until it reaches the new value.
+    /// Lowering it takes effect immediately, and the weeks that are no longer claimable are cleared.
+    #[only_owner]
+    #[endpoint(setUserMaxClaimWeeks)]
+    fn set_user_max_claim_weeks(&self, user_max_claim_weeks: usize) {
+        require!(
+            (USER_MAX_CLAIM_WEEKS..=MAX_CLAIM_WEEKS_HORIZON).contains(&user_max_claim_weeks),
+            "Invalid number of weeks"
+        );
+
+        let current_week = self.get_current_week();
+        self.set_user_max_claim_weeks_from_week(user_max_claim_weeks, current_week);
+    }
+
+    /// The number of past weeks that can be claimed in the current week
+    #[view(getUserMaxClaimWeeks)]
+    fn get_user_max_claim_weeks_view(&self) -> usize {
+        let current_week = self.get_current_week();
+        self.get_user_max_claim_weeks(current_week)
     }
 
     fn claim_rewards(
         &self,
         caller: ManagedAddress,
         original_caller: ManagedAddress,
+        opt_max_weeks: Option<usize>,
     ) -> PaymentsVec<Self::Api> {
         self.accumulate_additional_locked_tokens();
 
         let wrapper = FeesCollectorWrapper::new();
-        let mut rewards = self.claim_multi(&wrapper, &original_caller);
+        let mut rewards = match opt_max_weeks {
+            Some(max_weeks) => self.claim_multi_paginated(&wrapper, &original_caller, max_weeks),
+            None => self.claim_multi(&wrapper, &original_caller),
+        };
         if rewards.is_empty() {
             return rewards;
         }
@@ -110,6 +165,10 @@ pub trait FeesCollector:
             rewards.remove(i);
         }
 
+        if !rewards.is_empty() && self.claim_conversion_enabled(&original_caller).get() {
+            rewards = self.convert_rewards_to_base_token(rewards);
+        }
+
         if !rewards.is_empty() {
             self.send().direct_multi(&caller, &rewards);
         }
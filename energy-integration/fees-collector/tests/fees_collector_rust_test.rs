@@ -5,8 +5,11 @@ mod fees_collector_test_setup;
 use energy_query::Energy;
 use fees_collector::additional_locked_tokens::{AdditionalLockedTokensModule, BLOCKS_IN_WEEK};
 use fees_collector::fees_accumulation::FeesAccumulationModule;
+use fees_collector::fees_conversion::FeesConversionModule;
+use fees_collector::FeesCollector;
 use fees_collector_test_setup::*;
-use multiversx_sc::types::{BigInt, EsdtTokenPayment, ManagedVec};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::types::{BigInt, EsdtTokenPayment, ManagedVec, MultiValueEncoded};
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
     DebugApi,
@@ -1419,3 +1422,344 @@ fn additional_locked_tokens_test() {
         })
         .assert_ok();
 }
+
+#[test]
+fn claim_with_conversion_enabled_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let first_user = fc_setup.b_mock.create_user_account(&rust_zero);
+    fc_setup.set_energy(&first_user, 500, 1_000);
+
+    // locked token cannot be the conversion target
+    fc_setup
+        .b_mock
+        .execute_tx(
+            &fc_setup.owner_address,
+            &fc_setup.fc_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_conversion_base_token(managed_token_id!(LOCKED_TOKEN_ID));
+            },
+        )
+        .assert_user_error("Cannot convert into locked token");
+
+    fc_setup
+        .b_mock
+        .execute_tx(
+            &fc_setup.owner_address,
+            &fc_setup.fc_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_conversion_base_token(managed_token_id!(FIRST_TOKEN_ID));
+            },
+        )
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&first_user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_claim_conversion(true);
+        })
+        .assert_ok();
+
+    fc_setup.deposit(FIRST_TOKEN_ID, USER_BALANCE).assert_ok();
+    fc_setup
+        .deposit(SECOND_TOKEN_ID, USER_BALANCE / 2)
+        .assert_ok();
+
+    fc_setup.claim(&first_user).assert_ok();
+
+    fc_setup.advance_week();
+
+    // tokens without a conversion pair are still sent as they are
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&first_user, FIRST_TOKEN_ID, &rust_biguint!(USER_BALANCE));
+    fc_setup.b_mock.check_esdt_balance(
+        &first_user,
+        SECOND_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE / 2),
+    );
+
+    // week 1 rewards were already collected
+    fc_setup
+        .b_mock
+        .execute_tx(
+            &fc_setup.owner_address,
+            &fc_setup.fc_wrapper,
+            &rust_zero,
+            |sc| {
+                let mut tokens = MultiValueEncoded::new();
+                tokens.push(managed_token_id!(SECOND_TOKEN_ID));
+                let _ = sc.convert_accumulated_fees(1, tokens);
+            },
+        )
+        .assert_user_error("Rewards already collected for week");
+}
+
+#[test]
+fn claim_with_conversion_through_pair_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+    let owner_address = fc_setup.owner_address.clone();
+    let (router_wrapper, pair_wrapper) = setup_conversion_pair(
+        &mut fc_setup.b_mock,
+        &owner_address,
+        router::contract_obj,
+        pair::contract_obj,
+    );
+    let router_address = router_wrapper.address_ref().clone();
+    let pair_address = pair_wrapper.address_ref().clone();
+
+    let first_user = fc_setup.b_mock.create_user_account(&rust_zero);
+    fc_setup.set_energy(&first_user, 500, 1_000);
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner_address, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_conversion_base_token(managed_token_id!(FIRST_TOKEN_ID));
+        })
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner_address, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_conversion_pair(
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_address!(&pair_address),
+            );
+        })
+        .assert_user_error("Router address not set");
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner_address, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_conversion_router_address(managed_address!(&router_address));
+        })
+        .assert_ok();
+
+    // only the router's pair of the token and the base token is accepted
+    fc_setup
+        .b_mock
+        .execute_tx(&owner_address, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_conversion_pair(
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_address!(&router_address),
+            );
+        })
+        .assert_user_error("Not the router pair for the token");
+
+    fc_setup
+        .b_mock
+        .execute_tx(&owner_address, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_conversion_pair(
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_address!(&pair_address),
+            );
+        })
+        .assert_ok();
+
+    fc_setup
+        .b_mock
+        .execute_tx(&first_user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            sc.set_claim_conversion(true);
+        })
+        .assert_ok();
+
+    let reward_amount = 1_000_000u64;
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup.deposit(SECOND_TOKEN_ID, reward_amount).assert_ok();
+    fc_setup.advance_week();
+
+    let mut expected_output = rust_biguint!(0);
+    fc_setup
+        .b_mock
+        .execute_query(&pair_wrapper, |sc| {
+            use pair::pair_actions::views::ViewsModule;
+
+            let amount_out = sc.get_amount_out_view(
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_biguint!(reward_amount),
+            );
+            expected_output =
+                num_bigint::BigUint::from_bytes_be(amount_out.to_bytes_be().as_slice());
+        })
+        .assert_ok();
+    assert!(expected_output > rust_biguint!(0));
+
+    // the SECOND token rewards are swapped and sent as FIRST tokens
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&first_user, SECOND_TOKEN_ID, &rust_zero);
+    fc_setup
+        .b_mock
+        .check_esdt_balance(&first_user, FIRST_TOKEN_ID, &expected_output);
+}
+
+#[test]
+fn raised_claim_weeks_horizon_does_not_reach_cleared_weeks_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    for _ in 0..5 {
+        fc_setup.advance_week();
+    }
+    assert_eq!(fc_setup.get_current_week(), 6);
+
+    fc_setup
+        .b_mock
+        .execute_tx(
+            &fc_setup.owner_address,
+            &fc_setup.fc_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_user_max_claim_weeks(6);
+            },
+        )
+        .assert_ok();
+
+    // weeks older than the previous horizon were already cleared
+    for expected_max_claim_weeks in [4, 5, 6, 6] {
+        fc_setup
+            .b_mock
+            .execute_query(&fc_setup.fc_wrapper, |sc| {
+                assert_eq!(sc.get_user_max_claim_weeks_view(), expected_max_claim_weeks);
+            })
+            .assert_ok();
+        fc_setup.advance_week();
+    }
+
+    // lowering the horizon takes effect immediately,
+    // and the weeks that are no longer claimable are cleared
+    assert_eq!(fc_setup.get_current_week(), 10);
+    fc_setup
+        .b_mock
+        .execute_tx(
+            &fc_setup.owner_address,
+            &fc_setup.fc_wrapper,
+            &rust_zero,
+            |sc| {
+                for week in 4..10 {
+                    let mut rewards = ManagedVec::new();
+                    rewards.push(EsdtTokenPayment::new(
+                        managed_token_id!(FIRST_TOKEN_ID),
+                        0,
+                        managed_biguint!(1_000),
+                    ));
+                    sc.total_rewards_for_week(week).set(rewards);
+                    sc.total_energy_for_week(week).set(managed_biguint!(1_000));
+                }
+
+                sc.set_user_max_claim_weeks(4);
+            },
+        )
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            assert_eq!(sc.get_user_max_claim_weeks_view(), 4);
+
+            for week in 4..6 {
+                assert!(sc.total_rewards_for_week(week).is_empty());
+                assert!(sc.total_energy_for_week(week).is_empty());
+            }
+            for week in 6..10 {
+                assert!(!sc.total_rewards_for_week(week).is_empty());
+                assert!(!sc.total_energy_for_week(week).is_empty());
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn claim_paginated_beyond_default_claim_weeks_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut fc_setup =
+        FeesCollectorSetup::new(fees_collector::contract_obj, energy_factory::contract_obj);
+
+    let first_user = fc_setup.b_mock.create_user_account(&rust_zero);
+    let second_user = fc_setup.b_mock.create_user_account(&rust_zero);
+    fc_setup.set_energy(&first_user, 1, 1_000_000);
+    fc_setup.set_energy(&second_user, 1, 1_000_000);
+
+    fc_setup
+        .b_mock
+        .execute_tx(
+            &fc_setup.owner_address,
+            &fc_setup.fc_wrapper,
+            &rust_zero,
+            |sc| {
+                sc.set_user_max_claim_weeks(6);
+            },
+        )
+        .assert_ok();
+
+    // both users register in week 1
+    fc_setup.claim(&first_user).assert_ok();
+    fc_setup.claim(&second_user).assert_ok();
+
+    // only the second user claims every week
+    for _ in 0..6 {
+        fc_setup
+            .deposit(FIRST_TOKEN_ID, USER_BALANCE / 10)
+            .assert_ok();
+        fc_setup.advance_week();
+        fc_setup.claim(&second_user).assert_ok();
+    }
+    assert_eq!(fc_setup.get_current_week(), 7);
+
+    // first user catches up on weeks 1 and 2
+    fc_setup
+        .b_mock
+        .execute_tx(&first_user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_rewards_paginated_endpoint(2, OptionalValue::None);
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            let claim_progress = sc
+                .current_claim_progress(&managed_address!(&first_user))
+                .get();
+            assert_eq!(claim_progress.week, 3);
+        })
+        .assert_ok();
+    fc_setup.b_mock.check_esdt_balance(
+        &first_user,
+        FIRST_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE / 10),
+    );
+
+    // the remaining weeks fit in a single page
+    fc_setup
+        .b_mock
+        .execute_tx(&first_user, &fc_setup.fc_wrapper, &rust_zero, |sc| {
+            let _ = sc.claim_rewards_paginated_endpoint(10, OptionalValue::None);
+        })
+        .assert_ok();
+    fc_setup
+        .b_mock
+        .execute_query(&fc_setup.fc_wrapper, |sc| {
+            let claim_progress = sc
+                .current_claim_progress(&managed_address!(&first_user))
+                .get();
+            assert_eq!(claim_progress.week, 7);
+        })
+        .assert_ok();
+    fc_setup.b_mock.check_esdt_balance(
+        &first_user,
+        FIRST_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE * 3 / 10),
+    );
+    fc_setup.b_mock.check_esdt_balance(
+        &second_user,
+        FIRST_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE * 3 / 10),
+    );
+}
@@ -3,7 +3,7 @@
 use multiversx_sc::{
     codec::multi_types::OptionalValue,
     storage::mappers::StorageTokenWrapper,
-    types::{Address, BigInt, EsdtLocalRole, MultiValueEncoded},
+    types::{Address, BigInt, EsdtLocalRole, ManagedAddress, MultiValueEncoded},
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
@@ -262,3 +262,106 @@ where
             .assert_ok();
     }
 }
+
+pub static CONVERSION_LP_TOKEN_ID: &[u8] = b"LPTOK-123456";
+pub const CONVERSION_PAIR_LIQUIDITY: u64 = 1_000_000_000_000;
+
+/// Deploys a router and its SECOND/FIRST pair, with enough price observations for the safe price
+pub fn setup_conversion_pair<RouterObjBuilder, PairObjBuilder>(
+    b_mock: &mut BlockchainStateWrapper,
+    owner_address: &Address,
+    router_builder: RouterObjBuilder,
+    pair_builder: PairObjBuilder,
+) -> (
+    ContractObjWrapper<router::ContractObj<DebugApi>, RouterObjBuilder>,
+    ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+)
+where
+    RouterObjBuilder: 'static + Copy + Fn() -> router::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    use pair::{config::ConfigModule as _, pair_actions::add_liq::AddLiquidityModule, Pair};
+    use pausable::{PausableModule, State};
+    use router::{config::ConfigModule as _, factory::PairTokens, Router};
+
+    let rust_zero = rust_biguint!(0u64);
+    let router_wrapper =
+        b_mock.create_sc_account(&rust_zero, Some(owner_address), router_builder, "router");
+    let pair_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(router_wrapper.address_ref()),
+        pair_builder,
+        "pair",
+    );
+
+    b_mock
+        .execute_tx(owner_address, &pair_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_token_id!(SECOND_TOKEN_ID),
+                managed_token_id!(FIRST_TOKEN_ID),
+                managed_address!(router_wrapper.address_ref()),
+                managed_address!(owner_address),
+                300,
+                50,
+                ManagedAddress::zero(),
+                MultiValueEncoded::new(),
+            );
+            sc.lp_token_identifier()
+                .set(&managed_token_id!(CONVERSION_LP_TOKEN_ID));
+            sc.state().set(State::Active);
+        })
+        .assert_ok();
+    b_mock.set_esdt_local_roles(
+        pair_wrapper.address_ref(),
+        CONVERSION_LP_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+
+    b_mock
+        .execute_tx(owner_address, &router_wrapper, &rust_zero, |sc| {
+            sc.init(OptionalValue::None);
+            sc.pair_map().insert(
+                PairTokens {
+                    first_token_id: managed_token_id!(SECOND_TOKEN_ID),
+                    second_token_id: managed_token_id!(FIRST_TOKEN_ID),
+                },
+                managed_address!(pair_wrapper.address_ref()),
+            );
+        })
+        .assert_ok();
+
+    // 1 SECOND = 1 FIRST, added in two different rounds to record price observations
+    b_mock.set_esdt_balance(
+        owner_address,
+        FIRST_TOKEN_ID,
+        &rust_biguint!(CONVERSION_PAIR_LIQUIDITY * 2),
+    );
+    b_mock.set_esdt_balance(
+        owner_address,
+        SECOND_TOKEN_ID,
+        &rust_biguint!(CONVERSION_PAIR_LIQUIDITY * 2),
+    );
+    for round in [1, 20] {
+        b_mock.set_block_round(round);
+        let payments = vec![
+            TxTokenTransfer {
+                token_identifier: SECOND_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(CONVERSION_PAIR_LIQUIDITY),
+            },
+            TxTokenTransfer {
+                token_identifier: FIRST_TOKEN_ID.to_vec(),
+                nonce: 0,
+                value: rust_biguint!(CONVERSION_PAIR_LIQUIDITY),
+            },
+        ];
+        b_mock
+            .execute_esdt_multi_transfer(owner_address, &pair_wrapper, &payments, |sc| {
+                let _ = sc.add_liquidity(managed_biguint!(1), managed_biguint!(1));
+            })
+            .assert_ok();
+    }
+    b_mock.set_block_round(40);
+
+    (router_wrapper, pair_wrapper)
+}
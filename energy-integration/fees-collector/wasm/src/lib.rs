@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           37
+// Endpoints:                           52
 // Async Callback (empty):               1
-// Total number of exported functions:  40
+// Total number of exported functions:  55
 
 #![no_std]
 
@@ -21,7 +21,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         claimRewards => claim_rewards_endpoint
+        claimRewardsPaginated => claim_rewards_paginated_endpoint
         claimBoostedRewards => claim_boosted_rewards
+        setUserMaxClaimWeeks => set_user_max_claim_weeks
+        getUserMaxClaimWeeks => get_user_max_claim_weeks_view
         addKnownContracts => add_known_contracts
         removeKnownContracts => remove_known_contracts
         addKnownTokens => add_known_tokens
@@ -40,6 +43,18 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrentClaimProgress => current_claim_progress
         depositSwapFees => deposit_swap_fees
         getAccumulatedFees => accumulated_fees
+        setConversionBaseToken => set_conversion_base_token
+        setConversionRouterAddress => set_conversion_router_address
+        setConversionPair => set_conversion_pair
+        removeConversionPair => remove_conversion_pair
+        setMaxConversionSlippage => set_max_conversion_slippage
+        setClaimConversion => set_claim_conversion
+        convertAccumulatedFees => convert_accumulated_fees
+        getConversionRouterAddress => conversion_router_address
+        getConversionBaseToken => conversion_base_token
+        getConversionPair => conversion_pair
+        getMaxConversionSlippage => max_conversion_slippage
+        isClaimConversionEnabled => claim_conversion_enabled
         setLockedTokensPerBlock => set_locked_tokens_per_block
         getLastLockedTokensAddWeek => last_locked_token_add_week
         getLockedTokensPerBlock => locked_tokens_per_block
@@ -17,7 +17,8 @@ multiversx_sc::imports!();
 /// - `maxActionsPerProposal` - Maximum number of actions (transfers and/or smart contract calls) that a proposal may have  
 /// - `votingDelayInBlocks` - Number of blocks to wait after a block is proposed before being able to vote/downvote that proposal
 /// - `votingPeriodInBlocks` - Number of blocks the voting period lasts (voting delay does not count towards this)  
-/// - `lockTimeAfterVotingEndsInBlocks` - Number of blocks to wait before a successful proposal can be executed  
+/// - `lockTimeAfterVotingEndsInBlocks` - Number of blocks to wait before a queued proposal can be executed  
+/// - `executionPeriodInBlocks` - Number of blocks a queued proposal can be executed for, after the lock time passed  
 ///
 /// The module also provides events for most actions that happen:
 /// - `proposalCreated` - triggers when a proposal is created. Also provoides all the relevant information, like proposer, actions etc.  
@@ -39,6 +40,11 @@ const MAX_QUORUM: u64 = 6_000; // 60%
 const MIN_MIN_FEE_FOR_PROPOSE: u64 = 2_000_000;
 const MAX_MIN_FEE_FOR_PROPOSE: u64 = 200_000_000_000;
 const DECIMALS_CONST: u64 = 1_000_000_000_000_000_000;
+const MAX_LOCK_TIME_AFTER_VOTING_ENDS: u64 = 201_600; // 2 Weeks
+const MIN_EXECUTION_PERIOD: u64 = 14_400; // 24 Hours
+const MAX_EXECUTION_PERIOD: u64 = 403_200; // 4 Weeks
+pub const DEFAULT_LOCK_TIME_AFTER_VOTING_ENDS: u64 = 14_400; // 24 Hours
+pub const DEFAULT_EXECUTION_PERIOD: u64 = 100_800; // 1 Week
 pub const MAX_GAS_LIMIT_PER_BLOCK: u64 = 600_000_000;
 pub const FULL_PERCENTAGE: u64 = 10_000;
 
@@ -85,6 +91,18 @@ pub trait ConfigurablePropertiesModule:
         self.try_change_voting_period_in_blocks(new_value);
     }
 
+    #[only_owner]
+    #[endpoint(changeLockTimeAfterVotingEndsInBlocks)]
+    fn change_lock_time_after_voting_ends_in_blocks(&self, new_value: u64) {
+        self.try_change_lock_time_after_voting_ends_in_blocks(new_value);
+    }
+
+    #[only_owner]
+    #[endpoint(changeExecutionPeriodInBlocks)]
+    fn change_execution_period_in_blocks(&self, new_value: u64) {
+        self.try_change_execution_period_in_blocks(new_value);
+    }
+
     fn try_change_min_energy_for_propose(&self, new_value: BigUint) {
         self.min_energy_for_propose().set(&new_value);
     }
@@ -139,6 +157,25 @@ pub trait ConfigurablePropertiesModule:
             .set(new_withdraw_percentage);
     }
 
+    fn try_change_lock_time_after_voting_ends_in_blocks(&self, new_lock_time: u64) {
+        require!(
+            new_lock_time <= MAX_LOCK_TIME_AFTER_VOTING_ENDS,
+            "Not valid value for lock time after voting ends!"
+        );
+
+        self.lock_time_after_voting_ends_in_blocks()
+            .set(new_lock_time);
+    }
+
+    fn try_change_execution_period_in_blocks(&self, new_execution_period: u64) {
+        require!(
+            (MIN_EXECUTION_PERIOD..MAX_EXECUTION_PERIOD).contains(&new_execution_period),
+            "Not valid value for execution period!"
+        );
+
+        self.execution_period_in_blocks().set(new_execution_period);
+    }
+
     fn try_change_fee_token_id(&self, fee_token_id: TokenIdentifier) {
         require!(fee_token_id.is_valid_esdt_identifier(), ERROR_NOT_AN_ESDT);
         self.fee_token_id().set_if_empty(&fee_token_id);
@@ -175,4 +212,12 @@ pub trait ConfigurablePropertiesModule:
     #[view(getWithdrawPercentageDefeated)]
     #[storage_mapper("witdrawPercentageDefeated")]
     fn withdraw_percentage_defeated(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLockTimeAfterVotingEndsInBlocks)]
+    #[storage_mapper("lockTimeAfterVotingEndsInBlocks")]
+    fn lock_time_after_voting_ends_in_blocks(&self) -> SingleValueMapper<u64>;
+
+    #[view(getExecutionPeriodInBlocks)]
+    #[storage_mapper("executionPeriodInBlocks")]
+    fn execution_period_in_blocks(&self) -> SingleValueMapper<u64>;
 }
@@ -0,0 +1,83 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+
+pub const MAX_DELEGATORS_PER_DELEGATE: usize = 100;
+
+#[multiversx_sc::module]
+pub trait DelegationModule:
+    crate::configurable::ConfigurablePropertiesModule
+    + crate::events::EventsModule
+    + energy_query::EnergyQueryModule
+    + permissions_module::PermissionsModule
+{
+    /// Delegate the caller's governance voting power to another address.
+    /// The energy itself is not moved, it is only counted when the delegate votes.
+    /// While the delegation is active, the caller cannot vote personally.
+    #[endpoint(delegateVotingPower)]
+    fn delegate_voting_power(&self, delegate: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(caller != delegate, CANNOT_DELEGATE_TO_SELF);
+        require!(
+            self.user_delegate(&delegate).is_empty(),
+            DELEGATE_HAS_DELEGATED
+        );
+        require!(self.delegators(&caller).is_empty(), DELEGATOR_IS_DELEGATE);
+
+        self.remove_delegation(&caller);
+
+        let mut delegators_mapper = self.delegators(&delegate);
+        require!(
+            delegators_mapper.len() < MAX_DELEGATORS_PER_DELEGATE,
+            TOO_MANY_DELEGATORS
+        );
+
+        let _ = delegators_mapper.insert(caller.clone());
+        self.user_delegate(&caller).set(&delegate);
+
+        self.voting_power_delegated_event(&caller, &delegate);
+    }
+
+    #[endpoint(revokeDelegation)]
+    fn revoke_delegation(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.user_delegate(&caller).is_empty(), NO_DELEGATION);
+
+        self.remove_delegation(&caller);
+    }
+
+    /// Returns the aggregated energy and the voting power of the delegate,
+    /// including their own energy and the energy of all their delegators
+    #[view(getDelegateVotingPower)]
+    fn get_delegate_voting_power(&self, delegate: ManagedAddress) -> MultiValue2<BigUint, BigUint> {
+        let mut total_quorum = BigUint::zero();
+        let mut total_voting_power = BigUint::zero();
+        for user in core::iter::once(delegate.clone()).chain(self.delegators(&delegate).iter()) {
+            let user_quorum = self.get_energy_amount(&user);
+            total_voting_power += self.smoothing_function(&user_quorum);
+            total_quorum += user_quorum;
+        }
+
+        (total_quorum, total_voting_power).into()
+    }
+
+    fn remove_delegation(&self, user: &ManagedAddress) {
+        let delegate_mapper = self.user_delegate(user);
+        if delegate_mapper.is_empty() {
+            return;
+        }
+
+        let delegate = delegate_mapper.take();
+        let _ = self.delegators(&delegate).swap_remove(user);
+
+        self.delegation_revoked_event(user, &delegate);
+    }
+
+    #[view(getUserDelegate)]
+    #[storage_mapper("userDelegate")]
+    fn user_delegate(&self, user: &ManagedAddress) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getDelegators)]
+    #[storage_mapper("delegators")]
+    fn delegators(&self, delegate: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+}
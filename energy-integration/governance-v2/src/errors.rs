@@ -12,3 +12,22 @@ pub const FEE_ALREADY_WITHDRAWN: &[u8] = b"Fee already withdrawn!";
 pub const NO_PROPOSAL: &[u8] = b"Proposal does not exist";
 pub const WITHDRAW_NOT_ALLOWED: &[u8] = b"You may not withdraw funds from this proposal!";
 pub const PROPOSAL_NOT_ALLOWED_FOR_SC: &[u8] = b"Smart Contracts are not allowed to propose!";
+pub const PROPOSAL_NOT_SUCCEEDED: &[u8] = b"Only succeeded proposals can be queued";
+pub const PROPOSAL_NOT_QUEUED: &[u8] = b"Only queued proposals can be executed";
+pub const TIMELOCK_NOT_PASSED: &[u8] = b"Proposal timelock has not passed yet";
+pub const NOT_ENOUGH_GAS_FOR_EXECUTION: &[u8] = b"Not enough gas to execute all actions";
+pub const CANNOT_DELEGATE_TO_SELF: &[u8] = b"Cannot delegate to self";
+pub const DELEGATE_HAS_DELEGATED: &[u8] = b"Delegate has delegated their own voting power";
+pub const DELEGATOR_IS_DELEGATE: &[u8] = b"Users with delegators cannot delegate";
+pub const TOO_MANY_DELEGATORS: &[u8] = b"Delegate has too many delegators";
+pub const NO_DELEGATION: &[u8] = b"No active delegation";
+pub const VOTING_POWER_DELEGATED: &[u8] = b"Voting power is delegated";
+pub const ALREADY_VOTED_THROUGH_DELEGATE: &[u8] = b"Already voted through delegate";
+pub const TOO_MANY_SIGNED_VOTES: &[u8] = b"Too many signed votes in batch";
+pub const INVALID_VOTE_NONCE: &[u8] = b"Invalid vote nonce";
+pub const UNKNOWN_TEMPLATE_CONTRACT: &[u8] = b"Template targets an unknown contract";
+pub const INVALID_TEMPLATE_ARGUMENTS: &[u8] = b"Invalid template arguments";
+pub const LEGACY_GOVERNANCE_NOT_READ_ONLY: &[u8] = b"Legacy governance is not in read-only mode";
+pub const LEGACY_PROPOSAL_ALREADY_IMPORTED: &[u8] = b"Legacy proposal already imported";
+pub const LEGACY_PROPOSAL_NOT_OPEN: &[u8] = b"Legacy proposal is not open";
+pub const LEGACY_ACTION_WITH_PAYMENTS: &[u8] = b"Legacy actions with payments cannot be imported";
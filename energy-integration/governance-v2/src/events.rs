@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::proposal::{GovernanceProposal, ProposalId};
+use crate::{
+    proposal::{GovernanceProposal, ProposalId},
+    proposal_storage::VoteType,
+};
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -50,9 +53,67 @@ pub trait EventsModule {
         #[indexed] user_quorum: &BigUint,
     );
 
+    #[event("voteChanged")]
+    fn vote_changed_event(
+        &self,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] old_vote_type: VoteType,
+        #[indexed] new_vote_type: VoteType,
+    );
+
+    #[event("votingPowerDelegated")]
+    fn voting_power_delegated_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+    );
+
+    #[event("delegationRevoked")]
+    fn delegation_revoked_event(
+        &self,
+        #[indexed] delegator: &ManagedAddress,
+        #[indexed] delegate: &ManagedAddress,
+    );
+
     #[event("proposalCanceled")]
     fn proposal_canceled_event(&self, #[indexed] proposal_id: ProposalId);
 
     #[event("proposalWithdrawAfterDefeated")]
     fn proposal_withdraw_after_defeated_event(&self, #[indexed] proposal_id: ProposalId);
+
+    #[event("proposalQueued")]
+    fn proposal_queued_event(
+        &self,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] execution_start_block: u64,
+        #[indexed] execution_end_block: u64,
+    );
+
+    #[event("proposalExecuted")]
+    fn proposal_executed_event(&self, #[indexed] proposal_id: ProposalId);
+
+    #[event("actionExecutionSucceeded")]
+    fn action_execution_succeeded_event(
+        &self,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] action_index: usize,
+    );
+
+    #[event("actionExecutionFailed")]
+    fn action_execution_failed_event(
+        &self,
+        #[indexed] proposal_id: ProposalId,
+        #[indexed] action_index: usize,
+        #[indexed] err_code: u32,
+        err_msg: &ManagedBuffer,
+    );
+
+    #[event("legacyProposalImported")]
+    fn legacy_proposal_imported_event(
+        &self,
+        #[indexed] legacy_governance_address: &ManagedAddress,
+        #[indexed] legacy_proposal_id: u64,
+        #[indexed] proposal_id: ProposalId,
+    );
 }
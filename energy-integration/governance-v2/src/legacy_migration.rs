@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+
+use governance::config::ProxyTrait as _;
+use governance::proposal::{ProposalStatus, ProxyTrait as _};
+
+use crate::errors::*;
+
+pub type LegacyAction<M> = governance::proposal::Action<M>;
+pub type LegacyProposal<M> = governance::proposal::Proposal<M>;
+
+#[multiversx_sc::module]
+pub trait LegacyMigrationModule: crate::events::EventsModule {
+    fn is_legacy_governance_read_only(&self, legacy_governance_address: &ManagedAddress) -> bool {
+        self.legacy_governance_proxy(legacy_governance_address.clone())
+            .read_only_mode()
+            .execute_on_dest_context()
+    }
+
+    /// Returns the (voting delay, voting period) of the legacy contract, in blocks
+    fn get_legacy_voting_periods(&self, legacy_governance_address: &ManagedAddress) -> (u64, u64) {
+        let voting_delay: u64 = self
+            .legacy_governance_proxy(legacy_governance_address.clone())
+            .voting_delay_in_blocks()
+            .execute_on_dest_context();
+        let voting_period: u64 = self
+            .legacy_governance_proxy(legacy_governance_address.clone())
+            .voting_period_in_blocks()
+            .execute_on_dest_context();
+
+        (voting_delay, voting_period)
+    }
+
+    fn get_open_legacy_proposal(
+        &self,
+        legacy_governance_address: &ManagedAddress,
+        legacy_proposal_id: u64,
+    ) -> LegacyProposal<Self::Api> {
+        let status: ProposalStatus = self
+            .legacy_governance_proxy(legacy_governance_address.clone())
+            .get_proposal_status_view(legacy_proposal_id)
+            .execute_on_dest_context();
+        require!(
+            status == ProposalStatus::Pending || status == ProposalStatus::Active,
+            LEGACY_PROPOSAL_NOT_OPEN
+        );
+
+        self.legacy_governance_proxy(legacy_governance_address.clone())
+            .proposal(legacy_proposal_id)
+            .execute_on_dest_context()
+    }
+
+    #[proxy]
+    fn legacy_governance_proxy(&self, sc_address: ManagedAddress) -> governance::Proxy<Self::Api>;
+
+    #[view(getImportedLegacyProposal)]
+    #[storage_mapper("importedLegacyProposal")]
+    fn imported_legacy_proposal(
+        &self,
+        legacy_governance_address: &ManagedAddress,
+        legacy_proposal_id: u64,
+    ) -> SingleValueMapper<usize>;
+}
@@ -3,27 +3,44 @@
 multiversx_sc::imports!();
 
 pub mod configurable;
+pub mod delegation;
 mod errors;
 pub mod events;
+pub mod legacy_migration;
 pub mod proposal;
 pub mod proposal_storage;
+pub mod proposal_templates;
+pub mod signed_vote;
 pub mod views;
 
+use legacy_migration::LegacyAction;
 use proposal::*;
 use proposal_storage::VoteType;
+use proposal_templates::ProposalTemplate;
+use signed_vote::{SignedVote, MAX_SIGNED_VOTES_PER_BATCH};
 use weekly_rewards_splitting::events::Week;
 use weekly_rewards_splitting::global_info::ProxyTrait as _;
 
-use crate::configurable::{FULL_PERCENTAGE, MAX_GAS_LIMIT_PER_BLOCK};
+use crate::configurable::{
+    DEFAULT_EXECUTION_PERIOD, DEFAULT_LOCK_TIME_AFTER_VOTING_ENDS, FULL_PERCENTAGE,
+    MAX_GAS_LIMIT_PER_BLOCK,
+};
 use crate::errors::*;
-use crate::proposal_storage::ProposalVotes;
+use crate::proposal_storage::{ProposalVotes, VoteRecord};
+
+pub const ACTION_CALLBACK_GAS: u64 = 3_000_000;
+pub const MIN_GAS_FOR_EXECUTE: u64 = 10_000_000;
 
 /// An empty contract. To be used as a template when starting a new contract from scratch.
 #[multiversx_sc::contract]
 pub trait GovernanceV2:
     configurable::ConfigurablePropertiesModule
+    + delegation::DelegationModule
     + events::EventsModule
+    + legacy_migration::LegacyMigrationModule
     + proposal_storage::ProposalStorageModule
+    + proposal_templates::ProposalTemplatesModule
+    + signed_vote::SignedVoteModule
     + views::ViewsModule
     + energy_query::EnergyQueryModule
     + permissions_module::PermissionsModule
@@ -59,10 +76,17 @@ pub trait GovernanceV2:
         self.set_energy_factory_address(energy_factory_address);
         self.fees_collector_address().set(&fees_collector_address);
         self.try_change_fee_token_id(fee_token);
+        self.try_change_lock_time_after_voting_ends_in_blocks(DEFAULT_LOCK_TIME_AFTER_VOTING_ENDS);
+        self.try_change_execution_period_in_blocks(DEFAULT_EXECUTION_PERIOD);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.lock_time_after_voting_ends_in_blocks()
+            .set_if_empty(DEFAULT_LOCK_TIME_AFTER_VOTING_ENDS);
+        self.execution_period_in_blocks()
+            .set_if_empty(DEFAULT_EXECUTION_PERIOD);
+    }
 
     /// Propose a list of actions.
     /// A maximum of MAX_GOVERNANCE_PROPOSAL_ACTIONS can be proposed at a time.
@@ -77,17 +101,65 @@ pub trait GovernanceV2:
         description: ManagedBuffer,
         actions: MultiValueEncoded<GovernanceActionAsMultiArg<Self::Api>>,
     ) -> ProposalId {
-        let proposer = self.blockchain().get_caller();
         require!(
-            !self.blockchain().is_smart_contract(&proposer),
-            PROPOSAL_NOT_ALLOWED_FOR_SC
+            actions.len() <= MAX_GOVERNANCE_PROPOSAL_ACTIONS,
+            EXEEDED_MAX_ACTIONS
         );
 
+        let mut gov_actions = ArrayVec::new();
+        for action_multiarg in actions {
+            let gov_action = GovernanceAction::from(action_multiarg);
+            require!(
+                gov_action.gas_limit < MAX_GAS_LIMIT_PER_BLOCK,
+                "A single action cannot use more than the max gas limit per block"
+            );
+
+            unsafe {
+                gov_actions.push_unchecked(gov_action);
+            }
+        }
+
+        self.propose_common(description, gov_actions)
+    }
+
+    /// Propose a list of typed actions, which are validated against the known contracts
+    /// and encoded into regular actions. Same rules as for `propose` apply.
+    #[payable("*")]
+    #[endpoint(proposeFromTemplates)]
+    fn propose_from_templates(
+        &self,
+        description: ManagedBuffer,
+        templates: MultiValueEncoded<ProposalTemplate<Self::Api>>,
+    ) -> ProposalId {
         require!(
-            actions.len() <= MAX_GOVERNANCE_PROPOSAL_ACTIONS,
+            templates.len() <= MAX_GOVERNANCE_PROPOSAL_ACTIONS,
             EXEEDED_MAX_ACTIONS
         );
 
+        let fees_collector_address = self.fees_collector_address().get();
+        let mut gov_actions = ArrayVec::new();
+        for template in templates {
+            self.validate_proposal_template(&template);
+
+            unsafe {
+                gov_actions.push_unchecked(template.into_action(fees_collector_address.clone()));
+            }
+        }
+
+        self.propose_common(description, gov_actions)
+    }
+
+    fn propose_common(
+        &self,
+        description: ManagedBuffer,
+        gov_actions: ArrayVec<GovernanceAction<Self::Api>, MAX_GOVERNANCE_PROPOSAL_ACTIONS>,
+    ) -> ProposalId {
+        let proposer = self.blockchain().get_caller();
+        require!(
+            !self.blockchain().is_smart_contract(&proposer),
+            PROPOSAL_NOT_ALLOWED_FOR_SC
+        );
+
         let user_energy = self.get_energy_amount(&proposer);
         let min_energy_for_propose = self.min_energy_for_propose().get();
         require!(user_energy >= min_energy_for_propose, NOT_ENOUGH_ENERGY);
@@ -102,16 +174,108 @@ pub trait GovernanceV2:
             NOT_ENOUGH_FEE
         );
 
+        require!(
+            self.total_gas_needed(&gov_actions) < MAX_GAS_LIMIT_PER_BLOCK,
+            TOO_MUCH_GAS
+        );
+
+        let voting_delay_in_blocks = self.voting_delay_in_blocks().get();
+        let voting_period_in_blocks = self.voting_period_in_blocks().get();
+
+        self.create_proposal(
+            proposer,
+            description,
+            gov_actions,
+            user_fee,
+            voting_delay_in_blocks,
+            voting_period_in_blocks,
+        )
+    }
+
+    /// Recreates the open proposals of the legacy governance contract,
+    /// keeping their remaining voting window. Votes are not carried over.
+    ///
+    /// The legacy contract has to be in read-only mode, so no more votes can be cast there.
+    #[only_owner]
+    #[endpoint(importLegacyProposals)]
+    fn import_legacy_proposals(
+        &self,
+        legacy_governance_address: ManagedAddress,
+        legacy_proposal_ids: MultiValueEncoded<u64>,
+    ) -> MultiValueEncoded<ProposalId> {
+        require!(
+            self.is_legacy_governance_read_only(&legacy_governance_address),
+            LEGACY_GOVERNANCE_NOT_READ_ONLY
+        );
+
+        let (legacy_voting_delay, legacy_voting_period) =
+            self.get_legacy_voting_periods(&legacy_governance_address);
+        let current_block = self.blockchain().get_block_nonce();
+        let fee_token_id = self.fee_token_id().get();
+        let mut result = MultiValueEncoded::new();
+        for legacy_proposal_id in legacy_proposal_ids {
+            let imported_mapper =
+                self.imported_legacy_proposal(&legacy_governance_address, legacy_proposal_id);
+            require!(imported_mapper.is_empty(), LEGACY_PROPOSAL_ALREADY_IMPORTED);
+
+            let legacy_proposal =
+                self.get_open_legacy_proposal(&legacy_governance_address, legacy_proposal_id);
+            let gov_actions = self.convert_legacy_actions(&legacy_proposal.actions);
+
+            let voting_start = legacy_proposal.creation_block + legacy_voting_delay;
+            let voting_end = voting_start + legacy_voting_period;
+            let voting_delay_in_blocks = voting_start.saturating_sub(current_block);
+            let voting_period_in_blocks = voting_end - core::cmp::max(voting_start, current_block);
+
+            // legacy proposals had no fee
+            let proposal_id = self.create_proposal(
+                legacy_proposal.proposer,
+                legacy_proposal.description,
+                gov_actions,
+                EsdtTokenPayment::new(fee_token_id.clone(), 0, BigUint::zero()),
+                voting_delay_in_blocks,
+                voting_period_in_blocks,
+            );
+            imported_mapper.set(proposal_id);
+
+            self.legacy_proposal_imported_event(
+                &legacy_governance_address,
+                legacy_proposal_id,
+                proposal_id,
+            );
+            result.push(proposal_id);
+        }
+
+        result
+    }
+
+    fn convert_legacy_actions(
+        &self,
+        legacy_actions: &ManagedVec<LegacyAction<Self::Api>>,
+    ) -> ArrayVec<GovernanceAction<Self::Api>, MAX_GOVERNANCE_PROPOSAL_ACTIONS> {
+        require!(
+            legacy_actions.len() <= MAX_GOVERNANCE_PROPOSAL_ACTIONS,
+            EXEEDED_MAX_ACTIONS
+        );
+
         let mut gov_actions = ArrayVec::new();
-        for action_multiarg in actions {
-            let gov_action = GovernanceAction::from(action_multiarg);
+        for legacy_action in legacy_actions {
             require!(
-                gov_action.gas_limit < MAX_GAS_LIMIT_PER_BLOCK,
+                legacy_action.payments.is_empty(),
+                LEGACY_ACTION_WITH_PAYMENTS
+            );
+            require!(
+                legacy_action.gas_limit < MAX_GAS_LIMIT_PER_BLOCK,
                 "A single action cannot use more than the max gas limit per block"
             );
 
             unsafe {
-                gov_actions.push_unchecked(gov_action);
+                gov_actions.push_unchecked(GovernanceAction {
+                    gas_limit: legacy_action.gas_limit,
+                    dest_address: legacy_action.dest_address,
+                    function_name: legacy_action.endpoint_name,
+                    arguments: legacy_action.arguments,
+                });
             }
         }
 
@@ -120,28 +284,45 @@ pub trait GovernanceV2:
             TOO_MUCH_GAS
         );
 
+        gov_actions
+    }
+
+    fn create_proposal(
+        &self,
+        proposer: ManagedAddress,
+        description: ManagedBuffer,
+        gov_actions: ArrayVec<GovernanceAction<Self::Api>, MAX_GOVERNANCE_PROPOSAL_ACTIONS>,
+        fee_payment: EsdtTokenPayment,
+        voting_delay_in_blocks: u64,
+        voting_period_in_blocks: u64,
+    ) -> ProposalId {
         let minimum_quorum = self.quorum_percentage().get();
-        let voting_delay_in_blocks = self.voting_delay_in_blocks().get();
-        let voting_period_in_blocks = self.voting_period_in_blocks().get();
         let withdraw_percentage_defeated = self.withdraw_percentage_defeated().get();
         let current_block = self.blockchain().get_block_nonce();
+        let total_quorum = self.get_total_energy_from_fees_collector();
+        let fee_withdrawn = fee_payment.amount == 0;
 
         let proposal = GovernanceProposal {
             proposal_id: self.proposals().len() + 1,
             proposer: proposer.clone(),
             description,
             actions: gov_actions,
-            fee_payment: user_fee,
+            fee_payment,
             minimum_quorum,
             voting_delay_in_blocks,
             voting_period_in_blocks,
             withdraw_percentage_defeated,
-            total_quorum: BigUint::zero(),
+            total_quorum,
             proposal_start_block: current_block,
-            fee_withdrawn: false,
+            fee_withdrawn,
         };
         let proposal_id = self.proposals().push(&proposal);
 
+        // energy changes in the proposal's epoch are not taken into account
+        let snapshot_epoch = self.blockchain().get_block_epoch().saturating_sub(1);
+        self.proposal_snapshot_epoch(proposal_id)
+            .set(snapshot_epoch);
+
         self.proposal_votes(proposal_id)
             .set(ProposalVotes::default());
         self.proposal_created_event(proposal_id, &proposer, current_block, &proposal);
@@ -149,70 +330,178 @@ pub trait GovernanceV2:
         proposal_id
     }
 
-    /// Vote on a proposal. The voting power depends on the user's energy.
+    /// Vote on a proposal. The voting power depends on the user's energy
+    /// at the end of the epoch before the proposal was created.
+    ///
+    /// Delegates also vote with the energy of all their delegators that did not vote already.
+    /// Voting again while the proposal is active changes the vote, keeping the same voting power.
     #[endpoint]
     fn vote(&self, proposal_id: ProposalId, vote: VoteType) {
+        let voter = self.blockchain().get_caller();
+        self.vote_common(voter, proposal_id, vote);
+    }
+
+    /// Applies a batch of votes signed off-chain by the voters, so a relayer can pay the gas for them.
+    /// Each vote is signed with the voter's ed25519 key and has to use the voter's current nonce.
+    /// Each vote is processed exactly as if the voter called `vote`. If any of them fails, the whole batch fails.
+    #[endpoint(voteBatch)]
+    fn vote_batch(&self, signed_votes: MultiValueEncoded<SignedVote<Self::Api>>) {
+        require!(
+            signed_votes.len() <= MAX_SIGNED_VOTES_PER_BATCH,
+            TOO_MANY_SIGNED_VOTES
+        );
+
+        for signed_vote in signed_votes {
+            self.verify_signed_vote(&signed_vote);
+            self.vote_common(
+                signed_vote.voter,
+                signed_vote.proposal_id,
+                signed_vote.vote_type,
+            );
+        }
+    }
+
+    fn vote_common(&self, voter: ManagedAddress, proposal_id: ProposalId, vote: VoteType) {
         self.require_valid_proposal_id(proposal_id);
         require!(
             self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active,
             PROPOSAL_NOT_ACTIVE
         );
 
-        let voter = self.blockchain().get_caller();
-        let new_user = self.user_voted_proposals(&voter).insert(proposal_id);
-        require!(new_user, ALREADY_VOTED_ERR_MSG);
+        require!(
+            self.user_delegate(&voter).is_empty(),
+            VOTING_POWER_DELEGATED
+        );
+        require!(
+            self.voted_through_delegate(proposal_id, &voter).is_empty(),
+            ALREADY_VOTED_THROUGH_DELEGATE
+        );
 
+        let vote_record_mapper = self.proposal_vote_record(proposal_id, &voter);
+        if !vote_record_mapper.is_empty() {
+            self.change_vote(proposal_id, &voter, vote);
+            return;
+        }
+
+        let _ = self.user_voted_proposals(&voter).insert(proposal_id);
+        self.init_legacy_proposal_total_quorum(proposal_id);
+
+        let mut user_quorum = self.get_voting_energy_amount(proposal_id, &voter);
+        let mut voting_power = self.smoothing_function(&user_quorum);
+        for delegator in self.delegators(&voter).iter() {
+            // a delegator may have been counted by a previous delegate before re-delegating
+            let already_voted = !self
+                .proposal_vote_record(proposal_id, &delegator)
+                .is_empty()
+                || !self
+                    .voted_through_delegate(proposal_id, &delegator)
+                    .is_empty();
+            if already_voted {
+                continue;
+            }
+
+            let delegator_quorum = self.get_voting_energy_amount(proposal_id, &delegator);
+            if delegator_quorum == 0 {
+                continue;
+            }
+
+            voting_power += self.smoothing_function(&delegator_quorum);
+            user_quorum += delegator_quorum;
+            self.voted_through_delegate(proposal_id, &delegator)
+                .set(&voter);
+        }
+        require!(user_quorum > 0, "No energy");
+
+        self.proposal_votes(proposal_id).update(|proposal_votes| {
+            proposal_votes.add_vote(vote, &voting_power);
+            proposal_votes.quorum += &user_quorum;
+        });
+
+        self.emit_vote_cast_event(&voter, proposal_id, vote, &voting_power, &user_quorum);
+
+        let _ = self.proposal_voters(proposal_id).insert(voter);
+        vote_record_mapper.set(VoteRecord {
+            vote_type: vote,
+            voting_power,
+            user_quorum,
+        });
+    }
+
+    fn get_voting_energy_amount(&self, proposal_id: ProposalId, user: &ManagedAddress) -> BigUint {
+        let snapshot_epoch_mapper = self.proposal_snapshot_epoch(proposal_id);
+        if snapshot_epoch_mapper.is_empty() {
+            // proposals created before energy snapshots use the current energy
+            return self.get_energy_amount(user);
+        }
+
+        self.get_energy_amount_at_epoch(user, snapshot_epoch_mapper.get())
+    }
+
+    /// Proposals created before the total quorum was set on creation get it on their first vote
+    fn init_legacy_proposal_total_quorum(&self, proposal_id: ProposalId) {
         let current_quorum = self.proposal_votes(proposal_id).get().quorum;
+        if current_quorum != 0 {
+            return;
+        }
 
-        // First voter -> update total_energy
-        if current_quorum == BigUint::zero() {
-            let fees_collector_addr = self.fees_collector_address().get();
-            let last_global_update_week: Week = self
-                .fees_collector_proxy(fees_collector_addr.clone())
-                .last_global_update_week()
-                .execute_on_dest_context();
-
-            let total_quorum: BigUint = self
-                .fees_collector_proxy(fees_collector_addr)
-                .total_energy_for_week(last_global_update_week)
-                .execute_on_dest_context();
-
-            let mut proposal = self.proposals().get(proposal_id);
-            proposal.total_quorum = total_quorum;
-            self.proposals().set(proposal_id, &proposal);
+        let mut proposal = self.proposals().get(proposal_id);
+        if proposal.total_quorum != 0 && !self.proposal_snapshot_epoch(proposal_id).is_empty() {
+            return;
         }
 
-        let user_quorum = self.get_energy_amount_non_zero(&voter);
-        let voting_power = self.smoothing_function(&user_quorum);
+        proposal.total_quorum = self.get_total_energy_from_fees_collector();
+        self.proposals().set(proposal_id, &proposal);
+    }
+
+    fn get_total_energy_from_fees_collector(&self) -> BigUint {
+        let fees_collector_addr = self.fees_collector_address().get();
+        let last_global_update_week: Week = self
+            .fees_collector_proxy(fees_collector_addr.clone())
+            .last_global_update_week()
+            .execute_on_dest_context();
+
+        self.fees_collector_proxy(fees_collector_addr)
+            .total_energy_for_week(last_global_update_week)
+            .execute_on_dest_context()
+    }
 
+    fn change_vote(&self, proposal_id: ProposalId, voter: &ManagedAddress, new_vote: VoteType) {
+        let mut vote_record = self.proposal_vote_record(proposal_id, voter).get();
+        let old_vote = vote_record.vote_type;
+        require!(old_vote != new_vote, ALREADY_VOTED_ERR_MSG);
+
+        self.proposal_votes(proposal_id).update(|proposal_votes| {
+            proposal_votes.remove_vote(old_vote, &vote_record.voting_power);
+            proposal_votes.add_vote(new_vote, &vote_record.voting_power);
+        });
+
+        vote_record.vote_type = new_vote;
+        self.proposal_vote_record(proposal_id, voter)
+            .set(&vote_record);
+
+        self.vote_changed_event(voter, proposal_id, old_vote, new_vote);
+    }
+
+    fn emit_vote_cast_event(
+        &self,
+        voter: &ManagedAddress,
+        proposal_id: ProposalId,
+        vote: VoteType,
+        voting_power: &BigUint,
+        user_quorum: &BigUint,
+    ) {
         match vote {
             VoteType::UpVote => {
-                self.proposal_votes(proposal_id).update(|proposal_votes| {
-                    proposal_votes.up_votes += &voting_power.clone();
-                    proposal_votes.quorum += &user_quorum.clone();
-                });
-                self.up_vote_cast_event(&voter, proposal_id, &voting_power, &user_quorum);
+                self.up_vote_cast_event(voter, proposal_id, voting_power, user_quorum);
             }
             VoteType::DownVote => {
-                self.proposal_votes(proposal_id).update(|proposal_votes| {
-                    proposal_votes.down_votes += &voting_power.clone();
-                    proposal_votes.quorum += &user_quorum.clone();
-                });
-                self.down_vote_cast_event(&voter, proposal_id, &voting_power, &user_quorum);
+                self.down_vote_cast_event(voter, proposal_id, voting_power, user_quorum);
             }
             VoteType::DownVetoVote => {
-                self.proposal_votes(proposal_id).update(|proposal_votes| {
-                    proposal_votes.down_veto_votes += &voting_power.clone();
-                    proposal_votes.quorum += &user_quorum.clone();
-                });
-                self.down_veto_vote_cast_event(&voter, proposal_id, &voting_power, &user_quorum);
+                self.down_veto_vote_cast_event(voter, proposal_id, voting_power, user_quorum);
             }
             VoteType::AbstainVote => {
-                self.proposal_votes(proposal_id).update(|proposal_votes| {
-                    proposal_votes.abstain_votes += &voting_power.clone();
-                    proposal_votes.quorum += &user_quorum.clone();
-                });
-                self.abstain_vote_cast_event(&voter, proposal_id, &voting_power, &user_quorum);
+                self.abstain_vote_cast_event(voter, proposal_id, voting_power, user_quorum);
             }
         }
     }
@@ -249,7 +538,11 @@ pub trait GovernanceV2:
             GovernanceProposalStatus::None => {
                 sc_panic!(NO_PROPOSAL);
             }
-            GovernanceProposalStatus::Succeeded | GovernanceProposalStatus::Defeated => {
+            GovernanceProposalStatus::Succeeded
+            | GovernanceProposalStatus::Queued
+            | GovernanceProposalStatus::Executed
+            | GovernanceProposalStatus::Expired
+            | GovernanceProposalStatus::Defeated => {
                 let mut proposal = self.proposals().get(proposal_id);
 
                 require!(caller == proposal.proposer, ONLY_PROPOSER_WITHDRAW);
@@ -288,6 +581,106 @@ pub trait GovernanceV2:
         self.proposal_withdraw_after_defeated_event(proposal_id);
     }
 
+    /// Queue a succeeded proposal for execution.
+    /// The actions can be executed after the lock time passes, and until the execution period ends.
+    #[endpoint]
+    fn queue(&self, proposal_id: ProposalId) {
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Succeeded,
+            PROPOSAL_NOT_SUCCEEDED
+        );
+
+        let current_block = self.blockchain().get_block_nonce();
+        let execution_start_block =
+            current_block + self.lock_time_after_voting_ends_in_blocks().get();
+        let execution_end_block = execution_start_block + self.execution_period_in_blocks().get();
+        self.proposal_timelock(proposal_id).set(ProposalTimelock {
+            queued_block: current_block,
+            execution_start_block,
+            execution_end_block,
+        });
+
+        self.proposal_queued_event(proposal_id, execution_start_block, execution_end_block);
+    }
+
+    /// Execute the actions of a queued proposal, after its lock time passed.
+    /// Each action is launched separately, and its result is recorded once it finishes,
+    /// so a failed action does not prevent the others from being executed.
+    #[endpoint]
+    fn execute(&self, proposal_id: ProposalId) {
+        require!(
+            self.get_proposal_status(proposal_id) == GovernanceProposalStatus::Queued,
+            PROPOSAL_NOT_QUEUED
+        );
+
+        let current_block = self.blockchain().get_block_nonce();
+        let timelock = self.proposal_timelock(proposal_id).get();
+        require!(
+            current_block >= timelock.execution_start_block,
+            TIMELOCK_NOT_PASSED
+        );
+
+        let proposal = self.proposals().get(proposal_id);
+        let gas_needed = self.total_gas_needed(&proposal.actions)
+            + proposal.actions.len() as u64 * ACTION_CALLBACK_GAS
+            + MIN_GAS_FOR_EXECUTE;
+        require!(
+            self.blockchain().get_gas_left() >= gas_needed,
+            NOT_ENOUGH_GAS_FOR_EXECUTION
+        );
+
+        self.proposal_executed(proposal_id).set(true);
+
+        for (action_index, action) in proposal.actions.into_iter().enumerate() {
+            self.action_execution_status(proposal_id, action_index)
+                .set(ActionExecutionStatus::Pending);
+
+            self.tx()
+                .to(&action.dest_address)
+                .raw_call(action.function_name)
+                .arguments_raw(action.arguments.into())
+                .gas(action.gas_limit)
+                .callback(
+                    self.callbacks()
+                        .execute_action_callback(proposal_id, action_index),
+                )
+                .gas_for_callback(ACTION_CALLBACK_GAS)
+                .register_promise();
+        }
+
+        self.proposal_executed_event(proposal_id);
+    }
+
+    #[promises_callback]
+    fn execute_action_callback(
+        &self,
+        proposal_id: ProposalId,
+        action_index: usize,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                self.action_execution_status(proposal_id, action_index)
+                    .set(ActionExecutionStatus::Succeeded);
+                self.action_execution_succeeded_event(proposal_id, action_index);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                self.action_execution_failed_event(
+                    proposal_id,
+                    action_index,
+                    err.err_code,
+                    &err.err_msg,
+                );
+                self.action_execution_status(proposal_id, action_index).set(
+                    ActionExecutionStatus::Failed {
+                        err_code: err.err_code,
+                        err_msg: err.err_msg,
+                    },
+                );
+            }
+        }
+    }
+
     fn total_gas_needed(
         &self,
         actions: &ArrayVec<GovernanceAction<Self::Api>, MAX_GOVERNANCE_PROPOSAL_ACTIONS>,
@@ -18,6 +18,27 @@ pub enum GovernanceProposalStatus {
     Defeated,
     DefeatedWithVeto,
     Succeeded,
+    Queued,
+    Executed,
+    Expired,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct ProposalTimelock {
+    pub queued_block: u64,
+    pub execution_start_block: u64,
+    pub execution_end_block: u64,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum ActionExecutionStatus<M: ManagedTypeApi> {
+    NotExecuted,
+    Pending,
+    Succeeded,
+    Failed {
+        err_code: u32,
+        err_msg: ManagedBuffer<M>,
+    },
 }
 
 #[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
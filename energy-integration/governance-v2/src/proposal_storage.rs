@@ -1,9 +1,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::proposal::{GovernanceProposal, ProposalId};
+use crate::proposal::{ActionExecutionStatus, GovernanceProposal, ProposalId, ProposalTimelock};
 
-#[derive(TypeAbi, TopEncode, TopDecode)]
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
 pub enum VoteType {
     UpVote,
     DownVote,
@@ -11,6 +13,13 @@ pub enum VoteType {
     AbstainVote,
 }
 
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Debug)]
+pub struct VoteRecord<M: ManagedTypeApi> {
+    pub vote_type: VoteType,
+    pub voting_power: BigUint<M>,
+    pub user_quorum: BigUint<M>,
+}
+
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
 pub struct ProposalVotes<M: ManagedTypeApi> {
     pub up_votes: BigUint<M>,
@@ -39,6 +48,29 @@ impl<M: ManagedTypeApi> ProposalVotes<M> {
     pub fn get_total_votes(&self) -> BigUint<M> {
         &self.up_votes + &self.down_votes + &self.down_veto_votes + &self.abstain_votes
     }
+
+    /// Abstain votes only count towards the quorum
+    pub fn get_total_non_abstain_votes(&self) -> BigUint<M> {
+        &self.up_votes + &self.down_votes + &self.down_veto_votes
+    }
+
+    pub fn add_vote(&mut self, vote_type: VoteType, voting_power: &BigUint<M>) {
+        match vote_type {
+            VoteType::UpVote => self.up_votes += voting_power,
+            VoteType::DownVote => self.down_votes += voting_power,
+            VoteType::DownVetoVote => self.down_veto_votes += voting_power,
+            VoteType::AbstainVote => self.abstain_votes += voting_power,
+        }
+    }
+
+    pub fn remove_vote(&mut self, vote_type: VoteType, voting_power: &BigUint<M>) {
+        match vote_type {
+            VoteType::UpVote => self.up_votes -= voting_power,
+            VoteType::DownVote => self.down_votes -= voting_power,
+            VoteType::DownVetoVote => self.down_veto_votes -= voting_power,
+            VoteType::AbstainVote => self.abstain_votes -= voting_power,
+        }
+    }
 }
 
 #[multiversx_sc::module]
@@ -62,4 +94,42 @@ pub trait ProposalStorageModule {
         &self,
         proposal_id: ProposalId,
     ) -> SingleValueMapper<ProposalVotes<Self::Api>>;
+
+    #[view(getProposalVoteRecord)]
+    #[storage_mapper("proposalVoteRecord")]
+    fn proposal_vote_record(
+        &self,
+        proposal_id: ProposalId,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<VoteRecord<Self::Api>>;
+
+    #[view(getProposalVoters)]
+    #[storage_mapper("proposalVoters")]
+    fn proposal_voters(&self, proposal_id: ProposalId) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// The delegate that included the user's energy in their vote
+    #[storage_mapper("votedThroughDelegate")]
+    fn voted_through_delegate(
+        &self,
+        proposal_id: ProposalId,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getProposalTimelock)]
+    #[storage_mapper("proposalTimelock")]
+    fn proposal_timelock(&self, proposal_id: ProposalId) -> SingleValueMapper<ProposalTimelock>;
+
+    #[storage_mapper("proposalExecuted")]
+    fn proposal_executed(&self, proposal_id: ProposalId) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("actionExecutionStatus")]
+    fn action_execution_status(
+        &self,
+        proposal_id: ProposalId,
+        action_index: usize,
+    ) -> SingleValueMapper<ActionExecutionStatus<Self::Api>>;
+
+    #[view(getProposalSnapshotEpoch)]
+    #[storage_mapper("proposalSnapshotEpoch")]
+    fn proposal_snapshot_epoch(&self, proposal_id: ProposalId) -> SingleValueMapper<u64>;
 }
@@ -0,0 +1,294 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::proposal::{GovernanceAction, ProposalId};
+
+pub const TEMPLATE_ACTION_GAS_LIMIT: u64 = 20_000_000;
+pub const MAX_PAIR_FEE_PERCENTAGE: u64 = 5_000;
+
+static SET_FEE_PERCENTS_ENDPOINT: &[u8] = b"setFeePercents";
+static SET_PER_BLOCK_REWARD_AMOUNT_ENDPOINT: &[u8] = b"setPerBlockRewardAmount";
+static SET_BOOSTED_YIELDS_FACTORS_ENDPOINT: &[u8] = b"setBoostedYieldsFactors";
+static ADD_KNOWN_CONTRACTS_ENDPOINT: &[u8] = b"addKnownContracts";
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
+pub enum KnownContractType {
+    Pair,
+    Farm,
+}
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum ProposalTemplate<M: ManagedTypeApi> {
+    SetPairFeePercents {
+        pair_address: ManagedAddress<M>,
+        total_fee_percent: u64,
+        special_fee_percent: u64,
+    },
+    SetFarmPerBlockRewardAmount {
+        farm_address: ManagedAddress<M>,
+        per_block_amount: BigUint<M>,
+    },
+    SetBoostedYieldsFactors {
+        farm_address: ManagedAddress<M>,
+        max_rewards_factor: BigUint<M>,
+        user_rewards_energy_const: BigUint<M>,
+        user_rewards_farm_const: BigUint<M>,
+        min_energy_amount: BigUint<M>,
+        min_farm_amount: BigUint<M>,
+    },
+    WhitelistPairInFeesCollector {
+        pair_address: ManagedAddress<M>,
+    },
+}
+
+/// Human-readable form of a proposal action.
+/// Actions that do not match any template are returned as they are.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum TypedGovernanceAction<M: ManagedTypeApi> {
+    Template(ProposalTemplate<M>),
+    Raw(GovernanceAction<M>),
+}
+
+impl<M: ManagedTypeApi> ProposalTemplate<M> {
+    pub fn into_action(self, fees_collector_address: ManagedAddress<M>) -> GovernanceAction<M> {
+        let (dest_address, function_name, arguments) = match self {
+            ProposalTemplate::SetPairFeePercents {
+                pair_address,
+                total_fee_percent,
+                special_fee_percent,
+            } => (
+                pair_address,
+                SET_FEE_PERCENTS_ENDPOINT,
+                ManagedVec::from_iter([
+                    encode_arg(&total_fee_percent),
+                    encode_arg(&special_fee_percent),
+                ]),
+            ),
+            ProposalTemplate::SetFarmPerBlockRewardAmount {
+                farm_address,
+                per_block_amount,
+            } => (
+                farm_address,
+                SET_PER_BLOCK_REWARD_AMOUNT_ENDPOINT,
+                ManagedVec::from_single_item(encode_arg(&per_block_amount)),
+            ),
+            ProposalTemplate::SetBoostedYieldsFactors {
+                farm_address,
+                max_rewards_factor,
+                user_rewards_energy_const,
+                user_rewards_farm_const,
+                min_energy_amount,
+                min_farm_amount,
+            } => (
+                farm_address,
+                SET_BOOSTED_YIELDS_FACTORS_ENDPOINT,
+                ManagedVec::from_iter([
+                    encode_arg(&max_rewards_factor),
+                    encode_arg(&user_rewards_energy_const),
+                    encode_arg(&user_rewards_farm_const),
+                    encode_arg(&min_energy_amount),
+                    encode_arg(&min_farm_amount),
+                ]),
+            ),
+            ProposalTemplate::WhitelistPairInFeesCollector { pair_address } => (
+                fees_collector_address,
+                ADD_KNOWN_CONTRACTS_ENDPOINT,
+                ManagedVec::from_single_item(encode_arg(&pair_address)),
+            ),
+        };
+
+        GovernanceAction {
+            gas_limit: TEMPLATE_ACTION_GAS_LIMIT,
+            dest_address,
+            function_name: ManagedBuffer::new_from_bytes(function_name),
+            arguments,
+        }
+    }
+
+    /// Only matches on the endpoint name and arguments.
+    /// The destination address is checked separately against the known contracts.
+    pub fn try_from_action(
+        action: &GovernanceAction<M>,
+        fees_collector_address: &ManagedAddress<M>,
+    ) -> Option<Self> {
+        let function_name = &action.function_name;
+        let args = &action.arguments;
+        if *function_name == *SET_FEE_PERCENTS_ENDPOINT && args.len() == 2 {
+            return Some(ProposalTemplate::SetPairFeePercents {
+                pair_address: action.dest_address.clone(),
+                total_fee_percent: decode_arg(&args.get(0))?,
+                special_fee_percent: decode_arg(&args.get(1))?,
+            });
+        }
+        if *function_name == *SET_PER_BLOCK_REWARD_AMOUNT_ENDPOINT && args.len() == 1 {
+            return Some(ProposalTemplate::SetFarmPerBlockRewardAmount {
+                farm_address: action.dest_address.clone(),
+                per_block_amount: decode_arg(&args.get(0))?,
+            });
+        }
+        if *function_name == *SET_BOOSTED_YIELDS_FACTORS_ENDPOINT && args.len() == 5 {
+            return Some(ProposalTemplate::SetBoostedYieldsFactors {
+                farm_address: action.dest_address.clone(),
+                max_rewards_factor: decode_arg(&args.get(0))?,
+                user_rewards_energy_const: decode_arg(&args.get(1))?,
+                user_rewards_farm_const: decode_arg(&args.get(2))?,
+                min_energy_amount: decode_arg(&args.get(3))?,
+                min_farm_amount: decode_arg(&args.get(4))?,
+            });
+        }
+        if *function_name == *ADD_KNOWN_CONTRACTS_ENDPOINT
+            && args.len() == 1
+            && &action.dest_address == fees_collector_address
+        {
+            return Some(ProposalTemplate::WhitelistPairInFeesCollector {
+                pair_address: decode_arg(&args.get(0))?,
+            });
+        }
+
+        None
+    }
+
+    /// The known contracts the template targets, besides the fees collector
+    pub fn get_target_contract(&self) -> (KnownContractType, &ManagedAddress<M>) {
+        match self {
+            ProposalTemplate::SetPairFeePercents { pair_address, .. } => {
+                (KnownContractType::Pair, pair_address)
+            }
+            ProposalTemplate::SetFarmPerBlockRewardAmount { farm_address, .. } => {
+                (KnownContractType::Farm, farm_address)
+            }
+            ProposalTemplate::SetBoostedYieldsFactors { farm_address, .. } => {
+                (KnownContractType::Farm, farm_address)
+            }
+            ProposalTemplate::WhitelistPairInFeesCollector { pair_address } => {
+                (KnownContractType::Pair, pair_address)
+            }
+        }
+    }
+}
+
+fn encode_arg<M: ManagedTypeApi, T: TopEncode>(arg: &T) -> ManagedBuffer<M> {
+    let mut encoded = ManagedBuffer::new();
+    let _ = arg.top_encode(&mut encoded);
+
+    encoded
+}
+
+fn decode_arg<M: ManagedTypeApi, T: TopDecode>(arg: &ManagedBuffer<M>) -> Option<T> {
+    T::top_decode(arg.clone()).ok()
+}
+
+#[multiversx_sc::module]
+pub trait ProposalTemplatesModule:
+    crate::proposal_storage::ProposalStorageModule
+    + crate::configurable::ConfigurablePropertiesModule
+    + crate::views::ViewsModule
+    + permissions_module::PermissionsModule
+    + energy_query::EnergyQueryModule
+{
+    #[only_owner]
+    #[endpoint(addKnownContracts)]
+    fn add_known_contracts(
+        &self,
+        contract_type: KnownContractType,
+        contracts: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut mapper = self.known_contracts(contract_type);
+        for sc in contracts {
+            require!(
+                self.blockchain().is_smart_contract(&sc),
+                "Invalid SC address"
+            );
+            let _ = mapper.insert(sc);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeKnownContracts)]
+    fn remove_known_contracts(
+        &self,
+        contract_type: KnownContractType,
+        contracts: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut mapper = self.known_contracts(contract_type);
+        for sc in contracts {
+            let _ = mapper.swap_remove(&sc);
+        }
+    }
+
+    fn validate_proposal_template(&self, template: &ProposalTemplate<Self::Api>) {
+        let (contract_type, target_address) = template.get_target_contract();
+        require!(
+            self.known_contracts(contract_type).contains(target_address),
+            UNKNOWN_TEMPLATE_CONTRACT
+        );
+
+        match template {
+            ProposalTemplate::SetPairFeePercents {
+                total_fee_percent,
+                special_fee_percent,
+                ..
+            } => {
+                require!(
+                    total_fee_percent >= special_fee_percent
+                        && *total_fee_percent <= MAX_PAIR_FEE_PERCENTAGE,
+                    INVALID_TEMPLATE_ARGUMENTS
+                );
+            }
+            ProposalTemplate::SetFarmPerBlockRewardAmount {
+                per_block_amount, ..
+            } => {
+                require!(*per_block_amount > 0, INVALID_TEMPLATE_ARGUMENTS);
+            }
+            ProposalTemplate::SetBoostedYieldsFactors {
+                min_energy_amount,
+                min_farm_amount,
+                ..
+            } => {
+                require!(
+                    *min_energy_amount > 0 && *min_farm_amount > 0,
+                    INVALID_TEMPLATE_ARGUMENTS
+                );
+            }
+            ProposalTemplate::WhitelistPairInFeesCollector { .. } => {}
+        }
+    }
+
+    /// Decodes the proposal's actions into their templates.
+    /// Actions targeting contracts that are not known are returned as raw actions.
+    #[view(getProposalTypedActions)]
+    fn get_proposal_typed_actions(
+        &self,
+        proposal_id: ProposalId,
+    ) -> MultiValueEncoded<TypedGovernanceAction<Self::Api>> {
+        self.require_valid_proposal_id(proposal_id);
+
+        let fees_collector_address = self.fees_collector_address().get();
+        let proposal = self.proposals().get(proposal_id);
+        let mut result = MultiValueEncoded::new();
+        for action in proposal.actions {
+            let opt_template = ProposalTemplate::try_from_action(&action, &fees_collector_address)
+                .filter(|template| {
+                    let (contract_type, target_address) = template.get_target_contract();
+                    self.known_contracts(contract_type).contains(target_address)
+                });
+            let typed_action = match opt_template {
+                Some(template) => TypedGovernanceAction::Template(template),
+                None => TypedGovernanceAction::Raw(action),
+            };
+            result.push(typed_action);
+        }
+
+        result
+    }
+
+    #[view(getKnownContracts)]
+    #[storage_mapper("knownContracts")]
+    fn known_contracts(
+        &self,
+        contract_type: KnownContractType,
+    ) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,62 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::proposal::ProposalId;
+use crate::proposal_storage::VoteType;
+
+pub const MAX_SIGNED_VOTES_PER_BATCH: usize = 50;
+pub const ED25519_SIGNATURE_LEN: usize = 64;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct SignedVote<M: ManagedTypeApi> {
+    pub voter: ManagedAddress<M>,
+    pub proposal_id: ProposalId,
+    pub vote_type: VoteType,
+    pub nonce: u64,
+    pub signature: ManagedByteArray<M, ED25519_SIGNATURE_LEN>,
+}
+
+#[multiversx_sc::module]
+pub trait SignedVoteModule {
+    /// Checks the voter's signature and nonce, and consumes the nonce.
+    ///
+    /// The signed message is the nested encoding of
+    /// (governance SC address, proposal ID, vote type, nonce).
+    fn verify_signed_vote(&self, signed_vote: &SignedVote<Self::Api>) {
+        let nonce_mapper = self.user_vote_nonce(&signed_vote.voter);
+        let expected_nonce = nonce_mapper.get();
+        require!(signed_vote.nonce == expected_nonce, INVALID_VOTE_NONCE);
+
+        let message = self.get_signed_vote_message(
+            signed_vote.proposal_id,
+            signed_vote.vote_type,
+            signed_vote.nonce,
+        );
+        self.crypto().verify_ed25519(
+            signed_vote.voter.as_managed_buffer(),
+            &message,
+            signed_vote.signature.as_managed_buffer(),
+        );
+
+        nonce_mapper.set(expected_nonce + 1);
+    }
+
+    #[view(getSignedVoteMessage)]
+    fn get_signed_vote_message(
+        &self,
+        proposal_id: ProposalId,
+        vote_type: VoteType,
+        nonce: u64,
+    ) -> ManagedBuffer {
+        let mut message = ManagedBuffer::new();
+        let sc_address = self.blockchain().get_sc_address();
+        let _ = (sc_address, proposal_id, vote_type, nonce).dep_encode(&mut message);
+
+        message
+    }
+
+    #[view(getUserVoteNonce)]
+    #[storage_mapper("userVoteNonce")]
+    fn user_vote_nonce(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+}
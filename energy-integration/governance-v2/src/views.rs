@@ -1,7 +1,8 @@
 multiversx_sc::imports!();
 
 use crate::{
-    proposal::{GovernanceProposalStatus, ProposalId},
+    proposal::{ActionExecutionStatus, GovernanceProposalStatus, ProposalId},
+    proposal_storage::VoteRecord,
     FULL_PERCENTAGE,
 };
 
@@ -35,6 +36,20 @@ pub trait ViewsModule:
             return GovernanceProposalStatus::Active;
         }
 
+        if self.proposal_executed(proposal_id).get() {
+            return GovernanceProposalStatus::Executed;
+        }
+
+        let timelock_mapper = self.proposal_timelock(proposal_id);
+        if !timelock_mapper.is_empty() {
+            let timelock = timelock_mapper.get();
+            if current_block >= timelock.execution_end_block {
+                return GovernanceProposalStatus::Expired;
+            }
+
+            return GovernanceProposalStatus::Queued;
+        }
+
         if self.quorum_reached(proposal_id) && self.vote_reached(proposal_id) {
             GovernanceProposalStatus::Succeeded
         } else if self.vote_down_with_veto(proposal_id) {
@@ -44,11 +59,46 @@ pub trait ViewsModule:
         }
     }
 
+    #[view(getProposalActionsExecutionStatus)]
+    fn get_proposal_actions_execution_status(
+        &self,
+        proposal_id: ProposalId,
+    ) -> MultiValueEncoded<ActionExecutionStatus<Self::Api>> {
+        require!(self.proposal_exists(proposal_id), "Proposal does not exist");
+
+        let proposal = self.proposals().get(proposal_id);
+        let mut result = MultiValueEncoded::new();
+        for action_index in 0..proposal.actions.len() {
+            let status_mapper = self.action_execution_status(proposal_id, action_index);
+            if status_mapper.is_empty() {
+                result.push(ActionExecutionStatus::NotExecuted);
+            } else {
+                result.push(status_mapper.get());
+            }
+        }
+
+        result
+    }
+
+    #[view(getProposalVoteBreakdown)]
+    fn get_proposal_vote_breakdown(
+        &self,
+        proposal_id: ProposalId,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, VoteRecord<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for voter in self.proposal_voters(proposal_id).iter() {
+            let vote_record = self.proposal_vote_record(proposal_id, &voter).get();
+            result.push((voter, vote_record).into());
+        }
+
+        result
+    }
+
     // private
 
     fn vote_reached(&self, proposal_id: ProposalId) -> bool {
         let proposal_votes = self.proposal_votes(proposal_id).get();
-        let total_votes = proposal_votes.get_total_votes();
+        let total_votes = proposal_votes.get_total_non_abstain_votes();
         let total_up_votes = proposal_votes.up_votes;
         let total_down_veto_votes = proposal_votes.down_veto_votes;
         let third_total_votes = &total_votes / 3u64;
@@ -63,7 +113,7 @@ pub trait ViewsModule:
 
     fn vote_down_with_veto(&self, proposal_id: ProposalId) -> bool {
         let proposal_votes = self.proposal_votes(proposal_id).get();
-        let total_votes = proposal_votes.get_total_votes();
+        let total_votes = proposal_votes.get_total_non_abstain_votes();
         let total_down_veto_votes = proposal_votes.down_veto_votes;
         let third_total_votes = &total_votes / 3u64;
 
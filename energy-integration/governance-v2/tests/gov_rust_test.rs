@@ -2,13 +2,29 @@
 
 mod gov_test_setup;
 
+use ed25519_dalek::{Keypair, PublicKey, SecretKey};
 use gov_test_setup::*;
+use governance::{config::Config, proposal::ProposalCreationArgs, Governance};
 use governance_v2::{
-    configurable::ConfigurablePropertiesModule, proposal::GovernanceProposalStatus,
-    proposal_storage::ProposalStorageModule, views::ViewsModule,
+    configurable::{ConfigurablePropertiesModule, DEFAULT_EXECUTION_PERIOD},
+    delegation::DelegationModule,
+    legacy_migration::{LegacyAction, LegacyMigrationModule},
+    proposal::{ActionExecutionStatus, GovernanceProposalStatus},
+    proposal_storage::{ProposalStorageModule, VoteType},
+    proposal_templates::{
+        KnownContractType, ProposalTemplate, ProposalTemplatesModule, TypedGovernanceAction,
+        TEMPLATE_ACTION_GAS_LIMIT,
+    },
+    signed_vote::SignedVoteModule,
+    views::ViewsModule,
+    GovernanceV2,
 };
-use multiversx_sc::types::ManagedVec;
-use multiversx_sc_scenario::{managed_buffer, rust_biguint};
+use multiversx_sc::types::{Address, EsdtLocalRole, ManagedVec, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+
+const LEGACY_VOTE_NFT_ID: &[u8] = b"VOTE-123456";
 
 #[test]
 fn init_gov_test() {
@@ -207,12 +223,13 @@ fn gov_abstain_vote_test() {
 
     gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
 
+    // abstain votes only count towards the quorum
     gov_setup
         .b_mock
         .execute_query(&gov_setup.gov_wrapper, |sc| {
             assert!(
-                sc.get_proposal_status(1) == GovernanceProposalStatus::Defeated,
-                "Action should have been Defeated"
+                sc.get_proposal_status(1) == GovernanceProposalStatus::Succeeded,
+                "Action should have been Succeeded"
             );
         })
         .assert_ok();
@@ -257,6 +274,63 @@ fn gov_no_quorum_test() {
         .assert_ok();
 }
 
+#[test]
+fn gov_legacy_proposal_total_quorum_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    // simulate a proposal created before the upgrade
+    let mut expected_total_quorum = 0u64;
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &gov_setup.owner,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut proposal = sc.proposals().get(proposal_id);
+                expected_total_quorum = proposal.total_quorum.to_u64().unwrap();
+                proposal.total_quorum = managed_biguint!(0);
+                sc.proposals().set(proposal_id, &proposal);
+                sc.proposal_snapshot_epoch(proposal_id).clear();
+            },
+        )
+        .assert_ok();
+    assert!(expected_total_quorum > 0);
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+
+    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(
+                sc.proposals().get(proposal_id).total_quorum,
+                managed_biguint!(expected_total_quorum)
+            );
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Defeated);
+        })
+        .assert_ok();
+}
+
 #[test]
 fn gov_modify_quorum_after_end_vote_test() {
     let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
@@ -732,3 +806,853 @@ fn gov_propose_cancel_proposal_id_test() {
         .check_proposal_id_consistency(&first_user_addr, proposal_id)
         .assert_ok();
 }
+
+#[test]
+fn gov_queue_and_execute_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    // the second action fails, as the governance SC is not its own owner
+    let (result, proposal_id) = gov_setup.propose_actions(
+        &first_user_addr,
+        &min_fee,
+        vec![
+            (sc_addr.clone(), b"getQuorum", vec![]),
+            (
+                sc_addr.clone(),
+                b"changeQuorumPercentage",
+                vec![5_000u64.to_be_bytes().to_vec()],
+            ),
+        ],
+    );
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .up_vote(&second_user_addr, proposal_id)
+        .assert_ok();
+
+    // cannot queue before the voting ends
+    gov_setup
+        .queue(&first_user_addr, proposal_id)
+        .assert_user_error("Only succeeded proposals can be queued");
+
+    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
+
+    gov_setup
+        .execute(&first_user_addr, proposal_id)
+        .assert_user_error("Only queued proposals can be executed");
+    gov_setup.queue(&second_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .queue(&second_user_addr, proposal_id)
+        .assert_user_error("Only succeeded proposals can be queued");
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Queued);
+        })
+        .assert_ok();
+
+    gov_setup
+        .execute(&first_user_addr, proposal_id)
+        .assert_user_error("Proposal timelock has not passed yet");
+
+    let mut lock_time = 0;
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            lock_time = sc.lock_time_after_voting_ends_in_blocks().get();
+        })
+        .assert_ok();
+    gov_setup.increment_block_nonce(lock_time);
+
+    gov_setup.execute(&first_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .execute(&first_user_addr, proposal_id)
+        .assert_user_error("Only queued proposals can be executed");
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Executed);
+
+            let statuses: Vec<ActionExecutionStatus<DebugApi>> = sc
+                .get_proposal_actions_execution_status(proposal_id)
+                .into_iter()
+                .collect();
+            assert_eq!(statuses.len(), 2);
+            assert_eq!(statuses[0], ActionExecutionStatus::Succeeded);
+            assert!(matches!(statuses[1], ActionExecutionStatus::Failed { .. }));
+
+            // failed action had no effect
+            assert_eq!(sc.quorum_percentage().get(), QUORUM_PERCENTAGE);
+        })
+        .assert_ok();
+
+    // proposer can still withdraw the fee
+    gov_setup
+        .withdraw_after_defeated(&first_user_addr, proposal_id)
+        .assert_ok();
+    gov_setup
+        .b_mock
+        .check_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+}
+
+#[test]
+fn gov_queued_proposal_expires_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    let (result, proposal_id) =
+        gov_setup.propose(&first_user_addr, &min_fee, &sc_addr, b"getQuorum", vec![]);
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .up_vote(&second_user_addr, proposal_id)
+        .assert_ok();
+    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
+
+    gov_setup.queue(&first_user_addr, proposal_id).assert_ok();
+
+    let mut lock_time = 0;
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            lock_time = sc.lock_time_after_voting_ends_in_blocks().get();
+        })
+        .assert_ok();
+    gov_setup.increment_block_nonce(lock_time + DEFAULT_EXECUTION_PERIOD);
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Expired);
+        })
+        .assert_ok();
+
+    gov_setup
+        .execute(&first_user_addr, proposal_id)
+        .assert_user_error("Only queued proposals can be executed");
+}
+
+#[test]
+fn gov_delegate_and_change_vote_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    gov_setup
+        .delegate_voting_power(&first_user_addr, &first_user_addr)
+        .assert_user_error("Cannot delegate to self");
+    gov_setup
+        .delegate_voting_power(&second_user_addr, &first_user_addr)
+        .assert_ok();
+    gov_setup
+        .delegate_voting_power(&first_user_addr, &third_user_addr)
+        .assert_user_error("Users with delegators cannot delegate");
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let (quorum, voting_power) = sc
+                .get_delegate_voting_power(managed_address!(&first_user_addr))
+                .into_tuple();
+            assert_eq!(quorum, managed_biguint!(2 * USER_ENERGY));
+            assert_eq!(voting_power, managed_biguint!(2_000));
+        })
+        .assert_ok();
+
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup
+        .up_vote(&second_user_addr, proposal_id)
+        .assert_user_error("Voting power is delegated");
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+    gov_setup
+        .down_vote(&third_user_addr, proposal_id)
+        .assert_ok();
+
+    // delegator cannot vote after revoking, as their energy was already used
+    gov_setup.revoke_delegation(&second_user_addr).assert_ok();
+    gov_setup
+        .up_vote(&second_user_addr, proposal_id)
+        .assert_user_error("Already voted through delegate");
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal_votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(proposal_votes.up_votes, managed_biguint!(2_000));
+            assert_eq!(proposal_votes.down_votes, managed_biguint!(1_100));
+            assert_eq!(sc.get_proposal_vote_breakdown(proposal_id).len(), 2);
+        })
+        .assert_ok();
+
+    // change vote while active
+    gov_setup
+        .up_vote(&first_user_addr, proposal_id)
+        .assert_user_error("Already voted for this proposal");
+    gov_setup
+        .down_vote(&first_user_addr, proposal_id)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal_votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(proposal_votes.up_votes, managed_biguint!(0));
+            assert_eq!(proposal_votes.down_votes, managed_biguint!(3_100));
+            assert_eq!(
+                proposal_votes.quorum,
+                managed_biguint!(3 * USER_ENERGY + 210_000)
+            );
+
+            let vote_record = sc
+                .proposal_vote_record(proposal_id, &managed_address!(&first_user_addr))
+                .get();
+            assert!(vote_record.vote_type == VoteType::DownVote);
+        })
+        .assert_ok();
+
+    gov_setup.increment_block_nonce(LOCKING_PERIOD_BLOCKS);
+
+    gov_setup
+        .down_vote(&first_user_addr, proposal_id)
+        .assert_user_error("Proposal is not active");
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Defeated);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_redelegate_after_delegate_voted_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let third_user_addr = gov_setup.third_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    gov_setup
+        .delegate_voting_power(&second_user_addr, &first_user_addr)
+        .assert_ok();
+
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+
+    // the delegator moves to another delegate, who votes afterwards
+    gov_setup.revoke_delegation(&second_user_addr).assert_ok();
+    gov_setup
+        .delegate_voting_power(&second_user_addr, &third_user_addr)
+        .assert_ok();
+    gov_setup
+        .down_vote(&third_user_addr, proposal_id)
+        .assert_ok();
+
+    // the delegator's energy is only counted through the first delegate
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal_votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(proposal_votes.up_votes, managed_biguint!(2_000));
+            assert_eq!(proposal_votes.down_votes, managed_biguint!(1_100));
+            assert_eq!(
+                proposal_votes.quorum,
+                managed_biguint!(3 * USER_ENERGY + 210_000)
+            );
+
+            let vote_record = sc
+                .proposal_vote_record(proposal_id, &managed_address!(&third_user_addr))
+                .get();
+            assert_eq!(
+                vote_record.user_quorum,
+                managed_biguint!(USER_ENERGY + 210_000)
+            );
+            assert_eq!(
+                sc.voted_through_delegate(proposal_id, &managed_address!(&second_user_addr))
+                    .get(),
+                managed_address!(&first_user_addr)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_vote_with_energy_snapshot_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let second_user_addr = gov_setup.second_user.clone();
+    let no_energy_user_addr = gov_setup.no_energy_user.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    gov_setup.b_mock.set_block_epoch(5);
+
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    // total quorum is set when the proposal is created
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert_eq!(sc.proposal_snapshot_epoch(proposal_id).get(), 4);
+            assert_eq!(
+                sc.proposals().get(proposal_id).total_quorum,
+                managed_biguint!(3 * USER_ENERGY + 210_000)
+            );
+        })
+        .assert_ok();
+
+    // energy gained after the snapshot epoch is not taken into account
+    gov_setup.set_user_energy(&second_user_addr, 100 * USER_ENERGY);
+    gov_setup.set_user_energy(&no_energy_user_addr, USER_ENERGY);
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    gov_setup
+        .up_vote(&no_energy_user_addr, proposal_id)
+        .assert_user_error("No energy");
+    gov_setup
+        .up_vote(&second_user_addr, proposal_id)
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal_votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(proposal_votes.up_votes, managed_biguint!(1_000));
+            assert_eq!(proposal_votes.quorum, managed_biguint!(USER_ENERGY));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_vote_batch_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let relayer_addr = gov_setup.owner.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &min_fee);
+
+    // the voter's address is their ed25519 public key
+    let secret = SecretKey::from_bytes(&[7u8; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    let keypair = Keypair { secret, public };
+    let voter_addr = Address::from(public.to_bytes());
+    gov_setup
+        .b_mock
+        .create_user_account_fixed_address(&voter_addr, &rust_biguint!(0));
+    gov_setup.set_user_energy(&voter_addr, USER_ENERGY);
+
+    let (result, proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    gov_setup.increment_block_nonce(VOTING_PERIOD_BLOCKS);
+
+    let up_vote_signature = gov_setup.sign_vote(&keypair, proposal_id, VoteType::UpVote, 0);
+    let up_vote = (
+        voter_addr.clone(),
+        proposal_id,
+        VoteType::UpVote,
+        0,
+        up_vote_signature,
+    );
+    gov_setup
+        .vote_batch(&relayer_addr, vec![up_vote.clone()])
+        .assert_ok();
+
+    // replayed votes are rejected
+    gov_setup
+        .vote_batch(&relayer_addr, vec![up_vote])
+        .assert_user_error("Invalid vote nonce");
+
+    // signature does not match the submitted vote
+    let down_vote_signature = gov_setup.sign_vote(&keypair, proposal_id, VoteType::DownVote, 1);
+    gov_setup
+        .vote_batch(
+            &relayer_addr,
+            vec![(
+                voter_addr.clone(),
+                proposal_id,
+                VoteType::DownVetoVote,
+                1,
+                down_vote_signature,
+            )],
+        )
+        .assert_error(10, "invalid signature");
+
+    // the signed vote is applied like a regular vote change
+    gov_setup
+        .vote_batch(
+            &relayer_addr,
+            vec![(
+                voter_addr.clone(),
+                proposal_id,
+                VoteType::DownVote,
+                1,
+                down_vote_signature,
+            )],
+        )
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal_votes = sc.proposal_votes(proposal_id).get();
+            assert_eq!(proposal_votes.up_votes, managed_biguint!(0));
+            assert_eq!(proposal_votes.down_votes, managed_biguint!(1_000));
+            assert_eq!(proposal_votes.quorum, managed_biguint!(USER_ENERGY));
+            assert_eq!(sc.user_vote_nonce(&managed_address!(&voter_addr)).get(), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_propose_from_templates_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let owner_addr = gov_setup.owner.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let min_fee = rust_biguint!(MIN_FEE_FOR_PROPOSE) * DECIMALS_CONST;
+    gov_setup
+        .b_mock
+        .set_esdt_balance(&first_user_addr, MEX_TOKEN_ID, &(min_fee.clone() * 2u64));
+
+    let pair_wrapper = gov_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner_addr),
+        energy_factory_mock::contract_obj as EnergyFactoryBuilder,
+        "pair path",
+    );
+    let farm_wrapper = gov_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner_addr),
+        energy_factory_mock::contract_obj as EnergyFactoryBuilder,
+        "farm path",
+    );
+    let pair_addr = pair_wrapper.address_ref().clone();
+    let farm_addr = farm_wrapper.address_ref().clone();
+
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut pairs = MultiValueEncoded::new();
+                pairs.push(managed_address!(&pair_addr));
+                sc.add_known_contracts(KnownContractType::Pair, pairs);
+
+                let mut farms = MultiValueEncoded::new();
+                farms.push(managed_address!(&farm_addr));
+                sc.add_known_contracts(KnownContractType::Farm, farms);
+            },
+        )
+        .assert_ok();
+
+    // farm is not a known pair
+    gov_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user_addr,
+            &gov_setup.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            &min_fee,
+            |sc| {
+                let mut templates = MultiValueEncoded::new();
+                templates.push(ProposalTemplate::SetPairFeePercents {
+                    pair_address: managed_address!(&farm_addr),
+                    total_fee_percent: 300,
+                    special_fee_percent: 50,
+                });
+                let _ = sc.propose_from_templates(managed_buffer!(b"fees"), templates);
+            },
+        )
+        .assert_user_error("Template targets an unknown contract");
+
+    // fee above the pair's maximum
+    gov_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user_addr,
+            &gov_setup.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            &min_fee,
+            |sc| {
+                let mut templates = MultiValueEncoded::new();
+                templates.push(ProposalTemplate::SetPairFeePercents {
+                    pair_address: managed_address!(&pair_addr),
+                    total_fee_percent: 6_000,
+                    special_fee_percent: 50,
+                });
+                let _ = sc.propose_from_templates(managed_buffer!(b"fees"), templates);
+            },
+        )
+        .assert_user_error("Invalid template arguments");
+
+    let mut proposal_id = 0;
+    gov_setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user_addr,
+            &gov_setup.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            &min_fee,
+            |sc| {
+                let mut templates = MultiValueEncoded::new();
+                templates.push(ProposalTemplate::SetPairFeePercents {
+                    pair_address: managed_address!(&pair_addr),
+                    total_fee_percent: 300,
+                    special_fee_percent: 50,
+                });
+                templates.push(ProposalTemplate::SetFarmPerBlockRewardAmount {
+                    farm_address: managed_address!(&farm_addr),
+                    per_block_amount: managed_biguint!(1_000),
+                });
+                templates.push(ProposalTemplate::WhitelistPairInFeesCollector {
+                    pair_address: managed_address!(&pair_addr),
+                });
+                proposal_id = sc.propose_from_templates(managed_buffer!(b"fees"), templates);
+            },
+        )
+        .assert_ok();
+
+    let (result, raw_proposal_id) = gov_setup.propose(
+        &first_user_addr,
+        &min_fee,
+        &sc_addr,
+        b"changeTODO",
+        vec![1_000u64.to_be_bytes().to_vec()],
+    );
+    result.assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            // templates are stored as regular actions
+            let proposal = sc.proposals().get(proposal_id);
+            assert_eq!(proposal.actions.len(), 3);
+            assert_eq!(
+                proposal.actions[0].dest_address,
+                managed_address!(&pair_addr)
+            );
+            assert_eq!(
+                proposal.actions[0].function_name,
+                managed_buffer!(b"setFeePercents")
+            );
+            assert_eq!(proposal.actions[0].gas_limit, TEMPLATE_ACTION_GAS_LIMIT);
+            assert_eq!(
+                proposal.actions[0].arguments.get(0).clone_value(),
+                managed_buffer!(&300u64.to_be_bytes()[6..])
+            );
+            assert_eq!(
+                proposal.actions[2].function_name,
+                managed_buffer!(b"addKnownContracts")
+            );
+
+            let typed_actions = sc
+                .get_proposal_typed_actions(proposal_id)
+                .into_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(typed_actions.len(), 3);
+            assert_eq!(
+                typed_actions[0],
+                TypedGovernanceAction::Template(ProposalTemplate::SetPairFeePercents {
+                    pair_address: managed_address!(&pair_addr),
+                    total_fee_percent: 300,
+                    special_fee_percent: 50,
+                })
+            );
+            assert_eq!(
+                typed_actions[1],
+                TypedGovernanceAction::Template(ProposalTemplate::SetFarmPerBlockRewardAmount {
+                    farm_address: managed_address!(&farm_addr),
+                    per_block_amount: managed_biguint!(1_000),
+                })
+            );
+            assert_eq!(
+                typed_actions[2],
+                TypedGovernanceAction::Template(ProposalTemplate::WhitelistPairInFeesCollector {
+                    pair_address: managed_address!(&pair_addr),
+                })
+            );
+
+            // actions not matching a template are returned as they are
+            let raw_typed_actions = sc
+                .get_proposal_typed_actions(raw_proposal_id)
+                .into_iter()
+                .collect::<Vec<_>>();
+            assert!(matches!(
+                raw_typed_actions[0],
+                TypedGovernanceAction::Raw(_)
+            ));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn gov_import_legacy_proposals_test() {
+    let mut gov_setup = GovSetup::new(governance_v2::contract_obj);
+
+    let first_user_addr = gov_setup.first_user.clone();
+    let owner_addr = gov_setup.owner.clone();
+    let sc_addr = gov_setup.gov_wrapper.address_ref().clone();
+    let legacy_voting_delay = 10;
+    let legacy_voting_period = 100;
+    let legacy_min_weight = 1_000;
+
+    let legacy_wrapper = gov_setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&owner_addr),
+        governance::contract_obj,
+        "legacy governance path",
+    );
+    gov_setup
+        .b_mock
+        .execute_tx(&owner_addr, &legacy_wrapper, &rust_biguint!(0), |sc| {
+            sc.init(
+                managed_biguint!(1_000),
+                legacy_voting_delay,
+                legacy_voting_period,
+                managed_token_id!(LEGACY_VOTE_NFT_ID),
+                managed_token_id!(MEX_TOKEN_ID),
+                managed_biguint!(legacy_min_weight),
+                ManagedVec::from_single_item(managed_token_id!(MEX_TOKEN_ID)),
+                MultiValueEncoded::new(),
+            );
+        })
+        .assert_ok();
+    gov_setup.b_mock.set_esdt_local_roles(
+        legacy_wrapper.address_ref(),
+        LEGACY_VOTE_NFT_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn][..],
+    );
+    gov_setup.b_mock.set_esdt_balance(
+        &first_user_addr,
+        MEX_TOKEN_ID,
+        &rust_biguint!(2 * legacy_min_weight),
+    );
+
+    // legacy proposal 0 at block 0, legacy proposal 1 at block 50
+    for _ in 0..2 {
+        gov_setup
+            .b_mock
+            .execute_esdt_transfer(
+                &first_user_addr,
+                &legacy_wrapper,
+                MEX_TOKEN_ID,
+                0,
+                &rust_biguint!(legacy_min_weight),
+                |sc| {
+                    let mut actions = ManagedVec::new();
+                    actions.push(LegacyAction {
+                        gas_limit: GAS_LIMIT,
+                        dest_address: managed_address!(&sc_addr),
+                        payments: ManagedVec::new(),
+                        endpoint_name: managed_buffer!(b"changeTODO"),
+                        arguments: ManagedVec::new(),
+                    });
+                    sc.propose(ProposalCreationArgs {
+                        description: managed_buffer!(b"legacy"),
+                        actions,
+                    });
+                },
+            )
+            .assert_ok();
+        gov_setup.increment_block_nonce(50);
+    }
+
+    // legacy contract still accepts votes
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut legacy_proposal_ids = MultiValueEncoded::new();
+                legacy_proposal_ids.push(1);
+                let _ = sc.import_legacy_proposals(
+                    managed_address!(legacy_wrapper.address_ref()),
+                    legacy_proposal_ids,
+                );
+            },
+        )
+        .assert_user_error("Legacy governance is not in read-only mode");
+
+    gov_setup
+        .b_mock
+        .execute_tx(&owner_addr, &legacy_wrapper, &rust_biguint!(0), |sc| {
+            sc.enable_read_only_mode();
+        })
+        .assert_ok();
+
+    // block 120: legacy proposal 0 ended, legacy proposal 1 is active until block 160
+    gov_setup.increment_block_nonce(20);
+
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut legacy_proposal_ids = MultiValueEncoded::new();
+                legacy_proposal_ids.push(0);
+                let _ = sc.import_legacy_proposals(
+                    managed_address!(legacy_wrapper.address_ref()),
+                    legacy_proposal_ids,
+                );
+            },
+        )
+        .assert_user_error("Legacy proposal is not open");
+
+    let mut proposal_id = 0;
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut legacy_proposal_ids = MultiValueEncoded::new();
+                legacy_proposal_ids.push(1);
+                let imported_ids = sc
+                    .import_legacy_proposals(
+                        managed_address!(legacy_wrapper.address_ref()),
+                        legacy_proposal_ids,
+                    )
+                    .to_vec();
+                proposal_id = imported_ids.get(0);
+            },
+        )
+        .assert_ok();
+
+    gov_setup
+        .b_mock
+        .execute_tx(
+            &owner_addr,
+            &gov_setup.gov_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut legacy_proposal_ids = MultiValueEncoded::new();
+                legacy_proposal_ids.push(1);
+                let _ = sc.import_legacy_proposals(
+                    managed_address!(legacy_wrapper.address_ref()),
+                    legacy_proposal_ids,
+                );
+            },
+        )
+        .assert_user_error("Legacy proposal already imported");
+
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            let proposal = sc.proposals().get(proposal_id);
+            assert_eq!(proposal.proposer, managed_address!(&first_user_addr));
+            assert_eq!(proposal.description, managed_buffer!(b"legacy"));
+            assert_eq!(proposal.actions.len(), 1);
+            assert_eq!(
+                proposal.actions[0].function_name,
+                managed_buffer!(b"changeTODO")
+            );
+            assert_eq!(proposal.proposal_start_block, 120);
+            assert_eq!(proposal.voting_delay_in_blocks, 0);
+            assert_eq!(proposal.voting_period_in_blocks, 40);
+            assert!(proposal.fee_withdrawn);
+            assert_eq!(
+                sc.imported_legacy_proposal(&managed_address!(legacy_wrapper.address_ref()), 1)
+                    .get(),
+                proposal_id
+            );
+            assert!(sc.get_proposal_status(proposal_id) == GovernanceProposalStatus::Active);
+        })
+        .assert_ok();
+
+    gov_setup.up_vote(&first_user_addr, proposal_id).assert_ok();
+
+    gov_setup.increment_block_nonce(40);
+    gov_setup
+        .b_mock
+        .execute_query(&gov_setup.gov_wrapper, |sc| {
+            assert!(sc.get_proposal_status(proposal_id) != GovernanceProposalStatus::Active);
+        })
+        .assert_ok();
+}
@@ -1,16 +1,19 @@
 #![allow(deprecated)]
 
+use ed25519_dalek::{Keypair, Signer};
 use energy_factory_mock::EnergyFactoryMock;
 use energy_query::Energy;
 use fees_collector::FeesCollector;
 use governance_v2::{
     configurable::ConfigurablePropertiesModule,
+    delegation::DelegationModule,
     proposal_storage::{ProposalStorageModule, VoteType},
+    signed_vote::SignedVote,
     GovernanceV2,
 };
 use multiversx_sc::{
     codec::multi_types::OptionalValue,
-    types::{Address, BigInt, EsdtLocalRole, ManagedVec, MultiValueEncoded},
+    types::{Address, BigInt, EsdtLocalRole, ManagedByteArray, ManagedVec, MultiValueEncoded},
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint,
@@ -34,6 +37,14 @@ pub const FULL_PERCENTAGE: u64 = 10_000;
 pub const USER_ENERGY: u64 = 1_000_000;
 pub const GAS_LIMIT: u64 = 1_000_000;
 
+pub type EnergyFactoryBuilder = fn() -> energy_factory_mock::ContractObj<DebugApi>;
+
+/// (voter, proposal_id, vote_type, nonce, signature)
+pub type SignedVoteArgs = (Address, usize, VoteType, u64, [u8; 64]);
+
+/// (dest_address, endpoint_name, args)
+pub type ProposalAction<'a> = (Address, &'a [u8], Vec<Vec<u8>>);
+
 pub struct GovSetup<GovBuilder>
 where
     GovBuilder: 'static + Copy + Fn() -> governance_v2::ContractObj<DebugApi>,
@@ -44,6 +55,8 @@ where
     pub second_user: Address,
     pub third_user: Address,
     pub no_energy_user: Address,
+    pub energy_factory_wrapper:
+        ContractObjWrapper<energy_factory_mock::ContractObj<DebugApi>, EnergyFactoryBuilder>,
     pub gov_wrapper: ContractObjWrapper<governance_v2::ContractObj<DebugApi>, GovBuilder>,
     pub current_block: u64,
 }
@@ -65,7 +78,7 @@ where
         let energy_factory_wrapper = b_mock.create_sc_account(
             &rust_zero,
             Some(&owner),
-            energy_factory_mock::contract_obj,
+            energy_factory_mock::contract_obj as EnergyFactoryBuilder,
             "energy factory path",
         );
 
@@ -185,6 +198,7 @@ where
             second_user,
             third_user,
             no_energy_user,
+            energy_factory_wrapper,
             gov_wrapper,
             current_block: 0,
         }
@@ -229,6 +243,45 @@ where
         (result, proposal_id)
     }
 
+    pub fn propose_actions(
+        &mut self,
+        proposer: &Address,
+        fee_amount: &BigUint,
+        actions: Vec<ProposalAction>,
+    ) -> (TxResult, usize) {
+        let mut proposal_id = 0;
+        let result = self.b_mock.execute_esdt_transfer(
+            proposer,
+            &self.gov_wrapper,
+            MEX_TOKEN_ID,
+            0,
+            fee_amount,
+            |sc| {
+                let mut actions_managed = MultiValueEncoded::new();
+                for (dest_address, endpoint_name, args) in actions {
+                    let mut args_managed = ManagedVec::new();
+                    for arg in args {
+                        args_managed.push(managed_buffer!(&arg));
+                    }
+
+                    actions_managed.push(
+                        (
+                            GAS_LIMIT,
+                            managed_address!(&dest_address),
+                            managed_buffer!(endpoint_name),
+                            args_managed,
+                        )
+                            .into(),
+                    );
+                }
+
+                proposal_id = sc.propose(managed_buffer!(b"changeTODO"), actions_managed);
+            },
+        );
+
+        (result, proposal_id)
+    }
+
     pub fn up_vote(&mut self, voter: &Address, proposal_id: usize) -> TxResult {
         self.b_mock
             .execute_tx(voter, &self.gov_wrapper, &rust_biguint!(0), |sc| {
@@ -257,6 +310,38 @@ where
             })
     }
 
+    pub fn vote_batch(&mut self, relayer: &Address, signed_votes: Vec<SignedVoteArgs>) -> TxResult {
+        self.b_mock
+            .execute_tx(relayer, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                let mut signed_votes_managed = MultiValueEncoded::new();
+                for (voter, proposal_id, vote_type, nonce, signature) in signed_votes {
+                    signed_votes_managed.push(SignedVote {
+                        voter: managed_address!(&voter),
+                        proposal_id,
+                        vote_type,
+                        nonce,
+                        signature: ManagedByteArray::new_from_bytes(&signature),
+                    });
+                }
+
+                sc.vote_batch(signed_votes_managed);
+            })
+    }
+
+    pub fn delegate_voting_power(&mut self, user: &Address, delegate: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(user, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.delegate_voting_power(managed_address!(delegate));
+            })
+    }
+
+    pub fn revoke_delegation(&mut self, user: &Address) -> TxResult {
+        self.b_mock
+            .execute_tx(user, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.revoke_delegation();
+            })
+    }
+
     pub fn withdraw_after_defeated(&mut self, caller: &Address, proposal_id: usize) -> TxResult {
         self.b_mock
             .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
@@ -279,6 +364,20 @@ where
             })
     }
 
+    pub fn queue(&mut self, caller: &Address, proposal_id: usize) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.queue(proposal_id);
+            })
+    }
+
+    pub fn execute(&mut self, caller: &Address, proposal_id: usize) -> TxResult {
+        self.b_mock
+            .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
+                sc.execute(proposal_id);
+            })
+    }
+
     pub fn cancel_proposal(&mut self, caller: &Address, proposal_id: usize) -> TxResult {
         self.b_mock
             .execute_tx(caller, &self.gov_wrapper, &rust_biguint!(0), |sc| {
@@ -300,6 +399,40 @@ where
                 )
             })
     }
+    pub fn set_user_energy(&mut self, user: &Address, energy_amount: u64) {
+        self.b_mock
+            .execute_tx(
+                &self.owner,
+                &self.energy_factory_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_user_energy(
+                        managed_address!(user),
+                        managed_biguint!(energy_amount),
+                        managed_biguint!(0),
+                    );
+                },
+            )
+            .assert_ok();
+    }
+
+    /// Same message as the one built by the contract:
+    /// SC address (32 bytes) | proposal ID (u32) | vote type (u8) | nonce (u64)
+    pub fn sign_vote(
+        &self,
+        keypair: &Keypair,
+        proposal_id: usize,
+        vote_type: VoteType,
+        nonce: u64,
+    ) -> [u8; 64] {
+        let mut message = self.gov_wrapper.address_ref().to_vec();
+        message.extend_from_slice(&(proposal_id as u32).to_be_bytes());
+        message.push(vote_type as u8);
+        message.extend_from_slice(&nonce.to_be_bytes());
+
+        keypair.sign(&message).to_bytes()
+    }
+
     pub fn increment_block_nonce(&mut self, inc_amount: u64) {
         self.current_block += inc_amount;
         self.b_mock.set_block_nonce(self.current_block);
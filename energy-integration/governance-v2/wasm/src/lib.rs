@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           29
+// Endpoints:                           56
 // Async Callback (empty):               1
-// Total number of exported functions:  32
+// Promise callbacks:                    1
+// Total number of exported functions:  60
 
 #![no_std]
 
@@ -21,15 +22,22 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         propose => propose
+        proposeFromTemplates => propose_from_templates
+        importLegacyProposals => import_legacy_proposals
         vote => vote
+        voteBatch => vote_batch
         cancel => cancel
         withdrawDeposit => withdraw_deposit
+        queue => queue
+        execute => execute
         changeMinEnergyForProposal => change_min_energy_for_propose
         changeMinFeeForProposal => change_min_fee_for_propose
         changeQuorumPercentage => change_quorum_percentage
         changeWithdrawPercentage => change_withdraw_percentage
         changeVotingDelayInBlocks => change_voting_delay_in_blocks
         changeVotingPeriodInBlocks => change_voting_period_in_blocks
+        changeLockTimeAfterVotingEndsInBlocks => change_lock_time_after_voting_ends_in_blocks
+        changeExecutionPeriodInBlocks => change_execution_period_in_blocks
         getMinEnergyForPropose => min_energy_for_propose
         getMinFeeForPropose => min_fee_for_propose
         getQuorum => quorum_percentage
@@ -37,10 +45,30 @@ multiversx_sc_wasm_adapter::endpoints! {
         getVotingPeriodInBlocks => voting_period_in_blocks
         getFeeTokenId => fee_token_id
         getWithdrawPercentageDefeated => withdraw_percentage_defeated
+        getLockTimeAfterVotingEndsInBlocks => lock_time_after_voting_ends_in_blocks
+        getExecutionPeriodInBlocks => execution_period_in_blocks
+        delegateVotingPower => delegate_voting_power
+        revokeDelegation => revoke_delegation
+        getDelegateVotingPower => get_delegate_voting_power
+        getUserDelegate => user_delegate
+        getDelegators => delegators
+        getImportedLegacyProposal => imported_legacy_proposal
         getProposals => proposals
         getUserVotedProposals => user_voted_proposals
         getProposalVotes => proposal_votes
+        getProposalVoteRecord => proposal_vote_record
+        getProposalVoters => proposal_voters
+        getProposalTimelock => proposal_timelock
+        getProposalSnapshotEpoch => proposal_snapshot_epoch
+        addKnownContracts => add_known_contracts
+        removeKnownContracts => remove_known_contracts
+        getProposalTypedActions => get_proposal_typed_actions
+        getKnownContracts => known_contracts
+        getSignedVoteMessage => get_signed_vote_message
+        getUserVoteNonce => user_vote_nonce
         getProposalStatus => get_proposal_status
+        getProposalActionsExecutionStatus => get_proposal_actions_execution_status
+        getProposalVoteBreakdown => get_proposal_vote_breakdown
         changeFeesCollectorAddress => change_fees_collector_address
         getFeesCollectorAddress => fees_collector_address
         setEnergyFactoryAddress => set_energy_factory_address
@@ -49,6 +77,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         removeAdmin => remove_admin_endpoint
         updateOwnerOrAdmin => update_owner_or_admin_endpoint
         getPermissions => permissions
+        execute_action_callback => execute_action_callback
     )
 }
 
@@ -0,0 +1,216 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use pair::read_pair_storage;
+
+use crate::{external_contracts_interactions::SafePriceResult, result_types::PaymentsVec};
+
+#[derive(
+    TypeAbi,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct AdditionalStakingFarm<M: ManagedTypeApi> {
+    pub staking_farm_address: ManagedAddress<M>,
+    pub staking_token_id: TokenIdentifier<M>,
+    pub staking_farm_token_id: TokenIdentifier<M>,
+}
+
+pub struct AdditionalStakingFarmsEnterResult<M: ManagedTypeApi> {
+    pub new_staking_farm_tokens: PaymentsVec<M>,
+    pub boosted_rewards: PaymentsVec<M>,
+}
+
+pub struct AdditionalStakingFarmsClaimResult<M: ManagedTypeApi> {
+    pub new_staking_farm_tokens: PaymentsVec<M>,
+    pub staking_farm_rewards: PaymentsVec<M>,
+}
+
+pub struct AdditionalStakingFarmsUnstakeResult<M: ManagedTypeApi> {
+    pub remaining_other_token_payment: EsdtTokenPayment<M>,
+    pub staking_rewards: PaymentsVec<M>,
+    pub unbond_staking_farm_tokens: PaymentsVec<M>,
+}
+
+#[multiversx_sc::module]
+pub trait AdditionalStakingFarmsModule:
+    crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::lp_farm_token::LpFarmTokenModule
+    + read_pair_storage::ReadPairStorageModule
+    + utils::UtilsModule
+    + energy_query::EnergyQueryModule
+{
+    /// Adds a staking farm that is entered together with the main staking farm on new stakes,
+    /// e.g. a partner token staking farm, for a pair of MEX and the partner token.
+    ///
+    /// The staking token must be the other pair token, as the staking farm receives it on unstake.
+    /// The proxy must be whitelisted in the staking farm.
+    /// Staking farms cannot be removed, as the existing positions depend on them.
+    #[only_owner]
+    #[endpoint(addAdditionalStakingFarm)]
+    fn add_additional_staking_farm(
+        &self,
+        staking_farm_address: ManagedAddress,
+        staking_token_id: TokenIdentifier,
+        staking_farm_token_id: TokenIdentifier,
+    ) {
+        self.require_sc_address(&staking_farm_address);
+        self.require_valid_token_id(&staking_token_id);
+        self.require_valid_token_id(&staking_farm_token_id);
+
+        let pair_address = self.pair_address().get();
+        let first_token_id = self.get_first_token_id_mapper(pair_address.clone()).get();
+        let second_token_id = self.get_second_token_id_mapper(pair_address).get();
+        require!(
+            staking_token_id == first_token_id || staking_token_id == second_token_id,
+            "Staking token not in pair"
+        );
+        require!(
+            staking_token_id != self.staking_token_id().get()
+                && staking_farm_token_id != self.staking_farm_token_id().get(),
+            "Staking farm already added"
+        );
+
+        let mut additional_staking_farms = self.additional_staking_farms();
+        for staking_farm in additional_staking_farms.iter() {
+            require!(
+                staking_token_id != staking_farm.staking_token_id
+                    && staking_farm_token_id != staking_farm.staking_farm_token_id,
+                "Staking farm already added"
+            );
+        }
+
+        let _ = additional_staking_farms.push(&AdditionalStakingFarm {
+            staking_farm_address,
+            staking_token_id,
+            staking_farm_token_id,
+        });
+    }
+
+    /// Enters all the additional staking farms, merging the given positions of each farm
+    fn enter_additional_staking_farms(
+        &self,
+        orig_caller: &ManagedAddress,
+        safe_price_result: &SafePriceResult<Self::Api>,
+        additional_staking_farm_tokens: &PaymentsVec<Self::Api>,
+    ) -> AdditionalStakingFarmsEnterResult<Self::Api> {
+        let mut new_staking_farm_tokens = ManagedVec::new();
+        let mut boosted_rewards = ManagedVec::new();
+        for staking_farm in self.additional_staking_farms().iter() {
+            let mut staking_farm_tokens = ManagedVec::new();
+            for farm_token in additional_staking_farm_tokens {
+                if farm_token.token_identifier == staking_farm.staking_farm_token_id {
+                    staking_farm_tokens.push(farm_token);
+                }
+            }
+
+            let staking_token_amount =
+                self.get_safe_price_token_amount(safe_price_result, &staking_farm.staking_token_id);
+            let enter_result = self.staking_farm_enter(
+                staking_farm.staking_farm_address,
+                orig_caller.clone(),
+                staking_token_amount,
+                staking_farm_tokens,
+            );
+
+            new_staking_farm_tokens.push(enter_result.received_staking_farm_token);
+            boosted_rewards.push(enter_result.boosted_rewards);
+        }
+
+        AdditionalStakingFarmsEnterResult {
+            new_staking_farm_tokens,
+            boosted_rewards,
+        }
+    }
+
+    fn claim_additional_staking_farms(
+        &self,
+        orig_caller: &ManagedAddress,
+        safe_price_result: &SafePriceResult<Self::Api>,
+        staking_farm_tokens: PaymentsVec<Self::Api>,
+    ) -> AdditionalStakingFarmsClaimResult<Self::Api> {
+        let mut new_staking_farm_tokens = ManagedVec::new();
+        let mut staking_farm_rewards = ManagedVec::new();
+        for farm_token in &staking_farm_tokens {
+            let staking_farm = self.get_additional_staking_farm(&farm_token.token_identifier);
+            let new_staking_farm_value =
+                self.get_safe_price_token_amount(safe_price_result, &staking_farm.staking_token_id);
+            let claim_result = self.staking_farm_claim_rewards(
+                staking_farm.staking_farm_address,
+                orig_caller.clone(),
+                farm_token.token_identifier,
+                farm_token.token_nonce,
+                farm_token.amount,
+                new_staking_farm_value,
+            );
+
+            new_staking_farm_tokens.push(claim_result.new_staking_farm_tokens);
+            staking_farm_rewards.push(claim_result.staking_farm_rewards);
+        }
+
+        AdditionalStakingFarmsClaimResult {
+            new_staking_farm_tokens,
+            staking_farm_rewards,
+        }
+    }
+
+    /// The other pair token, received on removing the liquidity, is sent to its staking farm
+    fn unstake_additional_staking_farms(
+        &self,
+        orig_caller: &ManagedAddress,
+        other_token_payment: EsdtTokenPayment,
+        staking_farm_tokens: PaymentsVec<Self::Api>,
+    ) -> AdditionalStakingFarmsUnstakeResult<Self::Api> {
+        let mut remaining_other_token_payment = other_token_payment;
+        let mut staking_rewards = ManagedVec::new();
+        let mut unbond_staking_farm_tokens = ManagedVec::new();
+        for farm_token in &staking_farm_tokens {
+            let staking_farm = self.get_additional_staking_farm(&farm_token.token_identifier);
+            require!(
+                staking_farm.staking_token_id == remaining_other_token_payment.token_identifier
+                    && remaining_other_token_payment.amount > 0,
+                "Invalid staking token received"
+            );
+
+            let exit_result = self.staking_farm_unstake(
+                staking_farm.staking_farm_address,
+                orig_caller.clone(),
+                remaining_other_token_payment.clone(),
+                farm_token,
+            );
+            remaining_other_token_payment.amount = BigUint::zero();
+
+            staking_rewards.push(exit_result.staking_rewards);
+            unbond_staking_farm_tokens.push(exit_result.unbond_staking_farm_token);
+        }
+
+        AdditionalStakingFarmsUnstakeResult {
+            remaining_other_token_payment,
+            staking_rewards,
+            unbond_staking_farm_tokens,
+        }
+    }
+
+    fn get_additional_staking_farm(
+        &self,
+        staking_farm_token_id: &TokenIdentifier,
+    ) -> AdditionalStakingFarm<Self::Api> {
+        for staking_farm in self.additional_staking_farms().iter() {
+            if &staking_farm.staking_farm_token_id == staking_farm_token_id {
+                return staking_farm;
+            }
+        }
+
+        sc_panic!("Unknown staking farm token");
+    }
+
+    #[view(getAdditionalStakingFarms)]
+    #[storage_mapper("additionalStakingFarms")]
+    fn additional_staking_farms(&self) -> VecMapper<AdditionalStakingFarm<Self::Api>>;
+}
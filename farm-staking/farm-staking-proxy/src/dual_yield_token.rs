@@ -1,14 +1,55 @@
 use fixed_supply_token::FixedSupplyToken;
+use multiversx_sc::codec::{NestedDecodeInput, TopDecodeInput};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TypeAbi, TopEncode, TopDecode, Clone, PartialEq, Debug)]
+/// The additional staking farm tokens are the positions in the additional staking farms,
+/// which are entered together with the main staking farm. Older tokens don't have any.
+#[derive(TypeAbi, TopEncode, NestedEncode, Clone, PartialEq, Debug)]
 pub struct DualYieldTokenAttributes<M: ManagedTypeApi> {
     pub lp_farm_token_nonce: u64,
     pub lp_farm_token_amount: BigUint<M>,
     pub staking_farm_token_nonce: u64,
     pub staking_farm_token_amount: BigUint<M>,
+    pub additional_staking_farm_tokens: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
+impl<M: ManagedTypeApi> TopDecode for DualYieldTokenAttributes<M> {
+    fn top_decode<I>(input: I) -> Result<Self, DecodeError>
+    where
+        I: TopDecodeInput,
+    {
+        let mut buffer = input.into_nested_buffer();
+        Self::dep_decode(&mut buffer)
+    }
+}
+
+impl<M: ManagedTypeApi> NestedDecode for DualYieldTokenAttributes<M> {
+    fn dep_decode<I: NestedDecodeInput>(input: &mut I) -> Result<Self, DecodeError> {
+        let lp_farm_token_nonce = u64::dep_decode(input)?;
+        let lp_farm_token_amount = BigUint::dep_decode(input)?;
+        let staking_farm_token_nonce = u64::dep_decode(input)?;
+        let staking_farm_token_amount = BigUint::dep_decode(input)?;
+
+        let additional_staking_farm_tokens = if !input.is_depleted() {
+            ManagedVec::dep_decode(input)?
+        } else {
+            ManagedVec::new()
+        };
+
+        if !input.is_depleted() {
+            return Result::Err(DecodeError::INPUT_TOO_LONG);
+        }
+
+        Result::Ok(DualYieldTokenAttributes {
+            lp_farm_token_nonce,
+            lp_farm_token_amount,
+            staking_farm_token_nonce,
+            staking_farm_token_amount,
+            additional_staking_farm_tokens,
+        })
+    }
 }
 
 impl<M: ManagedTypeApi> FixedSupplyToken<M> for DualYieldTokenAttributes<M> {
@@ -25,11 +66,22 @@ impl<M: ManagedTypeApi> FixedSupplyToken<M> for DualYieldTokenAttributes<M> {
             self.rule_of_three_non_zero_result(payment_amount, &self.lp_farm_token_amount);
         let new_staking_farm_token_amount = payment_amount.clone();
 
+        let mut new_additional_staking_farm_tokens = ManagedVec::new();
+        for farm_token in &self.additional_staking_farm_tokens {
+            let new_amount = self.rule_of_three_non_zero_result(payment_amount, &farm_token.amount);
+            new_additional_staking_farm_tokens.push(EsdtTokenPayment::new(
+                farm_token.token_identifier,
+                farm_token.token_nonce,
+                new_amount,
+            ));
+        }
+
         DualYieldTokenAttributes {
             lp_farm_token_nonce: self.lp_farm_token_nonce,
             lp_farm_token_amount: new_lp_farm_token_amount,
             staking_farm_token_nonce: self.staking_farm_token_nonce,
             staking_farm_token_amount: new_staking_farm_token_amount,
+            additional_staking_farm_tokens: new_additional_staking_farm_tokens,
         }
     }
 }
@@ -91,11 +91,11 @@ pub trait ExternalContractsInteractionsModule:
 
     fn staking_farm_enter(
         &self,
+        staking_farm_address: ManagedAddress,
         orig_caller: ManagedAddress,
         staking_token_amount: BigUint,
         staking_farm_tokens: PaymentsVec<Self::Api>,
     ) -> StakingFarmEnterResult<Self::Api> {
-        let staking_farm_address = self.staking_farm_address().get();
         let enter_result: EnterFarmResultType<Self::Api> = self
             .staking_farm_proxy_obj(staking_farm_address)
             .stake_farm_through_proxy(staking_token_amount, orig_caller)
@@ -111,13 +111,13 @@ pub trait ExternalContractsInteractionsModule:
 
     fn staking_farm_claim_rewards(
         &self,
+        staking_farm_address: ManagedAddress,
         orig_caller: ManagedAddress,
         staking_farm_token_id: TokenIdentifier,
         staking_farm_token_nonce: u64,
         staking_farm_token_amount: BigUint,
         new_staking_farm_value: BigUint,
     ) -> StakingFarmClaimRewardsResult<Self::Api> {
-        let staking_farm_address = self.staking_farm_address().get();
         let staking_farm_result: ClaimRewardsResultType<Self::Api> = self
             .staking_farm_proxy_obj(staking_farm_address)
             .claim_rewards_with_new_value(new_staking_farm_value, orig_caller)
@@ -137,20 +137,14 @@ pub trait ExternalContractsInteractionsModule:
 
     fn staking_farm_unstake(
         &self,
+        staking_farm_address: ManagedAddress,
         orig_caller: ManagedAddress,
         staking_tokens: EsdtTokenPayment<Self::Api>,
-        farm_token_nonce: u64,
-        farm_token_amount: BigUint,
+        staking_farm_tokens: EsdtTokenPayment<Self::Api>,
     ) -> StakingFarmExitResult<Self::Api> {
-        let staking_farm_token_id = self.staking_farm_token_id().get();
         let mut payments = ManagedVec::from_single_item(staking_tokens);
-        payments.push(EsdtTokenPayment::new(
-            staking_farm_token_id,
-            farm_token_nonce,
-            farm_token_amount,
-        ));
+        payments.push(staking_farm_tokens);
 
-        let staking_farm_address = self.staking_farm_address().get();
         let unstake_result: ExitFarmWithPartialPosResultType<Self::Api> = self
             .staking_farm_proxy_obj(staking_farm_address)
             .unstake_farm_through_proxy(orig_caller)
@@ -198,17 +192,32 @@ pub trait ExternalContractsInteractionsModule:
     }
 
     fn get_lp_tokens_safe_price(&self, lp_tokens_amount: BigUint) -> BigUint {
+        let safe_price_result = self.get_lp_tokens_safe_price_result(lp_tokens_amount);
+        let staking_token_id = self.staking_token_id().get();
+
+        self.get_safe_price_token_amount(&safe_price_result, &staking_token_id)
+    }
+
+    fn get_lp_tokens_safe_price_result(
+        &self,
+        lp_tokens_amount: BigUint,
+    ) -> SafePriceResult<Self::Api> {
         let pair_address = self.pair_address().get();
-        let result: SafePriceResult<Self::Api> = self
-            .pair_proxy_obj(pair_address)
+        self.pair_proxy_obj(pair_address)
             .update_and_get_tokens_for_given_position_with_safe_price(lp_tokens_amount)
-            .execute_on_dest_context();
-        let (first_token_info, second_token_info) = result.into_tuple();
-        let staking_token_id = self.staking_token_id().get();
+            .execute_on_dest_context()
+    }
+
+    fn get_safe_price_token_amount(
+        &self,
+        safe_price_result: &SafePriceResult<Self::Api>,
+        token_id: &TokenIdentifier,
+    ) -> BigUint {
+        let (first_token_info, second_token_info) = safe_price_result.clone().into_tuple();
 
-        if first_token_info.token_identifier == staking_token_id {
+        if &first_token_info.token_identifier == token_id {
             first_token_info.amount
-        } else if second_token_info.token_identifier == staking_token_id {
+        } else if &second_token_info.token_identifier == token_id {
             second_token_info.amount
         } else {
             sc_panic!("Invalid Pair contract called");
@@ -2,6 +2,7 @@
 
 multiversx_sc::imports!();
 
+pub mod additional_staking_farms;
 pub mod dual_yield_token;
 pub mod external_contracts_interactions;
 pub mod lp_farm_token;
@@ -12,6 +13,8 @@ pub mod result_types;
 pub trait FarmStakingProxy:
     dual_yield_token::DualYieldTokenModule
     + external_contracts_interactions::ExternalContractsInteractionsModule
+    + additional_staking_farms::AdditionalStakingFarmsModule
+    + pair::read_pair_storage::ReadPairStorageModule
     + lp_farm_token::LpFarmTokenModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
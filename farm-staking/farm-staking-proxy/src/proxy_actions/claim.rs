@@ -1,10 +1,14 @@
-use crate::{dual_yield_token::DualYieldTokenAttributes, result_types::ClaimDualYieldResult};
+use crate::{
+    dual_yield_token::DualYieldTokenAttributes,
+    result_types::{ClaimDualYieldResult, PaymentsVec},
+};
 
 multiversx_sc::imports!();
 
 pub struct InternalClaimResult<M: ManagedTypeApi> {
     pub lp_farm_rewards: EsdtTokenPayment<M>,
     pub staking_farm_rewards: EsdtTokenPayment<M>,
+    pub additional_staking_farm_rewards: PaymentsVec<M>,
     pub new_dual_yield_attributes: DualYieldTokenAttributes<M>,
 }
 
@@ -12,7 +16,9 @@ pub struct InternalClaimResult<M: ManagedTypeApi> {
 pub trait ProxyClaimModule:
     crate::dual_yield_token::DualYieldTokenModule
     + crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::additional_staking_farms::AdditionalStakingFarmsModule
     + crate::lp_farm_token::LpFarmTokenModule
+    + pair::read_pair_storage::ReadPairStorageModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
     + token_send::TokenSendModule
@@ -47,6 +53,7 @@ pub trait ProxyClaimModule:
             lp_farm_rewards: internal_claim_result.lp_farm_rewards,
             staking_farm_rewards: internal_claim_result.staking_farm_rewards,
             new_dual_yield_tokens,
+            additional_staking_farm_rewards: internal_claim_result.additional_staking_farm_rewards,
         };
 
         dual_yield_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
@@ -67,7 +74,9 @@ pub trait ProxyClaimModule:
             attributes.lp_farm_token_nonce,
             &attributes.lp_farm_token_amount,
         );
-        let new_staking_farm_value = self.get_lp_tokens_safe_price(lp_tokens_in_position);
+        let safe_price_result = self.get_lp_tokens_safe_price_result(lp_tokens_in_position);
+        let new_staking_farm_value =
+            self.get_safe_price_token_amount(&safe_price_result, &self.staking_token_id().get());
 
         let staking_farm_token_id = self.staking_farm_token_id().get();
         let lp_farm_token_id = self.lp_farm_token_id().get();
@@ -78,12 +87,18 @@ pub trait ProxyClaimModule:
             attributes.lp_farm_token_amount,
         );
         let staking_farm_claim_rewards_result = self.staking_farm_claim_rewards(
-            orig_caller,
+            self.staking_farm_address().get(),
+            orig_caller.clone(),
             staking_farm_token_id,
             attributes.staking_farm_token_nonce,
             staking_claim_amount,
             new_staking_farm_value,
         );
+        let additional_staking_farms_claim_result = self.claim_additional_staking_farms(
+            &orig_caller,
+            &safe_price_result,
+            attributes.additional_staking_farm_tokens,
+        );
 
         let new_lp_farm_tokens = lp_farm_claim_rewards_result.new_lp_farm_tokens;
         let new_staking_farm_tokens = staking_farm_claim_rewards_result.new_staking_farm_tokens;
@@ -92,11 +107,15 @@ pub trait ProxyClaimModule:
             lp_farm_token_amount: new_lp_farm_tokens.amount,
             staking_farm_token_nonce: new_staking_farm_tokens.token_nonce,
             staking_farm_token_amount: new_staking_farm_tokens.amount,
+            additional_staking_farm_tokens: additional_staking_farms_claim_result
+                .new_staking_farm_tokens,
         };
 
         InternalClaimResult {
             lp_farm_rewards: lp_farm_claim_rewards_result.lp_farm_rewards,
             staking_farm_rewards: staking_farm_claim_rewards_result.staking_farm_rewards,
+            additional_staking_farm_rewards: additional_staking_farms_claim_result
+                .staking_farm_rewards,
             new_dual_yield_attributes: new_attributes,
         }
     }
@@ -6,7 +6,9 @@ multiversx_sc::imports!();
 pub trait ProxyStakeModule:
     crate::dual_yield_token::DualYieldTokenModule
     + crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::additional_staking_farms::AdditionalStakingFarmsModule
     + crate::lp_farm_token::LpFarmTokenModule
+    + pair::read_pair_storage::ReadPairStorageModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
     + token_send::TokenSendModule
@@ -37,6 +39,7 @@ pub trait ProxyStakeModule:
         let staking_farm_token_id = self.staking_farm_token_id().get();
         let mut additional_staking_farm_tokens = ManagedVec::new();
         let mut additional_lp_farm_tokens = ManagedVec::new();
+        let mut additional_staking_farms_tokens = ManagedVec::new();
         for p in &additional_payments {
             let attributes: DualYieldTokenAttributes<Self::Api> =
                 self.get_attributes_as_part_of_fixed_supply(&p, &dual_yield_token_mapper);
@@ -53,6 +56,8 @@ pub trait ProxyStakeModule:
                 attributes.lp_farm_token_amount,
             ));
 
+            additional_staking_farms_tokens.append_vec(attributes.additional_staking_farm_tokens);
+
             dual_yield_token_mapper.nft_burn(p.token_nonce, &p.amount);
         }
 
@@ -60,13 +65,21 @@ pub trait ProxyStakeModule:
             lp_farm_token_payment.token_nonce,
             &lp_farm_token_payment.amount,
         );
-        let staking_token_amount = self.get_lp_tokens_safe_price(lp_tokens_in_farm);
+        let safe_price_result = self.get_lp_tokens_safe_price_result(lp_tokens_in_farm);
+        let staking_token_amount =
+            self.get_safe_price_token_amount(&safe_price_result, &self.staking_token_id().get());
         let staking_farm_enter_result = self.staking_farm_enter(
+            self.staking_farm_address().get(),
             orig_caller.clone(),
             staking_token_amount,
             additional_staking_farm_tokens,
         );
         let received_staking_farm_token = staking_farm_enter_result.received_staking_farm_token;
+        let additional_staking_farms_enter_result = self.enter_additional_staking_farms(
+            &orig_caller,
+            &safe_price_result,
+            &additional_staking_farms_tokens,
+        );
 
         let (merged_lp_farm_tokens, lp_farm_boosted_rewards) = self
             .merge_lp_farm_tokens(
@@ -81,6 +94,8 @@ pub trait ProxyStakeModule:
             lp_farm_token_amount: merged_lp_farm_tokens.amount,
             staking_farm_token_nonce: received_staking_farm_token.token_nonce,
             staking_farm_token_amount: received_staking_farm_token.amount,
+            additional_staking_farm_tokens: additional_staking_farms_enter_result
+                .new_staking_farm_tokens,
         };
         let new_dual_yield_tokens =
             self.create_dual_yield_tokens(&dual_yield_token_mapper, &new_attributes);
@@ -88,6 +103,8 @@ pub trait ProxyStakeModule:
             dual_yield_tokens: new_dual_yield_tokens,
             staking_boosted_rewards: staking_farm_enter_result.boosted_rewards,
             lp_farm_boosted_rewards,
+            additional_staking_boosted_rewards: additional_staking_farms_enter_result
+                .boosted_rewards,
         };
 
         output_payments.send_and_return(self, &caller)
@@ -8,7 +8,9 @@ multiversx_sc::imports!();
 pub trait ProxyUnstakeModule:
     crate::dual_yield_token::DualYieldTokenModule
     + crate::external_contracts_interactions::ExternalContractsInteractionsModule
+    + crate::additional_staking_farms::AdditionalStakingFarmsModule
     + crate::lp_farm_token::LpFarmTokenModule
+    + pair::read_pair_storage::ReadPairStorageModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
     + utils::UtilsModule
     + token_send::TokenSendModule
@@ -47,18 +49,30 @@ pub trait ProxyUnstakeModule:
         );
 
         let staking_farm_exit_result = self.staking_farm_unstake(
-            orig_caller,
+            self.staking_farm_address().get(),
+            orig_caller.clone(),
             remove_liq_result.staking_token_payment,
-            exit_attributes.staking_farm_token_nonce,
-            exit_attributes.staking_farm_token_amount,
+            EsdtTokenPayment::new(
+                self.staking_farm_token_id().get(),
+                exit_attributes.staking_farm_token_nonce,
+                exit_attributes.staking_farm_token_amount,
+            ),
+        );
+        let additional_staking_farms_exit_result = self.unstake_additional_staking_farms(
+            &orig_caller,
+            remove_liq_result.other_token_payment,
+            exit_attributes.additional_staking_farm_tokens,
         );
 
         let caller = self.blockchain().get_caller();
         let unstake_result = UnstakeResult {
-            other_token_payment: remove_liq_result.other_token_payment,
+            other_token_payment: additional_staking_farms_exit_result.remaining_other_token_payment,
             lp_farm_rewards: lp_farm_exit_result.lp_farm_rewards,
             staking_rewards: staking_farm_exit_result.staking_rewards,
             unbond_staking_farm_token: staking_farm_exit_result.unbond_staking_farm_token,
+            additional_staking_rewards: additional_staking_farms_exit_result.staking_rewards,
+            additional_unbond_staking_farm_tokens: additional_staking_farms_exit_result
+                .unbond_staking_farm_tokens,
         };
 
         dual_yield_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
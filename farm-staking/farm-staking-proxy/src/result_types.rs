@@ -46,6 +46,7 @@ pub struct StakeProxyResult<M: ManagedTypeApi> {
     pub dual_yield_tokens: EsdtTokenPayment<M>,
     pub staking_boosted_rewards: EsdtTokenPayment<M>,
     pub lp_farm_boosted_rewards: EsdtTokenPayment<M>,
+    pub additional_staking_boosted_rewards: PaymentsVec<M>,
 }
 
 impl<M: ManagedTypeApi> StakeProxyResult<M> {
@@ -57,6 +58,7 @@ impl<M: ManagedTypeApi> StakeProxyResult<M> {
         sc.send_payment_non_zero(to, &self.dual_yield_tokens);
         sc.send_payment_non_zero(to, &self.staking_boosted_rewards);
         sc.send_payment_non_zero(to, &self.lp_farm_boosted_rewards);
+        sc.send_multiple_tokens_if_not_zero(to, &self.additional_staking_boosted_rewards);
 
         self
     }
@@ -67,6 +69,7 @@ pub struct ClaimDualYieldResult<M: ManagedTypeApi> {
     pub lp_farm_rewards: EsdtTokenPayment<M>,
     pub staking_farm_rewards: EsdtTokenPayment<M>,
     pub new_dual_yield_tokens: EsdtTokenPayment<M>,
+    pub additional_staking_farm_rewards: PaymentsVec<M>,
 }
 
 impl<M: ManagedTypeApi> ClaimDualYieldResult<M> {
@@ -79,6 +82,7 @@ impl<M: ManagedTypeApi> ClaimDualYieldResult<M> {
         payments.push(self.lp_farm_rewards.clone());
         payments.push(self.staking_farm_rewards.clone());
         payments.push(self.new_dual_yield_tokens.clone());
+        payments.append_vec(self.additional_staking_farm_rewards.clone());
 
         sc.send_multiple_tokens_if_not_zero(to, &payments);
 
@@ -92,6 +96,8 @@ pub struct UnstakeResult<M: ManagedTypeApi> {
     pub lp_farm_rewards: EsdtTokenPayment<M>,
     pub staking_rewards: EsdtTokenPayment<M>,
     pub unbond_staking_farm_token: EsdtTokenPayment<M>,
+    pub additional_staking_rewards: PaymentsVec<M>,
+    pub additional_unbond_staking_farm_tokens: PaymentsVec<M>,
 }
 
 impl<M: ManagedTypeApi> UnstakeResult<M> {
@@ -105,6 +111,8 @@ impl<M: ManagedTypeApi> UnstakeResult<M> {
         payments.push(self.lp_farm_rewards.clone());
         payments.push(self.staking_rewards.clone());
         payments.push(self.unbond_staking_farm_token.clone());
+        payments.append_vec(self.additional_staking_rewards.clone());
+        payments.append_vec(self.additional_unbond_staking_farm_tokens.clone());
 
         sc.send_multiple_tokens_if_not_zero(to, &payments);
 
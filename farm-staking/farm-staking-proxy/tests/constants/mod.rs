@@ -41,6 +41,10 @@ pub const MIN_ENERGY_AMOUNT_FOR_BOOSTED_YIELDS: u64 = 1;
 pub const MIN_FARM_AMOUNT_FOR_BOOSTED_YIELDS: u64 = 1;
 pub const BOOSTED_YIELDS_PERCENTAGE: u64 = 2_500; // 25%
 
+// Additional staking farm constants, staking the other pair token
+
+pub static WEGLD_STAKING_FARM_TOKEN_ID: &[u8] = b"WSTKFARM-abcdef";
+
 // Proxy constants
 
 pub static PROXY_WASM_PATH: &str = "farm-staking-proxy/output/farm-staking-proxy";
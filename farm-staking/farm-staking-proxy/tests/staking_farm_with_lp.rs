@@ -9,8 +9,11 @@ multiversx_sc::imports!();
 
 use config::ConfigModule;
 use constants::*;
+use farm_staking_proxy::additional_staking_farms::AdditionalStakingFarmsModule;
 use farm_staking_proxy::dual_yield_token::DualYieldTokenAttributes;
 
+use farm_staking_proxy::proxy_actions::claim::ProxyClaimModule;
+use farm_staking_proxy::proxy_actions::stake::ProxyStakeModule;
 use farm_staking_proxy::proxy_actions::unstake::ProxyUnstakeModule;
 
 use farm_staking::{
@@ -24,6 +27,9 @@ use multiversx_sc_scenario::{
 use pair::pair_actions::swap::SwapModule;
 use simple_lock::locked_token::LockedTokenAttributes;
 use staking_farm_with_lp_staking_contract_interactions::*;
+use staking_farm_with_lp_staking_contract_setup::{
+    add_proxy_to_whitelist, setup_staking_farm_with_tokens,
+};
 
 #[test]
 fn test_all_setup() {
@@ -437,6 +443,7 @@ fn test_stake_farm_through_proxy_with_merging() {
                 lp_farm_token_amount: managed_biguint!(400_000_000),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(400_000_000),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -467,6 +474,7 @@ fn test_stake_farm_through_proxy_with_merging() {
                 lp_farm_token_amount: managed_biguint!(1_000_000_000),
                 staking_farm_token_nonce: 2,
                 staking_farm_token_amount: managed_biguint!(1_000_000_000),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -540,6 +548,7 @@ fn test_farm_stake_proxy_merging_boosted_rewards() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -601,6 +610,7 @@ fn test_farm_stake_proxy_merging_boosted_rewards() {
                 lp_farm_token_amount: managed_biguint!(farm_amount * 2),
                 staking_farm_token_nonce: 2,
                 staking_farm_token_amount: managed_biguint!(farm_amount * 2),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -735,6 +745,7 @@ fn claim_for_others_positive_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -905,6 +916,7 @@ fn stake_farm_through_proxy_migration_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 1,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -946,6 +958,7 @@ fn stake_farm_through_proxy_migration_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount * 2),
                 staking_farm_token_nonce: 4,
                 staking_farm_token_amount: managed_biguint!(farm_amount * 2),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -982,6 +995,7 @@ fn stake_farm_through_proxy_migration_test() {
                 lp_farm_token_amount: managed_biguint!(farm_amount),
                 staking_farm_token_nonce: 5,
                 staking_farm_token_amount: managed_biguint!(farm_amount),
+                additional_staking_farm_tokens: ManagedVec::new(),
             }),
         )
     });
@@ -1216,3 +1230,186 @@ fn total_farm_position_after_claim_and_exit_metastaking_test() {
     // Total farm position should be 0 after full unstake
     setup.check_user_total_staking_farm_position(&user_address, 0);
 }
+
+#[test]
+fn additional_staking_farm_test() {
+    let mut setup = FarmStakingSetup::new(
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+        farm_staking::contract_obj,
+        farm_staking_proxy::contract_obj,
+    );
+
+    let owner_address = setup.owner_addr.clone();
+    let user_address = setup.user_addr.clone();
+    let wegld_staking_farm_wrapper = setup_staking_farm_with_tokens(
+        &owner_address,
+        setup.energy_factory_wrapper.address_ref(),
+        &mut setup.b_mock,
+        farm_staking::contract_obj,
+        WEGLD_TOKEN_ID,
+        WEGLD_STAKING_FARM_TOKEN_ID,
+    );
+    add_proxy_to_whitelist(
+        &owner_address,
+        setup.proxy_wrapper.address_ref(),
+        &mut setup.b_mock,
+        &wegld_staking_farm_wrapper,
+    );
+
+    let wegld_staking_farm_address = wegld_staking_farm_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_additional_staking_farm(
+                    managed_address!(&wegld_staking_farm_address),
+                    managed_token_id!(LP_TOKEN_ID),
+                    managed_token_id!(WEGLD_STAKING_FARM_TOKEN_ID),
+                );
+            },
+        )
+        .assert_user_error("Staking token not in pair");
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_additional_staking_farm(
+                    managed_address!(&wegld_staking_farm_address),
+                    managed_token_id!(RIDE_TOKEN_ID),
+                    managed_token_id!(WEGLD_STAKING_FARM_TOKEN_ID),
+                );
+            },
+        )
+        .assert_user_error("Staking farm already added");
+    setup
+        .b_mock
+        .execute_tx(
+            &owner_address,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_additional_staking_farm(
+                    managed_address!(&wegld_staking_farm_address),
+                    managed_token_id!(WEGLD_TOKEN_ID),
+                    managed_token_id!(WEGLD_STAKING_FARM_TOKEN_ID),
+                );
+            },
+        )
+        .assert_ok();
+
+    // the position enters both staking farms, with the safe price of the LP tokens in each token
+    let expected_staking_token_amount = 1_001_000_000;
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &setup.proxy_wrapper,
+            LP_FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_TOTAL_LP_TOKENS),
+            |sc| {
+                let stake_result = sc.stake_farm_tokens(OptionalValue::None);
+                assert_eq!(stake_result.additional_staking_boosted_rewards.len(), 1);
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.execute_in_managed_environment(|| {
+        let expected_attributes = DualYieldTokenAttributes::<DebugApi> {
+            lp_farm_token_nonce: 1,
+            lp_farm_token_amount: managed_biguint!(USER_TOTAL_LP_TOKENS),
+            staking_farm_token_nonce: 1,
+            staking_farm_token_amount: managed_biguint!(expected_staking_token_amount),
+            additional_staking_farm_tokens: ManagedVec::from_single_item(EsdtTokenPayment::new(
+                managed_token_id!(WEGLD_STAKING_FARM_TOKEN_ID),
+                1,
+                managed_biguint!(expected_staking_token_amount),
+            )),
+        };
+        setup.b_mock.check_nft_balance(
+            &user_address,
+            DUAL_YIELD_TOKEN_ID,
+            1,
+            &rust_biguint!(expected_staking_token_amount),
+            Some(&expected_attributes),
+        );
+    });
+
+    // both staking farms give the same rewards for the same position
+    setup
+        .b_mock
+        .set_block_nonce(BLOCK_NONCE_AFTER_PAIR_SETUP + 20);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &setup.proxy_wrapper,
+            DUAL_YIELD_TOKEN_ID,
+            1,
+            &rust_biguint!(expected_staking_token_amount),
+            |sc| {
+                let claim_result = sc.claim_dual_yield_endpoint(OptionalValue::None);
+                assert_eq!(claim_result.staking_farm_rewards.amount, 1_899);
+
+                let additional_rewards = claim_result.additional_staking_farm_rewards;
+                assert_eq!(additional_rewards.len(), 1);
+                let additional_reward = additional_rewards.get(0);
+                assert_eq!(
+                    additional_reward.token_identifier,
+                    managed_token_id!(WEGLD_TOKEN_ID)
+                );
+                assert_eq!(additional_reward.amount, 1_899);
+            },
+        )
+        .assert_ok();
+
+    // the WEGLD from the LP position goes to the WEGLD staking farm
+    setup.b_mock.set_block_epoch(10);
+    let mut unbond_token_amount = 0u64;
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &setup.proxy_wrapper,
+            DUAL_YIELD_TOKEN_ID,
+            2,
+            &rust_biguint!(expected_staking_token_amount),
+            |sc| {
+                let unstake_result = sc.unstake_farm_tokens(
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                    OptionalValue::None,
+                );
+                assert_eq!(unstake_result.other_token_payment.amount, 0);
+                assert_eq!(unstake_result.additional_staking_rewards.len(), 1);
+
+                let additional_unbond_tokens = unstake_result.additional_unbond_staking_farm_tokens;
+                assert_eq!(additional_unbond_tokens.len(), 1);
+                let additional_unbond_token = additional_unbond_tokens.get(0);
+                assert_eq!(
+                    additional_unbond_token.token_identifier,
+                    managed_token_id!(WEGLD_STAKING_FARM_TOKEN_ID)
+                );
+                assert_eq!(additional_unbond_token.token_nonce, 3);
+                unbond_token_amount = additional_unbond_token.amount.to_u64().unwrap();
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_nft_balance::<Empty>(
+        &user_address,
+        WEGLD_STAKING_FARM_TOKEN_ID,
+        3,
+        &rust_biguint!(unbond_token_amount),
+        None,
+    );
+    assert!(unbond_token_amount > 0);
+}
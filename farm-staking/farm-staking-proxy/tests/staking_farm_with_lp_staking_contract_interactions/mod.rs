@@ -6,7 +6,7 @@ use energy_query::Energy;
 use farm_with_locked_rewards::Farm;
 use multiversx_sc::{
     codec::multi_types::OptionalValue,
-    types::{Address, BigInt},
+    types::{Address, BigInt, ManagedVec},
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, rust_biguint,
@@ -193,6 +193,7 @@ where
                 lp_farm_token_amount: managed_biguint!(lp_farm_token_stake_amount),
                 staking_farm_token_nonce: expected_staking_farm_token_nonce,
                 staking_farm_token_amount: managed_biguint!(expected_staking_token_amount),
+                additional_staking_farm_tokens: ManagedVec::new(),
             };
 
             self.b_mock.check_nft_balance(
@@ -30,6 +30,27 @@ pub fn setup_staking_farm<StakingContractObjBuilder>(
     b_mock: &mut BlockchainStateWrapper,
     builder: StakingContractObjBuilder,
 ) -> ContractObjWrapper<farm_staking::ContractObj<DebugApi>, StakingContractObjBuilder>
+where
+    StakingContractObjBuilder: 'static + Copy + Fn() -> farm_staking::ContractObj<DebugApi>,
+{
+    setup_staking_farm_with_tokens(
+        owner_addr,
+        energy_factory_address,
+        b_mock,
+        builder,
+        STAKING_TOKEN_ID,
+        STAKING_FARM_TOKEN_ID,
+    )
+}
+
+pub fn setup_staking_farm_with_tokens<StakingContractObjBuilder>(
+    owner_addr: &Address,
+    energy_factory_address: &Address,
+    b_mock: &mut BlockchainStateWrapper,
+    builder: StakingContractObjBuilder,
+    staking_token_id: &[u8],
+    staking_farm_token_id: &[u8],
+) -> ContractObjWrapper<farm_staking::ContractObj<DebugApi>, StakingContractObjBuilder>
 where
     StakingContractObjBuilder: 'static + Copy + Fn() -> farm_staking::ContractObj<DebugApi>,
 {
@@ -39,7 +60,7 @@ where
 
     b_mock
         .execute_tx(owner_addr, &farm_staking_wrapper, &rust_zero, |sc| {
-            let farming_token_id = managed_token_id!(STAKING_TOKEN_ID);
+            let farming_token_id = managed_token_id!(staking_token_id);
             let div_const = managed_biguint!(DIVISION_SAFETY_CONSTANT);
             let max_apr = managed_biguint!(MAX_APR);
 
@@ -55,7 +76,7 @@ where
             sc.energy_factory_address()
                 .set(managed_address!(energy_factory_address));
             sc.farm_token()
-                .set_token_id(managed_token_id!(STAKING_FARM_TOKEN_ID));
+                .set_token_id(managed_token_id!(staking_farm_token_id));
 
             sc.state().set(State::Active);
             sc.produce_rewards_enabled().set(true);
@@ -81,7 +102,7 @@ where
 
     b_mock.set_esdt_balance(
         farm_staking_wrapper.address_ref(),
-        STAKING_REWARD_TOKEN_ID,
+        staking_token_id,
         &rust_biguint!(REWARD_CAPACITY),
     );
 
@@ -92,7 +113,7 @@ where
     ];
     b_mock.set_esdt_local_roles(
         farm_staking_wrapper.address_ref(),
-        STAKING_FARM_TOKEN_ID,
+        staking_farm_token_id,
         &farm_token_roles[..],
     );
 
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           17
+// Endpoints:                           19
 // Async Callback:                       1
-// Total number of exported functions:  20
+// Total number of exported functions:  22
 
 #![no_std]
 
@@ -28,6 +28,8 @@ multiversx_sc_wasm_adapter::endpoints! {
         getStakingTokenId => staking_token_id
         getFarmTokenId => staking_farm_token_id
         getLpTokenId => lp_token_id
+        addAdditionalStakingFarm => add_additional_staking_farm
+        getAdditionalStakingFarms => additional_staking_farms
         getLpFarmTokenId => lp_farm_token_id
         setEnergyFactoryAddress => set_energy_factory_address
         getEnergyFactoryAddress => energy_factory_address
@@ -166,8 +166,28 @@ impl<M: ManagedTypeApi> Energy<M> {
     }
 }
 
+pub const DEFAULT_ENERGY_SNAPSHOT_RETENTION_EPOCHS: Epoch = 30;
+pub const MAX_PRUNED_SNAPSHOTS_PER_UPDATE: usize = 2;
+
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct EnergySnapshot<M: ManagedTypeApi> {
+    pub epoch: Epoch,
+    pub energy: Energy<M>,
+}
+
 #[multiversx_sc::module]
 pub trait EnergyModule: crate::events::EventsModule {
+    /// Number of past epochs for which the users' energy can still be queried.
+    /// Has to cover the snapshot epochs of all the governance proposals that can still be voted.
+    #[only_owner]
+    #[endpoint(setEnergySnapshotRetentionEpochs)]
+    fn set_energy_snapshot_retention_epochs(&self, retention_epochs: Epoch) {
+        require!(retention_epochs > 0, "Invalid retention epochs");
+
+        self.energy_snapshot_retention_epochs()
+            .set(retention_epochs);
+    }
+
     fn update_energy<T, F: FnOnce(&mut Energy<Self::Api>) -> T>(
         &self,
         user: &ManagedAddress,
@@ -182,10 +202,132 @@ pub trait EnergyModule: crate::events::EventsModule {
 
     fn set_energy_entry(&self, user: &ManagedAddress, new_energy: Energy<Self::Api>) {
         let prev_energy = self.get_updated_energy_entry_for_user(user);
+        self.save_energy_snapshot(user, &new_energy);
         self.user_energy(user).set(&new_energy);
         self.emit_energy_updated_event(user, prev_energy, new_energy);
     }
 
+    /// Keeps one snapshot per epoch, holding the last energy entry set in that epoch
+    fn save_energy_snapshot(&self, user: &ManagedAddress, new_energy: &Energy<Self::Api>) {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut snapshots_mapper = self.user_energy_snapshots(user);
+        let snapshots_len = snapshots_mapper.len();
+        if snapshots_len == 0 {
+            // entries set before snapshots were introduced are kept as the first snapshot
+            let energy_mapper = self.user_energy(user);
+            if !energy_mapper.is_empty() {
+                let prev_energy = energy_mapper.get();
+                let prev_epoch = prev_energy.get_last_update_epoch();
+                if prev_epoch < current_epoch {
+                    let _ = snapshots_mapper.push(&EnergySnapshot {
+                        epoch: prev_epoch,
+                        energy: prev_energy,
+                    });
+                }
+            }
+        } else if snapshots_mapper.get(snapshots_len).epoch == current_epoch {
+            snapshots_mapper.set(
+                snapshots_len,
+                &EnergySnapshot {
+                    epoch: current_epoch,
+                    energy: new_energy.clone(),
+                },
+            );
+
+            return;
+        }
+
+        let _ = snapshots_mapper.push(&EnergySnapshot {
+            epoch: current_epoch,
+            energy: new_energy.clone(),
+        });
+
+        self.prune_energy_snapshots(user, current_epoch);
+    }
+
+    /// Removes the oldest snapshots that are no longer needed for queries in the retention period.
+    /// Only a few are removed at a time, so each energy update has a bounded cost.
+    fn prune_energy_snapshots(&self, user: &ManagedAddress, current_epoch: Epoch) {
+        let retention_epochs = self.energy_snapshot_retention_epochs().get();
+        let min_query_epoch = current_epoch.saturating_sub(retention_epochs);
+        let snapshots_mapper = self.user_energy_snapshots(user);
+        let snapshots_len = snapshots_mapper.len();
+        let first_index_mapper = self.user_energy_snapshots_first_index(user);
+        let mut first_index = core::cmp::max(first_index_mapper.get(), 1);
+
+        let mut pruned_snapshots = 0;
+        while pruned_snapshots < MAX_PRUNED_SNAPSHOTS_PER_UPDATE
+            && first_index < snapshots_len
+            && snapshots_mapper.get(first_index + 1).epoch <= min_query_epoch
+        {
+            snapshots_mapper.clear_entry(first_index);
+            first_index += 1;
+            pruned_snapshots += 1;
+        }
+
+        if pruned_snapshots > 0 {
+            first_index_mapper.set(first_index);
+        }
+    }
+
+    /// The user's energy entry at the end of the given epoch.
+    /// Epochs before the snapshot retention period might no longer be available.
+    #[view(getEnergyEntryForUserAtEpoch)]
+    fn get_energy_entry_for_user_at_epoch(
+        &self,
+        user: &ManagedAddress,
+        epoch: Epoch,
+    ) -> Energy<Self::Api> {
+        let current_epoch = self.blockchain().get_block_epoch();
+        require!(epoch <= current_epoch, "Invalid epoch");
+
+        let snapshots_mapper = self.user_energy_snapshots(user);
+        if snapshots_mapper.is_empty() {
+            let energy_mapper = self.user_energy(user);
+            if !energy_mapper.is_empty() {
+                let mut energy = energy_mapper.get();
+                if energy.get_last_update_epoch() <= epoch {
+                    energy.deplete(epoch);
+
+                    return energy;
+                }
+            }
+
+            return Energy::new_zero_energy(epoch);
+        }
+
+        // binary search for the last snapshot taken at or before the given epoch
+        let mut low = core::cmp::max(self.user_energy_snapshots_first_index(user).get(), 1);
+        let mut high = snapshots_mapper.len();
+        let mut opt_found_index = None;
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            if snapshots_mapper.get(mid).epoch <= epoch {
+                opt_found_index = Some(mid);
+                low = mid + 1;
+            } else {
+                high = mid - 1;
+            }
+        }
+
+        match opt_found_index {
+            Some(index) => {
+                let mut energy = snapshots_mapper.get(index).energy;
+                energy.deplete(epoch);
+
+                energy
+            }
+            None => Energy::new_zero_energy(epoch),
+        }
+    }
+
+    #[view(getEnergyAmountForUserAtEpoch)]
+    fn get_energy_amount_for_user_at_epoch(&self, user: ManagedAddress, epoch: Epoch) -> BigUint {
+        let energy = self.get_energy_entry_for_user_at_epoch(&user, epoch);
+
+        energy.get_energy_amount()
+    }
+
     #[view(getEnergyEntryForUser)]
     fn get_updated_energy_entry_for_user(&self, user: &ManagedAddress) -> Energy<Self::Api> {
         let current_epoch = self.blockchain().get_block_epoch();
@@ -209,4 +351,14 @@ pub trait EnergyModule: crate::events::EventsModule {
 
     #[storage_mapper("userEnergy")]
     fn user_energy(&self, user: &ManagedAddress) -> SingleValueMapper<Energy<Self::Api>>;
+
+    #[storage_mapper("userEnergySnapshots")]
+    fn user_energy_snapshots(&self, user: &ManagedAddress) -> VecMapper<EnergySnapshot<Self::Api>>;
+
+    #[storage_mapper("userEnergySnapshotsFirstIndex")]
+    fn user_energy_snapshots_first_index(&self, user: &ManagedAddress) -> SingleValueMapper<usize>;
+
+    #[view(getEnergySnapshotRetentionEpochs)]
+    #[storage_mapper("energySnapshotRetentionEpochs")]
+    fn energy_snapshot_retention_epochs(&self) -> SingleValueMapper<Epoch>;
 }
@@ -22,7 +22,7 @@ use mergeable::Mergeable;
 use simple_lock::locked_token::LockedTokenAttributes;
 use unwrappable::Unwrappable;
 
-use crate::energy::Energy;
+use crate::energy::{Energy, DEFAULT_ENERGY_SNAPSHOT_RETENTION_EPOCHS};
 
 #[multiversx_sc::contract]
 pub trait SimpleLockEnergy:
@@ -88,11 +88,17 @@ pub trait SimpleLockEnergy:
         self.old_locked_asset_factory_address()
             .set_if_empty(&old_locked_asset_factory_address);
 
+        self.energy_snapshot_retention_epochs()
+            .set(DEFAULT_ENERGY_SNAPSHOT_RETENTION_EPOCHS);
+
         self.set_paused(true);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.energy_snapshot_retention_epochs()
+            .set_if_empty(DEFAULT_ENERGY_SNAPSHOT_RETENTION_EPOCHS);
+    }
 
     /// Locks a whitelisted token until `unlock_epoch` and receive meta ESDT LOCKED tokens
     /// on a 1:1 ratio. Accepted input tokens:
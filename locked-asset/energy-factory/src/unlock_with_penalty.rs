@@ -87,7 +87,7 @@ pub trait UnlockWithPenaltyModule:
                 payment.token_nonce,
                 penalty_amount,
             );
-            self.send_fees_to_unstake_sc(fees);
+            self.send_fees_to_unstake_sc(caller.clone(), fees);
         }
 
         let mut energy = reduce_result.energy;
@@ -13,7 +13,7 @@ mod token_unstake_proxy {
 
         #[payable("*")]
         #[endpoint(depositFees)]
-        fn deposit_fees(&self);
+        fn deposit_fees(&self, opt_user: OptionalValue<ManagedAddress>);
     }
 }
 
@@ -51,6 +51,28 @@ pub trait UnstakeModule:
         self.set_energy_entry(&user, new_energy);
     }
 
+    /// Whitelisted SCs that unlock locked tokens early on behalf of users send the penalty here.
+    /// The penalty cannot be refunded, so it is burned and sent to the fees collector right away.
+    #[payable("*")]
+    #[endpoint(depositPenaltyFees)]
+    fn deposit_penalty_fees(&self) {
+        self.require_not_paused();
+
+        let caller = self.blockchain().get_caller();
+        self.require_sc_address_whitelisted(&caller);
+
+        let payment = self.call_value().single_esdt();
+        self.locked_token()
+            .require_same_token(&payment.token_identifier);
+
+        let locking_sc_address = self.token_unstake_sc_address().get();
+        let _: IgnoreValue = self
+            .token_unstake_sc_proxy_obj(locking_sc_address)
+            .deposit_fees(OptionalValue::<ManagedAddress>::None)
+            .with_esdt_transfer(payment)
+            .execute_on_dest_context();
+    }
+
     fn unstake_tokens(
         &self,
         caller: ManagedAddress,
@@ -69,11 +91,11 @@ pub trait UnstakeModule:
             .execute_on_dest_context();
     }
 
-    fn send_fees_to_unstake_sc(&self, fees: EsdtTokenPayment) {
+    fn send_fees_to_unstake_sc(&self, user: ManagedAddress, fees: EsdtTokenPayment) {
         let locking_sc_address = self.token_unstake_sc_address().get();
         let _: IgnoreValue = self
             .token_unstake_sc_proxy_obj(locking_sc_address)
-            .deposit_fees()
+            .deposit_fees(OptionalValue::Some(user))
             .with_esdt_transfer(fees)
             .execute_on_dest_context();
     }
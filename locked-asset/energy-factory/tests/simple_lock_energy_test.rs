@@ -566,3 +566,156 @@ fn extend_lock_period_endpoint_test() {
         LOCK_OPTIONS[1] * energy_per_epoch.clone() - energy_per_epoch.clone()
     );
 }
+
+#[test]
+fn energy_snapshots_test() {
+    let mut setup = SimpleLockEnergySetup::new(energy_factory::contract_obj);
+    let first_user = setup.first_user.clone();
+    let half_balance = USER_BALANCE / 2;
+    let quarter_balance = USER_BALANCE / 4;
+
+    let mut current_epoch = 1;
+    setup.b_mock.set_block_epoch(current_epoch);
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            half_balance,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    let first_unlock_epoch = to_start_of_month(current_epoch + LOCK_OPTIONS[0]);
+
+    // two locks in the same epoch only keep the latest energy
+    current_epoch = 5;
+    setup.b_mock.set_block_epoch(current_epoch);
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            quarter_balance,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    setup
+        .lock(
+            &first_user,
+            BASE_ASSET_TOKEN_ID,
+            quarter_balance,
+            LOCK_OPTIONS[0],
+        )
+        .assert_ok();
+    let second_unlock_epoch = to_start_of_month(current_epoch + LOCK_OPTIONS[0]);
+
+    setup
+        .b_mock
+        .execute_query(&setup.sc_wrapper, |sc| {
+            assert_eq!(
+                sc.user_energy_snapshots(&managed_address!(&first_user))
+                    .len(),
+                2
+            );
+        })
+        .assert_ok();
+
+    // before any lock
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, 0),
+        rust_biguint!(0)
+    );
+
+    // only the first lock, depleted to the requested epoch
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, 1),
+        rust_biguint!(half_balance) * (first_unlock_epoch - 1)
+    );
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, 3),
+        rust_biguint!(half_balance) * (first_unlock_epoch - 3)
+    );
+
+    // latest snapshot matches the current energy
+    let expected_energy = rust_biguint!(half_balance) * (first_unlock_epoch - current_epoch)
+        + rust_biguint!(half_balance) * (second_unlock_epoch - current_epoch);
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, current_epoch),
+        expected_energy
+    );
+    assert_eq!(setup.get_user_energy(&first_user), expected_energy);
+
+    // future epochs are not allowed
+    setup
+        .b_mock
+        .execute_query(&setup.sc_wrapper, |sc| {
+            let _ = sc.get_energy_amount_for_user_at_epoch(
+                managed_address!(&first_user),
+                current_epoch + 1,
+            );
+        })
+        .assert_user_error("Invalid epoch");
+}
+
+#[test]
+fn energy_snapshots_pruning_test() {
+    let mut setup = SimpleLockEnergySetup::new(energy_factory::contract_obj);
+    let first_user = setup.first_user.clone();
+    let owner = setup.owner.clone();
+    let lock_amount = USER_BALANCE / 8;
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_energy_snapshot_retention_epochs(5);
+        })
+        .assert_ok();
+
+    let mut unlock_epochs = Vec::new();
+    for lock_epoch in [1, 3, 5, 7, 20, 21] {
+        setup.b_mock.set_block_epoch(lock_epoch);
+        setup
+            .lock(
+                &first_user,
+                BASE_ASSET_TOKEN_ID,
+                lock_amount,
+                LOCK_OPTIONS[0],
+            )
+            .assert_ok();
+        unlock_epochs.push(to_start_of_month(lock_epoch + LOCK_OPTIONS[0]));
+    }
+
+    // at most two snapshots are pruned on each update,
+    // and the last one before the retention period is kept
+    setup
+        .b_mock
+        .execute_query(&setup.sc_wrapper, |sc| {
+            let user = managed_address!(&first_user);
+            let snapshots_mapper = sc.user_energy_snapshots(&user);
+            assert_eq!(snapshots_mapper.len(), 6);
+            assert_eq!(sc.user_energy_snapshots_first_index(&user).get(), 4);
+            for index in 1..=3 {
+                assert!(snapshots_mapper.item_is_empty(index));
+            }
+            assert_eq!(snapshots_mapper.get(4).epoch, 7);
+        })
+        .assert_ok();
+
+    let energy_at_epoch = |epoch: u64, nr_locks: usize| {
+        unlock_epochs[..nr_locks]
+            .iter()
+            .fold(rust_biguint!(0), |acc, unlock_epoch| {
+                acc + rust_biguint!(lock_amount) * (unlock_epoch - epoch)
+            })
+    };
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, 16),
+        energy_at_epoch(16, 4)
+    );
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, 20),
+        energy_at_epoch(20, 5)
+    );
+    assert_eq!(
+        setup.get_user_energy_at_epoch(&first_user, 21),
+        setup.get_user_energy(&first_user)
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           33
+// Endpoints:                           38
 // Async Callback:                       1
-// Total number of exported functions:  36
+// Total number of exported functions:  41
 
 #![no_std]
 
@@ -27,8 +27,12 @@ multiversx_sc_wasm_adapter::endpoints! {
         getLockedTokenId => locked_token
         getBaseAssetTokenId => base_asset_token_id
         getLegacyLockedTokenId => legacy_locked_token_id
+        setEnergySnapshotRetentionEpochs => set_energy_snapshot_retention_epochs
+        getEnergyEntryForUserAtEpoch => get_energy_entry_for_user_at_epoch
+        getEnergyAmountForUserAtEpoch => get_energy_amount_for_user_at_epoch
         getEnergyEntryForUser => get_updated_energy_entry_for_user
         getEnergyAmountForUser => get_energy_amount_for_user
+        getEnergySnapshotRetentionEpochs => energy_snapshot_retention_epochs
         addLockOptions => add_lock_options
         getLockOptions => get_lock_options_view
         unlockEarly => unlock_early
@@ -36,6 +40,7 @@ multiversx_sc_wasm_adapter::endpoints! {
         getPenaltyAmount => calculate_penalty_amount
         setTokenUnstakeAddress => set_token_unstake_address
         revertUnstake => revert_unstake
+        depositPenaltyFees => deposit_penalty_fees
         getTokenUnstakeScAddress => token_unstake_sc_address
         setEnergyForOldTokens => set_energy_for_old_tokens
         updateEnergyAfterOldTokenUnlock => update_energy_after_old_token_unlock
@@ -0,0 +1,38 @@
+multiversx_sc::imports!();
+
+use common_structs::Epoch;
+
+use crate::constants::CALLER_ON_COOLDOWN;
+
+#[multiversx_sc::module]
+pub trait ConfigModule {
+    fn check_address_on_cooldown(&self, last_transfer_mapper: &SingleValueMapper<Epoch>) {
+        if last_transfer_mapper.is_empty() {
+            return;
+        }
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let epochs_cooldown_duration = self.epochs_cooldown_duration().get();
+        let last_transfer_epoch = last_transfer_mapper.get();
+        let epochs_since_last_transfer = current_epoch - last_transfer_epoch;
+        require!(
+            epochs_since_last_transfer > epochs_cooldown_duration,
+            CALLER_ON_COOLDOWN
+        )
+    }
+
+    #[storage_mapper("lockedTokenId")]
+    fn locked_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("minLockEpochs")]
+    fn min_lock_epochs(&self) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("epochsCooldownDuration")]
+    fn epochs_cooldown_duration(&self) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("senderLastTransferEpoch")]
+    fn sender_last_transfer_epoch(&self, sender: &ManagedAddress) -> SingleValueMapper<Epoch>;
+
+    #[storage_mapper("receiverLastTransferEpoch")]
+    fn receiver_last_transfer_epoch(&self, receiver: &ManagedAddress) -> SingleValueMapper<Epoch>;
+}
@@ -7,3 +7,10 @@ pub static ALREADY_SENT_TO_ADDRESS: &[u8] =
 pub static TRANSFER_NON_EXISTENT: &[u8] = b"The transfer does not exist";
 pub static ADDRESS_BLACKLISTED: &[u8] = b"The address is blacklisted";
 pub static ADDRESS_NOT_BLACKLISTED: &[u8] = b"The address is not blacklisted";
+pub static INVALID_VESTING_SCHEDULE: &[u8] = b"Invalid vesting schedule";
+pub static VESTED_TRANSFER_NON_EXISTENT: &[u8] = b"The vested transfer does not exist";
+pub static ONLY_RECEIVER_CAN_WITHDRAW: &[u8] = b"Only the receiver can withdraw the vested funds";
+pub static ONLY_SENDER_CAN_CANCEL: &[u8] = b"Only the sender can cancel the vested transfer";
+pub static VESTING_ALREADY_STARTED: &[u8] = b"Vesting already started";
+
+pub const MAX_VESTING_TRANCHES: u64 = 52;
@@ -1,6 +1,9 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use common_structs::PaymentsVec;
+
+use crate::vesting::{TransferId, VestedTransfer};
 use crate::LockedFunds;
 
 #[derive(TypeAbi, TopEncode)]
@@ -10,6 +13,13 @@ pub struct LkmexTransferEvent<M: ManagedTypeApi> {
     locked_funds: LockedFunds<M>,
 }
 
+#[derive(TypeAbi, TopEncode)]
+pub struct VestedTransferEvent<M: ManagedTypeApi> {
+    transfer_id: TransferId,
+    vested_transfer: VestedTransfer<M>,
+    released_funds: PaymentsVec<M>,
+}
+
 #[multiversx_sc::module]
 pub trait LkmexTransferEventsModule {
     fn emit_withdraw_event(
@@ -54,6 +64,46 @@ pub trait LkmexTransferEventsModule {
         self.lock_funds_event(event_data);
     }
 
+    fn emit_lock_funds_vested_event(
+        &self,
+        transfer_id: TransferId,
+        vested_transfer: VestedTransfer<Self::Api>,
+    ) {
+        let event_data = VestedTransferEvent {
+            transfer_id,
+            vested_transfer,
+            released_funds: PaymentsVec::new(),
+        };
+        self.lock_funds_vested_event(event_data);
+    }
+
+    fn emit_withdraw_vested_event(
+        &self,
+        transfer_id: TransferId,
+        vested_transfer: VestedTransfer<Self::Api>,
+        released_funds: PaymentsVec<Self::Api>,
+    ) {
+        let event_data = VestedTransferEvent {
+            transfer_id,
+            vested_transfer,
+            released_funds,
+        };
+        self.withdraw_vested_event(event_data);
+    }
+
+    fn emit_cancel_vested_transfer_event(
+        &self,
+        transfer_id: TransferId,
+        vested_transfer: VestedTransfer<Self::Api>,
+    ) {
+        let event_data = VestedTransferEvent {
+            transfer_id,
+            released_funds: vested_transfer.funds.clone(),
+            vested_transfer,
+        };
+        self.cancel_vested_transfer_event(event_data);
+    }
+
     #[event("withdraw_event")]
     fn withdraw_event(&self, event_data: LkmexTransferEvent<Self::Api>);
 
@@ -62,4 +112,13 @@ pub trait LkmexTransferEventsModule {
 
     #[event("lock_funds_event")]
     fn lock_funds_event(&self, event_data: LkmexTransferEvent<Self::Api>);
+
+    #[event("lock_funds_vested_event")]
+    fn lock_funds_vested_event(&self, event_data: VestedTransferEvent<Self::Api>);
+
+    #[event("withdraw_vested_event")]
+    fn withdraw_vested_event(&self, event_data: VestedTransferEvent<Self::Api>);
+
+    #[event("cancel_vested_transfer_event")]
+    fn cancel_vested_transfer_event(&self, event_data: VestedTransferEvent<Self::Api>);
 }
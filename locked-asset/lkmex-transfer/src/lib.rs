@@ -4,9 +4,11 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod config;
 pub mod constants;
 pub mod energy_transfer;
 pub mod events;
+pub mod vesting;
 
 use common_structs::{Epoch, PaymentsVec};
 use permissions_module::Permissions;
@@ -29,7 +31,9 @@ pub struct ScheduledTransfer<M: ManagedTypeApi> {
 
 #[multiversx_sc::contract]
 pub trait LkmexTransfer:
-    energy_transfer::EnergyTransferModule
+    config::ConfigModule
+    + energy_transfer::EnergyTransferModule
+    + vesting::VestingModule
     + events::LkmexTransferEventsModule
     + energy_query::EnergyQueryModule
     + utils::UtilsModule
@@ -149,21 +153,6 @@ pub trait LkmexTransfer:
         self.emit_lock_funds_event(sender, receiver, locked_funds);
     }
 
-    fn check_address_on_cooldown(&self, last_transfer_mapper: &SingleValueMapper<Epoch>) {
-        if last_transfer_mapper.is_empty() {
-            return;
-        }
-
-        let current_epoch = self.blockchain().get_block_epoch();
-        let epochs_cooldown_duration = self.epochs_cooldown_duration().get();
-        let last_transfer_epoch = last_transfer_mapper.get();
-        let epochs_since_last_transfer = current_epoch - last_transfer_epoch;
-        require!(
-            epochs_since_last_transfer > epochs_cooldown_duration,
-            CALLER_ON_COOLDOWN
-        )
-    }
-
     #[view(getScheduledTransfers)]
     fn get_scheduled_transfers(
         &self,
@@ -193,19 +182,4 @@ pub trait LkmexTransfer:
     #[view(getAllSenders)]
     #[storage_mapper("allSenders")]
     fn all_senders(&self, receiver: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
-
-    #[storage_mapper("senderLastTransferEpoch")]
-    fn sender_last_transfer_epoch(&self, sender: &ManagedAddress) -> SingleValueMapper<Epoch>;
-
-    #[storage_mapper("receiverLastTransferEpoch")]
-    fn receiver_last_transfer_epoch(&self, receiver: &ManagedAddress) -> SingleValueMapper<Epoch>;
-
-    #[storage_mapper("lockedTokenId")]
-    fn locked_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
-
-    #[storage_mapper("minLockEpochs")]
-    fn min_lock_epochs(&self) -> SingleValueMapper<Epoch>;
-
-    #[storage_mapper("epochsCooldownDuration")]
-    fn epochs_cooldown_duration(&self) -> SingleValueMapper<Epoch>;
 }
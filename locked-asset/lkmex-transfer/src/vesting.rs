@@ -0,0 +1,253 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use common_structs::{Epoch, PaymentsVec};
+
+use crate::constants::*;
+
+pub type TransferId = u64;
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, ManagedVecItem, TypeAbi, Debug,
+)]
+pub struct VestedTransfer<M: ManagedTypeApi> {
+    pub sender: ManagedAddress<M>,
+    pub receiver: ManagedAddress<M>,
+    pub funds: PaymentsVec<M>,
+    pub locked_epoch: Epoch,
+    pub nr_tranches: u64,
+    pub epochs_between_tranches: Epoch,
+    pub withdrawn_tranches: u64,
+}
+
+impl<M: ManagedTypeApi> VestedTransfer<M> {
+    /// The first tranche unlocks under the same condition as regular transfers,
+    /// i.e. after more than `min_lock_epochs` passed. The rest follow every `epochs_between_tranches`.
+    pub fn get_unlocked_tranches(&self, current_epoch: Epoch, min_lock_epochs: Epoch) -> u64 {
+        let first_tranche_epoch = self.locked_epoch + min_lock_epochs + 1;
+        if current_epoch < first_tranche_epoch {
+            return 0;
+        }
+
+        let extra_tranches = (current_epoch - first_tranche_epoch) / self.epochs_between_tranches;
+        core::cmp::min(1 + extra_tranches, self.nr_tranches)
+    }
+
+    /// Amounts released between the `from_tranche` and `to_tranche` tranches.
+    /// Rounding is done on the cumulative amounts, so the last tranche releases all the remaining funds.
+    pub fn get_funds_between_tranches(&self, from_tranche: u64, to_tranche: u64) -> PaymentsVec<M> {
+        let mut result = PaymentsVec::new();
+        for payment in &self.funds {
+            let released_before = &payment.amount * from_tranche / self.nr_tranches;
+            let released_after = &payment.amount * to_tranche / self.nr_tranches;
+            let amount = released_after - released_before;
+            if amount > 0 {
+                result.push(EsdtTokenPayment::new(
+                    payment.token_identifier,
+                    payment.token_nonce,
+                    amount,
+                ));
+            }
+        }
+
+        result
+    }
+}
+
+#[multiversx_sc::module]
+pub trait VestingModule:
+    crate::config::ConfigModule
+    + crate::energy_transfer::EnergyTransferModule
+    + crate::events::LkmexTransferEventsModule
+    + energy_query::EnergyQueryModule
+    + utils::UtilsModule
+    + legacy_token_decode_module::LegacyTokenDecodeModule
+{
+    /// Locks the funds for the receiver, released in `nr_tranches` equal tranches.
+    /// The sender's energy is deducted entirely, while the receiver gains energy for each withdrawn tranche.
+    /// The same sender and receiver cooldowns as for regular transfers apply.
+    #[payable("*")]
+    #[endpoint(lockFundsVested)]
+    fn lock_funds_vested(
+        &self,
+        receiver: ManagedAddress,
+        nr_tranches: u64,
+        epochs_between_tranches: Epoch,
+    ) -> TransferId {
+        require!(
+            nr_tranches > 0 && nr_tranches <= MAX_VESTING_TRANCHES && epochs_between_tranches > 0,
+            INVALID_VESTING_SCHEDULE
+        );
+
+        let sender = self.blockchain().get_caller();
+        let sender_last_transfer_mapper = self.sender_last_transfer_epoch(&sender);
+        self.check_address_on_cooldown(&sender_last_transfer_mapper);
+
+        let payments = self.call_value().all_esdt_transfers().clone_value();
+        require!(!payments.is_empty(), "No payments");
+
+        let locked_token_id = self.locked_token_id().get();
+        for payment in payments.iter() {
+            require!(
+                payment.token_identifier == locked_token_id,
+                BAD_LOCKING_TOKEN
+            );
+        }
+
+        self.deduct_energy_from_sender(sender.clone(), &payments);
+
+        let transfer_id = self.last_vested_transfer_id().update(|id| {
+            *id += 1;
+            *id
+        });
+        let current_epoch = self.blockchain().get_block_epoch();
+        let vested_transfer = VestedTransfer {
+            sender: sender.clone(),
+            receiver: receiver.clone(),
+            funds: payments,
+            locked_epoch: current_epoch,
+            nr_tranches,
+            epochs_between_tranches,
+            withdrawn_tranches: 0,
+        };
+        self.vested_transfer(transfer_id).set(&vested_transfer);
+        self.receiver_vested_transfers(&receiver)
+            .insert(transfer_id);
+        self.sender_vested_transfers(&sender).insert(transfer_id);
+        sender_last_transfer_mapper.set(current_epoch);
+
+        self.emit_lock_funds_vested_event(transfer_id, vested_transfer);
+
+        transfer_id
+    }
+
+    #[endpoint(withdrawVested)]
+    fn withdraw_vested(&self, transfer_id: TransferId) -> PaymentsVec<Self::Api> {
+        let vested_transfer_mapper = self.vested_transfer(transfer_id);
+        require!(
+            !vested_transfer_mapper.is_empty(),
+            VESTED_TRANSFER_NON_EXISTENT
+        );
+
+        let caller = self.blockchain().get_caller();
+        let mut vested_transfer = vested_transfer_mapper.get();
+        require!(
+            caller == vested_transfer.receiver,
+            ONLY_RECEIVER_CAN_WITHDRAW
+        );
+
+        let receiver_last_transfer_mapper = self.receiver_last_transfer_epoch(&caller);
+        self.check_address_on_cooldown(&receiver_last_transfer_mapper);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let min_lock_epochs = self.min_lock_epochs().get();
+        let unlocked_tranches =
+            vested_transfer.get_unlocked_tranches(current_epoch, min_lock_epochs);
+        require!(
+            unlocked_tranches > vested_transfer.withdrawn_tranches,
+            TOKENS_STILL_LOCKED
+        );
+
+        let released_funds = vested_transfer
+            .get_funds_between_tranches(vested_transfer.withdrawn_tranches, unlocked_tranches);
+        vested_transfer.withdrawn_tranches = unlocked_tranches;
+        if unlocked_tranches == vested_transfer.nr_tranches {
+            vested_transfer_mapper.clear();
+            self.receiver_vested_transfers(&vested_transfer.receiver)
+                .swap_remove(&transfer_id);
+            self.sender_vested_transfers(&vested_transfer.sender)
+                .swap_remove(&transfer_id);
+        } else {
+            vested_transfer_mapper.set(&vested_transfer);
+        }
+
+        if !released_funds.is_empty() {
+            self.add_energy_to_destination(caller.clone(), &released_funds);
+            self.send().direct_multi(&caller, &released_funds);
+        }
+        receiver_last_transfer_mapper.set(current_epoch);
+
+        self.emit_withdraw_vested_event(transfer_id, vested_transfer, released_funds.clone());
+
+        released_funds
+    }
+
+    /// The sender may cancel the transfer only before the first tranche is unlocked
+    #[endpoint(cancelVestedTransfer)]
+    fn cancel_vested_transfer(&self, transfer_id: TransferId) {
+        let vested_transfer_mapper = self.vested_transfer(transfer_id);
+        require!(
+            !vested_transfer_mapper.is_empty(),
+            VESTED_TRANSFER_NON_EXISTENT
+        );
+
+        let caller = self.blockchain().get_caller();
+        let vested_transfer = vested_transfer_mapper.get();
+        require!(caller == vested_transfer.sender, ONLY_SENDER_CAN_CANCEL);
+
+        let current_epoch = self.blockchain().get_block_epoch();
+        let min_lock_epochs = self.min_lock_epochs().get();
+        require!(
+            vested_transfer.get_unlocked_tranches(current_epoch, min_lock_epochs) == 0,
+            VESTING_ALREADY_STARTED
+        );
+
+        vested_transfer_mapper.clear();
+        self.receiver_vested_transfers(&vested_transfer.receiver)
+            .swap_remove(&transfer_id);
+        self.sender_vested_transfers(&caller)
+            .swap_remove(&transfer_id);
+        self.sender_last_transfer_epoch(&caller).clear();
+
+        self.add_energy_to_destination(caller.clone(), &vested_transfer.funds);
+        self.send().direct_multi(&caller, &vested_transfer.funds);
+
+        self.emit_cancel_vested_transfer_event(transfer_id, vested_transfer);
+    }
+
+    #[view(getVestedTransfer)]
+    fn get_vested_transfer(&self, transfer_id: TransferId) -> VestedTransfer<Self::Api> {
+        let vested_transfer_mapper = self.vested_transfer(transfer_id);
+        require!(
+            !vested_transfer_mapper.is_empty(),
+            VESTED_TRANSFER_NON_EXISTENT
+        );
+
+        vested_transfer_mapper.get()
+    }
+
+    #[view(getWithdrawableVestedFunds)]
+    fn get_withdrawable_vested_funds(&self, transfer_id: TransferId) -> PaymentsVec<Self::Api> {
+        let vested_transfer = self.get_vested_transfer(transfer_id);
+        let current_epoch = self.blockchain().get_block_epoch();
+        let min_lock_epochs = self.min_lock_epochs().get();
+        let unlocked_tranches =
+            vested_transfer.get_unlocked_tranches(current_epoch, min_lock_epochs);
+        if unlocked_tranches <= vested_transfer.withdrawn_tranches {
+            return PaymentsVec::new();
+        }
+
+        vested_transfer
+            .get_funds_between_tranches(vested_transfer.withdrawn_tranches, unlocked_tranches)
+    }
+
+    #[view(getReceiverVestedTransfers)]
+    #[storage_mapper("receiverVestedTransfers")]
+    fn receiver_vested_transfers(
+        &self,
+        receiver: &ManagedAddress,
+    ) -> UnorderedSetMapper<TransferId>;
+
+    #[view(getSenderVestedTransfers)]
+    #[storage_mapper("senderVestedTransfers")]
+    fn sender_vested_transfers(&self, sender: &ManagedAddress) -> UnorderedSetMapper<TransferId>;
+
+    #[storage_mapper("vestedTransfer")]
+    fn vested_transfer(
+        &self,
+        transfer_id: TransferId,
+    ) -> SingleValueMapper<VestedTransfer<Self::Api>>;
+
+    #[storage_mapper("lastVestedTransferId")]
+    fn last_vested_transfer_id(&self) -> SingleValueMapper<TransferId>;
+}
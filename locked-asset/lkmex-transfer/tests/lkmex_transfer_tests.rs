@@ -1,6 +1,7 @@
 #![allow(deprecated)]
 
 use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::codec::Empty;
 use multiversx_sc::storage::mappers::StorageTokenWrapper;
 use multiversx_sc::types::{BigInt, EsdtLocalRole, MultiValueEncoded};
 use multiversx_sc_scenario::{managed_address, managed_biguint, whitebox_legacy::*};
@@ -11,6 +12,7 @@ use energy_factory::lock_options::LockOptionsModule;
 use energy_factory::locked_token_transfer::LockedTokenTransferModule;
 use energy_factory::SimpleLockEnergy;
 use energy_query::Energy;
+use lkmex_transfer::vesting::VestingModule;
 use lkmex_transfer::LkmexTransfer;
 use multiversx_sc_modules::pause::PauseModule;
 use permissions_module::PermissionsModule;
@@ -818,3 +820,259 @@ fn transfer_locked_token_after_unlock_period_test() {
         })
         .assert_ok();
 }
+
+#[test]
+fn vested_transfer_test() {
+    let rust_zero = rust_biguint!(0);
+    let mut b_mock = BlockchainStateWrapper::new();
+
+    let user_addr = b_mock.create_user_account(&rust_zero);
+    let claimer_addr = b_mock.create_user_account(&rust_zero);
+    let owner_addr = b_mock.create_user_account(&rust_zero);
+    let transfer_sc_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner_addr),
+        lkmex_transfer::contract_obj,
+        "Some path",
+    );
+    let factory_sc_wrapper = b_mock.create_sc_account(
+        &rust_zero,
+        Some(&owner_addr),
+        energy_factory::contract_obj,
+        "Some other path",
+    );
+
+    b_mock.set_block_epoch(5);
+
+    // Setup transfer SC
+    b_mock
+        .execute_tx(&owner_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.init(
+                managed_address!(factory_sc_wrapper.address_ref()),
+                managed_token_id!(LOCKED_TOKEN_ID),
+                4,
+                6,
+            );
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_local_roles(
+        transfer_sc_wrapper.address_ref(),
+        LOCKED_TOKEN_ID,
+        &[EsdtLocalRole::Transfer],
+    );
+
+    // setup energy factory SC
+    b_mock
+        .execute_tx(&owner_addr, &factory_sc_wrapper, &rust_zero, |sc| {
+            let mut lock_options = MultiValueEncoded::new();
+            for (option, penalty) in LOCK_OPTIONS.iter().zip(PENALTY_PERCENTAGES.iter()) {
+                lock_options.push((*option, *penalty).into());
+            }
+
+            // sc addresses don't matter here, we don't test that part
+            sc.init(
+                managed_token_id!(BASE_ASSET_TOKEN_ID),
+                managed_token_id!(LEGACY_LOCKED_TOKEN_ID),
+                managed_address!(transfer_sc_wrapper.address_ref()),
+                0,
+                lock_options,
+            );
+
+            sc.locked_token()
+                .set_token_id(managed_token_id!(LOCKED_TOKEN_ID));
+            sc.token_transfer_whitelist()
+                .add(&managed_address!(transfer_sc_wrapper.address_ref()));
+            sc.set_paused(false);
+        })
+        .assert_ok();
+
+    b_mock.set_esdt_local_roles(
+        factory_sc_wrapper.address_ref(),
+        BASE_ASSET_TOKEN_ID,
+        &[EsdtLocalRole::Mint, EsdtLocalRole::Burn],
+    );
+    b_mock.set_esdt_local_roles(
+        factory_sc_wrapper.address_ref(),
+        LOCKED_TOKEN_ID,
+        &[
+            EsdtLocalRole::NftCreate,
+            EsdtLocalRole::NftAddQuantity,
+            EsdtLocalRole::NftBurn,
+            EsdtLocalRole::Transfer,
+        ],
+    );
+    b_mock.set_esdt_local_roles(
+        factory_sc_wrapper.address_ref(),
+        LEGACY_LOCKED_TOKEN_ID,
+        &[EsdtLocalRole::NftBurn],
+    );
+
+    // setup user balance
+
+    b_mock.set_esdt_balance(
+        &user_addr,
+        BASE_ASSET_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE),
+    );
+
+    // lock tokens
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &factory_sc_wrapper,
+            BASE_ASSET_TOKEN_ID,
+            0,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                sc.lock_tokens_endpoint(LOCK_OPTIONS[0], OptionalValue::None);
+            },
+        )
+        .assert_ok();
+
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &transfer_sc_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE / 4),
+            |sc| {
+                let transfer_id = sc.lock_funds_vested(managed_address!(&claimer_addr), 2, 3);
+                assert_eq!(transfer_id, 1);
+            },
+        )
+        .assert_ok();
+
+    // vested transfers are subject to the sender cooldown
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &transfer_sc_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE / 2),
+            |sc| {
+                let _ = sc.lock_funds_vested(managed_address!(&claimer_addr), 4, 2);
+            },
+        )
+        .assert_user_error("caller cannot use this contract at this time");
+
+    // only the sender can cancel
+    b_mock
+        .execute_tx(&claimer_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.cancel_vested_transfer(1);
+        })
+        .assert_user_error("Only the sender can cancel the vested transfer");
+
+    // cancelling resets the sender cooldown
+    b_mock.set_block_epoch(6);
+    b_mock
+        .execute_tx(&user_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.cancel_vested_transfer(1);
+        })
+        .assert_ok();
+
+    b_mock.check_nft_balance::<Empty>(
+        &user_addr,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(USER_BALANCE),
+        None,
+    );
+
+    b_mock
+        .execute_esdt_transfer(
+            &user_addr,
+            &transfer_sc_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE / 2),
+            |sc| {
+                let transfer_id = sc.lock_funds_vested(managed_address!(&claimer_addr), 4, 2);
+                assert_eq!(transfer_id, 2);
+            },
+        )
+        .assert_ok();
+
+    b_mock
+        .execute_query(&factory_sc_wrapper, |sc| {
+            let energy = sc.user_energy(&managed_address!(&user_addr)).get();
+            assert_eq!(
+                energy.get_total_locked_tokens(),
+                &managed_biguint!(USER_BALANCE / 2)
+            );
+        })
+        .assert_ok();
+
+    // first tranche is still locked
+    b_mock.set_block_epoch(10);
+    b_mock
+        .execute_tx(&claimer_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.withdraw_vested(2);
+        })
+        .assert_user_error("requested funds are still locked");
+
+    // first tranche unlocked, sender can no longer cancel
+    b_mock.set_block_epoch(11);
+    b_mock
+        .execute_tx(&user_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.cancel_vested_transfer(2);
+        })
+        .assert_user_error("Vesting already started");
+
+    b_mock
+        .execute_tx(&claimer_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.withdraw_vested(2);
+        })
+        .assert_ok();
+
+    b_mock.check_nft_balance::<Empty>(
+        &claimer_addr,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(USER_BALANCE / 8),
+        None,
+    );
+
+    // two more tranches unlocked, but the receiver is on cooldown
+    b_mock.set_block_epoch(15);
+    b_mock
+        .execute_tx(&claimer_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.withdraw_vested(2);
+        })
+        .assert_user_error("caller cannot use this contract at this time");
+
+    // all the remaining tranches
+    b_mock.set_block_epoch(18);
+    b_mock
+        .execute_tx(&claimer_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.withdraw_vested(2);
+        })
+        .assert_ok();
+
+    b_mock.check_nft_balance::<Empty>(
+        &claimer_addr,
+        LOCKED_TOKEN_ID,
+        1,
+        &rust_biguint!(USER_BALANCE / 2),
+        None,
+    );
+
+    b_mock
+        .execute_query(&factory_sc_wrapper, |sc| {
+            let energy = sc.user_energy(&managed_address!(&claimer_addr)).get();
+            assert_eq!(
+                energy.get_total_locked_tokens(),
+                &managed_biguint!(USER_BALANCE / 2)
+            );
+        })
+        .assert_ok();
+
+    b_mock.set_block_epoch(25);
+    b_mock
+        .execute_tx(&claimer_addr, &transfer_sc_wrapper, &rust_zero, |sc| {
+            sc.withdraw_vested(2);
+        })
+        .assert_user_error("The vested transfer does not exist");
+}
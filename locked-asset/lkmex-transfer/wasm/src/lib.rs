@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
+// Endpoints:                           18
 // Async Callback (empty):               1
-// Total number of exported functions:  14
+// Total number of exported functions:  21
 
 #![no_std]
 
@@ -25,6 +25,13 @@ multiversx_sc_wasm_adapter::endpoints! {
         lockFunds => lock_funds
         getScheduledTransfers => get_scheduled_transfers
         getAllSenders => all_senders
+        lockFundsVested => lock_funds_vested
+        withdrawVested => withdraw_vested
+        cancelVestedTransfer => cancel_vested_transfer
+        getVestedTransfer => get_vested_transfer
+        getWithdrawableVestedFunds => get_withdrawable_vested_funds
+        getReceiverVestedTransfers => receiver_vested_transfers
+        getSenderVestedTransfers => sender_vested_transfers
         setEnergyFactoryAddress => set_energy_factory_address
         getEnergyFactoryAddress => energy_factory_address
         addAdmin => add_admin_endpoint
@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
 
 use common_structs::{Epoch, Nonce};
-use energy_factory::{locked_token_transfer::ProxyTrait as _, ProxyTrait as _};
+use energy_factory::{
+    locked_token_transfer::ProxyTrait as _, unlock_with_penalty::ProxyTrait as _,
+    unstake::ProxyTrait as _, ProxyTrait as _,
+};
 use energy_query::Energy;
 use simple_lock::locked_token::LockedTokenAttributes;
 
@@ -63,6 +66,41 @@ pub trait EnergyUpdateModule:
         self.set_energy_in_factory(user.clone(), energy, energy_factory_addr);
     }
 
+    /// The energy factory's penalty for unlocking the given amount of locked tokens right away
+    fn get_early_unlock_penalty(
+        &self,
+        locked_tokens: &EsdtTokenPayment,
+        unlock_amount: &BigUint,
+    ) -> BigUint {
+        let attributes: LockedTokenAttributes<Self::Api> = self
+            .blockchain()
+            .get_token_attributes(&locked_tokens.token_identifier, locked_tokens.token_nonce);
+        let current_epoch = self.blockchain().get_block_epoch();
+        if attributes.unlock_epoch <= current_epoch {
+            return BigUint::zero();
+        }
+
+        let lock_epochs_remaining = attributes.unlock_epoch - current_epoch;
+        let energy_factory_addr = self.energy_factory_address().get();
+        self.energy_factory_proxy(energy_factory_addr)
+            .calculate_penalty_amount(unlock_amount, lock_epochs_remaining, 0u64)
+            .execute_on_dest_context()
+    }
+
+    /// The energy factory handles the penalty like the one of its own early unlocks
+    fn send_early_unlock_penalty(&self, penalty: EsdtTokenPayment) {
+        if penalty.amount == 0u64 {
+            return;
+        }
+
+        let energy_factory_addr = self.energy_factory_address().get();
+        let _: IgnoreValue = self
+            .energy_factory_proxy(energy_factory_addr)
+            .deposit_penalty_fees()
+            .with_esdt_transfer(penalty)
+            .execute_on_dest_context();
+    }
+
     fn call_increase_energy(
         &self,
         user: ManagedAddress,
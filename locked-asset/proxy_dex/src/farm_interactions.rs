@@ -1,10 +1,11 @@
 multiversx_sc::imports!();
 
+use config::ProxyTrait as _;
 use farm::{
     base_functions::{ClaimRewardsResultType, ClaimRewardsResultWrapper},
     EnterFarmResultType, ExitFarmWithPartialPosResultType,
 };
-use farm_with_locked_rewards::ProxyTrait as _;
+use farm_with_locked_rewards::{ExitFarmForMigrationResultType, ProxyTrait as _};
 
 pub struct EnterFarmResultWrapper<M: ManagedTypeApi> {
     pub farm_token: EsdtTokenPayment<M>,
@@ -16,6 +17,12 @@ pub struct ExitFarmResultWrapper<M: ManagedTypeApi> {
     pub reward_tokens: EsdtTokenPayment<M>,
 }
 
+pub struct ExitFarmForMigrationResultWrapper<M: ManagedTypeApi> {
+    pub farming_tokens: EsdtTokenPayment<M>,
+    pub reward_tokens: EsdtTokenPayment<M>,
+    pub entering_epoch: u64,
+}
+
 #[multiversx_sc::module]
 pub trait FarmInteractionsModule {
     fn call_enter_farm(
@@ -58,6 +65,47 @@ pub trait FarmInteractionsModule {
         }
     }
 
+    fn call_exit_farm_for_migration(
+        &self,
+        user: ManagedAddress,
+        farm_address: ManagedAddress,
+        farm_token: EsdtTokenPayment,
+    ) -> ExitFarmForMigrationResultWrapper<Self::Api> {
+        let raw_result: ExitFarmForMigrationResultType<Self::Api> = self
+            .farm_contract_proxy(farm_address)
+            .exit_farm_for_migration(user)
+            .with_esdt_transfer(farm_token)
+            .execute_on_dest_context();
+        let (farming_tokens, reward_tokens, entering_epoch) = raw_result.into_tuple();
+
+        ExitFarmForMigrationResultWrapper {
+            farming_tokens,
+            reward_tokens,
+            entering_epoch,
+        }
+    }
+
+    fn call_enter_farm_for_migration(
+        &self,
+        user: ManagedAddress,
+        farm_address: ManagedAddress,
+        farming_tokens: EsdtTokenPayment,
+        entering_epoch: u64,
+    ) -> EnterFarmResultWrapper<Self::Api> {
+        let enter_farm_result: EnterFarmResultType<Self::Api> = self
+            .farm_contract_proxy(farm_address)
+            .enter_farm_for_migration(user, entering_epoch)
+            .with_esdt_transfer(farming_tokens)
+            .execute_on_dest_context();
+
+        let (output_farm_token_payment, rewards_payment) = enter_farm_result.into_tuple();
+
+        EnterFarmResultWrapper {
+            farm_token: output_farm_token_payment,
+            reward_token: rewards_payment,
+        }
+    }
+
     fn call_claim_rewards_farm(
         &self,
         user: ManagedAddress,
@@ -77,6 +125,12 @@ pub trait FarmInteractionsModule {
         }
     }
 
+    fn get_farming_token_id(&self, farm_address: ManagedAddress) -> TokenIdentifier {
+        self.farm_contract_proxy(farm_address)
+            .farming_token_id()
+            .execute_on_dest_context()
+    }
+
     #[proxy]
     fn farm_contract_proxy(&self, to: ManagedAddress)
         -> farm_with_locked_rewards::Proxy<Self::Api>;
@@ -11,6 +11,7 @@ pub mod other_sc_whitelist;
 pub mod pair_interactions;
 pub mod proxy_common;
 pub mod proxy_farm;
+pub mod proxy_farm_migration;
 pub mod proxy_pair;
 pub mod wrapped_farm_attributes;
 pub mod wrapped_farm_token_merge;
@@ -24,6 +25,7 @@ pub trait ProxyDexImpl:
     + proxy_pair::ProxyPairModule
     + pair_interactions::PairInteractionsModule
     + proxy_farm::ProxyFarmModule
+    + proxy_farm_migration::ProxyFarmMigrationModule
     + farm_interactions::FarmInteractionsModule
     + token_merge_helper::TokenMergeHelperModule
     + token_send::TokenSendModule
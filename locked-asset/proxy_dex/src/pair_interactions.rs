@@ -1,9 +1,15 @@
 multiversx_sc::imports!();
 
-use pair::pair_actions::{
-    add_liq::ProxyTrait as _,
-    common_result_types::{AddLiquidityResultType, RemoveLiquidityResultType},
-    remove_liq::ProxyTrait as _,
+use pair::{
+    config::{ProxyTrait as _, MAX_PERCENTAGE},
+    pair_actions::{
+        add_liq::ProxyTrait as _,
+        common_result_types::{
+            AddLiquidityResultType, RemoveLiquidityResultType, SwapTokensFixedInputResultType,
+        },
+        remove_liq::ProxyTrait as _,
+        swap::ProxyTrait as _,
+    },
 };
 
 pub struct AddLiquidityResultWrapper<M: ManagedTypeApi> {
@@ -87,6 +93,67 @@ pub trait PairInteractionsModule {
         }
     }
 
+    fn call_swap_tokens_fixed_input(
+        &self,
+        pair_address: ManagedAddress,
+        input_tokens: EsdtTokenPayment,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+    ) -> EsdtTokenPayment {
+        let swap_result: SwapTokensFixedInputResultType<Self::Api> = self
+            .pair_contract_proxy(pair_address)
+            .swap_tokens_fixed_input(token_out, amount_out_min)
+            .with_esdt_transfer(input_tokens)
+            .execute_on_dest_context();
+
+        swap_result
+    }
+
+    fn get_pair_token_ids(
+        &self,
+        pair_address: ManagedAddress,
+    ) -> (TokenIdentifier, TokenIdentifier) {
+        let first_token_id: TokenIdentifier = self
+            .pair_contract_proxy(pair_address.clone())
+            .first_token_id()
+            .execute_on_dest_context();
+        let second_token_id: TokenIdentifier = self
+            .pair_contract_proxy(pair_address)
+            .second_token_id()
+            .execute_on_dest_context();
+
+        (first_token_id, second_token_id)
+    }
+
+    /// The amount of input tokens to swap so that the swapped and the remaining tokens
+    /// can be added as liquidity with minimal leftovers, taking the pair's fee into account.
+    ///
+    /// swap_amount = (sqrt(r^2 * (2F - f)^2 + 4 * r * amount * F * (F - f)) - r * (2F - f)) / (2 * (F - f)),
+    /// where r is the input token reserve, f the total fee percent and F the max percentage
+    fn get_single_sided_swap_amount(
+        &self,
+        pair_address: ManagedAddress,
+        token_in: &TokenIdentifier,
+        amount_in: &BigUint,
+    ) -> BigUint {
+        let reserve_in: BigUint = self
+            .pair_contract_proxy(pair_address.clone())
+            .pair_reserve(token_in)
+            .execute_on_dest_context();
+        let total_fee_percent: u64 = self
+            .pair_contract_proxy(pair_address)
+            .total_fee_percent()
+            .execute_on_dest_context();
+
+        let fee_multiplier = BigUint::from(2 * MAX_PERCENTAGE - total_fee_percent);
+        let fee_complement = BigUint::from(MAX_PERCENTAGE - total_fee_percent);
+        let reserve_term = &reserve_in * &fee_multiplier;
+        let discriminant = &reserve_term * &reserve_term
+            + &reserve_in * amount_in * MAX_PERCENTAGE * &fee_complement * 4u64;
+
+        (discriminant.sqrt() - reserve_term) / (fee_complement * 2u64)
+    }
+
     #[proxy]
     fn pair_contract_proxy(&self, to: ManagedAddress) -> pair::Proxy<Self::Api>;
 }
@@ -18,6 +18,11 @@ pub struct EnterFarmResult<M: ManagedTypeApi> {
     pub rewards: EsdtTokenPayment<M>,
 }
 
+pub struct ExitFarmProxyResult<M: ManagedTypeApi> {
+    pub proxy_farming_token: EsdtTokenPayment<M>,
+    pub rewards: EsdtTokenPayment<M>,
+}
+
 pub type EnterFarmProxyResultType<M> = MultiValue2<EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
 pub type ExitFarmProxyResultType<M> = MultiValue2<EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
 pub type ClaimRewardsFarmProxyResultType<M> = MultiValue2<EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
@@ -184,8 +189,27 @@ pub trait ProxyFarmModule:
         self.require_wrapped_farm_token_id_not_empty();
         self.require_wrapped_lp_token_id_not_empty();
 
-        let wrapped_farm_token_mapper = self.wrapped_farm_token();
         let payment = self.call_value().single_esdt();
+        let caller = self.blockchain().get_caller();
+        let original_caller = self.get_orig_caller_from_opt(&caller, opt_original_caller);
+
+        let exit_result = self.exit_farm_proxy_common(&original_caller, farm_address, payment);
+
+        self.send_payment_non_zero(&caller, &exit_result.proxy_farming_token);
+        self.send_payment_non_zero(&caller, &exit_result.rewards);
+
+        (exit_result.proxy_farming_token, exit_result.rewards).into()
+    }
+
+    /// Exits the farm with the given wrapped farm tokens, and returns the initial proxy farming tokens
+    /// (minus any penalty) together with the rewards, without sending them.
+    fn exit_farm_proxy_common(
+        &self,
+        original_caller: &ManagedAddress,
+        farm_address: ManagedAddress,
+        payment: EsdtTokenPayment,
+    ) -> ExitFarmProxyResult<Self::Api> {
+        let wrapped_farm_token_mapper = self.wrapped_farm_token();
         wrapped_farm_token_mapper.require_same_token(&payment.token_identifier);
 
         let full_wrapped_farm_attributes: WrappedFarmTokenAttributes<Self::Api> = self
@@ -195,9 +219,6 @@ pub trait ProxyFarmModule:
         let wrapped_farm_attributes_for_exit: WrappedFarmTokenAttributes<Self::Api> =
             full_wrapped_farm_attributes.into_part(&payment.amount);
 
-        let caller = self.blockchain().get_caller();
-        let original_caller = self.get_orig_caller_from_opt(&caller, opt_original_caller);
-
         let exit_result = self.call_exit_farm(
             original_caller.clone(),
             farm_address.clone(),
@@ -213,25 +234,143 @@ pub trait ProxyFarmModule:
 
         let initial_proxy_farming_tokens = self
             .handle_farm_penalty_and_get_output_proxy_farming_token(
-                &original_caller,
+                original_caller,
                 wrapped_farm_tokens_for_initial_tokens,
                 exit_result.farming_tokens.amount,
             );
 
-        self.send_payment_non_zero(&caller, &initial_proxy_farming_tokens);
-        self.send_payment_non_zero(&caller, &exit_result.reward_tokens);
-
         wrapped_farm_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
 
         self.emit_exit_farm_proxy_event(
-            &original_caller,
+            original_caller,
             &farm_address,
             payment,
             wrapped_farm_attributes_for_exit,
             exit_result.reward_tokens.clone(),
         );
 
-        (initial_proxy_farming_tokens, exit_result.reward_tokens).into()
+        ExitFarmProxyResult {
+            proxy_farming_token: initial_proxy_farming_tokens,
+            rewards: exit_result.reward_tokens,
+        }
+    }
+
+    /// Adds liquidity with the locked token and the other pair token, and enters the farm
+    /// with the resulting wrapped LP tokens, in a single transaction.
+    ///
+    /// Returns the wrapped farm token, the farm rewards, and the locked and other token leftovers.
+    #[payable("*")]
+    #[endpoint(addLiquidityAndEnterFarmProxy)]
+    fn add_liquidity_and_enter_farm_proxy(
+        &self,
+        pair_address: ManagedAddress,
+        farm_address: ManagedAddress,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        self.require_is_intermediated_pair(&pair_address);
+        self.require_is_intermediated_farm(&farm_address);
+        self.require_wrapped_farm_token_id_not_empty();
+        self.require_wrapped_lp_token_id_not_empty();
+
+        let caller = self.blockchain().get_caller();
+        let [first_payment, second_payment] = self.call_value().multi_esdt();
+
+        let add_liq_result = self.add_liquidity_proxy_common(
+            pair_address.clone(),
+            &first_payment,
+            &second_payment,
+            first_token_amount_min,
+            second_token_amount_min,
+        );
+        let wrapped_lp_attributes = add_liq_result.wrapped_lp_attributes;
+        let new_token_amount = wrapped_lp_attributes.get_total_supply();
+        let wrapped_lp_token = self
+            .wrapped_lp_token()
+            .nft_create(new_token_amount, &wrapped_lp_attributes);
+
+        self.emit_add_liquidity_proxy_event(
+            &caller,
+            &pair_address,
+            first_payment,
+            second_payment,
+            wrapped_lp_token.clone(),
+            wrapped_lp_attributes,
+            false,
+        );
+
+        let enter_result = self.enter_farm_wrapped_lp(
+            caller.clone(),
+            farm_address.clone(),
+            wrapped_lp_token.clone(),
+        );
+        let wrapped_farm_attributes = WrappedFarmTokenAttributes {
+            farm_token: enter_result.farm_token,
+            proxy_farming_token: wrapped_lp_token,
+        };
+        let new_token_amount = wrapped_farm_attributes.get_total_supply();
+        let wrapped_farm_token = self
+            .wrapped_farm_token()
+            .nft_create(new_token_amount, &wrapped_farm_attributes);
+
+        self.emit_enter_farm_proxy_event(
+            &caller,
+            &farm_address,
+            enter_result.farming_token,
+            wrapped_farm_token.clone(),
+            wrapped_farm_attributes,
+            false,
+        );
+
+        let mut output_payments = ManagedVec::new();
+        output_payments.push(wrapped_farm_token);
+        output_payments.push(enter_result.rewards);
+        output_payments.push(add_liq_result.locked_token_leftover);
+        output_payments.push(add_liq_result.other_token_leftover);
+
+        self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
+
+        output_payments.into()
+    }
+
+    /// Exits the farm with a wrapped farm token that holds wrapped LP tokens,
+    /// and removes the liquidity, in a single transaction.
+    ///
+    /// Returns the tokens received from removing liquidity, followed by the farm rewards.
+    #[payable("*")]
+    #[endpoint(exitFarmAndRemoveLiquidityProxy)]
+    fn exit_farm_and_remove_liquidity_proxy(
+        &self,
+        farm_address: ManagedAddress,
+        pair_address: ManagedAddress,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        self.require_is_intermediated_farm(&farm_address);
+        self.require_is_intermediated_pair(&pair_address);
+        self.require_wrapped_farm_token_id_not_empty();
+        self.require_wrapped_lp_token_id_not_empty();
+
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_esdt();
+        let exit_result = self.exit_farm_proxy_common(&caller, farm_address, payment);
+        require!(
+            exit_result.proxy_farming_token.token_identifier
+                == self.wrapped_lp_token().get_token_id(),
+            INVALID_PAYMENTS_ERR_MSG
+        );
+
+        let mut output_payments = self.remove_liquidity_proxy_common(
+            exit_result.proxy_farming_token,
+            pair_address,
+            first_token_amount_min,
+            second_token_amount_min,
+        );
+        output_payments.push(exit_result.rewards);
+
+        self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
+
+        output_payments.into()
     }
 
     fn handle_farm_penalty_and_get_output_proxy_farming_token(
@@ -0,0 +1,135 @@
+multiversx_sc::imports!();
+
+use fixed_supply_token::FixedSupplyToken;
+
+use crate::wrapped_farm_attributes::WrappedFarmTokenAttributes;
+
+pub type MigrateFarmProxyResultType<M> =
+    MultiValue3<EsdtTokenPayment<M>, EsdtTokenPayment<M>, EsdtTokenPayment<M>>;
+
+pub static NO_FARM_MIGRATION_ERR_MSG: &[u8] = b"No migration registered for farm";
+pub static DIFFERENT_FARMING_TOKENS_ERR_MSG: &[u8] = b"Farms have different farming tokens";
+
+#[multiversx_sc::module]
+pub trait ProxyFarmMigrationModule:
+    crate::proxy_common::ProxyCommonModule
+    + crate::other_sc_whitelist::OtherScWhitelistModule
+    + crate::farm_interactions::FarmInteractionsModule
+    + crate::events::EventsModule
+    + energy_query::EnergyQueryModule
+    + token_send::TokenSendModule
+    + utils::UtilsModule
+{
+    /// Registers the farm that positions in the old farm can be migrated to.
+    /// Both farms must be intermediated and have the same farming token,
+    /// and the proxy must be whitelisted in both farms.
+    #[only_owner]
+    #[endpoint(setFarmMigration)]
+    fn set_farm_migration(
+        &self,
+        old_farm_address: ManagedAddress,
+        new_farm_address: ManagedAddress,
+    ) {
+        self.require_is_intermediated_farm(&old_farm_address);
+        self.require_is_intermediated_farm(&new_farm_address);
+        require!(old_farm_address != new_farm_address, "Same farm address");
+
+        let old_farming_token_id = self.get_farming_token_id(old_farm_address.clone());
+        let new_farming_token_id = self.get_farming_token_id(new_farm_address.clone());
+        require!(
+            old_farming_token_id == new_farming_token_id,
+            DIFFERENT_FARMING_TOKENS_ERR_MSG
+        );
+
+        self.farm_migration(&old_farm_address).set(new_farm_address);
+    }
+
+    #[only_owner]
+    #[endpoint(removeFarmMigration)]
+    fn remove_farm_migration(&self, old_farm_address: ManagedAddress) {
+        self.farm_migration(&old_farm_address).clear();
+    }
+
+    /// Exits the old farm and enters the registered new farm with the same farming tokens.
+    /// The wrapped LP or locked tokens of the position are kept as they are, so the user's energy is unchanged.
+    /// The old farm's exit penalty is not applied, and the new position keeps the entering epoch of the old one.
+    ///
+    /// Returns the new wrapped farm token, the rewards from the old farm and the rewards from the new farm.
+    #[payable("*")]
+    #[endpoint(migrateWrappedFarmPosition)]
+    fn migrate_wrapped_farm_position(
+        &self,
+        old_farm_address: ManagedAddress,
+    ) -> MigrateFarmProxyResultType<Self::Api> {
+        self.require_is_intermediated_farm(&old_farm_address);
+        let new_farm_mapper = self.farm_migration(&old_farm_address);
+        require!(!new_farm_mapper.is_empty(), NO_FARM_MIGRATION_ERR_MSG);
+
+        let new_farm_address = new_farm_mapper.get();
+        self.require_is_intermediated_farm(&new_farm_address);
+
+        let wrapped_farm_token_mapper = self.wrapped_farm_token();
+        let payment = self.call_value().single_esdt();
+        wrapped_farm_token_mapper.require_same_token(&payment.token_identifier);
+
+        let old_attributes: WrappedFarmTokenAttributes<Self::Api> =
+            self.get_attributes_as_part_of_fixed_supply(&payment, &wrapped_farm_token_mapper);
+
+        let caller = self.blockchain().get_caller();
+        let exit_result = self.call_exit_farm_for_migration(
+            caller.clone(),
+            old_farm_address.clone(),
+            old_attributes.farm_token.clone(),
+        );
+        let enter_result = self.call_enter_farm_for_migration(
+            caller.clone(),
+            new_farm_address.clone(),
+            exit_result.farming_tokens.clone(),
+            exit_result.entering_epoch,
+        );
+
+        let new_attributes = WrappedFarmTokenAttributes {
+            farm_token: enter_result.farm_token,
+            proxy_farming_token: old_attributes.proxy_farming_token.clone(),
+        };
+        let new_token_amount = new_attributes.get_total_supply();
+        let new_wrapped_farm_token =
+            wrapped_farm_token_mapper.nft_create(new_token_amount, &new_attributes);
+
+        wrapped_farm_token_mapper.nft_burn(payment.token_nonce, &payment.amount);
+
+        self.send_payment_non_zero(&caller, &new_wrapped_farm_token);
+        self.send_payment_non_zero(&caller, &exit_result.reward_tokens);
+        self.send_payment_non_zero(&caller, &enter_result.reward_token);
+
+        self.emit_exit_farm_proxy_event(
+            &caller,
+            &old_farm_address,
+            payment,
+            old_attributes,
+            exit_result.reward_tokens.clone(),
+        );
+        self.emit_enter_farm_proxy_event(
+            &caller,
+            &new_farm_address,
+            exit_result.farming_tokens,
+            new_wrapped_farm_token.clone(),
+            new_attributes,
+            false,
+        );
+
+        (
+            new_wrapped_farm_token,
+            exit_result.reward_tokens,
+            enter_result.reward_token,
+        )
+            .into()
+    }
+
+    #[view(getFarmMigration)]
+    #[storage_mapper("farmMigration")]
+    fn farm_migration(
+        &self,
+        old_farm_address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedAddress>;
+}
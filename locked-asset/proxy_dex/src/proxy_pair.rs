@@ -5,10 +5,17 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::proxy_common::INVALID_PAYMENTS_ERR_MSG;
 use crate::wrapped_lp_attributes::{WrappedLpToken, WrappedLpTokenAttributes};
 use common_structs::Epoch;
 use fixed_supply_token::FixedSupplyToken;
 
+pub struct AddLiquidityProxyResult<M: ManagedTypeApi> {
+    pub wrapped_lp_attributes: WrappedLpTokenAttributes<M>,
+    pub locked_token_leftover: EsdtTokenPayment<M>,
+    pub other_token_leftover: EsdtTokenPayment<M>,
+}
+
 #[multiversx_sc::module]
 pub trait ProxyPairModule:
     crate::proxy_common::ProxyCommonModule
@@ -39,7 +46,70 @@ pub trait ProxyPairModule:
         let first_payment = self.pop_first_payment(&mut payments);
         let second_payment = self.pop_first_payment(&mut payments);
 
-        let input_token_refs = self.require_exactly_one_locked(&first_payment, &second_payment);
+        let add_liq_result = self.add_liquidity_proxy_common(
+            pair_address.clone(),
+            &first_payment,
+            &second_payment,
+            first_token_amount_min,
+            second_token_amount_min,
+        );
+        let new_token_attributes = add_liq_result.wrapped_lp_attributes;
+
+        let wrapped_lp_mapper = self.wrapped_lp_token();
+        let token_merge_requested = !payments.is_empty();
+        let new_wrapped_token = if token_merge_requested {
+            let wrapped_lp_tokens =
+                WrappedLpToken::new_from_payments(&payments, &wrapped_lp_mapper);
+
+            self.send().esdt_local_burn_multi(&payments);
+
+            self.merge_wrapped_lp_tokens_with_virtual_pos(
+                &caller,
+                wrapped_lp_tokens,
+                new_token_attributes,
+            )
+        } else {
+            let new_token_amount = new_token_attributes.get_total_supply();
+            let output_wrapped_lp_token =
+                wrapped_lp_mapper.nft_create(new_token_amount, &new_token_attributes);
+
+            WrappedLpToken {
+                payment: output_wrapped_lp_token,
+                attributes: new_token_attributes,
+            }
+        };
+
+        let mut output_payments = ManagedVec::new();
+        output_payments.push(new_wrapped_token.payment.clone());
+        output_payments.push(add_liq_result.locked_token_leftover);
+        output_payments.push(add_liq_result.other_token_leftover);
+
+        self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
+
+        self.emit_add_liquidity_proxy_event(
+            &caller,
+            &pair_address,
+            first_payment,
+            second_payment,
+            new_wrapped_token.payment.clone(),
+            new_wrapped_token.attributes,
+            token_merge_requested,
+        );
+
+        output_payments.into()
+    }
+
+    /// Adds liquidity with the given locked token and other token payments.
+    /// The base asset leftover is burned and returned as locked tokens.
+    fn add_liquidity_proxy_common(
+        &self,
+        pair_address: ManagedAddress,
+        first_payment: &EsdtTokenPayment,
+        second_payment: &EsdtTokenPayment,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> AddLiquidityProxyResult<Self::Api> {
+        let input_token_refs = self.require_exactly_one_locked(first_payment, second_payment);
         let asset_amount = input_token_refs.locked_token_ref.amount.clone();
         let asset_token_id = self.get_base_token_id();
         self.send()
@@ -50,7 +120,7 @@ pub trait ProxyPairModule:
         let second_unlocked_token_id =
             self.get_underlying_token(second_payment.token_identifier.clone());
         let add_liq_result = self.call_add_liquidity(
-            pair_address.clone(),
+            pair_address,
             first_unlocked_token_id,
             first_payment.amount.clone(),
             first_token_amount_min,
@@ -68,64 +138,176 @@ pub trait ProxyPairModule:
             second_payment.amount.clone() - &add_liq_result.second_token_leftover.amount
         };
 
-        let new_token_attributes = WrappedLpTokenAttributes {
+        let wrapped_lp_attributes = WrappedLpTokenAttributes {
             locked_tokens: locked_token_used,
             lp_token_id: add_liq_result.lp_tokens_received.token_identifier.clone(),
             lp_token_amount: add_liq_result.lp_tokens_received.amount.clone(),
         };
 
-        let wrapped_lp_mapper = self.wrapped_lp_token();
-        let token_merge_requested = !payments.is_empty();
-        let new_wrapped_token = if token_merge_requested {
-            let wrapped_lp_tokens =
-                WrappedLpToken::new_from_payments(&payments, &wrapped_lp_mapper);
+        let received_token_refs = self.require_exactly_one_base_asset(
+            &add_liq_result.first_token_leftover,
+            &add_liq_result.second_token_leftover,
+        );
+        let other_token_leftover = received_token_refs.other_token_ref.clone();
+        let mut locked_token_leftover = input_token_refs.locked_token_ref.clone();
+        locked_token_leftover.amount = received_token_refs.base_asset_token_ref.amount.clone();
 
-            self.send().esdt_local_burn_multi(&payments);
+        if locked_token_leftover.amount > 0 {
+            self.send()
+                .esdt_local_burn(&asset_token_id, 0, &locked_token_leftover.amount);
+        }
 
-            self.merge_wrapped_lp_tokens_with_virtual_pos(
-                &caller,
-                wrapped_lp_tokens,
-                new_token_attributes,
-            )
+        AddLiquidityProxyResult {
+            wrapped_lp_attributes,
+            locked_token_leftover,
+            other_token_leftover,
+        }
+    }
+
+    /// Adds liquidity using only locked tokens. Part of the base asset is swapped
+    /// for the pair's other token, and the rest is added as liquidity together with the swapped tokens.
+    /// The locked tokens used for the swap are burned, and the user's energy is updated accordingly.
+    /// As the swapped part is unlocked, it incurs the energy factory's early unlock penalty.
+    /// The penalty is not swapped, and its locked tokens are sent to the energy factory,
+    /// which burns them and sends them to the fees collector as for unlockEarly.
+    #[payable("*")]
+    #[endpoint(addLiquiditySingleLockedProxy)]
+    fn add_liquidity_single_locked_proxy(
+        &self,
+        pair_address: ManagedAddress,
+        swap_amount_out_min: BigUint,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        self.require_is_intermediated_pair(&pair_address);
+        self.require_wrapped_lp_token_id_not_empty();
+
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.get_locked_token_id(),
+            INVALID_PAYMENTS_ERR_MSG
+        );
+
+        let asset_token_id = self.get_base_token_id();
+        let (first_token_id, second_token_id) = self.get_pair_token_ids(pair_address.clone());
+        let base_asset_is_first = first_token_id == asset_token_id;
+        let other_token_id = if base_asset_is_first {
+            second_token_id
         } else {
-            let new_token_amount = new_token_attributes.get_total_supply();
-            let output_wrapped_lp_token =
-                wrapped_lp_mapper.nft_create(new_token_amount, &new_token_attributes);
+            require!(second_token_id == asset_token_id, INVALID_PAYMENTS_ERR_MSG);
+            first_token_id
+        };
 
-            WrappedLpToken {
-                payment: output_wrapped_lp_token,
-                attributes: new_token_attributes,
-            }
+        let swap_amount = self.get_single_sided_swap_amount(
+            pair_address.clone(),
+            &asset_token_id,
+            &payment.amount,
+        );
+        require!(
+            swap_amount > 0 && swap_amount < payment.amount,
+            INVALID_PAYMENTS_ERR_MSG
+        );
+
+        let penalty_amount = self.get_early_unlock_penalty(&payment, &swap_amount);
+        let unlocked_swap_amount = &swap_amount - &penalty_amount;
+        require!(unlocked_swap_amount > 0, INVALID_PAYMENTS_ERR_MSG);
+
+        self.send()
+            .esdt_local_mint(&asset_token_id, 0, &(&payment.amount - &penalty_amount));
+
+        let other_tokens = self.call_swap_tokens_fixed_input(
+            pair_address.clone(),
+            EsdtTokenPayment::new(asset_token_id.clone(), 0, unlocked_swap_amount.clone()),
+            other_token_id.clone(),
+            swap_amount_out_min,
+        );
+
+        // the locked tokens used for the swap are no longer backed by base asset in the LP
+        self.deduct_energy_from_user(
+            &caller,
+            &payment.token_identifier,
+            payment.token_nonce,
+            &swap_amount,
+        );
+        self.send().esdt_local_burn(
+            &payment.token_identifier,
+            payment.token_nonce,
+            &unlocked_swap_amount,
+        );
+        self.send_early_unlock_penalty(EsdtTokenPayment::new(
+            payment.token_identifier.clone(),
+            payment.token_nonce,
+            penalty_amount,
+        ));
+
+        let base_asset_amount = &payment.amount - &swap_amount;
+        let add_liq_result = if base_asset_is_first {
+            self.call_add_liquidity(
+                pair_address.clone(),
+                asset_token_id.clone(),
+                base_asset_amount.clone(),
+                first_token_amount_min,
+                other_token_id,
+                other_tokens.amount.clone(),
+                second_token_amount_min,
+            )
+        } else {
+            self.call_add_liquidity(
+                pair_address.clone(),
+                other_token_id,
+                other_tokens.amount.clone(),
+                first_token_amount_min,
+                asset_token_id.clone(),
+                base_asset_amount.clone(),
+                second_token_amount_min,
+            )
         };
 
         let received_token_refs = self.require_exactly_one_base_asset(
             &add_liq_result.first_token_leftover,
             &add_liq_result.second_token_leftover,
         );
+        let base_asset_leftover = received_token_refs.base_asset_token_ref.amount.clone();
         let other_token_leftover = received_token_refs.other_token_ref.clone();
-        let mut locked_token_leftover = input_token_refs.locked_token_ref.clone();
-        locked_token_leftover.amount = received_token_refs.base_asset_token_ref.amount.clone();
 
+        let mut locked_token_used = payment.clone();
+        locked_token_used.amount = &base_asset_amount - &base_asset_leftover;
+
+        let new_token_attributes = WrappedLpTokenAttributes {
+            locked_tokens: locked_token_used,
+            lp_token_id: add_liq_result.lp_tokens_received.token_identifier.clone(),
+            lp_token_amount: add_liq_result.lp_tokens_received.amount.clone(),
+        };
+        let new_token_amount = new_token_attributes.get_total_supply();
+        let new_wrapped_token = self
+            .wrapped_lp_token()
+            .nft_create(new_token_amount, &new_token_attributes);
+
+        let mut locked_token_leftover = payment.clone();
+        locked_token_leftover.amount = base_asset_leftover;
         if locked_token_leftover.amount > 0 {
             self.send()
                 .esdt_local_burn(&asset_token_id, 0, &locked_token_leftover.amount);
         }
 
         let mut output_payments = ManagedVec::new();
-        output_payments.push(new_wrapped_token.payment.clone());
+        output_payments.push(new_wrapped_token.clone());
         output_payments.push(locked_token_leftover);
         output_payments.push(other_token_leftover);
 
         self.send_multiple_tokens_if_not_zero(&caller, &output_payments);
 
+        let mut locked_tokens_added = payment;
+        locked_tokens_added.amount = base_asset_amount;
         self.emit_add_liquidity_proxy_event(
             &caller,
             &pair_address,
-            first_payment,
-            second_payment,
-            new_wrapped_token.payment.clone(),
-            new_wrapped_token.attributes,
-            token_merge_requested,
+            locked_tokens_added,
+            other_tokens,
+            new_wrapped_token,
+            new_token_attributes,
+            false,
         );
 
         output_payments.into()
@@ -1,6 +1,8 @@
 #![allow(dead_code)]
 #![allow(deprecated)]
 
+pub mod fees_collector_mock;
+
 use common_structs::{LockedAssetTokenAttributesEx, UnlockMilestoneEx, UnlockScheduleEx};
 use config::ConfigModule;
 use energy_factory::{locked_token_transfer::LockedTokenTransferModule, SimpleLockEnergy};
@@ -40,6 +42,7 @@ pub static LP_TOKEN_ID: &[u8] = b"LPTOK-123456";
 
 // Farm
 pub static FARM_LOCKED_TOKEN_ID: &[u8] = b"FARML-123456";
+pub static NEW_FARM_LOCKED_TOKEN_ID: &[u8] = b"FARMLNEW-123456";
 pub const DIVISION_SAFETY_CONSTANT: u64 = 1_000_000_000_000_000_000;
 pub const PER_BLOCK_REWARD_AMOUNT: u64 = 5_000;
 pub const USER_REWARDS_BASE_CONST: u64 = 10;
@@ -108,6 +111,7 @@ where
             &owner,
             farm_locked_builder,
             simple_lock_wrapper.address_ref(),
+            FARM_LOCKED_TOKEN_ID,
         );
         let proxy_wrapper = setup_proxy(
             &mut b_mock,
@@ -286,11 +290,12 @@ where
     pair_wrapper
 }
 
-fn setup_farm_locked<FarmLockedObjBuilder>(
+pub fn setup_farm_locked<FarmLockedObjBuilder>(
     b_mock: &mut BlockchainStateWrapper,
     owner: &Address,
     farm_builder: FarmLockedObjBuilder,
     simple_lock_addr: &Address,
+    farm_token_id: &[u8],
 ) -> ContractObjWrapper<farm_with_locked_rewards::ContractObj<DebugApi>, FarmLockedObjBuilder>
 where
     FarmLockedObjBuilder: 'static + Copy + Fn() -> farm_with_locked_rewards::ContractObj<DebugApi>,
@@ -319,8 +324,8 @@ where
                 MultiValueEncoded::new(),
             );
 
-            let farm_token_id = managed_token_id!(FARM_LOCKED_TOKEN_ID);
-            sc.farm_token().set_token_id(farm_token_id);
+            sc.farm_token()
+                .set_token_id(managed_token_id!(farm_token_id));
 
             sc.per_block_reward_amount()
                 .set(&managed_biguint!(PER_BLOCK_REWARD_AMOUNT));
@@ -349,7 +354,7 @@ where
     ];
     b_mock.set_esdt_local_roles(
         farm_wrapper.address_ref(),
-        FARM_LOCKED_TOKEN_ID,
+        farm_token_id,
         &farm_token_roles[..],
     );
 
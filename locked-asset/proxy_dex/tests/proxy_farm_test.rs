@@ -18,12 +18,18 @@ use multiversx_sc_scenario::{
 };
 use num_traits::ToPrimitive;
 use proxy_dex::{
-    proxy_farm::ProxyFarmModule, proxy_pair::ProxyPairModule,
+    other_sc_whitelist::OtherScWhitelistModule,
+    proxy_farm::ProxyFarmModule,
+    proxy_farm_migration::{
+        ProxyFarmMigrationModule, DIFFERENT_FARMING_TOKENS_ERR_MSG, NO_FARM_MIGRATION_ERR_MSG,
+    },
+    proxy_pair::ProxyPairModule,
     wrapped_farm_attributes::WrappedFarmTokenAttributes,
     wrapped_farm_token_merge::WrappedFarmTokenMerge,
     wrapped_lp_attributes::WrappedLpTokenAttributes,
 };
 use proxy_dex_test_setup::*;
+use sc_whitelist_module::SCWhitelistModule;
 use simple_lock::locked_token::LockedTokenAttributes;
 
 #[test]
@@ -2761,3 +2767,430 @@ fn total_farm_position_migration_mechanism_test() {
         })
         .assert_ok();
 }
+
+#[test]
+fn add_liquidity_and_enter_farm_proxy_test() {
+    let mut setup = ProxySetup::new(
+        proxy_dex::contract_obj,
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.farm_locked_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.farming_token_id().set(&managed_token_id!(LP_TOKEN_ID));
+
+                // set produce rewards to false for easier calculation
+                sc.produce_rewards_enabled().set(false);
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.set_esdt_local_roles(
+        setup.farm_locked_wrapper.address_ref(),
+        LP_TOKEN_ID,
+        &[EsdtLocalRole::Burn],
+    );
+
+    let first_user = setup.first_user.clone();
+    let full_balance = rust_biguint!(USER_BALANCE);
+    let locked_token_amount = rust_biguint!(1_000_000_000);
+    let other_token_amount = rust_biguint!(500_000_000);
+    let expected_lp_token_amount = rust_biguint!(499_999_000);
+
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: LOCKED_TOKEN_ID.to_vec(),
+            nonce: 1,
+            value: locked_token_amount.clone(),
+        },
+        TxTokenTransfer {
+            token_identifier: WEGLD_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: other_token_amount.clone(),
+        },
+    ];
+
+    // add liquidity and enter farm
+    let pair_addr = setup.pair_wrapper.address_ref().clone();
+    let farm_locked_addr = setup.farm_locked_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_esdt_multi_transfer(&first_user, &setup.proxy_wrapper, &payments, |sc| {
+            let output_payments = sc.add_liquidity_and_enter_farm_proxy(
+                managed_address!(&pair_addr),
+                managed_address!(&farm_locked_addr),
+                managed_biguint!(locked_token_amount.to_u64().unwrap()),
+                managed_biguint!(other_token_amount.to_u64().unwrap()),
+            );
+            let output_vec = output_payments.to_vec();
+
+            assert_eq!(output_payments.len(), 4);
+            assert_eq!(
+                output_vec.get(0).amount.to_u64().unwrap(),
+                expected_lp_token_amount.to_u64().unwrap()
+            );
+            // no rewards or leftovers
+            assert_eq!(output_vec.get(1).amount.to_u64().unwrap(), 0);
+            assert_eq!(output_vec.get(2).amount.to_u64().unwrap(), 0);
+            assert_eq!(output_vec.get(3).amount.to_u64().unwrap(), 0);
+        })
+        .assert_ok();
+
+    // check user balance
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        WRAPPED_FARM_TOKEN_ID,
+        1,
+        &expected_lp_token_amount,
+        Some(&WrappedFarmTokenAttributes::<DebugApi> {
+            proxy_farming_token: EsdtTokenPayment {
+                token_identifier: managed_token_id!(WRAPPED_LP_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(expected_lp_token_amount.to_u64().unwrap()),
+            },
+            farm_token: EsdtTokenPayment {
+                token_identifier: managed_token_id!(FARM_LOCKED_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(expected_lp_token_amount.to_u64().unwrap()),
+            },
+        }),
+    );
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        1,
+        &(&full_balance - &locked_token_amount),
+        None,
+    );
+
+    // check proxy holds the wrapped LP tokens
+    setup.b_mock.check_nft_balance(
+        setup.proxy_wrapper.address_ref(),
+        WRAPPED_LP_TOKEN_ID,
+        1,
+        &expected_lp_token_amount,
+        Some(&WrappedLpTokenAttributes::<DebugApi> {
+            locked_tokens: EsdtTokenPayment {
+                token_identifier: managed_token_id!(LOCKED_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(locked_token_amount.to_u64().unwrap()),
+            },
+            lp_token_id: managed_token_id!(LP_TOKEN_ID),
+            lp_token_amount: managed_biguint!(expected_lp_token_amount.to_u64().unwrap()),
+        }),
+    );
+
+    // check farm balance
+    setup.b_mock.check_esdt_balance(
+        setup.farm_locked_wrapper.address_ref(),
+        LP_TOKEN_ID,
+        &expected_lp_token_amount,
+    );
+
+    // pass the minimum farming epochs, to avoid the exit penalty
+    setup.b_mock.set_block_epoch(10);
+
+    // exit farm and remove liquidity
+    // should be 1_000_000_000 and 500_000_000, but ends up so due to approximations
+    let removed_locked_token_amount = rust_biguint!(999_998_000);
+    let removed_other_token_amount = rust_biguint!(499_999_000);
+
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WRAPPED_FARM_TOKEN_ID,
+            1,
+            &expected_lp_token_amount,
+            |sc| {
+                let output_payments = sc.exit_farm_and_remove_liquidity_proxy(
+                    managed_address!(&farm_locked_addr),
+                    managed_address!(&pair_addr),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+                let output_vec = output_payments.to_vec();
+
+                assert_eq!(output_payments.len(), 3);
+                assert_eq!(
+                    output_vec.get(0).amount.to_u64().unwrap(),
+                    removed_locked_token_amount.to_u64().unwrap()
+                );
+                assert_eq!(
+                    output_vec.get(1).amount.to_u64().unwrap(),
+                    removed_other_token_amount.to_u64().unwrap()
+                );
+                assert_eq!(output_vec.get(2).amount.to_u64().unwrap(), 0);
+            },
+        )
+        .assert_ok();
+
+    // check user balance
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        WRAPPED_FARM_TOKEN_ID,
+        1,
+        &rust_biguint!(0),
+        None,
+    );
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        LOCKED_TOKEN_ID,
+        1,
+        &(&full_balance - &locked_token_amount + &removed_locked_token_amount),
+        None,
+    );
+    setup.b_mock.check_esdt_balance(
+        &first_user,
+        WEGLD_TOKEN_ID,
+        &(&full_balance - &other_token_amount + &removed_other_token_amount),
+    );
+
+    // check proxy burned the wrapped LP tokens
+    setup.b_mock.check_nft_balance::<Empty>(
+        setup.proxy_wrapper.address_ref(),
+        WRAPPED_LP_TOKEN_ID,
+        1,
+        &rust_biguint!(0),
+        None,
+    );
+}
+
+#[test]
+fn migrate_wrapped_farm_position_test() {
+    let mut setup = ProxySetup::new(
+        proxy_dex::contract_obj,
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+    let first_user = setup.first_user.clone();
+    let old_farm_addr = setup.farm_locked_wrapper.address_ref().clone();
+
+    let new_farm_wrapper = setup_farm_locked(
+        &mut setup.b_mock,
+        &setup.owner,
+        farm_with_locked_rewards::contract_obj,
+        setup.simple_lock_wrapper.address_ref(),
+        NEW_FARM_LOCKED_TOKEN_ID,
+    );
+    let new_farm_addr = new_farm_wrapper.address_ref().clone();
+    let proxy_addr = setup.proxy_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_tx(&setup.owner, &new_farm_wrapper, &rust_biguint!(0), |sc| {
+            sc.add_sc_address_to_whitelist(managed_address!(&proxy_addr));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.simple_lock_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_sc_address_to_whitelist(managed_address!(&new_farm_addr));
+            },
+        )
+        .assert_ok();
+
+    // migration to a farm that is not intermediated
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_farm_migration(
+                    managed_address!(&old_farm_addr),
+                    managed_address!(&new_farm_addr),
+                );
+            },
+        )
+        .assert_user_error("Not an intermediated farm");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_farm_to_intermediate(managed_address!(&new_farm_addr));
+            },
+        )
+        .assert_ok();
+
+    // migration to a farm with a different farming token
+    setup
+        .b_mock
+        .execute_tx(&setup.owner, &new_farm_wrapper, &rust_biguint!(0), |sc| {
+            sc.farming_token_id().set(managed_token_id!(WEGLD_TOKEN_ID));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_farm_migration(
+                    managed_address!(&old_farm_addr),
+                    managed_address!(&new_farm_addr),
+                );
+            },
+        )
+        .assert_user_error(core::str::from_utf8(DIFFERENT_FARMING_TOKENS_ERR_MSG).unwrap());
+
+    setup
+        .b_mock
+        .execute_tx(&setup.owner, &new_farm_wrapper, &rust_biguint!(0), |sc| {
+            sc.farming_token_id().set(managed_token_id!(MEX_TOKEN_ID));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.owner,
+            &setup.proxy_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_farm_migration(
+                    managed_address!(&old_farm_addr),
+                    managed_address!(&new_farm_addr),
+                );
+            },
+        )
+        .assert_ok();
+
+    // enter old farm
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                sc.enter_farm_proxy_endpoint(managed_address!(&old_farm_addr), OptionalValue::None);
+            },
+        )
+        .assert_ok();
+
+    // no migration registered for the new farm
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WRAPPED_FARM_TOKEN_ID,
+            1,
+            &rust_biguint!(USER_BALANCE),
+            |sc| {
+                sc.migrate_wrapped_farm_position(managed_address!(&new_farm_addr));
+            },
+        )
+        .assert_user_error(core::str::from_utf8(NO_FARM_MIGRATION_ERR_MSG).unwrap());
+
+    let entering_epoch = 1u64;
+    let block_epoch = 2u64;
+    setup.b_mock.set_block_epoch(block_epoch);
+
+    // migrate half the position, still inside the old farm's penalty window
+    let migrated_amount = rust_biguint!(USER_BALANCE / 2);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &first_user,
+            &setup.proxy_wrapper,
+            WRAPPED_FARM_TOKEN_ID,
+            1,
+            &migrated_amount,
+            |sc| {
+                let (new_wrapped_farm_token, _, _) = sc
+                    .migrate_wrapped_farm_position(managed_address!(&old_farm_addr))
+                    .into_tuple();
+                assert_eq!(new_wrapped_farm_token.token_nonce, 2);
+                assert_eq!(
+                    new_wrapped_farm_token.amount,
+                    managed_biguint!(USER_BALANCE / 2)
+                );
+            },
+        )
+        .assert_ok();
+
+    // check user balance
+    setup.b_mock.check_nft_balance::<Empty>(
+        &first_user,
+        WRAPPED_FARM_TOKEN_ID,
+        1,
+        &(rust_biguint!(USER_BALANCE) - &migrated_amount),
+        None,
+    );
+    setup.b_mock.check_nft_balance(
+        &first_user,
+        WRAPPED_FARM_TOKEN_ID,
+        2,
+        &migrated_amount,
+        Some(&WrappedFarmTokenAttributes::<DebugApi> {
+            proxy_farming_token: EsdtTokenPayment {
+                token_identifier: managed_token_id!(LOCKED_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(USER_BALANCE / 2),
+            },
+            farm_token: EsdtTokenPayment {
+                token_identifier: managed_token_id!(NEW_FARM_LOCKED_TOKEN_ID),
+                token_nonce: 1,
+                amount: managed_biguint!(USER_BALANCE / 2),
+            },
+        }),
+    );
+
+    // the migrated position keeps its entering epoch
+    let new_farm_token_attributes = setup
+        .b_mock
+        .get_nft_attributes::<FarmTokenAttributes<DebugApi>>(
+            &proxy_addr,
+            NEW_FARM_LOCKED_TOKEN_ID,
+            1,
+        )
+        .unwrap();
+    assert_eq!(new_farm_token_attributes.entering_epoch, entering_epoch);
+
+    // check farm balances, no exit penalty was applied
+    setup.b_mock.check_esdt_balance(
+        &old_farm_addr,
+        MEX_TOKEN_ID,
+        &(rust_biguint!(USER_BALANCE) - &migrated_amount),
+    );
+    setup
+        .b_mock
+        .check_esdt_balance(&new_farm_addr, MEX_TOKEN_ID, &migrated_amount);
+
+    // user's energy is unchanged
+    setup
+        .b_mock
+        .execute_query(&setup.simple_lock_wrapper, |sc| {
+            let lock_epochs = LOCK_OPTIONS[0] - block_epoch;
+            let expected_energy = Energy::new(
+                BigInt::from(USER_BALANCE as i64) * BigInt::from(lock_epochs as i64),
+                block_epoch,
+                managed_biguint!(USER_BALANCE),
+            );
+            let actual_energy =
+                sc.get_updated_energy_entry_for_user(&managed_address!(&first_user));
+            assert_eq!(expected_energy, actual_energy);
+        })
+        .assert_ok();
+}
@@ -2,23 +2,31 @@
 
 mod proxy_dex_test_setup;
 
-use energy_factory::{energy::EnergyModule, SimpleLockEnergy};
+use energy_factory::{
+    energy::EnergyModule, unlock_with_penalty::UnlockWithPenaltyModule, unstake::UnstakeModule,
+    SimpleLockEnergy,
+};
 use energy_query::Energy;
 use multiversx_sc::{
     codec::{multi_types::OptionalValue, Empty},
-    types::{BigInt, EsdtTokenPayment},
+    types::{BigInt, EsdtLocalRole, EsdtTokenPayment},
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
     whitebox_legacy::TxTokenTransfer, DebugApi,
 };
 use num_traits::ToPrimitive;
+use pair::pair_actions::views::ViewsModule;
 use proxy_dex::{
     proxy_pair::ProxyPairModule, wrapped_lp_attributes::WrappedLpTokenAttributes,
     wrapped_lp_token_merge::WrappedLpTokenMerge,
 };
-use proxy_dex_test_setup::*;
+use proxy_dex_test_setup::{fees_collector_mock::FeesCollectorMock, *};
 use simple_lock::locked_token::LockedTokenAttributes;
+use token_unstake::TokenUnstakeModule;
+
+const UNBOND_EPOCHS: u64 = 10;
+const FEES_BURN_PERCENTAGE: u64 = 5_000; // 50%
 
 #[test]
 fn setup_test() {
@@ -1402,3 +1410,239 @@ fn increase_proxy_lp_legacy_token_energy() {
         )
         .assert_error(4, "Invalid payment");
 }
+
+#[test]
+fn add_liquidity_single_locked_proxy_test() {
+    let mut setup = ProxySetup::new(
+        proxy_dex::contract_obj,
+        pair::contract_obj,
+        farm_with_locked_rewards::contract_obj,
+        energy_factory::contract_obj,
+    );
+    let first_user = setup.first_user.clone();
+    let second_user = setup.second_user.clone();
+    let full_balance = rust_biguint!(USER_BALANCE);
+    let locked_token_amount = rust_biguint!(1_000_000_000);
+    let other_token_amount = rust_biguint!(500_000_000);
+
+    // the early unlock penalty is handled by the token unstake SC
+    let rust_zero = rust_biguint!(0);
+    let fees_collector_mock = setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&setup.owner),
+        FeesCollectorMock::new,
+        "fees collector mock",
+    );
+    let unstake_wrapper = setup.b_mock.create_sc_account(
+        &rust_zero,
+        Some(&setup.owner),
+        token_unstake::contract_obj,
+        "token unstake",
+    );
+    setup
+        .b_mock
+        .execute_tx(&setup.owner, &unstake_wrapper, &rust_zero, |sc| {
+            sc.init(
+                UNBOND_EPOCHS,
+                managed_address!(setup.simple_lock_wrapper.address_ref()),
+                FEES_BURN_PERCENTAGE,
+                managed_address!(fees_collector_mock.address_ref()),
+            );
+        })
+        .assert_ok();
+    setup.b_mock.set_esdt_local_roles(
+        unstake_wrapper.address_ref(),
+        LOCKED_TOKEN_ID,
+        &[EsdtLocalRole::NftBurn],
+    );
+    setup
+        .b_mock
+        .execute_tx(&setup.owner, &setup.simple_lock_wrapper, &rust_zero, |sc| {
+            sc.set_token_unstake_address(managed_address!(unstake_wrapper.address_ref()));
+        })
+        .assert_ok();
+
+    // first user sets the price to 1 EGLD = 2 MEX
+    let payments = vec![
+        TxTokenTransfer {
+            token_identifier: LOCKED_TOKEN_ID.to_vec(),
+            nonce: 1,
+            value: locked_token_amount.clone(),
+        },
+        TxTokenTransfer {
+            token_identifier: WEGLD_TOKEN_ID.to_vec(),
+            nonce: 0,
+            value: other_token_amount.clone(),
+        },
+    ];
+    let pair_addr = setup.pair_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .execute_esdt_multi_transfer(&first_user, &setup.proxy_wrapper, &payments, |sc| {
+            sc.add_liquidity_proxy(
+                managed_address!(&pair_addr),
+                managed_biguint!(locked_token_amount.to_u64().unwrap()),
+                managed_biguint!(other_token_amount.to_u64().unwrap()),
+            );
+        })
+        .assert_ok();
+
+    // second user adds liquidity with locked tokens only
+    // the swapped part is unlocked, so it pays the early unlock penalty
+    let single_locked_amount = rust_biguint!(100_000_000);
+    let swapped_locked_amount = rust_biguint!(48_882_173);
+    let mut penalty_amount = rust_biguint!(0);
+    setup
+        .b_mock
+        .execute_query(&setup.simple_lock_wrapper, |sc| {
+            let penalty = sc.calculate_penalty_amount(
+                &managed_biguint!(swapped_locked_amount.to_u64().unwrap()),
+                LOCK_OPTIONS[1] - 1,
+                0,
+            );
+            penalty_amount = rust_biguint!(penalty.to_u64().unwrap());
+        })
+        .assert_ok();
+    assert!(penalty_amount > rust_biguint!(0));
+
+    let mut swapped_other_amount = rust_biguint!(0);
+    let mut penalty_free_other_amount = rust_biguint!(0);
+    setup
+        .b_mock
+        .execute_query(&setup.pair_wrapper, |sc| {
+            let amount_out = sc.get_amount_out_view(
+                managed_token_id!(MEX_TOKEN_ID),
+                managed_biguint!((&swapped_locked_amount - &penalty_amount).to_u64().unwrap()),
+            );
+            swapped_other_amount = rust_biguint!(amount_out.to_u64().unwrap());
+
+            let amount_out = sc.get_amount_out_view(
+                managed_token_id!(MEX_TOKEN_ID),
+                managed_biguint!(swapped_locked_amount.to_u64().unwrap()),
+            );
+            penalty_free_other_amount = rust_biguint!(amount_out.to_u64().unwrap());
+        })
+        .assert_ok();
+
+    let mut lp_token_amount = rust_biguint!(0);
+    let mut locked_token_leftover = rust_biguint!(0);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &second_user,
+            &setup.proxy_wrapper,
+            LOCKED_TOKEN_ID,
+            2,
+            &single_locked_amount,
+            |sc| {
+                let output_payments = sc.add_liquidity_single_locked_proxy(
+                    managed_address!(&pair_addr),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+                let output_vec = output_payments.to_vec();
+
+                assert_eq!(output_payments.len(), 3);
+                lp_token_amount = rust_biguint!(output_vec.get(0).amount.to_u64().unwrap());
+                locked_token_leftover = rust_biguint!(output_vec.get(1).amount.to_u64().unwrap());
+            },
+        )
+        .assert_ok();
+    let added_locked_amount =
+        &single_locked_amount - &swapped_locked_amount - &locked_token_leftover;
+
+    // check user's balance - the unused locked tokens are returned
+    setup.b_mock.check_nft_balance::<Empty>(
+        &second_user,
+        LOCKED_TOKEN_ID,
+        2,
+        &(&full_balance - &single_locked_amount + &locked_token_leftover),
+        None,
+    );
+    setup.b_mock.check_nft_balance(
+        &second_user,
+        WRAPPED_LP_TOKEN_ID,
+        2,
+        &lp_token_amount,
+        Some(&WrappedLpTokenAttributes::<DebugApi> {
+            locked_tokens: EsdtTokenPayment {
+                token_identifier: managed_token_id!(LOCKED_TOKEN_ID),
+                token_nonce: 2,
+                amount: managed_biguint!(added_locked_amount.to_u64().unwrap()),
+            },
+            lp_token_id: managed_token_id!(LP_TOKEN_ID),
+            lp_token_amount: managed_biguint!(lp_token_amount.to_u64().unwrap()),
+        }),
+    );
+
+    // the penalty never reaches the pair
+    setup.b_mock.check_esdt_balance(
+        setup.pair_wrapper.address_ref(),
+        MEX_TOKEN_ID,
+        &(&locked_token_amount + &swapped_locked_amount - &penalty_amount + &added_locked_amount),
+    );
+
+    // the penalty is partially burned, the rest is sent to the fees collector
+    let collected_penalty_amount =
+        &penalty_amount - &penalty_amount * FEES_BURN_PERCENTAGE / 10_000u64;
+    setup.b_mock.check_nft_balance::<Empty>(
+        unstake_wrapper.address_ref(),
+        LOCKED_TOKEN_ID,
+        2,
+        &rust_zero,
+        None,
+    );
+    setup.b_mock.check_nft_balance::<Empty>(
+        fees_collector_mock.address_ref(),
+        LOCKED_TOKEN_ID,
+        2,
+        &collected_penalty_amount,
+        None,
+    );
+
+    // the swapped locked tokens no longer count towards the user's energy
+    setup
+        .b_mock
+        .execute_query(&setup.simple_lock_wrapper, |sc| {
+            let block_epoch = 1u64;
+            let lock_epochs = LOCK_OPTIONS[1] - block_epoch;
+            let user_locked_tokens = USER_BALANCE - swapped_locked_amount.to_u64().unwrap();
+            let expected_energy = Energy::new(
+                BigInt::from(user_locked_tokens as i64) * BigInt::from(lock_epochs as i64),
+                block_epoch,
+                managed_biguint!(user_locked_tokens),
+            );
+            let actual_energy =
+                sc.get_updated_energy_entry_for_user(&managed_address!(&second_user));
+            assert_eq!(expected_energy, actual_energy);
+        })
+        .assert_ok();
+
+    // removing the liquidity only gives back the tokens swapped after the penalty
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &second_user,
+            &setup.proxy_wrapper,
+            WRAPPED_LP_TOKEN_ID,
+            2,
+            &lp_token_amount,
+            |sc| {
+                let output_payments = sc.remove_liquidity_proxy(
+                    managed_address!(&pair_addr),
+                    managed_biguint!(1),
+                    managed_biguint!(1),
+                );
+                assert_eq!(output_payments.len(), 2);
+            },
+        )
+        .assert_ok();
+
+    let other_token_received = setup
+        .b_mock
+        .get_esdt_balance(&second_user, WEGLD_TOKEN_ID, 0)
+        - &full_balance;
+    assert!(other_token_received <= swapped_other_amount);
+    assert!(other_token_received < penalty_free_other_amount);
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           30
+// Endpoints:                           37
 // Async Callback:                       1
-// Total number of exported functions:  33
+// Total number of exported functions:  40
 
 #![no_std]
 
@@ -37,12 +37,19 @@ multiversx_sc_wasm_adapter::endpoints! {
         getIntermediatedPairs => intermediated_pairs
         getIntermediatedFarms => intermediated_farms
         addLiquidityProxy => add_liquidity_proxy
+        addLiquiditySingleLockedProxy => add_liquidity_single_locked_proxy
         removeLiquidityProxy => remove_liquidity_proxy
         increaseProxyPairTokenEnergy => increase_proxy_pair_token_energy_endpoint
         enterFarmProxy => enter_farm_proxy_endpoint
         exitFarmProxy => exit_farm_proxy
+        addLiquidityAndEnterFarmProxy => add_liquidity_and_enter_farm_proxy
+        exitFarmAndRemoveLiquidityProxy => exit_farm_and_remove_liquidity_proxy
         claimRewardsProxy => claim_rewards_proxy
         increaseProxyFarmTokenEnergy => increase_proxy_farm_token_energy_endpoint
+        setFarmMigration => set_farm_migration
+        removeFarmMigration => remove_farm_migration
+        migrateWrappedFarmPosition => migrate_wrapped_farm_position
+        getFarmMigration => farm_migration
         mergeWrappedFarmTokens => merge_wrapped_farm_tokens_endpoint
         mergeWrappedLpTokens => merge_wrapped_lp_tokens_endpoint
         setEnergyFactoryAddress => set_energy_factory_address
@@ -1,60 +1,161 @@
 multiversx_sc::imports!();
 
+use common_structs::PaymentsVec;
 use energy_factory::unstake::ProxyTrait as _;
+use energy_query::Energy;
 use simple_lock::locked_token::LockedTokenAttributes;
 
-use crate::events;
+use crate::{events, tokens_per_user::UnstakePair};
 
 #[multiversx_sc::module]
 pub trait CancelUnstakeModule:
     crate::tokens_per_user::TokensPerUserModule
+    + crate::fees_handler::FeesHandlerModule
     + energy_query::EnergyQueryModule
     + utils::UtilsModule
     + events::EventsModule
 {
+    /// Number of epochs during which the fees deposited for a user are refunded by `cancelUnbond`.
+    /// Cancelling unstake entries always returns the full locked amount.
+    #[only_owner]
+    #[endpoint(setCancelGracePeriod)]
+    fn set_cancel_grace_period(&self, grace_period_epochs: u64) {
+        self.cancel_grace_period_epochs().set(grace_period_epochs);
+    }
+
+    /// Cancels all the unstake entries, and refunds the fees deposited
+    /// during the cancel grace period.
     #[endpoint(cancelUnbond)]
     fn cancel_unbond(&self) -> MultiValueEncoded<EsdtTokenPayment> {
         let caller = self.blockchain().get_caller();
-        let current_epoch = self.blockchain().get_block_epoch();
+        let user_entries = self.get_unlocked_tokens_for_user(&caller);
+        let refunded_fees = self.take_refundable_fees(&caller);
+        require!(
+            !user_entries.is_empty() || !refunded_fees.is_empty(),
+            "No tokens to unbond"
+        );
 
-        let mut output_payments = ManagedVec::new();
-        let mut energy = self.get_energy_entry(&caller);
+        let amounts_to_cancel = user_entries
+            .iter()
+            .map(|entry| entry.locked_tokens.amount)
+            .collect();
 
-        let entries_mapper = self.unlocked_tokens_for_user(&caller);
-        let user_entries = entries_mapper.get();
-        require!(!user_entries.is_empty(), "No tokens to unbond");
+        self.cancel_unbond_common(caller, user_entries, amounts_to_cancel, refunded_fees)
+    }
 
-        for entry in &user_entries {
-            let locked_tokens = entry.locked_tokens;
-            let attributes: LockedTokenAttributes<Self::Api> = self
-                .blockchain()
-                .get_token_attributes(&locked_tokens.token_identifier, locked_tokens.token_nonce);
-            if attributes.unlock_epoch >= current_epoch {
-                energy.add_after_token_lock(
-                    &locked_tokens.amount,
-                    attributes.unlock_epoch,
-                    current_epoch,
-                );
+    /// Cancels the given amount of locked tokens from the entry at the given index.
+    /// If no amount is provided, the whole entry is cancelled.
+    #[endpoint(cancelUnbondEntry)]
+    fn cancel_unbond_entry(
+        &self,
+        index: usize,
+        opt_amount: OptionalValue<BigUint>,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let user_entries = self.get_unlocked_tokens_for_user(&caller);
+        require!(index < user_entries.len(), "Invalid entry index");
+
+        let mut amounts_to_cancel = ManagedVec::new();
+        for (i, entry) in user_entries.iter().enumerate() {
+            let amount = if i != index {
+                BigUint::zero()
             } else {
-                // account for energy refund on unlock
-                let epoch_diff = current_epoch - attributes.unlock_epoch;
-                let energy_to_reduce = &locked_tokens.amount * epoch_diff;
-                energy.add_energy_raw(locked_tokens.amount.clone(), BigInt::zero());
-                energy.remove_energy_raw(BigUint::zero(), energy_to_reduce);
+                match &opt_amount {
+                    OptionalValue::Some(amount) => amount.clone(),
+                    OptionalValue::None => entry.locked_tokens.amount,
+                }
+            };
+            amounts_to_cancel.push(amount);
+        }
+
+        self.cancel_unbond_common(caller, user_entries, amounts_to_cancel, PaymentsVec::new())
+    }
+
+    /// Cancels the given amount of locked tokens from the entries with the given unlock epoch,
+    /// in the order they were created. If no amount is provided, all those entries are cancelled.
+    #[endpoint(cancelUnbondByUnlockEpoch)]
+    fn cancel_unbond_by_unlock_epoch(
+        &self,
+        unlock_epoch: u64,
+        opt_amount: OptionalValue<BigUint>,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        let caller = self.blockchain().get_caller();
+        let user_entries = self.get_unlocked_tokens_for_user(&caller);
+
+        let mut remaining_amount = match opt_amount {
+            OptionalValue::Some(amount) => Some(amount),
+            OptionalValue::None => None,
+        };
+        let mut amounts_to_cancel = ManagedVec::new();
+        let mut found = false;
+        for entry in &user_entries {
+            if entry.unlock_epoch != unlock_epoch {
+                amounts_to_cancel.push(BigUint::zero());
+                continue;
             }
 
-            output_payments.push(locked_tokens);
+            found = true;
+            let amount = match &mut remaining_amount {
+                Some(remaining) => {
+                    let amount = core::cmp::min(remaining.clone(), entry.locked_tokens.amount);
+                    *remaining -= &amount;
+                    amount
+                }
+                None => entry.locked_tokens.amount,
+            };
+            amounts_to_cancel.push(amount);
+        }
 
-            self.send().esdt_local_burn(
-                &entry.unlocked_tokens.token_identifier,
-                0,
-                &entry.unlocked_tokens.amount,
+        require!(found, "No entries for unlock epoch");
+        if let Some(remaining) = remaining_amount {
+            require!(remaining == 0, "Invalid amount");
+        }
+
+        self.cancel_unbond_common(caller, user_entries, amounts_to_cancel, PaymentsVec::new())
+    }
+
+    /// amounts_to_cancel contains one amount for each of the user's entries
+    fn cancel_unbond_common(
+        &self,
+        caller: ManagedAddress,
+        user_entries: ManagedVec<UnstakePair<Self::Api>>,
+        amounts_to_cancel: ManagedVec<BigUint>,
+        refunded_fees: PaymentsVec<Self::Api>,
+    ) -> MultiValueEncoded<EsdtTokenPayment> {
+        let current_epoch = self.blockchain().get_block_epoch();
+
+        let mut output_payments = ManagedVec::new();
+        let mut remaining_entries = ManagedVec::new();
+        let mut energy = self.get_energy_entry(&caller);
+        let mut cancelled_any = !refunded_fees.is_empty();
+        for fees in &refunded_fees {
+            self.return_locked_tokens(fees, current_epoch, &mut energy, &mut output_payments);
+        }
+        for (entry, amount) in user_entries.iter().zip(amounts_to_cancel.iter()) {
+            if *amount == 0 {
+                remaining_entries.push(entry);
+                continue;
+            }
+
+            cancelled_any = true;
+            let opt_remaining_entry = self.cancel_unstake_pair(
+                entry,
+                &amount,
+                current_epoch,
+                &mut energy,
+                &mut output_payments,
             );
+            if let Some(remaining_entry) = opt_remaining_entry {
+                remaining_entries.push(remaining_entry);
+            }
         }
+        require!(cancelled_any, "Invalid amount");
 
-        entries_mapper.clear();
+        self.set_unlocked_tokens_for_user(&caller, &remaining_entries);
 
-        self.send().direct_multi(&caller, &output_payments);
+        if !output_payments.is_empty() {
+            self.send().direct_multi(&caller, &output_payments);
+        }
 
         let sc_address = self.energy_factory_address().get();
         let _: IgnoreValue = self
@@ -62,7 +163,92 @@ pub trait CancelUnstakeModule:
             .revert_unstake(caller.clone(), energy)
             .execute_on_dest_context();
 
-        self.emit_unlocked_tokens_event(&caller, ManagedVec::new());
+        self.emit_unlocked_tokens_event(&caller, remaining_entries);
         output_payments.into()
     }
+
+    /// Returns the part of the entry that was not cancelled, if any
+    fn cancel_unstake_pair(
+        &self,
+        entry: UnstakePair<Self::Api>,
+        cancel_amount: &BigUint,
+        current_epoch: u64,
+        energy: &mut Energy<Self::Api>,
+        output_payments: &mut PaymentsVec<Self::Api>,
+    ) -> Option<UnstakePair<Self::Api>> {
+        let locked_tokens = entry.locked_tokens;
+        let unlocked_tokens = entry.unlocked_tokens;
+        require!(cancel_amount <= &locked_tokens.amount, "Invalid amount");
+
+        let is_full_cancel = cancel_amount == &locked_tokens.amount;
+        let unlocked_cancel_amount = if is_full_cancel {
+            unlocked_tokens.amount.clone()
+        } else {
+            &unlocked_tokens.amount * cancel_amount / &locked_tokens.amount
+        };
+        if unlocked_cancel_amount > 0 {
+            self.send().esdt_local_burn(
+                &unlocked_tokens.token_identifier,
+                0,
+                &unlocked_cancel_amount,
+            );
+        }
+
+        self.return_locked_tokens(
+            EsdtTokenPayment::new(
+                locked_tokens.token_identifier.clone(),
+                locked_tokens.token_nonce,
+                cancel_amount.clone(),
+            ),
+            current_epoch,
+            energy,
+            output_payments,
+        );
+
+        if is_full_cancel {
+            return None;
+        }
+
+        Some(UnstakePair {
+            unlock_epoch: entry.unlock_epoch,
+            locked_tokens: EsdtTokenPayment::new(
+                locked_tokens.token_identifier,
+                locked_tokens.token_nonce,
+                &locked_tokens.amount - cancel_amount,
+            ),
+            unlocked_tokens: EsdtTokenPayment::new(
+                unlocked_tokens.token_identifier,
+                0,
+                unlocked_tokens.amount - unlocked_cancel_amount,
+            ),
+            unstake_epoch: entry.unstake_epoch,
+        })
+    }
+
+    fn return_locked_tokens(
+        &self,
+        locked_tokens: EsdtTokenPayment,
+        current_epoch: u64,
+        energy: &mut Energy<Self::Api>,
+        output_payments: &mut PaymentsVec<Self::Api>,
+    ) {
+        let attributes: LockedTokenAttributes<Self::Api> = self
+            .blockchain()
+            .get_token_attributes(&locked_tokens.token_identifier, locked_tokens.token_nonce);
+        if attributes.unlock_epoch >= current_epoch {
+            energy.add_after_token_lock(
+                &locked_tokens.amount,
+                attributes.unlock_epoch,
+                current_epoch,
+            );
+        } else {
+            // account for energy refund on unlock
+            let epoch_diff = current_epoch - attributes.unlock_epoch;
+            let energy_to_reduce = &locked_tokens.amount * epoch_diff;
+            energy.add_energy_raw(locked_tokens.amount.clone(), BigInt::zero());
+            energy.remove_energy_raw(BigUint::zero(), energy_to_reduce);
+        }
+
+        output_payments.push(locked_tokens);
+    }
 }
@@ -1,7 +1,10 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 pub const MAX_PENALTY_PERCENTAGE: u64 = 10_000;
 
+use common_structs::PaymentsVec;
+
 use crate::{events, tokens_per_user::UnstakePair};
 
 pub mod fees_collector_proxy {
@@ -15,6 +18,22 @@ pub mod fees_collector_proxy {
     }
 }
 
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct DepositedFees<M: ManagedTypeApi> {
+    pub deposit_epoch: u64,
+    pub fees: EsdtTokenPayment<M>,
+}
+
 #[multiversx_sc::module]
 pub trait FeesHandlerModule:
     crate::tokens_per_user::TokensPerUserModule
@@ -36,23 +55,23 @@ pub trait FeesHandlerModule:
         let current_epoch = self.blockchain().get_block_epoch();
         let unbond_epochs = self.unbond_epochs().get();
         let unlock_epoch = current_epoch + unbond_epochs;
-        self.unlocked_tokens_for_user(&user)
-            .update(|unstake_pairs| {
-                let unstake_pair = UnstakePair {
-                    unlock_epoch,
-                    locked_tokens,
-                    unlocked_tokens,
-                };
-                unstake_pairs.push(unstake_pair);
-            });
-
-        let new_unlocked_tokens = self.unlocked_tokens_for_user(&user).get();
-        self.emit_unlocked_tokens_event(&user, new_unlocked_tokens);
+        let mut unstake_pairs = self.get_unlocked_tokens_for_user(&user);
+        unstake_pairs.push(UnstakePair {
+            unlock_epoch,
+            locked_tokens,
+            unlocked_tokens,
+            unstake_epoch: current_epoch,
+        });
+        self.set_unlocked_tokens_for_user(&user, &unstake_pairs);
+
+        self.emit_unlocked_tokens_event(&user, unstake_pairs);
     }
 
+    /// Fees deposited for a user are held during the cancel grace period,
+    /// so cancelling the unbond refunds them. Afterwards, they are burned and sent to the fees collector.
     #[payable("*")]
     #[endpoint(depositFees)]
-    fn deposit_fees(&self) {
+    fn deposit_fees(&self, opt_user: OptionalValue<ManagedAddress>) {
         let energy_factory_addr = self.energy_factory_address().get();
         let caller = self.blockchain().get_caller();
         require!(
@@ -64,7 +83,86 @@ pub trait FeesHandlerModule:
         let locked_token_id = self.get_locked_token_id();
         require!(payment.token_identifier == locked_token_id, "Invalid token");
 
-        self.burn_penalty(payment);
+        let user = match opt_user {
+            OptionalValue::Some(user) => user,
+            OptionalValue::None => {
+                self.burn_penalty(payment);
+                return;
+            }
+        };
+
+        self.burn_expired_fees(&user);
+
+        if self.cancel_grace_period_epochs().get() == 0 {
+            self.burn_penalty(payment);
+            return;
+        }
+
+        let deposit_epoch = self.blockchain().get_block_epoch();
+        self.deposited_fees_for_user(&user)
+            .update(|deposited_fees| {
+                deposited_fees.push(DepositedFees {
+                    deposit_epoch,
+                    fees: payment,
+                })
+            });
+    }
+
+    /// Burns the user's deposited fees that are past the cancel grace period
+    #[endpoint(burnExpiredFees)]
+    fn burn_expired_fees_endpoint(&self, user: ManagedAddress) {
+        self.burn_expired_fees(&user);
+    }
+
+    fn burn_expired_fees(&self, user: &ManagedAddress) {
+        let deposited_fees_mapper = self.deposited_fees_for_user(user);
+        if deposited_fees_mapper.is_empty() {
+            return;
+        }
+
+        let remaining_fees = self.burn_expired_deposited_fees(deposited_fees_mapper.get());
+        if remaining_fees.is_empty() {
+            deposited_fees_mapper.clear();
+        } else {
+            deposited_fees_mapper.set(remaining_fees);
+        }
+    }
+
+    /// Burns the expired fees and returns the ones still in the cancel grace period,
+    /// which are removed from storage
+    fn take_refundable_fees(&self, user: &ManagedAddress) -> PaymentsVec<Self::Api> {
+        let deposited_fees_mapper = self.deposited_fees_for_user(user);
+        if deposited_fees_mapper.is_empty() {
+            return PaymentsVec::new();
+        }
+
+        let remaining_fees = self.burn_expired_deposited_fees(deposited_fees_mapper.get());
+        deposited_fees_mapper.clear();
+
+        let mut refundable_fees = PaymentsVec::new();
+        for deposited_fees in &remaining_fees {
+            refundable_fees.push(deposited_fees.fees);
+        }
+
+        refundable_fees
+    }
+
+    fn burn_expired_deposited_fees(
+        &self,
+        deposited_fees: ManagedVec<DepositedFees<Self::Api>>,
+    ) -> ManagedVec<DepositedFees<Self::Api>> {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let grace_period_epochs = self.cancel_grace_period_epochs().get();
+        let mut remaining_fees = ManagedVec::new();
+        for entry in &deposited_fees {
+            if current_epoch < entry.deposit_epoch + grace_period_epochs {
+                remaining_fees.push(entry);
+            } else {
+                self.burn_penalty(entry.fees);
+            }
+        }
+
+        remaining_fees
     }
 
     fn burn_penalty(&self, payment: EsdtTokenPayment) {
@@ -101,6 +199,17 @@ pub trait FeesHandlerModule:
         sc_address: ManagedAddress,
     ) -> fees_collector_proxy::Proxy<Self::Api>;
 
+    #[view(getCancelGracePeriodEpochs)]
+    #[storage_mapper("cancelGracePeriodEpochs")]
+    fn cancel_grace_period_epochs(&self) -> SingleValueMapper<u64>;
+
+    #[view(getDepositedFeesForUser)]
+    #[storage_mapper("depositedFeesForUser")]
+    fn deposited_fees_for_user(
+        &self,
+        user: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<DepositedFees<Self::Api>>>;
+
     #[view(getFeesBurnPercentage)]
     #[storage_mapper("feesBurnPercentage")]
     fn fees_burn_percentage(&self) -> SingleValueMapper<u64>;
@@ -37,11 +37,16 @@ pub trait TokenUnstakeModule:
         );
 
         self.unbond_epochs().set(unbond_epochs);
+        self.cancel_grace_period_epochs().set(unbond_epochs);
         self.energy_factory_address().set(&energy_factory_address);
         self.fees_collector_address().set(&fees_collector_address);
         self.fees_burn_percentage().set(fees_burn_percentage);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        let unbond_epochs = self.unbond_epochs().get();
+        self.cancel_grace_period_epochs()
+            .set_if_empty(unbond_epochs);
+    }
 }
@@ -16,8 +16,30 @@ pub struct UnstakePair<M: ManagedTypeApi> {
     pub unlock_epoch: u64,
     pub locked_tokens: EsdtTokenPayment<M>,
     pub unlocked_tokens: EsdtTokenPayment<M>,
+    pub unstake_epoch: u64,
 }
 
+/// Entries created before the unstake epoch was stored
+#[derive(
+    ManagedVecItem,
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    TypeAbi,
+    Clone,
+    PartialEq,
+    Debug,
+)]
+pub struct LegacyUnstakePair<M: ManagedTypeApi> {
+    pub unlock_epoch: u64,
+    pub locked_tokens: EsdtTokenPayment<M>,
+    pub unlocked_tokens: EsdtTokenPayment<M>,
+}
+
+/// Unstake epoch of the legacy entries, which is not known
+pub const UNKNOWN_UNSTAKE_EPOCH: u64 = 0;
+
 #[multiversx_sc::module]
 pub trait TokensPerUserModule {
     #[view(getUnbondEpochs)]
@@ -25,7 +47,41 @@ pub trait TokensPerUserModule {
     fn unbond_epochs(&self) -> SingleValueMapper<u64>;
 
     #[view(getUnlockedTokensForUser)]
+    fn get_unlocked_tokens_for_user(
+        &self,
+        address: &ManagedAddress,
+    ) -> ManagedVec<UnstakePair<Self::Api>> {
+        let mut entries = ManagedVec::new();
+        for legacy_entry in &self.legacy_unlocked_tokens_for_user(address).get() {
+            entries.push(UnstakePair {
+                unlock_epoch: legacy_entry.unlock_epoch,
+                locked_tokens: legacy_entry.locked_tokens,
+                unlocked_tokens: legacy_entry.unlocked_tokens,
+                unstake_epoch: UNKNOWN_UNSTAKE_EPOCH,
+            });
+        }
+        entries.append_vec(self.unlocked_tokens_for_user(address).get());
+
+        entries
+    }
+
+    /// Also migrates the user's legacy entries, which are always included in `entries`
+    fn set_unlocked_tokens_for_user(
+        &self,
+        address: &ManagedAddress,
+        entries: &ManagedVec<UnstakePair<Self::Api>>,
+    ) {
+        self.legacy_unlocked_tokens_for_user(address).clear();
+        self.unlocked_tokens_for_user(address).set(entries);
+    }
+
     #[storage_mapper("unlockedTokensForUser")]
+    fn legacy_unlocked_tokens_for_user(
+        &self,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<ManagedVec<LegacyUnstakePair<Self::Api>>>;
+
+    #[storage_mapper("unstakeEntriesForUser")]
     fn unlocked_tokens_for_user(
         &self,
         address: &ManagedAddress,
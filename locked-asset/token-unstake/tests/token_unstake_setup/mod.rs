@@ -308,6 +308,32 @@ where
                 let _ = sc.cancel_unbond();
             })
     }
+
+    pub fn cancel_unbond_entry(
+        &mut self,
+        user: &Address,
+        index: usize,
+        opt_amount: Option<num_bigint::BigUint>,
+    ) -> TxResult {
+        self.b_mock
+            .execute_tx(user, &self.unstake_sc_wrapper, &rust_biguint!(0), |sc| {
+                let opt_amount = opt_amount.map(to_managed_biguint).into();
+                let _ = sc.cancel_unbond_entry(index, opt_amount);
+            })
+    }
+
+    pub fn cancel_unbond_by_unlock_epoch(
+        &mut self,
+        user: &Address,
+        unlock_epoch: u64,
+        opt_amount: Option<num_bigint::BigUint>,
+    ) -> TxResult {
+        self.b_mock
+            .execute_tx(user, &self.unstake_sc_wrapper, &rust_biguint!(0), |sc| {
+                let opt_amount = opt_amount.map(to_managed_biguint).into();
+                let _ = sc.cancel_unbond_by_unlock_epoch(unlock_epoch, opt_amount);
+            })
+    }
 }
 
 pub fn to_rust_biguint(
@@ -9,7 +9,11 @@ use multiversx_sc_scenario::{
 use num_bigint::ToBigInt;
 use num_traits::cast::ToPrimitive;
 use simple_lock::locked_token::LockedTokenAttributes;
-use token_unstake::tokens_per_user::{TokensPerUserModule, UnstakePair};
+use token_unstake::{
+    cancel_unstake::CancelUnstakeModule,
+    fees_handler::FeesHandlerModule,
+    tokens_per_user::{TokensPerUserModule, UnstakePair},
+};
 use token_unstake_setup::*;
 
 pub struct ResultWrapper<EnergyFactoryBuilder, UnstakeScBuilder>
@@ -18,6 +22,8 @@ where
     UnstakeScBuilder: 'static + Copy + Fn() -> token_unstake::ContractObj<DebugApi>,
 {
     pub setup: TokenUnstakeSetup<EnergyFactoryBuilder, UnstakeScBuilder>,
+    pub first_penalty_amount: num_bigint::BigUint,
+    pub second_penalty_amount: num_bigint::BigUint,
     pub balance_after_second_reduce: num_bigint::BigUint,
     pub final_penalty_amount: num_bigint::BigUint,
 }
@@ -75,7 +81,7 @@ fn cancel_unbond_test() {
         .b_mock
         .execute_query(&setup.unstake_sc_wrapper, |sc| {
             assert!(sc
-                .unlocked_tokens_for_user(&managed_address!(&first_user))
+                .get_unlocked_tokens_for_user(&managed_address!(&first_user))
                 .is_empty());
         })
         .assert_ok();
@@ -99,6 +105,266 @@ fn cancel_unbond_test() {
     assert_eq!(user_energy, expected_energy);
 }
 
+#[test]
+fn cancel_unbond_after_grace_period_test() {
+    let result = unbond_test_common(energy_factory::contract_obj, token_unstake::contract_obj);
+    let (mut setup, first_penalty_amount, balance_after_second_reduce) = (
+        result.setup,
+        result.first_penalty_amount,
+        result.balance_after_second_reduce,
+    );
+    let first_user = setup.first_user.clone();
+    let owner = setup.owner.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.unstake_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_cancel_grace_period(1);
+        })
+        .assert_ok();
+
+    // the unstaked tokens are still returned, but the expired fees are not
+    setup.b_mock.set_block_epoch(10 + UNBOND_EPOCHS - 1);
+    setup.cancel_unbond(&first_user).assert_ok();
+
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            3,
+            &balance_after_second_reduce,
+            None,
+        );
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            1,
+            &rust_biguint!(0),
+            None,
+        );
+
+    // half of the expired fees are burned, the rest are sent to the fees collector
+    let fees_collector_mock = setup.fees_collector_mock.clone();
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &fees_collector_mock,
+            LOCKED_TOKEN_ID,
+            1,
+            &(&first_penalty_amount - &first_penalty_amount * FEES_BURN_PERCENTAGE / 10_000u64),
+            None,
+        );
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &fees_collector_mock,
+            LOCKED_TOKEN_ID,
+            3,
+            &rust_biguint!(0),
+            None,
+        );
+}
+
+#[test]
+fn partial_cancel_unbond_test() {
+    let result = unbond_test_common(energy_factory::contract_obj, token_unstake::contract_obj);
+    let (mut setup, balance_after_second_reduce, final_penalty_amount) = (
+        result.setup,
+        result.balance_after_second_reduce,
+        result.final_penalty_amount,
+    );
+    let first_user = setup.first_user.clone();
+    let final_user_balance = &balance_after_second_reduce - &final_penalty_amount;
+
+    // cancel half of the entry during the grace period - full amount is returned
+    let first_cancel_amount = &balance_after_second_reduce / 2u64;
+    setup
+        .cancel_unbond_entry(&first_user, 1, Some(first_cancel_amount.clone()))
+        .assert_user_error("Invalid entry index");
+    setup
+        .cancel_unbond_entry(&first_user, 0, Some(first_cancel_amount.clone()))
+        .assert_ok();
+
+    let remaining_locked_amount = &balance_after_second_reduce - &first_cancel_amount;
+    let remaining_unlocked_amount = &final_user_balance
+        - &final_user_balance * &first_cancel_amount / &balance_after_second_reduce;
+    setup
+        .b_mock
+        .execute_query(&setup.unstake_sc_wrapper, |sc| {
+            let unbond_entries = sc.get_unlocked_tokens_for_user(&managed_address!(&first_user));
+            let expected_entries = ManagedVec::from_single_item(UnstakePair {
+                locked_tokens: EsdtTokenPayment::new(
+                    managed_token_id!(LOCKED_TOKEN_ID),
+                    3,
+                    to_managed_biguint(remaining_locked_amount.clone()),
+                ),
+                unlocked_tokens: EsdtTokenPayment::new(
+                    managed_token_id!(BASE_ASSET_TOKEN_ID),
+                    0,
+                    to_managed_biguint(remaining_unlocked_amount.clone()),
+                ),
+                unlock_epoch: 10 + UNBOND_EPOCHS,
+                unstake_epoch: 10,
+            });
+            assert_eq!(unbond_entries, expected_entries);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            3,
+            &first_cancel_amount,
+            None,
+        );
+
+    // cancel the rest after the grace period - the full amount is still returned
+    setup.b_mock.set_block_epoch(10 + UNBOND_EPOCHS + 5);
+    setup
+        .cancel_unbond_by_unlock_epoch(&first_user, 10, None)
+        .assert_user_error("No entries for unlock epoch");
+    setup
+        .cancel_unbond_by_unlock_epoch(&first_user, 10 + UNBOND_EPOCHS, None)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.unstake_sc_wrapper, |sc| {
+            assert!(sc
+                .get_unlocked_tokens_for_user(&managed_address!(&first_user))
+                .is_empty());
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            3,
+            &balance_after_second_reduce,
+            None,
+        );
+
+    let fees_collector_mock = setup.fees_collector_mock.clone();
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &fees_collector_mock,
+            LOCKED_TOKEN_ID,
+            3,
+            &rust_biguint!(0),
+            None,
+        );
+}
+
+#[test]
+fn cancel_unbond_refunds_deposited_fees_test() {
+    let result = unbond_test_common(energy_factory::contract_obj, token_unstake::contract_obj);
+    let (mut setup, first_penalty_amount, second_penalty_amount, balance_after_second_reduce) = (
+        result.setup,
+        result.first_penalty_amount,
+        result.second_penalty_amount,
+        result.balance_after_second_reduce,
+    );
+    let first_user = setup.first_user.clone();
+    let owner = setup.owner.clone();
+
+    // the reduce lock period fees are held for the user
+    setup
+        .b_mock
+        .execute_query(&setup.unstake_sc_wrapper, |sc| {
+            let deposited_fees = sc
+                .deposited_fees_for_user(&managed_address!(&first_user))
+                .get();
+            assert_eq!(deposited_fees.len(), 2);
+            assert_eq!(deposited_fees.get(0).deposit_epoch, 0);
+            assert_eq!(
+                deposited_fees.get(0).fees,
+                EsdtTokenPayment::new(
+                    managed_token_id!(LOCKED_TOKEN_ID),
+                    1,
+                    to_managed_biguint(first_penalty_amount.clone()),
+                )
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.unstake_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_cancel_grace_period(UNBOND_EPOCHS + 1);
+        })
+        .assert_ok();
+
+    // burning expired fees keeps the ones still in the grace period
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.unstake_sc_wrapper, &rust_biguint!(0), |sc| {
+            sc.burn_expired_fees_endpoint(managed_address!(&first_user));
+            assert_eq!(
+                sc.deposited_fees_for_user(&managed_address!(&first_user))
+                    .get()
+                    .len(),
+                2
+            );
+        })
+        .assert_ok();
+
+    setup.cancel_unbond(&first_user).assert_ok();
+
+    // the unstaked tokens and both fees are returned
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            1,
+            &first_penalty_amount,
+            None,
+        );
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            2,
+            &second_penalty_amount,
+            None,
+        );
+    setup
+        .b_mock
+        .check_nft_balance::<LockedTokenAttributes<DebugApi>>(
+            &first_user,
+            LOCKED_TOKEN_ID,
+            3,
+            &balance_after_second_reduce,
+            None,
+        );
+    setup
+        .b_mock
+        .execute_query(&setup.unstake_sc_wrapper, |sc| {
+            assert!(sc
+                .deposited_fees_for_user(&managed_address!(&first_user))
+                .is_empty());
+        })
+        .assert_ok();
+
+    // energy is added back for all the returned tokens - current epoch is 10
+    let user_energy = setup.get_user_energy(&first_user);
+    let expected_energy = rust_biguint!(LOCK_OPTIONS[2] - 10) * &first_penalty_amount
+        + rust_biguint!(LOCK_OPTIONS[1] - 10) * &second_penalty_amount
+        + rust_biguint!(LOCK_OPTIONS[0] - 10) * &balance_after_second_reduce;
+    assert_eq!(user_energy, expected_energy);
+
+    setup
+        .cancel_unbond(&first_user)
+        .assert_user_error("No tokens to unbond");
+}
+
 fn unbond_test_common<EnergyFactoryBuilder, UnstakeScBuilder>(
     energy_factory_builder: EnergyFactoryBuilder,
     unstake_sc_builder: UnstakeScBuilder,
@@ -160,7 +426,7 @@ where
         .reduce_lock_period(&first_user, 2, balance_u64, LOCK_OPTIONS[0])
         .assert_ok();
 
-    let balance_after_second_reduce = new_user_balance - second_expected_penalty_amount;
+    let balance_after_second_reduce = new_user_balance - &second_expected_penalty_amount;
     setup.b_mock.check_nft_balance(
         &first_user,
         LOCKED_TOKEN_ID,
@@ -198,9 +464,7 @@ where
     setup
         .b_mock
         .execute_query(&setup.unstake_sc_wrapper, |sc| {
-            let unbond_entries = sc
-                .unlocked_tokens_for_user(&managed_address!(&first_user))
-                .get();
+            let unbond_entries = sc.get_unlocked_tokens_for_user(&managed_address!(&first_user));
             let expected_entries = ManagedVec::from_single_item(UnstakePair {
                 locked_tokens: EsdtTokenPayment::new(
                     managed_token_id!(LOCKED_TOKEN_ID),
@@ -213,6 +477,7 @@ where
                     to_managed_biguint(final_user_balance.clone()),
                 ),
                 unlock_epoch: 10 + UNBOND_EPOCHS,
+                unstake_epoch: 10,
             });
             assert_eq!(unbond_entries, expected_entries);
         })
@@ -220,6 +485,8 @@ where
 
     ResultWrapper {
         setup,
+        first_penalty_amount: penalty_amount,
+        second_penalty_amount,
         balance_after_second_reduce,
         final_penalty_amount,
     }
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           10
+// Endpoints:                           16
 // Async Callback (empty):               1
-// Total number of exported functions:  13
+// Total number of exported functions:  19
 
 #![no_std]
 
@@ -21,11 +21,17 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         getUnbondEpochs => unbond_epochs
-        getUnlockedTokensForUser => unlocked_tokens_for_user
+        getUnlockedTokensForUser => get_unlocked_tokens_for_user
         claimUnlockedTokens => claim_unlocked_tokens
+        setCancelGracePeriod => set_cancel_grace_period
         cancelUnbond => cancel_unbond
+        cancelUnbondEntry => cancel_unbond_entry
+        cancelUnbondByUnlockEpoch => cancel_unbond_by_unlock_epoch
         depositUserTokens => deposit_user_tokens
         depositFees => deposit_fees
+        burnExpiredFees => burn_expired_fees_endpoint
+        getCancelGracePeriodEpochs => cancel_grace_period_epochs
+        getDepositedFeesForUser => deposited_fees_for_user
         getFeesBurnPercentage => fees_burn_percentage
         getFeesCollectorAddress => fees_collector_address
         setEnergyFactoryAddress => set_energy_factory_address
@@ -0,0 +1,57 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug,
+)]
+pub enum Category {
+    Pairs,
+    Farms,
+    Energy,
+}
+
+#[multiversx_sc::module]
+pub trait CategoriesModule {
+    /// Moves the given contracts to the category. A contract can only be in one category.
+    #[only_owner]
+    #[endpoint(setContractsCategory)]
+    fn set_contracts_category(
+        &self,
+        category: Category,
+        contracts: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut category_contracts = self.category_contracts(category);
+        for contract in contracts {
+            self.remove_contract_category(&contract);
+
+            let _ = category_contracts.insert(contract.clone());
+            self.contract_category(&contract).set(category);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeContractsCategory)]
+    fn remove_contracts_category(&self, contracts: MultiValueEncoded<ManagedAddress>) {
+        for contract in contracts {
+            self.remove_contract_category(&contract);
+        }
+    }
+
+    fn remove_contract_category(&self, contract: &ManagedAddress) {
+        let category_mapper = self.contract_category(contract);
+        if category_mapper.is_empty() {
+            return;
+        }
+
+        let category = category_mapper.take();
+        let _ = self.category_contracts(category).swap_remove(contract);
+    }
+
+    #[view(getContractCategory)]
+    #[storage_mapper("contractCategory")]
+    fn contract_category(&self, contract: &ManagedAddress) -> SingleValueMapper<Category>;
+
+    #[view(getCategoryContracts)]
+    #[storage_mapper("categoryContracts")]
+    fn category_contracts(&self, category: Category) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,32 @@
+multiversx_sc::imports!();
+
+pub type ReasonCode = u32;
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    #[event("pauseContract")]
+    fn pause_contract_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] contract: &ManagedAddress,
+        #[indexed] reason_code: ReasonCode,
+        #[indexed] expiry_block: u64,
+    );
+
+    #[event("resumeContract")]
+    fn resume_contract_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] contract: &ManagedAddress,
+        #[indexed] reason_code: ReasonCode,
+    );
+
+    #[event("extendPause")]
+    fn extend_pause_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] contract: &ManagedAddress,
+        #[indexed] reason_code: ReasonCode,
+        #[indexed] expiry_block: u64,
+    );
+}
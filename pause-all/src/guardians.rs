@@ -0,0 +1,55 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait GuardiansModule {
+    /// Guardians can pause the contracts, but not resume them.
+    /// Their pauses expire after the configured number of blocks, unless extended by the owner.
+    #[only_owner]
+    #[endpoint(addGuardians)]
+    fn add_guardians(&self, guardians: MultiValueEncoded<ManagedAddress>) {
+        let mut guardians_mapper = self.guardians();
+        for guardian in guardians {
+            let _ = guardians_mapper.insert(guardian);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(removeGuardians)]
+    fn remove_guardians(&self, guardians: MultiValueEncoded<ManagedAddress>) {
+        let mut guardians_mapper = self.guardians();
+        for guardian in guardians {
+            let _ = guardians_mapper.swap_remove(&guardian);
+        }
+    }
+
+    #[only_owner]
+    #[endpoint(setGuardianPauseDuration)]
+    fn set_guardian_pause_duration(&self, duration_blocks: u64) {
+        require!(duration_blocks > 0, "Invalid pause duration");
+
+        self.guardian_pause_duration_blocks().set(duration_blocks);
+    }
+
+    /// Returns true if the caller is the owner, and false if the caller is a guardian
+    fn require_owner_or_guardian(&self, caller: &ManagedAddress) -> bool {
+        let owner = self.blockchain().get_owner_address();
+        if caller == &owner {
+            return true;
+        }
+
+        require!(
+            self.guardians().contains(caller),
+            "Only owner or guardians may call this endpoint"
+        );
+
+        false
+    }
+
+    #[view(getGuardians)]
+    #[storage_mapper("guardians")]
+    fn guardians(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getGuardianPauseDuration)]
+    #[storage_mapper("guardianPauseDurationBlocks")]
+    fn guardian_pause_duration_blocks(&self) -> SingleValueMapper<u64>;
+}
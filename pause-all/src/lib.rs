@@ -1,13 +1,18 @@
 #![no_std]
 
+use categories::Category;
+use events::ReasonCode;
 use multiversx_sc_modules::ongoing_operation::{CONTINUE_OP, STOP_OP};
 use ongoing_pause_operation::{OngoingOperation, MIN_GAS_TO_SAVE_PROGRESS};
+use pausable::State;
 
 multiversx_sc::imports!();
 
 mod pause_proxy {
     multiversx_sc::imports!();
 
+    use pausable::State;
+
     #[multiversx_sc::proxy]
     pub trait Pausable {
         #[endpoint]
@@ -15,15 +20,30 @@ mod pause_proxy {
 
         #[endpoint]
         fn resume(&self);
+
+        #[view(getState)]
+        fn state(&self) -> State;
     }
 }
 
+pub mod categories;
+pub mod events;
+pub mod guardians;
 pub mod ongoing_pause_operation;
 
+/// Expiry block of the pauses made by the owner, which never expire
+pub const NO_EXPIRY_BLOCK: u64 = u64::MAX;
+/// Used by the endpoints that were available before the reason codes
+pub const UNSPECIFIED_REASON_CODE: ReasonCode = 0;
+pub const PAUSE_EXPIRED_REASON_CODE: ReasonCode = ReasonCode::MAX;
+
 #[multiversx_sc::contract]
 pub trait PauseAll:
     ongoing_pause_operation::OngoingPauseOperationModule
     + multiversx_sc_modules::ongoing_operation::OngoingOperationModule
+    + guardians::GuardiansModule
+    + categories::CategoriesModule
+    + events::EventsModule
 {
     #[init]
     fn init(&self) {}
@@ -46,104 +66,326 @@ pub trait PauseAll:
         let mut whitelist = self.pausable_contracts();
         for addr in pausable_sc_addr {
             let _ = whitelist.swap_remove(&addr);
+            self.remove_contract_category(&addr);
+            self.pause_expiry_block(&addr).clear();
+            self.pre_pause_state(&addr).clear();
         }
     }
 
+    /// Same as pauseSelectedWithReason, with an unspecified reason code
+    #[endpoint(pauseSelected)]
+    fn pause_selected(&self, pausable_sc_addr: MultiValueEncoded<ManagedAddress>) {
+        self.pause_selected_with_reason(UNSPECIFIED_REASON_CODE, pausable_sc_addr);
+    }
+
     /// Will pause the given list of contracts.
     /// Contracts will only be paused if they are in the pausable_contracts list.
     /// Other contracts will be ignored.
-    #[only_owner]
-    #[endpoint(pauseSelected)]
-    fn pause_selected(&self, pausable_sc_addr: MultiValueEncoded<ManagedAddress>) {
+    /// Pauses made by guardians expire after the configured number of blocks.
+    #[endpoint(pauseSelectedWithReason)]
+    fn pause_selected_with_reason(
+        &self,
+        reason_code: ReasonCode,
+        pausable_sc_addr: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let expiry_block = self.get_new_pause_expiry_block(&caller);
+
         let whitelist = self.pausable_contracts();
         for addr in pausable_sc_addr {
             if whitelist.contains(&addr) {
-                self.call_pause(addr);
+                self.call_pause(&caller, addr, reason_code, expiry_block);
             }
         }
     }
 
+    /// Same as pauseAllWithReason, with an unspecified reason code
+    #[endpoint(pauseAll)]
+    fn pause_all(&self) -> OperationCompletionStatus {
+        self.pause_all_with_reason(UNSPECIFIED_REASON_CODE)
+    }
+
     /// Will attempt to pause all contracts from the whitelist.
     /// Returns "completed" if all were paused.
     /// Otherwise, it will save progress and return "interrupted",
     /// and will require more calls to complete
-    #[only_owner]
-    #[endpoint(pauseAll)]
-    fn pause_all(&self) -> OperationCompletionStatus {
-        let mut current_index = self.load_pause_all_operation();
-        let whitelist = self.pausable_contracts();
-        let whitelist_len = whitelist.len();
+    #[endpoint(pauseAllWithReason)]
+    fn pause_all_with_reason(&self, reason_code: ReasonCode) -> OperationCompletionStatus {
+        let caller = self.blockchain().get_caller();
+        let expiry_block = self.get_new_pause_expiry_block(&caller);
 
-        let run_result = self.run_while_it_has_gas(MIN_GAS_TO_SAVE_PROGRESS, || {
-            if current_index > whitelist_len {
-                return STOP_OP;
-            }
+        let current_index = self.load_pause_all_operation();
+        let (run_result, addr_index) =
+            self.run_for_contracts(self.pausable_contracts(), current_index, |sc_addr| {
+                self.call_pause(&caller, sc_addr, reason_code, expiry_block);
+            });
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&OngoingOperation::PauseAll { addr_index });
+        }
 
-            let sc_addr = whitelist.get_by_index(current_index);
-            self.call_pause(sc_addr);
-            current_index += 1;
+        run_result
+    }
 
-            CONTINUE_OP
-        });
+    /// Will attempt to pause all the whitelisted contracts from the given category.
+    /// Same return values as pauseAllWithReason.
+    #[endpoint(pauseCategory)]
+    fn pause_category(
+        &self,
+        reason_code: ReasonCode,
+        category: Category,
+    ) -> OperationCompletionStatus {
+        let caller = self.blockchain().get_caller();
+        let expiry_block = self.get_new_pause_expiry_block(&caller);
+
+        let whitelist = self.pausable_contracts();
+        let current_index = self.load_pause_category_operation(category);
+        let (run_result, addr_index) = self.run_for_contracts(
+            self.category_contracts(category),
+            current_index,
+            |sc_addr| {
+                if whitelist.contains(&sc_addr) {
+                    self.call_pause(&caller, sc_addr, reason_code, expiry_block);
+                }
+            },
+        );
         if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
-            self.save_progress(&OngoingOperation::PauseAll {
-                addr_index: current_index,
+            self.save_progress(&OngoingOperation::PauseCategory {
+                category,
+                addr_index,
             });
         }
 
         run_result
     }
 
-    fn call_pause(&self, sc_addr: ManagedAddress) {
-        let _: IgnoreValue = self.pause_proxy(sc_addr).pause().execute_on_dest_context();
+    fn get_new_pause_expiry_block(&self, caller: &ManagedAddress) -> u64 {
+        let caller_is_owner = self.require_owner_or_guardian(caller);
+        if caller_is_owner {
+            return NO_EXPIRY_BLOCK;
+        }
+
+        let pause_duration = self.guardian_pause_duration_blocks().get();
+        require!(pause_duration > 0, "Guardian pause duration not set");
+
+        self.blockchain().get_block_nonce() + pause_duration
     }
 
-    /// Will unpause the given list of contracts.
-    /// Contracts not in the whitelist will be ignored.
+    fn call_pause(
+        &self,
+        caller: &ManagedAddress,
+        sc_addr: ManagedAddress,
+        reason_code: ReasonCode,
+        expiry_block: u64,
+    ) {
+        let state: State = self
+            .pause_proxy(sc_addr.clone())
+            .state()
+            .execute_on_dest_context();
+        let _: IgnoreValue = self
+            .pause_proxy(sc_addr.clone())
+            .pause()
+            .execute_on_dest_context();
+
+        // The state is only recorded by the first pause, as later ones see the contract paused
+        if state != State::Inactive {
+            self.pre_pause_state(&sc_addr).set(state);
+        }
+
+        // Guardians may not extend existing pauses, nor make pauses they did not start expire.
+        // Owner pauses never expire.
+        let expiry_mapper = self.pause_expiry_block(&sc_addr);
+        if state != State::Inactive || expiry_block == NO_EXPIRY_BLOCK {
+            expiry_mapper.set(expiry_block);
+        }
+
+        let new_expiry_block = if !expiry_mapper.is_empty() {
+            expiry_mapper.get()
+        } else {
+            NO_EXPIRY_BLOCK
+        };
+        self.pause_contract_event(caller, &sc_addr, reason_code, new_expiry_block);
+    }
+
+    /// Extends the given guardian pauses by extra_blocks.
+    /// Contracts that are not paused through this contract are ignored.
+    #[only_owner]
+    #[endpoint(extendPause)]
+    fn extend_pause(
+        &self,
+        reason_code: ReasonCode,
+        extra_blocks: u64,
+        pausable_sc_addr: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        for addr in pausable_sc_addr {
+            let expiry_mapper = self.pause_expiry_block(&addr);
+            if expiry_mapper.is_empty() {
+                continue;
+            }
+
+            let new_expiry_block = expiry_mapper.get().saturating_add(extra_blocks);
+            expiry_mapper.set(new_expiry_block);
+
+            self.extend_pause_event(&caller, &addr, reason_code, new_expiry_block);
+        }
+    }
+
+    /// Same as resumeSelectedWithReason, with an unspecified reason code
     #[only_owner]
     #[endpoint(resumeSelected)]
     fn resume_selected(&self, pausable_sc_addr: MultiValueEncoded<ManagedAddress>) {
+        self.resume_selected_with_reason(UNSPECIFIED_REASON_CODE, pausable_sc_addr);
+    }
+
+    /// Will unpause the given list of contracts.
+    /// Contracts not in the whitelist will be ignored.
+    #[only_owner]
+    #[endpoint(resumeSelectedWithReason)]
+    fn resume_selected_with_reason(
+        &self,
+        reason_code: ReasonCode,
+        pausable_sc_addr: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let caller = self.blockchain().get_caller();
         let whitelist = self.pausable_contracts();
         for addr in pausable_sc_addr {
             if whitelist.contains(&addr) {
-                self.call_resume(addr);
+                self.call_resume(&caller, addr, reason_code);
             }
         }
     }
 
+    /// Same as resumeAllWithReason, with an unspecified reason code
+    #[only_owner]
+    #[endpoint(resumeAll)]
+    fn resume_all(&self) -> OperationCompletionStatus {
+        self.resume_all_with_reason(UNSPECIFIED_REASON_CODE)
+    }
+
     /// Will attempt to unpause all contracts from the whitelist.
     /// Returns "completed" if all were unpaused.
     /// Otherwise, it will save progress and return "interrupted",
     /// and will require more calls to complete
     #[only_owner]
-    #[endpoint(resumeAll)]
-    fn resume_all(&self) -> OperationCompletionStatus {
-        let mut current_index = self.load_resume_all_operation();
+    #[endpoint(resumeAllWithReason)]
+    fn resume_all_with_reason(&self, reason_code: ReasonCode) -> OperationCompletionStatus {
+        let caller = self.blockchain().get_caller();
+        let current_index = self.load_resume_all_operation();
+        let (run_result, addr_index) =
+            self.run_for_contracts(self.pausable_contracts(), current_index, |sc_addr| {
+                self.call_resume(&caller, sc_addr, reason_code);
+            });
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&OngoingOperation::ResumeAll { addr_index });
+        }
+
+        run_result
+    }
+
+    /// Will attempt to unpause all the whitelisted contracts from the given category.
+    /// Same return values as resumeAllWithReason.
+    #[only_owner]
+    #[endpoint(resumeCategory)]
+    fn resume_category(
+        &self,
+        reason_code: ReasonCode,
+        category: Category,
+    ) -> OperationCompletionStatus {
+        let caller = self.blockchain().get_caller();
         let whitelist = self.pausable_contracts();
-        let whitelist_len = whitelist.len();
+        let current_index = self.load_resume_category_operation(category);
+        let (run_result, addr_index) = self.run_for_contracts(
+            self.category_contracts(category),
+            current_index,
+            |sc_addr| {
+                if whitelist.contains(&sc_addr) {
+                    self.call_resume(&caller, sc_addr, reason_code);
+                }
+            },
+        );
+        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+            self.save_progress(&OngoingOperation::ResumeCategory {
+                category,
+                addr_index,
+            });
+        }
+
+        run_result
+    }
+
+    /// Anyone can resume the contracts whose guardian pause has expired.
+    /// Only the contracts that were active before the pause, and are still paused, are resumed.
+    /// The ones that were partially active are left for the owner to resume,
+    /// as resuming would also enable their swaps.
+    /// Other contracts are ignored.
+    #[endpoint(resumeExpired)]
+    fn resume_expired(&self, pausable_sc_addr: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        let current_block = self.blockchain().get_block_nonce();
+        for addr in pausable_sc_addr {
+            let expiry_mapper = self.pause_expiry_block(&addr);
+            if expiry_mapper.is_empty() || current_block < expiry_mapper.get() {
+                continue;
+            }
+            if self.pre_pause_state(&addr).get() != State::Active {
+                continue;
+            }
+
+            let state: State = self
+                .pause_proxy(addr.clone())
+                .state()
+                .execute_on_dest_context();
+            if state != State::Inactive {
+                // the state was changed outside this contract, so the pause is no longer tracked
+                expiry_mapper.clear();
+                self.pre_pause_state(&addr).clear();
+                continue;
+            }
+
+            self.call_resume(&caller, addr, PAUSE_EXPIRED_REASON_CODE);
+        }
+    }
+
+    fn call_resume(
+        &self,
+        caller: &ManagedAddress,
+        sc_addr: ManagedAddress,
+        reason_code: ReasonCode,
+    ) {
+        let _: IgnoreValue = self
+            .pause_proxy(sc_addr.clone())
+            .resume()
+            .execute_on_dest_context();
+
+        self.pause_expiry_block(&sc_addr).clear();
+        self.pre_pause_state(&sc_addr).clear();
+        self.resume_contract_event(caller, &sc_addr, reason_code);
+    }
+
+    /// Calls the action for the contracts in the set, starting from the given index.
+    /// Returns the run result and the index of the next contract.
+    fn run_for_contracts<F: FnMut(ManagedAddress)>(
+        &self,
+        contracts: UnorderedSetMapper<ManagedAddress>,
+        start_index: usize,
+        mut action: F,
+    ) -> (OperationCompletionStatus, usize) {
+        let mut current_index = start_index;
+        let contracts_len = contracts.len();
 
         let run_result = self.run_while_it_has_gas(MIN_GAS_TO_SAVE_PROGRESS, || {
-            if current_index > whitelist_len {
+            if current_index > contracts_len {
                 return STOP_OP;
             }
 
-            let sc_addr = whitelist.get_by_index(current_index);
-            self.call_resume(sc_addr);
+            let sc_addr = contracts.get_by_index(current_index);
+            action(sc_addr);
             current_index += 1;
 
             CONTINUE_OP
         });
-        if run_result == OperationCompletionStatus::InterruptedBeforeOutOfGas {
-            self.save_progress(&OngoingOperation::ResumeAll {
-                addr_index: current_index,
-            });
-        }
-
-        run_result
-    }
 
-    fn call_resume(&self, sc_addr: ManagedAddress) {
-        let _: IgnoreValue = self.pause_proxy(sc_addr).resume().execute_on_dest_context();
+        (run_result, current_index)
     }
 
     #[proxy]
@@ -152,4 +394,16 @@ pub trait PauseAll:
     #[view(getPausableContracts)]
     #[storage_mapper("pausableContracts")]
     fn pausable_contracts(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// The block at which the pause made through this contract expires.
+    /// Empty if the contract was not paused through this contract.
+    #[view(getPauseExpiryBlock)]
+    #[storage_mapper("pauseExpiryBlock")]
+    fn pause_expiry_block(&self, pausable_sc_addr: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    /// The state of the contract before it was first paused through this contract.
+    /// Inactive if it was already paused, or was not paused through this contract.
+    #[view(getPrePauseState)]
+    #[storage_mapper("prePauseState")]
+    fn pre_pause_state(&self, pausable_sc_addr: &ManagedAddress) -> SingleValueMapper<State>;
 }
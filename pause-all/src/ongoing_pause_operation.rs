@@ -1,9 +1,13 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::categories::Category;
+
 pub const MIN_GAS_TO_SAVE_PROGRESS: u64 = 10_000_000;
 const FIRST_INDEX: usize = 1;
 
+static OTHER_OPERATION_ERR_MSG: &[u8] = b"Another operation in progress";
+
 #[derive(TopEncode, TopDecode, Default)]
 pub enum OngoingOperation {
     #[default]
@@ -14,6 +18,14 @@ pub enum OngoingOperation {
     ResumeAll {
         addr_index: usize,
     },
+    PauseCategory {
+        category: Category,
+        addr_index: usize,
+    },
+    ResumeCategory {
+        category: Category,
+        addr_index: usize,
+    },
 }
 
 #[multiversx_sc::module]
@@ -25,9 +37,7 @@ pub trait OngoingPauseOperationModule:
         match current_op {
             OngoingOperation::None => FIRST_INDEX,
             OngoingOperation::PauseAll { addr_index } => addr_index,
-            OngoingOperation::ResumeAll { addr_index: _ } => {
-                sc_panic!("Resume operation in progress")
-            }
+            _ => sc_panic!(OTHER_OPERATION_ERR_MSG),
         }
     }
 
@@ -35,10 +45,32 @@ pub trait OngoingPauseOperationModule:
         let current_op: OngoingOperation = self.load_operation();
         match current_op {
             OngoingOperation::None => FIRST_INDEX,
-            OngoingOperation::PauseAll { addr_index: _ } => {
-                sc_panic!("Pause operation in progress")
-            }
             OngoingOperation::ResumeAll { addr_index } => addr_index,
+            _ => sc_panic!(OTHER_OPERATION_ERR_MSG),
+        }
+    }
+
+    fn load_pause_category_operation(&self, category: Category) -> usize {
+        let current_op: OngoingOperation = self.load_operation();
+        match current_op {
+            OngoingOperation::None => FIRST_INDEX,
+            OngoingOperation::PauseCategory {
+                category: op_category,
+                addr_index,
+            } if op_category == category => addr_index,
+            _ => sc_panic!(OTHER_OPERATION_ERR_MSG),
+        }
+    }
+
+    fn load_resume_category_operation(&self, category: Category) -> usize {
+        let current_op: OngoingOperation = self.load_operation();
+        match current_op {
+            OngoingOperation::None => FIRST_INDEX,
+            OngoingOperation::ResumeCategory {
+                category: op_category,
+                addr_index,
+            } if op_category == category => addr_index,
+            _ => sc_panic!(OTHER_OPERATION_ERR_MSG),
         }
     }
 }
@@ -5,8 +5,10 @@ use multiversx_sc::types::{Address, ManagedAddress, MultiValueEncoded, Operation
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id, rust_biguint, whitebox_legacy::*, DebugApi,
 };
-use pair::Pair;
+use pair::{config::ConfigModule, Pair};
 use pausable::{PausableModule, State};
+use pause_all::categories::{CategoriesModule, Category};
+use pause_all::guardians::GuardiansModule;
 use pause_all::*;
 
 static REWARD_TOKEN_ID: &[u8] = b"REWARD-123456";
@@ -18,27 +20,50 @@ static SECOND_TOKEN_ID: &[u8] = b"BEST-123456";
 static TOTAL_FEE_PERCENT: u64 = 50;
 static SPECIAL_FEE_PERCENT: u64 = 50;
 
-#[test]
-fn pause_all_test() {
+pub struct PauseAllSetup<PauseAllObjBuilder, FarmObjBuilder, PairObjBuilder>
+where
+    PauseAllObjBuilder: 'static + Copy + Fn() -> pause_all::ContractObj<DebugApi>,
+    FarmObjBuilder: 'static + Copy + Fn() -> farm::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub guardian_address: Address,
+    pub pause_sc: ContractObjWrapper<pause_all::ContractObj<DebugApi>, PauseAllObjBuilder>,
+    pub farm_sc: ContractObjWrapper<farm::ContractObj<DebugApi>, FarmObjBuilder>,
+    pub pair_sc: ContractObjWrapper<pair::ContractObj<DebugApi>, PairObjBuilder>,
+}
+
+fn setup_pause_all<PauseAllObjBuilder, FarmObjBuilder, PairObjBuilder>(
+    pause_all_builder: PauseAllObjBuilder,
+    farm_builder: FarmObjBuilder,
+    pair_builder: PairObjBuilder,
+) -> PauseAllSetup<PauseAllObjBuilder, FarmObjBuilder, PairObjBuilder>
+where
+    PauseAllObjBuilder: 'static + Copy + Fn() -> pause_all::ContractObj<DebugApi>,
+    FarmObjBuilder: 'static + Copy + Fn() -> farm::ContractObj<DebugApi>,
+    PairObjBuilder: 'static + Copy + Fn() -> pair::ContractObj<DebugApi>,
+{
     let rust_zero = rust_biguint!(0u64);
     let mut b_mock = BlockchainStateWrapper::new();
     let owner_address = b_mock.create_user_account(&rust_zero);
+    let guardian_address = b_mock.create_user_account(&rust_zero);
     let pause_sc = b_mock.create_sc_account(
         &rust_zero,
         Some(&owner_address),
-        pause_all::contract_obj,
+        pause_all_builder,
         "output/pause-all.wasm",
     );
     let farm_sc = b_mock.create_sc_account(
         &rust_zero,
         Some(&owner_address),
-        farm::contract_obj,
+        farm_builder,
         "output/farm.wasm",
     );
     let pair_sc = b_mock.create_sc_account(
         &rust_zero,
         Some(&owner_address),
-        pair::contract_obj,
+        pair_builder,
         "output/pair.wasm",
     );
 
@@ -98,43 +123,473 @@ fn pause_all_test() {
         })
         .assert_ok();
 
+    PauseAllSetup {
+        b_mock,
+        owner_address,
+        guardian_address,
+        pause_sc,
+        farm_sc,
+        pair_sc,
+    }
+}
+
+#[test]
+fn pause_all_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut setup = setup_pause_all(
+        pause_all::contract_obj,
+        farm::contract_obj,
+        pair::contract_obj,
+    );
+    let b_mock = &mut setup.b_mock;
+    let owner_address = setup.owner_address.clone();
+    let pause_sc = &setup.pause_sc;
+    let farm_sc = &setup.farm_sc;
+    let pair_sc = &setup.pair_sc;
+
     // resume farm and pair (initially paused)
     b_mock
-        .execute_tx(&owner_address, &pause_sc, &rust_zero, |sc| {
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
             let run_result = sc.resume_all();
             assert_eq!(run_result, OperationCompletionStatus::Completed);
         })
         .assert_ok();
 
     b_mock
-        .execute_query(&farm_sc, |sc| {
+        .execute_query(farm_sc, |sc| {
             assert_eq!(sc.state().get(), State::Active);
         })
         .assert_ok();
 
     b_mock
-        .execute_query(&pair_sc, |sc| {
+        .execute_query(pair_sc, |sc| {
             assert_eq!(sc.state().get(), State::Active);
         })
         .assert_ok();
 
     // pause all
     b_mock
-        .execute_tx(&owner_address, &pause_sc, &rust_zero, |sc| {
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
             let run_result = sc.pause_all();
             assert_eq!(run_result, OperationCompletionStatus::Completed);
         })
         .assert_ok();
 
     b_mock
-        .execute_query(&farm_sc, |sc| {
+        .execute_query(farm_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Inactive);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(pair_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Inactive);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn guardian_pause_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut setup = setup_pause_all(
+        pause_all::contract_obj,
+        farm::contract_obj,
+        pair::contract_obj,
+    );
+    let b_mock = &mut setup.b_mock;
+    let owner_address = setup.owner_address.clone();
+    let guardian_address = setup.guardian_address.clone();
+    let other_user_address = b_mock.create_user_account(&rust_zero);
+    let pause_sc = &setup.pause_sc;
+    let farm_sc = &setup.farm_sc;
+    let pair_sc = &setup.pair_sc;
+
+    // add guardian and categories, then resume all
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let mut guardians = MultiValueEncoded::new();
+            guardians.push(managed_address!(&guardian_address));
+            sc.add_guardians(guardians);
+
+            let mut farms = MultiValueEncoded::new();
+            farms.push(managed_address!(farm_sc.address_ref()));
+            sc.set_contracts_category(Category::Farms, farms);
+
+            let mut pairs = MultiValueEncoded::new();
+            pairs.push(managed_address!(pair_sc.address_ref()));
+            sc.set_contracts_category(Category::Pairs, pairs);
+
+            let run_result = sc.resume_all_with_reason(1);
+            assert_eq!(run_result, OperationCompletionStatus::Completed);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let _ = sc.pause_category(7, Category::Farms);
+        })
+        .assert_user_error("Guardian pause duration not set");
+
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            sc.set_guardian_pause_duration(10);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let _ = sc.pause_all_with_reason(7);
+        })
+        .assert_user_error("Only owner or guardians may call this endpoint");
+
+    // guardian pauses the farms category
+    b_mock.set_block_nonce(5);
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let run_result = sc.pause_category(7, Category::Farms);
+            assert_eq!(run_result, OperationCompletionStatus::Completed);
+            assert_eq!(
+                sc.pause_expiry_block(&managed_address!(farm_sc.address_ref()))
+                    .get(),
+                15
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(farm_sc, |sc| {
             assert_eq!(sc.state().get(), State::Inactive);
         })
         .assert_ok();
+    b_mock
+        .execute_query(pair_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Active);
+        })
+        .assert_ok();
 
+    // owner extends the pause
     b_mock
-        .execute_query(&pair_sc, |sc| {
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(farm_sc.address_ref()));
+            sc.extend_pause(8, 5, contracts);
+        })
+        .assert_ok();
+
+    // pause not expired yet
+    b_mock.set_block_nonce(15);
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(farm_sc.address_ref()));
+            sc.resume_expired(contracts);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(farm_sc, |sc| {
             assert_eq!(sc.state().get(), State::Inactive);
         })
         .assert_ok();
+
+    // anyone can resume after expiry
+    b_mock.set_block_nonce(20);
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(farm_sc.address_ref()));
+            sc.resume_expired(contracts);
+
+            assert!(sc
+                .pause_expiry_block(&managed_address!(farm_sc.address_ref()))
+                .is_empty());
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(farm_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Active);
+        })
+        .assert_ok();
+
+    // owner pauses never expire
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let run_result = sc.pause_category(9, Category::Pairs);
+            assert_eq!(run_result, OperationCompletionStatus::Completed);
+        })
+        .assert_ok();
+
+    b_mock.set_block_nonce(1_000);
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.resume_expired(contracts);
+
+            assert_eq!(
+                sc.pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                NO_EXPIRY_BLOCK
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(pair_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Inactive);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn guardian_pause_of_paused_contracts_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut setup = setup_pause_all(
+        pause_all::contract_obj,
+        farm::contract_obj,
+        pair::contract_obj,
+    );
+    let b_mock = &mut setup.b_mock;
+    let owner_address = setup.owner_address.clone();
+    let guardian_address = setup.guardian_address.clone();
+    let other_user_address = b_mock.create_user_account(&rust_zero);
+    let pause_sc = &setup.pause_sc;
+    let farm_sc = &setup.farm_sc;
+    let pair_sc = &setup.pair_sc;
+
+    // only the pair is resumed, the farm stays paused
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let mut guardians = MultiValueEncoded::new();
+            guardians.push(managed_address!(&guardian_address));
+            sc.add_guardians(guardians);
+            sc.set_guardian_pause_duration(10);
+
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.resume_selected(contracts);
+        })
+        .assert_ok();
+
+    // a guardian pause of an already paused contract does not expire
+    b_mock.set_block_nonce(5);
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(farm_sc.address_ref()));
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.pause_selected_with_reason(7, contracts);
+
+            assert!(sc
+                .pause_expiry_block(&managed_address!(farm_sc.address_ref()))
+                .is_empty());
+            assert_eq!(
+                sc.pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                15
+            );
+        })
+        .assert_ok();
+
+    // guardians cannot extend their own pauses
+    b_mock.set_block_nonce(10);
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.pause_selected_with_reason(7, contracts);
+
+            assert_eq!(
+                sc.pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                15
+            );
+        })
+        .assert_ok();
+
+    b_mock.set_block_nonce(15);
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(farm_sc.address_ref()));
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.resume_expired(contracts);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(farm_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Inactive);
+        })
+        .assert_ok();
+    b_mock
+        .execute_query(pair_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Active);
+        })
+        .assert_ok();
+
+    // the owner can turn a guardian pause into one that never expires
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.pause_selected_with_reason(7, contracts);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.pause_selected(contracts);
+
+            assert_eq!(
+                sc.pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                NO_EXPIRY_BLOCK
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn resume_expired_only_previously_active_contracts_test() {
+    let rust_zero = rust_biguint!(0u64);
+    let mut setup = setup_pause_all(
+        pause_all::contract_obj,
+        farm::contract_obj,
+        pair::contract_obj,
+    );
+    let b_mock = &mut setup.b_mock;
+    let owner_address = setup.owner_address.clone();
+    let guardian_address = setup.guardian_address.clone();
+    let other_user_address = b_mock.create_user_account(&rust_zero);
+    let pause_sc = &setup.pause_sc;
+    let farm_sc = &setup.farm_sc;
+    let pair_sc = &setup.pair_sc;
+
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let mut guardians = MultiValueEncoded::new();
+            guardians.push(managed_address!(&guardian_address));
+            sc.add_guardians(guardians);
+            sc.set_guardian_pause_duration(10);
+
+            let run_result = sc.resume_all();
+            assert_eq!(run_result, OperationCompletionStatus::Completed);
+        })
+        .assert_ok();
+
+    // the pair only allows liquidity operations
+    b_mock
+        .execute_tx(&owner_address, pair_sc, &rust_zero, |sc| {
+            sc.set_state_active_no_swaps();
+        })
+        .assert_ok();
+
+    b_mock.set_block_nonce(5);
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let run_result = sc.pause_all_with_reason(7);
+            assert_eq!(run_result, OperationCompletionStatus::Completed);
+
+            assert_eq!(
+                sc.pre_pause_state(&managed_address!(farm_sc.address_ref()))
+                    .get(),
+                State::Active
+            );
+            assert_eq!(
+                sc.pre_pause_state(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                State::PartialActive
+            );
+        })
+        .assert_ok();
+
+    // the partially active pair is left for the owner to resume
+    b_mock.set_block_nonce(15);
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(farm_sc.address_ref()));
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.resume_expired(contracts);
+
+            assert!(sc
+                .pause_expiry_block(&managed_address!(farm_sc.address_ref()))
+                .is_empty());
+            assert_eq!(
+                sc.pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                15
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(farm_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Active);
+        })
+        .assert_ok();
+    b_mock
+        .execute_query(pair_sc, |sc| {
+            assert_eq!(sc.state().get(), State::Inactive);
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_tx(&owner_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.resume_selected(contracts);
+
+            assert!(sc
+                .pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                .is_empty());
+            assert_eq!(
+                sc.pre_pause_state(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                State::Inactive
+            );
+        })
+        .assert_ok();
+
+    // the pair state is changed outside this contract during the pause
+    b_mock.set_block_nonce(20);
+    b_mock
+        .execute_tx(&guardian_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.pause_selected_with_reason(7, contracts);
+        })
+        .assert_ok();
+    b_mock
+        .execute_tx(&owner_address, pair_sc, &rust_zero, |sc| {
+            sc.set_state_active_no_swaps();
+        })
+        .assert_ok();
+
+    b_mock.set_block_nonce(30);
+    b_mock
+        .execute_tx(&other_user_address, pause_sc, &rust_zero, |sc| {
+            let mut contracts = MultiValueEncoded::new();
+            contracts.push(managed_address!(pair_sc.address_ref()));
+            sc.resume_expired(contracts);
+
+            assert!(sc
+                .pause_expiry_block(&managed_address!(pair_sc.address_ref()))
+                .is_empty());
+            assert_eq!(
+                sc.pre_pause_state(&managed_address!(pair_sc.address_ref()))
+                    .get(),
+                State::Inactive
+            );
+        })
+        .assert_ok();
+
+    b_mock
+        .execute_query(pair_sc, |sc| {
+            assert_eq!(sc.state().get(), State::PartialActive);
+        })
+        .assert_ok();
 }
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            7
+// Endpoints:                           26
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions:  29
 
 #![no_std]
 
@@ -23,10 +23,29 @@ multiversx_sc_wasm_adapter::endpoints! {
         addPausableContracts => add_pausable_contracts
         removePausableContracts => remove_pausable_contracts
         pauseSelected => pause_selected
+        pauseSelectedWithReason => pause_selected_with_reason
         pauseAll => pause_all
+        pauseAllWithReason => pause_all_with_reason
+        pauseCategory => pause_category
+        extendPause => extend_pause
         resumeSelected => resume_selected
+        resumeSelectedWithReason => resume_selected_with_reason
         resumeAll => resume_all
+        resumeAllWithReason => resume_all_with_reason
+        resumeCategory => resume_category
+        resumeExpired => resume_expired
         getPausableContracts => pausable_contracts
+        getPauseExpiryBlock => pause_expiry_block
+        getPrePauseState => pre_pause_state
+        addGuardians => add_guardians
+        removeGuardians => remove_guardians
+        setGuardianPauseDuration => set_guardian_pause_duration
+        getGuardians => guardians
+        getGuardianPauseDuration => guardian_pause_duration_blocks
+        setContractsCategory => set_contracts_category
+        removeContractsCategory => remove_contracts_category
+        getContractCategory => contract_category
+        getCategoryContracts => category_contracts
     )
 }
 